@@ -1,6 +1,25 @@
 from tincture import Color
+from ._appearance import *
+from ._array import *
 from ._blending import *
+from ._calibration import *
 from ._color import *
+from ._custom_space import *
+from ._frozen import *
+from ._gradient import *
+from ._lut import *
+from ._matrix import *
+from ._palette import *
+from ._parallel import *
+from ._picker import *
+from ._pool import *
+from ._random import *
+from ._reactive import *
+from ._scale import *
+from ._tinct import *
+from . import batch
+from . import brewer
+from . import colormaps
 
 WHITE: Color
 BLACK: Color
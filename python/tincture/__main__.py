@@ -0,0 +1,201 @@
+import argparse
+import sys
+from typing import List, Optional
+
+from tincture import Color, Gradient, Palette, colormaps
+
+CONVERSIONS = {
+    "hex": lambda color: color.to_hex(include_transparency=color.a != 255),
+    "rgba": lambda color: color.to_rgba_tuple(),
+    "decimal_rgba": lambda color: color.to_decimal_rgba(),
+    "hsl": lambda color: color.to_hsl(),
+    "hsv": lambda color: color.to_hsv(),
+    "cmyk": lambda color: color.to_cmyk(),
+    "xyz": lambda color: color.to_xyz(),
+    "oklab": lambda color: color.to_oklab(),
+    "lch": lambda color: color.to_lch(),
+}
+
+EXTRACTORS = {
+    "kmeans": lambda pixels, count, seed: Palette.kmeans(pixels, count, seed=seed),
+    "median_cut": lambda pixels, count, seed: Palette.from_colors_median_cut(pixels, count),
+    "octree": lambda pixels, count, seed: Palette.octree(pixels, count),
+}
+
+PALETTE_FORMATS = {
+    "hex": lambda palette: palette.to_hex_text(include_transparency=any(color.a != 255 for color in palette)),
+    "json": lambda palette: palette.to_json(indent=2) + "\n",
+    "gpl": lambda palette: palette.to_gpl(),
+}
+
+COLORMAPS = ["viridis", "magma", "inferno", "plasma", "cividis", "turbo"]
+
+
+def parse_color(value: str) -> Color:
+    """
+    Parses a color supplied on the command line, it can either be a hex string (with or without
+    the leading "#") or comma separated RGB(A) values such as "255,0,0" or "255,0,0,128"
+    """
+    try:
+        if "," in value:
+            channels = [int(channel) for channel in value.split(",")]
+            if len(channels) not in (3, 4):
+                raise ValueError("Expected 3 or 4 comma separated channels")
+            return Color(*channels)
+        return Color.from_hex(value)
+    except (ValueError, OverflowError) as error:
+        raise argparse.ArgumentTypeError(f"Invalid color \"{value}\": {error}")
+
+
+def positive_int(value: str) -> int:
+    try:
+        number = int(value)
+    except ValueError:
+        raise argparse.ArgumentTypeError(f"Invalid count \"{value}\"")
+    if number < 1:
+        raise argparse.ArgumentTypeError(f"Expected a count of at least 1 but got {number}")
+    return number
+
+
+def read_pixels(path: str, raw: bool) -> bytes:
+    """
+    Reads the pixels of an image as tightly packed RGBA bytes. Images are decoded through Pillow,
+    unless they are raw in which case the file has to hold the RGBA bytes already
+    """
+    if raw:
+        with open(path, "rb") as file:
+            return file.read()
+    try:
+        from PIL import Image
+    except ImportError:
+        raise ValueError("Decoding images requires Pillow, install it or pass --raw with RGBA pixel data")
+    with Image.open(path) as image:
+        return image.convert("RGBA").tobytes()
+
+
+def load_gradient(arguments: argparse.Namespace) -> Gradient:
+    """
+    Builds the gradient to sample out of exactly one of the sources, the colors on the command line,
+    a built-in colormap or a file in the text or JSON format of Gradient
+    """
+    sources = [bool(arguments.colors), arguments.colormap is not None, arguments.file is not None]
+    if sum(sources) != 1:
+        raise ValueError("Expected either colors, --colormap or --file as the gradient")
+    if arguments.colormap is not None:
+        return getattr(colormaps, arguments.colormap)
+    if arguments.file is not None:
+        with open(arguments.file) as file:
+            text = file.read()
+        return Gradient.from_json(text) if text.lstrip().startswith("{") else Gradient.from_text(text)
+    return Gradient.from_colors(arguments.colors, arguments.space, arguments.hue_strategy)
+
+
+def swatch(color: Color, width: int = 6) -> str:
+    return f"\x1b[48;2;{color.r};{color.g};{color.b}m{' ' * width}\x1b[0m"
+
+
+def convert(arguments: argparse.Namespace) -> int:
+    targets = list(CONVERSIONS) if arguments.to == "all" else [arguments.to]
+    for color in arguments.colors:
+        for target in targets:
+            result = CONVERSIONS[target](color)
+            print(f"{target}: {result}" if len(targets) > 1 else result)
+    return 0
+
+
+def contrast(arguments: argparse.Namespace) -> int:
+    luminance = sorted((arguments.foreground.get_luminance(), arguments.background.get_luminance()))
+    ratio = (luminance[1] + 0.05) / (luminance[0] + 0.05)
+    print(f"{ratio:.2f}:1")
+    print(f"AA (normal text): {'pass' if ratio >= 4.5 else 'fail'}")
+    print(f"AA (large text): {'pass' if ratio >= 3.0 else 'fail'}")
+    print(f"AAA (normal text): {'pass' if ratio >= 7.0 else 'fail'}")
+    return 0 if ratio >= arguments.minimum else 1
+
+
+def preview(arguments: argparse.Namespace) -> int:
+    for color in arguments.colors:
+        print(f"{swatch(color)} {color.to_hex(include_transparency=True)}  {color!r}")
+    return 0
+
+
+def palette_extract(arguments: argparse.Namespace) -> int:
+    try:
+        pixels = read_pixels(arguments.image, arguments.raw)
+        palette = EXTRACTORS[arguments.method](pixels, arguments.count, arguments.seed)
+    except (OSError, ValueError) as error:
+        print(f"error: {error}", file=sys.stderr)
+        return 2
+    print(PALETTE_FORMATS[arguments.format](palette), end="")
+    return 0
+
+
+def gradient_sample(arguments: argparse.Namespace) -> int:
+    try:
+        gradient = load_gradient(arguments)
+    except (OSError, ValueError) as error:
+        print(f"error: {error}", file=sys.stderr)
+        return 2
+    divisor = max(arguments.steps - 1, 1)
+    for step in range(arguments.steps):
+        color = gradient.sample(step / divisor)
+        result = CONVERSIONS[arguments.to](color)
+        print(f"{swatch(color)} {result}" if arguments.preview else result)
+    return 0
+
+
+def build_parser() -> argparse.ArgumentParser:
+    parser = argparse.ArgumentParser(prog="python -m tincture", description="Tincture color utilities")
+    subcommands = parser.add_subparsers(dest="command", required=True)
+
+    convert_parser = subcommands.add_parser("convert", help="Convert colors into other color spaces")
+    convert_parser.add_argument("colors", nargs="+", type=parse_color)
+    convert_parser.add_argument("--to", choices=[*CONVERSIONS, "all"], default="all")
+    convert_parser.set_defaults(handler=convert)
+
+    contrast_parser = subcommands.add_parser(
+        "contrast",
+        help="Compute the WCAG contrast ratio between two colors, exits with 1 when under --minimum"
+    )
+    contrast_parser.add_argument("foreground", type=parse_color)
+    contrast_parser.add_argument("background", type=parse_color)
+    contrast_parser.add_argument("--minimum", type=float, default=0.0)
+    contrast_parser.set_defaults(handler=contrast)
+
+    preview_parser = subcommands.add_parser("preview", help="Preview colors as truecolor swatches")
+    preview_parser.add_argument("colors", nargs="+", type=parse_color)
+    preview_parser.set_defaults(handler=preview)
+
+    palette_parser = subcommands.add_parser("palette", help="Work with palettes")
+    palette_commands = palette_parser.add_subparsers(dest="palette_command", required=True)
+    extract_parser = palette_commands.add_parser("extract", help="Extract the dominant colors of an image")
+    extract_parser.add_argument("image", help="The image to read, decoded through Pillow unless --raw is passed")
+    extract_parser.add_argument("--raw", action="store_true", help="Read the file as tightly packed RGBA bytes")
+    extract_parser.add_argument("--count", type=positive_int, default=8)
+    extract_parser.add_argument("--method", choices=list(EXTRACTORS), default="kmeans")
+    extract_parser.add_argument("--seed", type=int, default=None, help="Makes the kmeans method reproducible")
+    extract_parser.add_argument("--format", choices=list(PALETTE_FORMATS), default="hex")
+    extract_parser.set_defaults(handler=palette_extract)
+
+    gradient_parser = subcommands.add_parser("gradient", help="Work with gradients")
+    gradient_commands = gradient_parser.add_subparsers(dest="gradient_command", required=True)
+    sample_parser = gradient_commands.add_parser("sample", help="Sample evenly spaced colors from a gradient")
+    sample_parser.add_argument("colors", nargs="*", type=parse_color, help="Evenly spaced stops of the gradient")
+    sample_parser.add_argument("--colormap", choices=COLORMAPS, help="Sample one of the built-in colormaps")
+    sample_parser.add_argument("--file", help="A gradient in the text or JSON format of Gradient")
+    sample_parser.add_argument("--steps", type=positive_int, default=5)
+    sample_parser.add_argument("--space", default="oklab")
+    sample_parser.add_argument("--hue-strategy", default="shorter")
+    sample_parser.add_argument("--to", choices=list(CONVERSIONS), default="hex")
+    sample_parser.add_argument("--preview", action="store_true", help="Print a truecolor swatch before every sample")
+    sample_parser.set_defaults(handler=gradient_sample)
+    return parser
+
+
+def main(argv: Optional[List[str]] = None) -> int:
+    arguments = build_parser().parse_args(argv)
+    return arguments.handler(arguments)
+
+
+if __name__ == "__main__":
+    sys.exit(main())
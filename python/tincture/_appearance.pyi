@@ -0,0 +1,65 @@
+class ViewingConditions:
+    def __init__(
+        self,
+        adapting_luminance: float = 4.074366543152521,
+        background_luminance: float = 20.0,
+        surround: str = "average",
+        white: str | tuple[float, float] = "D65",
+        discounting: bool = False,
+    ) -> None:
+        """
+        ViewingConditions describes the environment a color is seen in, as the CAM16 color appearance model
+        understands it. The same color looks different on a phone under direct sunlight than on a monitor in a dark
+        room, so colors can be re-rendered from one set of conditions into another with ``Color.adapt_appearance``.
+        The defaults are the reference conditions of sRGB, a 64 lux dim office lighting a gray background
+
+        :param adapting_luminance: The luminance of the adapting field in cd/m², usually a fifth of the luminance of
+            white. By default 64 / pi * 0.2, which corresponds to 64 lux
+        :param background_luminance: The relative luminance of the background from (0.0, 100.0], by default 20.0
+            (a middle gray)
+        :param surround: How bright the area around the scene is, either "average" (a lit room or outdoors), "dim"
+            (television in a living room) or "dark" (a cinema or a display in a dark room). By default "average"
+        :param white: The white point the observer is adapted to, either one of the standard illuminants "A", "C",
+            "D50", "D55", "D65", "D75", "E", "F2", "F7" and "F11" or the (x, y) chromaticity of a custom one,
+            by default "D65"
+        :param discounting: Whenever the observer fully discounts the color of the illuminant, as happens for
+            surface colors under familiar lighting. By default False
+
+        :raises ValueError: If a luminance is out of range or the surround or white point is unknown
+        """
+        ...
+
+    @staticmethod
+    def dark_room() -> "ViewingConditions":
+        """A display in a dark room where the screen is the only light, with an adapting luminance of 20 cd/m²"""
+        ...
+
+    @staticmethod
+    def sunlight() -> "ViewingConditions":
+        """A display or print viewed outdoors under direct sunlight, with an adapting luminance of 2000 cd/m²"""
+        ...
+
+    @property
+    def adapting_luminance(self) -> float:
+        """The luminance of the adapting field in cd/m²"""
+        ...
+
+    @property
+    def background_luminance(self) -> float:
+        """The relative luminance of the background"""
+        ...
+
+    @property
+    def surround(self) -> str:
+        """The surround, either "average", "dim" or "dark\""""
+        ...
+
+    @property
+    def white(self) -> tuple[float, float, float]:
+        """The white point as XYZ values, with the luminance (Y) normalized to 1.0"""
+        ...
+
+    @property
+    def discounting(self) -> bool:
+        """Whenever the color of the illuminant is discounted"""
+        ...
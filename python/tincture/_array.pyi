@@ -0,0 +1,155 @@
+from typing import Iterator, overload
+
+from ._color import Color
+
+class ColorArray:
+    def __init__(self, colors_or_buffer: list[Color] | bytes | bytearray | memoryview | None = None) -> None:
+        """
+        ColorArray stores many colors inside one contiguous RGBA buffer (4 bytes per color) and offers elementwise
+        versions of the color math, which run in Rust without holding the GIL. This avoids the overhead of a Python
+        object per color when working with millions of colors at once. It supports indexing (including negative
+        indices), slicing, iteration and equality, slicing results in a new array
+
+        The array implements the buffer protocol, exposing its colors as a read-only buffer of unsigned bytes with
+        the shape (len, 4). This means ``numpy.asarray(array)`` and ``memoryview(array)`` view the colors without
+        copying them
+
+        :param colors_or_buffer: Either a list of colors or a buffer of tightly packed RGBA pixels, by default the
+            array starts out empty. Buffers may either hold bytes (such as ``uint8`` NumPy arrays) or 32-bit floats
+            from 0.0 to 1.0 (such as ``float32`` NumPy arrays), floats outside this range are clamped
+
+        :raises ValueError: If the buffer length is not a multiple of 4
+        """
+        ...
+
+    def __buffer__(self, flags: int, /) -> memoryview: ...
+
+    @staticmethod
+    def from_components(components: list[tuple[float, float, float, float]], space: str = "oklab") -> "ColorArray":
+        """
+        Creates an array from the components of the colors inside a color space, the reverse of [to_components].
+        Components outside the sRGB gamut are brought into it the same way as
+        :func:`Color.clamp_to_srgb() <tincture.Color.clamp_to_srgb>` does
+
+        :param components: The three components of every color inside the space followed by its alpha (0.0 to 1.0)
+        :param space: The color space the components belong to, by default "oklab"
+
+        :raises ValueError: If the color space is unknown
+        """
+        ...
+
+    def to_components(self, space: str = "oklab") -> list[tuple[float, float, float, float]]:
+        """
+        Converts every color into the color space, for example to analyze or plot the colors
+
+        :param space: The color space to convert into, by default "oklab"
+
+        :return: The three components of every color inside the space followed by its alpha (0.0 to 1.0)
+
+        :raises ValueError: If the color space is unknown
+        """
+        ...
+
+    def to_list(self) -> list[Color]:
+        """
+        :return: Every color of the array as a separate color object
+        """
+        ...
+
+    def to_bytes(self) -> bytes:
+        """
+        :return: The raw RGBA buffer of the array, 4 bytes per color
+        """
+        ...
+
+    def lerp(
+            self,
+            other: "Color | ColorArray",
+            t: float,
+            space: str = "srgb",
+            hue_strategy: str = "shorter"
+    ) -> "ColorArray":
+        """
+        Linearly interpolates every color towards either a single color or the color at the same position of
+        another array, the same way ``Color.lerp`` does for a single color
+
+        :param other: The color or the array (of the same length) to interpolate towards
+        :param t: The interpolation factor, 0.0 keeps the colors and 1.0 results in the other colors
+        :param space: The color space the interpolation happens in, by default "srgb"
+        :param hue_strategy: How hues are interpolated inside cylindrical color spaces, by default "shorter"
+
+        :raises ValueError: If the arrays differ in length, or the color space or hue strategy is unknown
+        """
+        ...
+
+    def delta_e(self, reference: Color, metric: str = "ciede2000") -> list[float]:
+        """
+        Measures the perceptual difference between every color and the reference color, alpha is ignored
+
+        :param reference: The color to compare against
+        :param metric: Either "ciede2000", "cie76" or "oklab" (the Oklab distance scaled by 100), by default
+            "ciede2000"
+
+        :raises ValueError: If the metric is unknown
+        """
+        ...
+
+    def apply_rules(self, rules: list[dict]) -> "ColorArray":
+        """
+        Recolors the array through declarative rules, such as
+        ``{"if": {"hue": [200, 260]}, "then": {"shift_hue": -30, "saturate": 0.2}}``. The rules are compiled once
+        and then run in Rust without holding the GIL. Every rule is tried on every color in order, so later rules
+        see the colors the earlier ones produced, and the actions of a rule run in the order they are listed
+
+        The conditions under "if" map a property to an inclusive range, a rule applies when all of them hold and
+        a rule without "if" applies to every color. The properties are the "hue" (HSL hue in degrees, a range
+        whose start lies past its end wraps around 360 so [330, 30] matches the reds), "saturation" and
+        "lightness" (of HSL, 0.0 to 1.0), "luminance" (the relative luminance, 0.0 to 1.0) and "alpha" (0.0 to 1.0)
+
+        The actions under "then" are "shift_hue" (degrees of HSL hue), "saturate" and "desaturate" (scale the HSV
+        saturation by one plus the amount, clamped to 0.0 to 1.0), "lighten" and "darken" (the same as
+        :func:`Color.lighten() <tincture.Color.lighten>`), "set_alpha" (0.0 to 1.0) and "replace" (a color or a hex
+        string)
+
+        :param rules: The rules to apply, in order
+
+        :return: A new array holding the recolored colors
+
+        :raises ValueError: If a rule has unknown keys, conditions or actions, has no "then" entry, or has a
+            range or amount that is not finite
+        """
+        ...
+
+    def __add__(self, other: "Color | ColorArray") -> "ColorArray":
+        """
+        Adds the RGB channels of a color (or of the colors of an equally long array) to every color, saturating
+        at 255. The alpha channels are kept as is
+        """
+        ...
+
+    def __sub__(self, other: "Color | ColorArray") -> "ColorArray":
+        """
+        Subtracts the RGB channels of a color (or of the colors of an equally long array) from every color,
+        saturating at 0. The alpha channels are kept as is
+        """
+        ...
+
+    def __mul__(self, factor: float | Color) -> "ColorArray":
+        """
+        Multiplies the RGB channels of every color either by a factor or channel-wise by a color, the same way
+        multiplying a single color does. The alpha channels are kept as is
+        """
+        ...
+
+    def __len__(self) -> int: ...
+    @overload
+    def __getitem__(self, index: int) -> Color: ...
+    @overload
+    def __getitem__(self, index: slice) -> "ColorArray": ...
+    def __setitem__(self, index: int, color: Color) -> None: ...
+    def __iter__(self) -> Iterator[Color]: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __repr__(self) -> str: ...
+    def __copy__(self) -> "ColorArray": ...
+    def __deepcopy__(self, memo: dict) -> "ColorArray": ...
+    def __reduce__(self) -> tuple: ...
@@ -0,0 +1,55 @@
+from ._color import Color
+
+class GrayRampReport:
+    """
+    The result of :func:`evaluate_gray_ramp() <tincture.evaluate_gray_ramp>`, it describes how far
+    a measured gray step wedge deviates from an ideal sRGB gray ramp
+    """
+
+    @property
+    def gamma(self) -> float:
+        """
+        The exponent of the power law that maps the step's input level to the measured relative luminance,
+        an ideal sRGB ramp results in roughly 2.2. If the ramp has no usable middle steps, it is NaN
+        """
+        ...
+
+    @property
+    def cast(self) -> tuple[float, float]:
+        """
+        The average (a*, b*) values of the measured steps in CIELAB, a neutral ramp has a cast close to (0, 0).
+        Positive a* is a magenta cast, negative a* is a green cast, positive b* is a yellow cast
+        and negative b* is a blue cast
+        """
+        ...
+
+    @property
+    def delta_e(self) -> list[float]:
+        """The CIE76 delta-E of every measured step against the ideal gray of that step"""
+        ...
+
+    @property
+    def average_delta_e(self) -> float:
+        """The average delta-E of all the measured steps"""
+        ...
+
+    @property
+    def max_delta_e(self) -> float:
+        """The largest delta-E out of all the measured steps"""
+        ...
+
+    def __repr__(self) -> str: ...
+
+def evaluate_gray_ramp(measured_colors: list[Color]) -> GrayRampReport:
+    """
+    Evaluates the measured colors of a gray step wedge, this is useful for basic display and camera calibration
+    workflows. The steps are assumed to be evenly spaced from black to white, so the first color is the measured
+    black patch while the last color is the measured white patch. Each step is compared against the ideal
+    sRGB gray of that step, the report contains the estimated gamma, the color cast and the per-step delta-E
+
+    :param measured_colors: The measured colors of the gray ramp, ordered from black to white. There has to be at
+    least 3 steps
+
+    :raises ValueError: If fewer than 3 colors are supplied
+    """
+    ...
@@ -1,11 +1,31 @@
+from typing import Iterator, Sequence, overload
+
+from ._appearance import ViewingConditions
 from ._blending import BlendingMode
+from ._frozen import FrozenColor
+
+# A value for each of the RGB channels, optionally followed by one for the alpha channel
+Channels = tuple[int, int, int] | tuple[int, int, int, int] | list[int]
+Factors = tuple[float, float, float] | tuple[float, float, float, float] | list[float]
 
 class Color:
+    __match_args__ = ("r", "g", "b", "a")
+
+    r: int
+    g: int
+    b: int
+    a: int
+
     def __init__(self, r: int, g: int, b: int, a: int = 255) -> None:
         """
         Color stores RGBA values that can be freely manipulated with all sorts of operation.
         These operations can change the color or can get specific values such as saturation
-        in the process. You can convert from any color space to RGBA or even from RGBA to another color space
+        in the process. You can convert from any color space to RGBA or even from RGBA to another color space.
+        Colors also behave like a sequence of their 4 channels, so ``r, g, b, a = color`` and ``tuple(color)``
+        work, which is handy for APIs that expect RGB(A) tuples such as Pillow, matplotlib or pygame. Slicing
+        works as well, ``color[:3]`` gives the ``[r, g, b]`` list and ``color[:3] = (10, 20, 30)`` sets them.
+        Colors can also be used in ``match`` statements, either positionally as ``case Color(255, 0, 0)`` or
+        by keyword as ``case Color(r=255, g=0)``
 
         :param r: The Red Component Of The Color.
         :param g: The Green Component Of The Color.
@@ -38,20 +58,21 @@ class Color:
         ...
 
     @staticmethod
-    def from_decimal_rgba(r: float, g: float, b: float) -> "Color":
+    def from_decimal_rgba(r: float, g: float, b: float, a: float) -> "Color":
         """
-        Construct a new color based on provided Decimal RGB values. These values
-        (R, G, B) are percentages that range from 0.0 to 1.0 (inclusion on both).
+        Construct a new color based on provided Decimal RGBA values. These values
+        (R, G, B, A) are percentages that range from 0.0 to 1.0 (inclusion on both).
         If the values do not meet the specified range, then the code will return an error
 
         :param r: The Red Component Of The Color As Percentage (from 0.0 to 1.0)
         :param g: The Green Component Of The Color As Percentage (from 0.0 to 1.0)
         :param b: The Blue Component Of The Color As Percentage (from 0.0 to 1.0)
+        :param a: The Alpha Component Of The Color As Percentage (from 0.0 to 1.0)
         """
         ...
 
     @staticmethod
-    def from_cmyk(c: float, y: float, m: float, k: float, transparency: bool) -> "Color":
+    def from_cmyk(c: float, m: float, y: float, k: float, transparency: float) -> "Color":
         """
         Construct a new color based on provided CMYK values. Where it
         is an acronym for **C**yan, Magenta, Yellow, and Key (Black).
@@ -59,30 +80,60 @@ class Color:
         since they are percentage values. Otherwise, the code will return an error
 
         :param c: The Cyan Component Of The CMYK Color As Percentage (from 0.0 to 1.0)
-        :param y: The Yellow Component Of The CMYK Color As Percentage (from 0.0 to 1.0)
         :param m: The Magenta Component Of The CMYK Color As Percentage (from 0.0 to 1.0)
+        :param y: The Yellow Component Of The CMYK Color As Percentage (from 0.0 to 1.0)
         :param k: The Key (also known as Black) Component Of The CMYK Color As Percentage (from 0.0 to 1.0)
         :param transparency : The transparency value that ranges from [0.0, 1.0]
         """
         ...
 
     @staticmethod
-    def from_xyz(x: float, y: float, z: float, transparency: float) -> "Color":
+    def from_xyz(
+        x: float, y: float, z: float, transparency: float = 1.0, illuminant: str | tuple[float, float] = "D65"
+    ) -> "Color":
         """
         Construct a new color based on provided XYZ values. The X value
         ranges from [0.0, 95.047], Y value ranges from [0.0, 100.0], and
         finally Z ranges from [0.0, 108.883]. If they have invalid ranges,
         then the code returns an error
 
+        XYZ values relative to another illuminant range up to its own white point scaled to 100, and are
+        brought over to the D65 white of sRGB with the Bradford transform
+
         :param x: The "X" Component Which Ranges [0.0, 95.047]
         :param y: The "Y" Component Which Ranges [0.0, 100.0]
         :param z: The "Z" Component Which Ranges [0.0, 108.883]
         :param transparency : The transparency value that ranges from [0.0, 1.0]
+        :param illuminant: The white point the values are relative to, either one of the standard illuminants
+            "A", "C", "D50", "D55", "D65", "D75", "E", "F2", "F7" and "F11" or the (x, y) chromaticity of a custom one
+
+        :raises ValueError: If a component is out of range or the illuminant is unknown
+        """
+        ...
+
+    @staticmethod
+    def from_lab(
+        l: float, a: float, b: float, transparency: float = 1.0, illuminant: str | tuple[float, float] = "D65"
+    ) -> "Color":
+        """
+        Construct a new color from CIELAB values, where "L" is the lightness from [0.0, 100.0] while "A" and "B"
+        are the green to red and blue to yellow axes. Colors outside of sRGB are brought into it by reducing
+        their chroma
+
+        :param l: The lightness component which ranges from [0.0, 100.0]
+        :param a: The green to red component
+        :param b: The blue to yellow component
+        :param transparency: The transparency value that ranges from [0.0, 1.0]
+        :param illuminant: The white point the values are relative to, either one of the standard illuminants
+            "A", "C", "D50", "D55", "D65", "D75", "E", "F2", "F7" and "F11" or the (x, y) chromaticity of a custom one.
+            Lab values of print and ICC profiles are usually relative to "D50"
+
+        :raises ValueError: If the transparency is out of range or the illuminant is unknown
         """
         ...
 
     @staticmethod
-    def from_hsv(h: int, s: float, v: float, transparency: float) -> "Color":
+    def from_hsv(h: int, s: float, v: float, transparency: float = 1.0) -> "Color":
         """
         Construct a new color based on provided CMYK values.
         Where it is an acronym for Hue, Saturation, and Value.
@@ -99,7 +150,7 @@ class Color:
         ...
 
     @staticmethod
-    def from_hsl(h: int, s: float, l: float, transparency: float) -> "Color":
+    def from_hsl(h: int, s: float, l: float, transparency: float = 1.0) -> "Color":
         """
         Construct a new color based on provided HSL values.
         Where it is an acronym for Hue, Saturation, and Lightness.
@@ -132,6 +183,16 @@ class Color:
         """
         ...
 
+    @staticmethod
+    def from_ansi256(index: int) -> "Color":
+        """
+        Creates the color of an entry of the xterm 256 color terminal palette, the first 16 entries use the
+        default xterm values of the basic colors
+
+        :param index: The index of the palette entry, from 0 to 255
+        """
+        ...
+
     @staticmethod
     def from_hex(hex_string: str) -> "Color":
         """
@@ -145,7 +206,61 @@ class Color:
         ...
 
     @staticmethod
-    def from_oklab(l: float, a: float, b: float, transparency: float) -> "Color":
+    def from_dict(values: dict[str, int]) -> "Color":
+        """
+        Construct a new color from a dict of its channels, the keys can either be the short names
+        ("r", "g", "b", "a") or the long names ("red", "green", "blue", "alpha") in any letter case.
+        The alpha channel is optional and defaults to fully opaque. This is the inverse of [to_dict]
+
+        :param values: The channels of the color, each in range [0, 255]
+        :raises ValueError: If a key is not a channel name, a channel is given twice or the red, green or blue
+        channel is missing
+        """
+        ...
+
+    @staticmethod
+    def from_json(text: str) -> "Color":
+        """
+        Construct a new color from the JSON produced by [to_json], a JSON string holding a hex color
+        such as ``"#ff0000"`` is accepted as well
+
+        :param text: The JSON text
+        :raises ValueError: If the JSON is invalid or does not describe a color
+        """
+        ...
+
+    @staticmethod
+    def parse_hex_many(hex_strings: list[str] | str) -> list["Color"]:
+        """
+        Parses many hex strings at once without holding the GIL, which avoids the overhead of calling
+        [from_hex] once per color, for example when importing a CSV column of hex colors. The strings
+        follow the same rules as [from_hex], surrounding whitespace is ignored
+
+        :param hex_strings: Either a list of hex strings or a text with one hex string per line, in which
+            case empty lines are skipped
+
+        :raises ValueError: If any of the hex strings is invalid, the message contains the string and its index
+        """
+        ...
+
+    @staticmethod
+    def format_hex_many(
+            colors: list["Color"] | bytes | bytearray | memoryview,
+            include_transparency: bool = False
+    ) -> list[str]:
+        """
+        Formats many colors into hex strings at once without holding the GIL, the reverse of [parse_hex_many].
+        Works the same way as calling [to_hex] on every color
+
+        :param colors: Either a list of colors or a buffer of tightly packed RGBA pixels, such as a ColorArray
+        :param include_transparency: Whenever to include the transparent part on the hex strings, by default False
+
+        :raises ValueError: If the buffer length is not a multiple of 4
+        """
+        ...
+
+    @staticmethod
+    def from_oklab(l: float, a: float, b: float, transparency: float = 1.0) -> "Color":
         """
         Construct a new color based on provided Oklab values. Where it
         "l" ranges from [0.0, 1.0] as well as "a" and b ranges from [-0.5, 0.5]
@@ -158,7 +273,7 @@ class Color:
         ...
 
     @staticmethod
-    def mlerp(start: "Color", end: "Color", t: float) -> "Color":
+    def mlerp(start: "Color", end: "Color", t: float, linear: bool = False) -> "Color":
         """
         Construct a new color based on a mathematical lerp(linear interpolation). Given a
         starting color, an ending color and a **t** value which is a percentage value. It creates
@@ -173,20 +288,19 @@ class Color:
         :param start: The beginning color that will be used in the operation.
         :param end: The ending color that will be used
         :param t: A "t" value that is a percentage and is used to produce the intermediate color
+        :param linear: Interpolates in linear sRGB (linear light) and re-encodes the result afterward,
+        which avoids the muddy midpoints of gamma-space interpolation. By default, it is set to be false
         """
 
     @staticmethod
-    def clerp(start: Color, end: Color, t: float) -> Color:
+    def clerp(start: Color, end: Color, t: float, hue_strategy: str = "shorter") -> Color:
         """
         Construct a new color based on a more accurate color lerp model but a bit heavier to compute.
         Given a starting color, an ending color and a **t** value which is a percentage value. It creates
         the color half-way. If the t value is out of range, then it throws an error
 
-        NOTE: As of now, there are major miscalculations that as of the time writing.
-        It should NOT be used in production use until otherwise fixed. We want to fix this but it will take time
-
         **Important Note:** This differs from [mlerp] which interpolates the 3 RGB channels.
-        The operation uses the LCH color space which can be.a tiny bit heavier on the computer
+        The operation uses the OKLCH color space which can be a tiny bit heavier on the computer
 
         Note: There is a function that does this in place as opposed to generating a new color value.
         The function is called [clerp_inplace]
@@ -194,31 +308,279 @@ class Color:
         :param start: The beginning color that will be used in the operation.
         :param end: The ending color that will be used
         :param t: A "t" value that is a percentage and is used to produce the intermediate color
+        :param hue_strategy: Which way around the color wheel the hue is interpolated, it can be "shorter",
+        "longer", "increasing" or "decreasing" (same as CSS Color 4). By default, it is set to "shorter"
+        """
+        ...
+
+    @staticmethod
+    def average(colors: list["Color"], weights: list[float] | None = None, space: str = "oklab") -> "Color":
+        """
+        Computes the (weighted) average of any amount of colors inside the supplied color space. Unlike chaining
+        [mix] calls pairwise, all colors are accumulated at once so no precision is lost in between. Hues of the
+        cylindrical color spaces are averaged as angles, so the average of 350 and 10 degrees is 0 degrees
+
+        Supported color spaces are the same as in [mix]. If the weights are supplied, they have to be as many as
+        the colors, finite, none of them can be negative and their sum has to be above zero, otherwise it throws an
+        error
+
+        :param colors: The colors to average, there has to be at least one color
+        :param weights: The weight of every color, by default every color has the same weight
+        :param space: The color space the averaging happens in, by default "oklab"
+        """
+        ...
+
+    @staticmethod
+    def bezier(colors: list["Color"], t: float, space: str = "oklab") -> "Color":
+        """
+        Interpolates along a Bézier curve that is defined by the supplied control colors, much like chroma.js's
+        bezier(). The curve starts at the first color and ends at the last color, while the colors in between pull
+        the curve towards them without it passing through them. This produces much smoother multi-hue scales than
+        linearly interpolating between the stops. If t is out of range, then it throws an error
+
+        Supported color spaces are the same as in [mix], hues of the cylindrical color spaces take the shorter path
+
+        :param colors: The control colors of the curve, there has to be at least one color
+        :param t: The position on the curve which ranges from [0.0, 1.0]
+        :param space: The color space the curve is evaluated in, by default "oklab"
+        """
+        ...
+
+    @staticmethod
+    def is_in_gamut(components: tuple[float, float, float], space: str = "oklch", gamut: str = "srgb") -> bool:
+        """
+        Checks whenever the components of a color space describe a color inside an RGB gamut, such as a color
+        picked in "oklch" or taken from a "display_p3" image. This way the caller can decide between clipping
+        the color and mapping it perceptually with :func:`clamp_to_srgb() <tincture.Color.clamp_to_srgb>`,
+        or keep it for a wide gamut display. A tiny tolerance is allowed for the rounding errors of the conversion
+
+        Note: There is no ``in_gamut_of()`` method on colors, since a [Color] stores 8-bit sRGB channels and so
+        always lies inside all three gamuts. Colors converted from a wide space are already mapped into sRGB,
+        check their components with [is_in_gamut] before converting them instead
+
+        :param components: The three components of the color in the color space
+        :param space: The color space the components belong to, by default "oklch"
+        :param gamut: The gamut to check against, either "srgb", "display_p3" or "rec2020". By default "srgb"
+
+        :raises ValueError: If the color space or the gamut is unknown
+        """
+        ...
+
+    @staticmethod
+    def clamp_to_srgb(
+            components: tuple[float, float, float],
+            space: str = "oklch",
+            method: str = "oklch_chroma",
+            transparency: float = 1.0
+    ) -> "Color":
+        """
+        Converts the components of a color space into a color, bringing the ones that lie outside the sRGB gamut
+        back into it. There are two methods of doing so:
+
+        - "oklch_chroma" reduces the OKLCH chroma at a constant lightness and hue until the color fits, the same way
+          as CSS Color 4 maps colors into a gamut. The color keeps its hue and lightness, only getting less vivid
+        - "clip" clips every RGB channel on its own, which is cheaper but shifts the hue of the color
+
+        The conversions out of the spaces reaching beyond the sRGB gamut ("oklab", "oklch", "lab", "lch", "xyz",
+        "display_p3" and "rec2020"), such as mixing, gradients and the batch conversions, always use "oklch_chroma"
+        while the other spaces clip
+
+        :param components: The three components of the color in the color space
+        :param space: The color space the components belong to, by default "oklch"
+        :param method: The gamut mapping method, either "oklch_chroma" or "clip". By default "oklch_chroma"
+        :param transparency: The alpha of the color from 0.0 to 1.0, by default 1.0
+
+        :raises ValueError: If the color space or the method is unknown, or the transparency is not within [0.0, 1.0]
+        """
+        ...
+
+    def mix(self, other: "Color", weight: float = 0.5, space: str = "oklab", hue: str = "shorter") -> "Color":
+        """
+        Mixes this color with another color inside the supplied color space, mirroring CSS's color-mix().
+        The weight is a percentage value describing how much of the other color ends up in the result, so
+        0.0 returns this color and 1.0 returns the other color. If the weight is out of range, then it throws an error
+
+        Supported color spaces are "srgb", "linear" (linear sRGB), "oklab", "oklch", "hsv", "hsl", "lab", "lch",
+        "xyz", "display_p3" and "rec2020", along with the names of the registered ``ColorSpace`` definitions. For the
+        cylindrical spaces (oklch, hsv, hsl, lch) the hue is interpolated based on the hue strategy, which can be
+        "shorter", "longer", "increasing" or "decreasing"
+
+        Note: [mlerp] is the same as mixing in the "srgb" color space
+
+        :param other: The other color that will be mixed in
+        :param weight: A percentage value describing how much of the other color is used, by default 0.5
+        :param space: The color space the mixing operation happens in, by default "oklab"
+        :param hue: The hue interpolation strategy for cylindrical color spaces, by default "shorter"
+        """
+        ...
+
+    def tint_with_white(self, amount: float, space: str = "srgb") -> "Color":
+        """
+        Tints the color by mixing it with white, the same as Sass's ``tint()`` and Bootstrap's ``tint-color()``.
+        Unlike :func:`tint() <tincture.Color.tint>`, which rotates the hue, the hue stays while the color gets lighter
+        and paler. The alpha channel is left unchanged
+
+        :param amount: How much white ends up in the result from [0.0, 1.0], 0.0 returns the color unchanged
+        :param space: The color space the mixing happens in, any space accepted by
+            :func:`mix() <tincture.Color.mix>`. By default "srgb"
+
+        :raises ValueError: If the amount is out of range or the space is unknown
+        """
+        ...
+
+    def shade_with_black(self, amount: float, space: str = "srgb") -> "Color":
+        """
+        Shades the color by mixing it with black, the same as Sass's ``shade()`` and Bootstrap's ``shade-color()``.
+        The alpha channel is left unchanged
+
+        :param amount: How much black ends up in the result from [0.0, 1.0], 0.0 returns the color unchanged
+        :param space: The color space the mixing happens in, any space accepted by
+            :func:`mix() <tincture.Color.mix>`. By default "srgb"
+
+        :raises ValueError: If the amount is out of range or the space is unknown
+        """
+        ...
+
+    def tone_with_gray(self, amount: float, space: str = "srgb") -> "Color":
+        """
+        Tones the color by mixing it with middle gray (128, 128, 128), which mutes it while pulling its lightness
+        toward the middle. The alpha channel is left unchanged
+
+        :param amount: How much gray ends up in the result from [0.0, 1.0], 0.0 returns the color unchanged
+        :param space: The color space the mixing happens in, any space accepted by
+            :func:`mix() <tincture.Color.mix>`. By default "srgb"
+
+        :raises ValueError: If the amount is out of range or the space is unknown
+        """
+        ...
+
+    @staticmethod
+    def from_heat(value: float, min: float = 0.0, max: float = 1.0, scheme: str = "turbo") -> "Color":
+        """
+        Maps a scalar onto a color of a heat scheme without constructing a gradient first, which is handy for quick
+        instrumentation and debug overlays. The value is placed inside the range from min to max, values outside of
+        it get the color of the closest end. The schemes are:
+
+        - "viridis", "magma", "inferno", "plasma", "cividis" and "turbo", the same as the gradients of
+          :mod:`tincture.colormaps`
+        - "blackbody" goes from black over red and yellow to white, like the "hot" colormap of matplotlib
+
+        :param value: The value to map onto a color
+        :param min: The value that maps onto the start of the scheme, by default 0.0
+        :param max: The value that maps onto the end of the scheme, by default 1.0
+        :param scheme: The name of the scheme, by default "turbo"
+
+        :raises ValueError: If the value is NaN, min is not below max or the scheme is unknown
+        """
+        ...
+
+    @staticmethod
+    def duotone(value: float, dark: "Color", light: "Color", mid: "Color | None" = None, space: str = "oklab") -> "Color":
+        """
+        Maps a grayscale value through two anchor colors, where 0.0 results in the dark color and 1.0 in the light
+        color. Supplying a midtone color turns the duotone into a tritone, with the midtone at 0.5. This is the
+        classic effect of printing an image with two inks, see :func:`batch.duotone() <tincture.batch.duotone>` for
+        applying it onto whole images and :func:`Gradient.duotone() <tincture.Gradient.duotone>` for the gradient
+        behind it
+
+        :param value: The grayscale value from [0.0, 1.0]
+        :param dark: The color of the shadows
+        :param light: The color of the highlights
+        :param mid: The color of the midtones, by default there is none
+        :param space: The color space the anchors are interpolated in, by default "oklab"
+
+        :raises ValueError: If the value is out of range or the space is unknown
+        """
+        ...
+
+    def neutralize(self, amount: float) -> "Color":
+        """
+        Neutralizes the color by blending it toward the gray of the same relative luminance, rather than toward
+        middle gray like :func:`tone_with_gray() <tincture.Color.tone_with_gray>` does. This mutes accent colors
+        while the contrast ratios against other colors stay the same. The blending happens in linear light so the
+        luminance is kept along the way. The alpha channel is left unchanged
+
+        :param amount: How far the color moves toward the gray from [0.0, 1.0], 0.0 returns the color unchanged
+
+        :raises ValueError: If the amount is out of range
+        """
+        ...
+
+    @staticmethod
+    def lerp(start: "Color", end: "Color", t: float, space: str = "srgb", hue_strategy: str = "shorter") -> "Color":
+        """
+        Construct a new color based on a linear interpolation inside the supplied color space. Given a
+        starting color, an ending color and a **t** value which is a percentage value. It creates
+        the color half-way. If the t value is out of range, then it throws an error
+
+        Different color spaces produce very different gradients, "srgb" interpolates the 3 RGB channels
+        just like [mlerp], "linear" interpolates in linear light, "oklab" and "lab" are perceptual while
+        "oklch", "lch", "hsv" and "hsl" interpolate the hue around the color wheel based on the hue strategy
+
+        :param start: The beginning color that will be used in the operation.
+        :param end: The ending color that will be used
+        :param t: A "t" value that is a percentage and is used to produce the intermediate color
+        :param space: The color space the interpolation happens in, by default "srgb"
+        :param hue_strategy: Which way around the color wheel the hue is interpolated for cylindrical color
+        spaces, it can be "shorter", "longer", "increasing" or "decreasing" (same as CSS Color 4).
+        By default, it is set to "shorter"
+        """
+        ...
+
+    @staticmethod
+    def bilerp(
+            c00: "Color",
+            c10: "Color",
+            c01: "Color",
+            c11: "Color",
+            u: float,
+            v: float,
+            space: str = "srgb",
+            hue_strategy: str = "shorter"
+    ) -> "Color":
+        """
+        Construct a new color based on a bilinear interpolation between four corner colors inside the supplied
+        color space, which is useful for 2D gradient fills and mesh shading. The u value moves horizontally from
+        the left corners (c00, c01) to the right corners (c10, c11) while the v value moves vertically from the
+        top corners (c00, c10) to the bottom corners (c01, c11). If u or v is out of range, then it throws an error
+
+        Supported color spaces and hue strategies are the same as in [lerp]
+
+        :param c00: The top left corner color
+        :param c10: The top right corner color
+        :param c01: The bottom left corner color
+        :param c11: The bottom right corner color
+        :param u: The horizontal percentage value which ranges from [0.0, 1.0]
+        :param v: The vertical percentage value which ranges from [0.0, 1.0]
+        :param space: The color space the interpolation happens in, by default "srgb"
+        :param hue_strategy: Which way around the color wheel the hue is interpolated for cylindrical color
+        spaces, by default "shorter"
         """
         ...
+
     @staticmethod
-    def blend(blend_mode: BlendingMode, *args: tuple[Color]) -> Color:
+    def blend(blend_mode: BlendingMode, *args: tuple[Color], linear: bool = False) -> Color:
         """
         Blends multiple colors together with a blending operation. For blending modes,
         you should look into the BlendingMode class. There should be a minimum of two Colors,
         if there are more than two, then the operation will pick the first color and second color
         to blend with. Afterward, it will pick the blended result with the third color and so on
         until all the supplied colors are used in blending
+
+        :param linear: Decodes the colors to linear sRGB (linear light) before blending and re-encodes
+        the blended result afterward. By default, it is set to be false
         """
         ...
 
 
-    def clerp_inplace(self, end: "Color", t: float) -> None:
+    def clerp_inplace(self, end: "Color", t: float, hue_strategy: str = "shorter") -> None:
         """
         Perform a more accurate color lerp version of mlerp operation on this specific color and
         modify the RGB channels. It needs an ending color and a **t** value, which is a percentage value. It creates
         the color half-way. If the t value is out of range, then it throws an error
 
-        NOTE: As of now, there are major miscalculations that as of the time writing.
-        It should NOT be used in production use until otherwise fixed. We want to fix this but it will take time
-
         **Important Note:** This differs from [mlerp] which interpolates the 3 RGB channels.
-        The operation uses the LCH color space which might involve a tiny bit of more computation to lerp
+        The operation uses the OKLCH color space which might involve a tiny bit of more computation to lerp
         as opposed to [mlerp] but produces a more pleasing result at the end
 
         Note: There is a function that generates a new color value as opposed to perform in place.
@@ -226,10 +588,12 @@ class Color:
 
         :param end: The ending color that will be used
         :param t: A "t" value that is a percentage and is used to produce the intermediate color
+        :param hue_strategy: Which way around the color wheel the hue is interpolated, it can be "shorter",
+        "longer", "increasing" or "decreasing" (same as CSS Color 4). By default, it is set to "shorter"
         """
         ...
 
-    def mlerp_inplace(self, end: Color, t: float) -> None:
+    def mlerp_inplace(self, end: Color, t: float, linear: bool = False) -> None:
         """
         Perform an RGB color lerp operation on this specific color and modify the RGB channels.
         It needs an ending color and a **t** value, which is a percentage value. It creates
@@ -243,36 +607,46 @@ class Color:
 
         :param end: The ending color that will be used
         :param t: A "t" value that is a percentage and is used to produce the intermediate color
+        :param linear: Interpolates in linear sRGB (linear light) and re-encodes the result afterward.
+        By default, it is set to be false
         """
         ...
 
-    def add(self, other: "Color", include_transparency: bool = False) -> "Color":
+    def add(self, other: "Color | int | Channels", include_transparency: bool = False, linear: bool = False) -> "Color":
         """
         Performs an addition operation between this color and the other color, then it returns
         a new color value. The RGB values are maxed to 255, for subtraction it is recommended
         to use the dedicated function called [Self::sub] since the RGB values cannot below 0.
-        If you want, you can opt in to include the alpha channel as well
+        If you want, you can opt in to include the alpha channel as well. Instead of a color, a tuple
+        or list of 3 or 4 per-channel amounts can be added, these may also be negative
 
         :param other : The other color for the subtraction operation
         :param include_transparency : Performs the operation in addition to the alpha channel when set to true;
         By default, it is set to be false
+        :param linear : Performs the addition in linear sRGB (linear light) and re-encodes the result afterward.
+        The alpha channel is never gamma-encoded so it is unaffected. By default, it is set to be false
+
+        :raises ValueError: If a tuple or list does not hold 3 or 4 amounts
         """
         ...
 
-    def sub(self, other: "Color", include_transparency: bool = False) -> "Color":
+    def sub(self, other: "Color | int | Channels", include_transparency: bool = False) -> "Color":
         """
         Performs a subtraction operation between this color and the other color, then it returns
         a new color value. The RGB values are minimized to 0, for adding; it is recommended
         to use the dedicated function called [Self::add] since the RGB values cannot below 0.
-        If you want, you can opt in to include the alpha channel as well
+        If you want, you can opt in to include the alpha channel as well. Like [add], a tuple or
+        list of 3 or 4 per-channel amounts can be subtracted instead of a color
 
         :param other : The other color for the subtraction operation
         :param include_transparency : Performs the operation in addition to the alpha channel when set to true;
         By default, it is set to be false
+
+        :raises ValueError: If a tuple or list does not hold 3 or 4 amounts
         """
         ...
 
-    def mul(self, scalar: float,  include_transparency: bool = False) -> "Color":
+    def mul(self, scalar: float,  include_transparency: bool = False, linear: bool = False) -> "Color":
         """
         Performs a multiplication operation between this color and a scalar value, then it returns
         a new color value. The RGB values are clamped to the range of 0.0 and 255.0 (including both),
@@ -282,6 +656,8 @@ class Color:
         :param scalar : The scalar value for the multiplication operation
         :param include_transparency : Performs the operation in addition to the alpha channel when set to true;
         By default, it is set to be false
+        :param linear : Performs the multiplication in linear sRGB (linear light) and re-encodes the result
+        afterward. The alpha channel is never gamma-encoded so it is unaffected. By default, it is set to be false
         """
         ...
 
@@ -299,6 +675,62 @@ class Color:
         """
         ...
 
+    def shift(self, places: int) -> "Color":
+        """
+        Rotates the RGBA channels by the given amount of places and returns a new color, so shifting by
+        one place turns (R, G, B, A) into (A, R, G, B). This is what the ``>>`` and ``<<`` operators use
+
+        :param places: The amount of places to rotate the channels by
+        """
+        ...
+
+    def add_inplace(self, other: "Color", include_transparency: bool = False, linear: bool = False) -> None:
+        """
+        Performs the same addition as [add] but writes the result into this color instead of returning
+        a new one. This is also what ``color += other`` does, with the alpha channel included
+
+        :param other : The other color for the addition operation
+        :param include_transparency : Performs the operation in addition to the alpha channel when set to true;
+        By default, it is set to be false
+        :param linear : Performs the addition in linear sRGB (linear light). By default, it is set to be false
+        """
+        ...
+
+    def sub_inplace(self, other: "Color", include_transparency: bool = False) -> None:
+        """
+        Performs the same subtraction as [sub] but writes the result into this color instead of returning
+        a new one. This is also what ``color -= other`` does, with the alpha channel included
+
+        :param other : The other color for the subtraction operation
+        :param include_transparency : Performs the operation in addition to the alpha channel when set to true;
+        By default, it is set to be false
+        """
+        ...
+
+    def mul_inplace(self, scalar: float, include_transparency: bool = False, linear: bool = False) -> None:
+        """
+        Performs the same multiplication as [mul] but writes the result into this color instead of returning
+        a new one. This is also what ``color *= scalar`` does, with the alpha channel included
+
+        :param scalar : The scalar value for the multiplication operation
+        :param include_transparency : Performs the operation in addition to the alpha channel when set to true;
+        By default, it is set to be false
+        :param linear : Performs the multiplication in linear sRGB (linear light). By default, it is set to be false
+        """
+        ...
+
+    def div_inplace(self, scalar: float, include_transparency: bool = False) -> None:
+        """
+        Performs the same division as [div] but writes the result into this color instead of returning
+        a new one. This is also what ``color /= scalar`` does, with the alpha channel included. If the
+        scalar value is zero, then it throws an error and the color is left unchanged
+
+        :param scalar : The scalar value for the division operation
+        :param include_transparency : Performs the operation in addition to the alpha channel when set to true;
+        By default, it is set to be false
+        """
+        ...
+
     def tensor(self, other: "Color", include_transparency: bool = False) -> "Color":
         """
         Performs a tensor operation between this color and another color, then it returns
@@ -359,10 +791,174 @@ class Color:
         """
         ...
 
+    def swizzle(self, pattern: str) -> "Color":
+        """
+        Rearranges the channels of the color according to the pattern, where every letter names the channel that
+        the red, green, blue and alpha channel of the new color are taken from. Channels may be repeated or left out,
+        "0" and "1" stand for a channel at 0 and at 255. For example "bgra" swaps the red and blue channels (fixing
+        colors read in the wrong byte order) while "rrr1" builds an opaque gray mask out of the red channel. A
+        pattern of only 3 letters keeps the alpha channel as it is. The letters are case-insensitive
+
+        :param pattern: The 3 or 4 letters out of "r", "g", "b", "a", "0" and "1"
+
+        :raises ValueError: If the pattern does not have 3 or 4 letters or contains an unknown one
+        """
+        ...
+
     def grayscale(self) -> "Color":
         """ Performs a grayscale operation. This basically grayscales the color """
         ...
 
+    def exposure(self, stops: float) -> "Color":
+        """
+        Adjusts the exposure of the color by photographic stops, where every stop doubles (or for negative
+        stops halves) the light. Unlike :func:`brightness() <tincture.Color.brightness>` the channels are
+        multiplied in linear light, channels that end up above the sRGB gamut are clipped. The alpha
+        channel is left unchanged
+
+        :param stops: The number of stops to adjust the exposure by, may be negative or fractional
+
+        :raises ValueError: If the number of stops is NaN or infinite
+        """
+        ...
+
+    def gamma(self, gamma: float) -> "Color":
+        """
+        Performs a gamma adjustment, where every RGB channel is scaled to [0.0, 1.0] and raised to the power of the
+        gamma. Values above 1.0 darken the midtones while values below 1.0 brighten them, black and white stay
+        the same. The alpha channel is left unchanged
+
+        :param gamma: The exponent to raise the channels to
+
+        :raises ValueError: If the gamma is not a positive number
+        """
+        ...
+
+    def levels(
+            self,
+            in_black: int,
+            in_white: int,
+            gamma: float = 1.0,
+            out_black: int = 0,
+            out_white: int = 255,
+            luminance: bool = False
+    ) -> "Color":
+        """
+        Performs a levels adjustment the same way as Photoshop's Levels, where the input range from the black point to
+        the white point is stretched onto the output range. Values outside the input range are clipped to its ends,
+        while the gamma bends the midtones in between: values above 1.0 brighten them and values below 1.0 darken
+        them. Swapping the output black and white points inverts the color. The alpha channel is left unchanged
+
+        :param in_black: The input value that turns into the output black point
+        :param in_white: The input value that turns into the output white point
+        :param gamma: The midtone gamma, by default 1.0 (linear)
+        :param out_black: The darkest output value, by default 0
+        :param out_white: The brightest output value, by default 255
+        :param luminance: Whenever to adjust only the gray value, the sRGB gray with the same relative luminance as
+        :func:`get_luminance() <tincture.Color.get_luminance>` computes, and scale all linear channels by the same ratio,
+        which keeps the hue. Otherwise, every RGB channel is adjusted on its own. By default, it is set to False
+
+        :raises ValueError: If the input black point is not below the input white point or the gamma is not a
+        positive number
+        """
+        ...
+
+    def adapt(
+        self,
+        src_white: str | tuple[float, float] = "D65",
+        dst_white: str | tuple[float, float] = "D50",
+        method: str = "bradford",
+    ) -> "Color":
+        """
+        Performs a chromatic adaptation, which gives the color that looks under the destination white the way this
+        color looks under the source white. The color is converted into XYZ, where its cone responses are scaled by
+        the ratio of both white points, and back into sRGB. Channels that end up outside the sRGB gamut are clipped,
+        while the alpha channel is left unchanged
+
+        The white points are the standard illuminants "A", "C", "D50", "D55", "D65", "D75", "E", "F2", "F7" and "F11"
+        (case-insensitive), or the (x, y) chromaticity of a custom white point. The method picks the cone response space the scaling happens in:
+
+        - "bradford" is the one used by ICC profiles and most color management systems
+        - "cat16" is the one of the CAM16 color appearance model
+        - "von_kries" uses the Hunt-Pointer-Estevez cone responses
+        - "xyz_scaling" scales the XYZ values directly, the crudest of all
+
+        :param src_white: The illuminant the color is currently seen under, by default "D65" (the white of sRGB)
+        :param dst_white: The illuminant to adapt the color to, by default "D50" (the white of print and ICC profiles)
+        :param method: The chromatic adaptation transform, by default "bradford"
+
+        :raises ValueError: If an illuminant or the method is unknown
+        """
+        ...
+
+    def adapt_appearance(self, from_conditions: ViewingConditions, to_conditions: ViewingConditions) -> "Color":
+        """
+        Re-renders the color for other viewing conditions using the CAM16 color appearance model, giving the color
+        that looks under the new conditions the way this color looked under the original ones. For instance a UI
+        color picked on a monitor in a dark room needs to be lighter and more saturated to look the same on a phone
+        under sunlight. The lightness, chroma and hue the color has under the original conditions are kept, channels
+        that end up outside the sRGB gamut are clipped while the alpha channel is left unchanged
+
+        :param from_conditions: The conditions the color is currently seen in
+        :param to_conditions: The conditions to re-render the color for
+        """
+        ...
+
+    def white_balance(self, reference_white: "Color") -> "Color":
+        """
+        Performs a white balance correction, where the channels are scaled in linear light so that the reference
+        white (such as the color of a white wall or a gray card in a photo) turns into a neutral gray of the same
+        luminance. The same scaling is then applied to this color. Channels that end up above the sRGB gamut are
+        clipped, while the alpha channel is left unchanged
+
+        :param reference_white: The color that is supposed to be neutral
+
+        :raises ValueError: If any of the channels of the reference white is zero
+        """
+        ...
+
+    def sepia(self, amount: float = 1.0) -> "Color":
+        """
+        Gives the color a sepia tone, the same way as CSS's ``sepia()`` filter and
+        :func:`ColorMatrix.sepia() <tincture.ColorMatrix.sepia>`. The alpha channel is left unchanged
+
+        :param amount: How strong the effect is from 0.0 (unchanged) to 1.0 (fully sepia), by default 1.0.
+        It is clamped to that range
+
+        :raises ValueError: If the amount is NaN or infinite
+        """
+        ...
+
+    def posterize(self, levels: int) -> "Color":
+        """
+        Reduces every RGB channel to the given number of evenly spaced levels, snapping it to the closest one.
+        For example 2 levels only leave 0 and 255 while 3 levels leave 0, 128 and 255
+
+        :param levels: The number of levels per channel in the range of [2, 255]
+
+        :raises ValueError: If there are less than 2 levels
+        """
+        ...
+
+    def solarize(self, threshold: int = 128) -> "Color":
+        """
+        Performs a solarization, where every RGB channel at or above the threshold is inverted while
+        the channels below it are kept, just like photographic film that has been exposed to light
+
+        :param threshold: The channel value from which on the channels are inverted, by default 128
+        """
+        ...
+
+    def threshold(self, value: int = 128) -> "Color":
+        """
+        Turns the color into either white or black, white when its gray value (the same as
+        :func:`grayscale() <tincture.Color.grayscale>` computes) reaches the threshold and black otherwise.
+        The alpha channel is left unchanged
+
+        :param value: The gray value from which on the color turns white, by default 128
+        """
+        ...
+
     def triadic_colors(self) -> list["Color"]:
         """
         Gets the two triadic colors based on this color, the tetradic colors have a difference of hue 120 degrees
@@ -371,10 +967,19 @@ class Color:
         """
         ...
 
-    def adjust_temperature(self, temperature: int):
+    def adjust_temperature(self, delta_kelvin: float) -> "Color":
         """
-        Adjusts the temperature of the color where positive temperature makes the color warmer while
-        negative temperature makes the color colder. With temperature zero, it does nothing to the color
+        Shifts the color temperature of the color, as if the light it is seen under moved along the Planckian locus
+        (the colors of glowing black bodies) from 6504 K, the temperature of the sRGB white, by the supplied amount
+        of Kelvin. Negative values move toward candle light and make the color warmer, positive values move toward
+        blue sky and make the color cooler. The color is chromatically adapted between both white points with the
+        Bradford transform, see :func:`adapt() <tincture.Color.adapt>`, and the alpha channel is left unchanged
+
+        The locus is covered from 1667 K to 25000 K, so the change can range from -4837 to 18496
+
+        :param delta_kelvin: The change of the color temperature, 0 leaves the color unchanged
+
+        :raises ValueError: If the change is not a finite number or moves the temperature outside of the locus
         """
         ...
 
@@ -396,12 +1001,40 @@ class Color:
         """
         ...
 
+    def lighten(self, amount: float) -> "Color":
+        """
+        Lightens the color by moving its OKLCH lightness up by the amount. Unlike
+        :func:`brightness() <tincture.Color.brightness>`, which scales the RGB channels, equal amounts produce
+        steps that look equally large, which makes it suited for generating the shades of a theme. The hue is
+        kept while the chroma gets reduced whenever the lighter color would fall outside of sRGB. The alpha
+        channel is left unchanged
+
+        :param amount: How much lightness to add on the [0.0, 1.0] scale of the OKLCH lightness, negative values darken
+
+        :raises ValueError: If the amount is NaN or infinite
+        """
+        ...
+
+    def darken(self, amount: float) -> "Color":
+        """
+        Darkens the color by moving its OKLCH lightness down by the amount, the counterpart of
+        :func:`lighten() <tincture.Color.lighten>`. The alpha channel is left unchanged
+
+        :param amount: How much lightness to remove on the [0.0, 1.0] scale of the OKLCH lightness, negative values lighten
+
+        :raises ValueError: If the amount is NaN or infinite
+        """
+        ...
+
     def tint(self, degrees: int) -> "Color":
         """
         Performs a tint / hue shifting operation. Where it adjusts the hue of the color
         based on the provided degrees which is in the range of [0, 360] and
         is automatically rounded to that range
 
+        Note: Despite the name this does not mix the color with white, which is what
+        :func:`tint_with_white() <tincture.Color.tint_with_white>` does
+
         Note: For simpler implementation it converts to HSV color space, modifies
         then back to RGB color space so it can be a bit expensive sometimes
 
@@ -409,19 +1042,201 @@ class Color:
         """
         ...
 
+    def hue_rotate(self, degrees: float, space: str = "hsl") -> "Color":
+        """
+        Rotates the hue of the color by the provided degrees. Unlike :func:`tint() <tincture.Color.tint>` the degrees
+        may be fractional and the color space that the hue is rotated in can be chosen:
+
+        - "hsl" rotates the HSL hue, the same as :func:`tint() <tincture.Color.tint>`
+        - "oklch" rotates the OKLCH hue, which keeps the perceived lightness and chroma. Colors that end up outside
+          the sRGB gamut lose chroma until they fit, as described by :func:`clamp_to_srgb() <tincture.Color.clamp_to_srgb>`
+        - "matrix" applies the hue rotation matrix of CSS's ``hue-rotate()`` filter, which is what browsers do. It is
+          the cheapest of the three but does not preserve the lightness of the color
+
+        :param degrees: The angle to rotate the hue by, may be negative or fractional
+        :param space: The color space to rotate the hue in, either "hsl", "oklch" or "matrix". By default "hsl"
+
+        :raises ValueError: If the space is unknown or the degrees are NaN or infinite
+        """
+        ...
+
+    def harmonize(self, target: "Color", strength: float = 0.5) -> "Color":
+        """
+        Pulls the hue of the color partway toward the hue of the target color, along the shorter way around the
+        color wheel. Harmonizing a set of unrelated colors toward the same target makes them feel like one palette.
+        The hue is taken from OKLCH, whose lightness is kept while the chroma is only reduced when the new hue would
+        fall outside sRGB. Grays have no hue, so when either color is gray the color is returned unchanged. The alpha
+        channel is left unchanged
+
+        :param target: The color whose hue is pulled toward
+        :param strength: How far the hue moves from [0.0, 1.0], 0.0 keeps the hue while 1.0 takes the hue of the
+            target. By default 0.5
+
+        :raises ValueError: If the strength is out of range
+        """
+        ...
+
+    def rotate_hue_preserve_luminance(self, degrees: float) -> "Color":
+        """
+        Rotates the OKLCH hue of the color like :func:`hue_rotate(degrees, "oklch") <tincture.Color.hue_rotate>`,
+        then corrects the OKLCH lightness until the result has the same relative luminance (as defined by WCAG) as
+        the original. Hues at the same lightness can differ noticeably in luminance, so without the correction a
+        recolored element could lose contrast against its background. Colors that end up outside sRGB have their
+        chroma reduced. The alpha channel is left unchanged
+
+        Note: Since the result is rounded to 8-bit channels its luminance may differ by a few thousandths
+
+        :param degrees: The degrees to rotate the hue by, negative values rotate the other way
+
+        :raises ValueError: If the degrees are NaN or infinite
+        """
+        ...
+
     def saturate(self, factor: float) -> "Color":
         """
         Performs a saturation operation. Where it adjusts the saturation of the color
         based on the provided factor which is in the range of [-1.0, 1.0]. 0.0 Has no influence
-        whereas positive numbers saturate more and negative numbers desaturate
+        whereas positive numbers saturate more and negative numbers desaturate. The HSV saturation
+        is clamped to [0.0, 1.0]
 
         Note: For simpler implementation it converts to HSV color space, modifies
         then back to RGB color space so it can be a bit expensive sometimes
+
+        :raises ValueError: If the factor is NaN or infinite
+        """
+        ...
+
+    def normalize(self, method: str = "value") -> "Color":
+        """
+        Brings the color to its brightest version without shifting its hue, which is handy for fixing dim colors
+        extracted out of photos. The method decides what brightest means:
+
+        - "value" scales the RGB channels until the largest one reaches 255, maximizing the HSV value. Black has no
+          channel to scale and is returned unchanged
+        - "oklch" raises the OKLCH lightness as far as possible while the chroma and hue still fit inside sRGB, so
+          the result looks as colorful as the original. Black has no hue to keep and is returned unchanged
+
+        The alpha channel is left unchanged
+
+        :param method: Either "value" or "oklch", by default "value"
+
+        :raises ValueError: If the method is unknown
+        """
+        ...
+
+    def saturate_oklch(self, amount: float) -> "Color":
+        """
+        Saturates the color by scaling its OKLCH chroma by 1.0 + amount while keeping the lightness and hue. Unlike
+        :func:`saturate() <tincture.Color.saturate>`, which works on the HSV saturation and noticeably changes how
+        bright the color looks, the perceived lightness stays the same. Chroma that no longer fits inside sRGB is
+        reduced to the most saturated color of the same lightness and hue. The alpha channel is left unchanged
+
+        :param amount: How much the chroma grows, 0.0 has no influence while 1.0 doubles it. Negative values desaturate
+
+        :raises ValueError: If the amount is NaN or infinite
+        """
+        ...
+
+    def desaturate_oklch(self, amount: float) -> "Color":
+        """
+        Desaturates the color by scaling its OKLCH chroma by 1.0 - amount while keeping the lightness and hue, the
+        counterpart of :func:`saturate_oklch() <tincture.Color.saturate_oklch>`. An amount of 1.0 results in the gray
+        of the same perceived lightness. The alpha channel is left unchanged
+
+        :param amount: How much of the chroma is removed, 0.0 has no influence while 1.0 removes all of it
+
+        :raises ValueError: If the amount is NaN or infinite
+        """
+        ...
+
+    def vibrance(self, factor: float) -> "Color":
+        """
+        Performs a vibrance operation, a gentler form of :func:`saturate() <tincture.Color.saturate>`. The HSV
+        saturation is scaled the less the more saturated the color already is, so muted colors are boosted while
+        vivid colors barely change. Hues close to skin tones (around 25 degrees) only receive up to half of the
+        effect, which keeps portraits from looking sunburnt. 0.0 Has no influence whereas positive numbers saturate
+        more and negative numbers desaturate. The alpha channel is left unchanged
+
+        :param factor: The vibrance factor, usually in the range of [-1.0, 1.0]
+
+        :raises ValueError: If the factor is NaN or infinite
+        """
+        ...
+
+    def jitter(
+            self,
+            sigma: float = 5.0,
+            channels: str | None = None,
+            space: str = "rgb",
+            seed: int | None = None
+    ) -> "Color":
+        """
+        Adds Gaussian noise to the channels of the color, each channel is shifted on its own and the result is
+        clamped into range. This is useful for data augmentation and generative art, see
+        :func:`batch.jitter() <tincture.batch.jitter>` for jittering whole images at once. The noise is added
+        in one of two spaces:
+
+        - "rgb" shifts the red, green and blue channels, named "r", "g" and "b"
+        - "oklch" shifts the OKLCH lightness, chroma and hue, named "l", "c" and "h". Colors that end up outside of
+          sRGB have their chroma reduced
+
+        Both spaces name the alpha channel "a", which is only jittered when it is listed. The sigma is on the scale
+        of the RGB channels (0 to 255), the OKLCH components are shifted by the same share of their own range (1.0
+        for the lightness, 0.4 for the chroma and 360 degrees for the hue)
+
+        :param sigma: The standard deviation of the noise on a scale of 0 to 255, by default 5.0
+        :param channels: The names of the channels to jitter, by default every channel except the alpha
+        :param space: The space the noise is added in, either "rgb" or "oklch". By default "rgb"
+        :param seed: The seed of the noise which makes the result reproducible, by default a new random seed is
+            picked every call
+
+        :raises ValueError: If the sigma is negative or not finite, the space is unknown or a channel does not
+            belong to the space
+        """
+        ...
+
+    @staticmethod
+    def random(
+            space: str = "oklch",
+            l: tuple[float, float] | None = (0.7, 0.9),
+            c: tuple[float, float] | None = (0.05, 0.12),
+            h: tuple[float, float] | None = None,
+            seed: int | None = None
+    ) -> "Color":
+        """
+        Draws a random color whose components lie inside the ranges of a cylindrical color space. Unlike picking
+        random RGB channels this can target a look directly, for example "random pastel" colors (the defaults) or
+        "random dark saturated" colors with ``l=(0.25, 0.4), c=(0.12, 0.2)``. Each component is drawn uniformly
+        from its range:
+
+        - l is the lightness, the OKLCH and LCh lightness, the HSL lightness or the HSV value
+        - c is the colorfulness, the OKLCH and LCh chroma or the HSL and HSV saturation
+        - h is the hue in degrees, a range whose start lies past its end wraps around, so (330, 30) draws reds
+
+        A range of None spans the whole component. Note that the ranges are in the scale of the space, LCh for
+        example has its lightness in [0, 100], so the defaults only make sense for "oklch". Colors that fall outside
+        of sRGB have their chroma reduced at the same lightness and hue. The colors are always opaque
+
+        :param space: The space the components are drawn in, either "oklch", "lch", "hsl" or "hsv". By default "oklch"
+        :param l: The (start, end) range of the lightness, by default (0.7, 0.9)
+        :param c: The (start, end) range of the chroma or saturation, by default (0.05, 0.12)
+        :param h: The (start, end) range of the hue in degrees, by default the whole color wheel
+        :param seed: The seed of the randomization which makes the result reproducible, by default a new random seed
+            is picked every call. See :class:`RandomColorGenerator <tincture.RandomColorGenerator>` for drawing many
+            reproducible colors
+
+        :raises ValueError: If the space is not one of the cylindrical spaces, or a range is not finite or its start
+            lies above its end
         """
         ...
 
     # noinspection PyDefaultArgument
-    def randomise(self, start: list[int | None] = [0, 0, 0, 0], end: list[int | None] = [255, 255, 255, 255]) -> "Color":
+    def randomise(
+            self,
+            start: list[int | None] = [0, 0, 0, 0],
+            end: list[int | None] = [255, 255, 255, 255],
+            seed: int | None = None
+    ) -> "Color":
         """
         Creates a randomized color based on the ranges provided. It's iterating both lists and indexing to the elements,
         so for the first field it grabs start[0] and end[0] then so on. If both fields are set to None, then there will be
@@ -431,8 +1246,13 @@ class Color:
 
         Note: start and end must be both int or both None otherwise an error will appear
 
+        Note: Use a :class:`RandomColorGenerator <tincture.RandomColorGenerator>` to draw many reproducible colors
+        from one seed, since calling this with the same seed always results in the same color
+
         :param start: The starting range for the randomization
         :param end: The ending range for the randomization
+        :param seed: The seed of the randomization which makes the result reproducible, by default a new random seed
+            is picked every call
         """
         ...
 
@@ -470,13 +1290,77 @@ class Color:
         """
         ...
 
+    def with_red(self, value: int) -> "Color":
+        """
+        Creates a new color with the red channel replaced, leaving this color unchanged
+
+        :param value: The new red channel, in range [0, 255]
+        """
+        ...
+
+    def with_green(self, value: int) -> "Color":
+        """
+        Creates a new color with the green channel replaced, leaving this color unchanged
+
+        :param value: The new green channel, in range [0, 255]
+        """
+        ...
+
+    def with_blue(self, value: int) -> "Color":
+        """
+        Creates a new color with the blue channel replaced, leaving this color unchanged
+
+        :param value: The new blue channel, in range [0, 255]
+        """
+        ...
+
+    def with_alpha(self, value: int) -> "Color":
+        """
+        Creates a new color with the alpha channel replaced, leaving this color unchanged
+
+        :param value: The new alpha channel, in range [0, 255]
+        """
+        ...
+
+    def replace(
+        self, r: int | None = None, g: int | None = None, b: int | None = None, a: int | None = None
+    ) -> "Color":
+        """
+        Creates a new color where the given channels are replaced and the omitted ones are kept,
+        for example ``color.replace(g=0, a=128)``. This color itself is left unchanged
+
+        :param r: The new red channel, by default the red channel is kept
+        :param g: The new green channel, by default the green channel is kept
+        :param b: The new blue channel, by default the blue channel is kept
+        :param a: The new alpha channel, by default the alpha channel is kept
+        """
+        ...
+
+    def sort_key(self, by: str = "hue") -> tuple[float, float, float]:
+        """
+        Returns a tuple that orders colors the same way [Palette.sorted] does, so lists of colors can be sorted
+        with ``sorted(colors, key=Color.sort_key)`` or ``sorted(colors, key=lambda color: color.sort_key("step"))``.
+        The supported orders are "hue", "luminance", "saturation" and "step", see [Palette.sorted] for what they do
+
+        :param by: The order the key is built for, by default "hue"
+        :raises ValueError: If the sort order is unknown
+        """
+        ...
+
+    def freeze(self) -> FrozenColor:
+        """
+        Creates an immutable [FrozenColor] with the same channels, which can safely be used as a dict key or
+        inside a set. Use [FrozenColor.thaw] to get a mutable color back
+        """
+        ...
+
     def copy(self) -> "Color":
         """
         Copies all the RGBA components and creates a new color
         """
         ...
 
-    def to_hex(self, include_transparency: bool) -> str:
+    def to_hex(self, include_transparency: bool = False) -> str:
         """
         Converts the color object into a Hexadecimal string format
         where it contains a leading "#" and then the hexadecimal parts.
@@ -533,11 +1417,32 @@ class Color:
         """
         ...
 
-    def to_xyz(self) -> tuple[float]:
+    def to_xyz(self, illuminant: str | tuple[float, float] = "D65") -> tuple[float, float, float, float]:
         """
         Converts the color object into the XYZ color space format. Where
         The X values ranges from [0.0, 95.047], Y value ranges from [0.0, 100.0],
         and finally Z ranges from [0.0, 108.883]. Includes transparency as a field
+
+        With another illuminant the values are adapted to its white point with the Bradford transform, so that
+        white maps onto the white point of the illuminant scaled to 100
+
+        :param illuminant: The white point the values are relative to, either one of the standard illuminants
+            "A", "C", "D50", "D55", "D65", "D75", "E", "F2", "F7" and "F11" or the (x, y) chromaticity of a custom one
+
+        :raises ValueError: If the illuminant is unknown
+        """
+        ...
+
+    def to_lab(self, illuminant: str | tuple[float, float] = "D65") -> tuple[float, float, float, float]:
+        """
+        Converts the color object into CIELAB, where "L" is the lightness from [0.0, 100.0] while "A" and "B" are
+        the green to red and blue to yellow axes. Includes transparency as a field
+
+        :param illuminant: The white point the values are relative to, either one of the standard illuminants
+            "A", "C", "D50", "D55", "D65", "D75", "E", "F2", "F7" and "F11" or the (x, y) chromaticity of a custom one.
+            Lab values of print and ICC profiles are usually relative to "D50"
+
+        :raises ValueError: If the illuminant is unknown
         """
         ...
 
@@ -554,6 +1459,20 @@ class Color:
         """Converts the color object into a list that contains the RGBA values from [0, 255]"""
         ...
 
+    def to_dict(self) -> dict[str, int]:
+        """
+        Converts the color object into a dict with the "r", "g", "b" and "a" keys, which is handy for
+        config files. [from_dict] turns it back into a color
+        """
+        ...
+
+    def to_json(self) -> str:
+        """
+        Serializes the color into a JSON object of the same shape as [to_dict], such as
+        ``{"r": 255, "g": 0, "b": 0, "a": 255}``
+        """
+        ...
+
     def to_decimal_rgba_list(self) -> list[float]:
         """Converts the color object into a list that contains the Decimal RGBA values from [0, 1.0]"""
         ...
@@ -562,11 +1481,155 @@ class Color:
         """Converts the color object into a tuple that contains the RGBA values from [0, 255]"""
         ...
 
-    def __add__(self, other: "Color") -> "Color": ...
-    def __sub__(self, other: "Color") -> "Color": ...
-    def __mul__(self, factor: float) -> "Color": ...
+    def to_int(self, premultiplied: bool = False) -> int:
+        """
+        Converts the color object into a packed 32-bit integer in the RRGGBBAA channel order,
+        where red occupies the most significant byte and alpha the least significant byte
+
+        :param premultiplied: Whenever to premultiply the RGB channels by the alpha channel before packing
+        """
+        ...
+
+    def to_bytes(self, premultiplied: bool = False) -> bytes:
+        """
+        Converts the color object into 4 bytes in the R, G, B, A order, which is the layout
+        most GPU texture uploads and image buffers expect
+
+        :param premultiplied: Whenever to premultiply the RGB channels by the alpha channel before exporting
+        """
+        ...
+
+    def to_ansi256(self) -> int:
+        """
+        Finds the closest color of the xterm 256 color terminal palette and returns its index. Only the 6x6x6
+        color cube (16 to 231) and the grayscale ramp (232 to 255) are considered, since terminals commonly
+        redefine the first 16 colors. The alpha channel is ignored
+        """
+        ...
+
+    def to_ansi16(self) -> int:
+        """
+        Finds the closest of the 16 basic terminal colors (using the default xterm values) and returns its index,
+        0 to 7 being the normal colors and 8 to 15 their bright variants. The alpha channel is ignored
+        """
+        ...
+
+    def ansi_fg(self) -> str:
+        """
+        Creates the truecolor ANSI escape sequence (``\\x1b[38;2;r;g;bm``) which sets the text color of a terminal
+        to this color. The alpha channel is ignored
+        """
+        ...
+
+    def ansi_bg(self) -> str:
+        """
+        Creates the truecolor ANSI escape sequence (``\\x1b[48;2;r;g;bm``) which sets the background color of a
+        terminal to this color. The alpha channel is ignored
+        """
+        ...
+
+    def paint(self, text: str, bg: "Color | None" = None) -> str:
+        """
+        Wraps the text in the escape sequences which print it in this color, followed by a reset sequence
+        so that the following output is unaffected
+
+        :param text: The text to color
+        :param bg: The background color of the text, by default the background is left untouched
+        """
+        ...
+
+    def to_web_safe(self) -> "Color":
+        """
+        Snaps every RGB channel to the closest level of the 216 color web-safe cube (0, 51, 102, 153, 204 or 255)
+        and returns a new color, the alpha channel is kept as is. For the perceptually closest web-safe color
+        instead, use ``Palette.web_safe().nearest(color)``
+        """
+        ...
+
+    def to_unity(self) -> tuple[float, float, float, float]:
+        """
+        Converts the color object into the linear RGBA float4 values Unity expects when the project
+        uses the linear color space, each value ranges from [0.0, 1.0]
+        """
+        ...
+
+    def to_godot(self) -> str:
+        """
+        Converts the color object into a Godot ``Color(r, g, b, a)`` constructor string,
+        which can be pasted directly into GDScript or a scene file
+        """
+        ...
+
+    def to_unreal(self) -> str:
+        """
+        Converts the color object into the ``(R=...,G=...,B=...,A=...)`` text representation of
+        Unreal's FLinearColor, which can be pasted directly into a color property in the editor
+        """
+        ...
+
+    def premultiply(self) -> "Color":
+        """
+        Premultiplies the RGB channels by the alpha channel and returns a new color, the alpha
+        channel itself is kept as is. For the reverse operation, check out [unpremultiply]
+        """
+        ...
+
+    def unpremultiply(self) -> "Color":
+        """
+        Divides the RGB channels by the alpha channel and returns a new color, this reverses
+        [premultiply] (within rounding). A fully transparent color has no recoverable RGB values,
+        so it results in a color with all channels set to zero
+        """
+        ...
+
+    def _repr_html_(self) -> str:
+        """
+        Renders the color as a small inline swatch next to its hex value, Jupyter notebooks use this to display
+        colors. Transparent colors are drawn over a checkerboard
+        """
+        ...
+
+    def __format__(self, spec: str) -> str:
+        """
+        Formats the color inside f-strings and ``str.format``, for example ``f"{color:css}"``. The supported specs are
+        ``hex`` / ``hex8`` (with the alpha channel), ``rgb`` / ``rgba``, ``hsl`` / ``hsla`` and ``css``, which gives the
+        hex string for opaque colors and ``rgba(...)`` otherwise. Writing a spec in uppercase, such as ``HEX`` or ``HEX8``,
+        gives uppercase hex digits, and an empty spec gives the same as ``str(color)``
+
+        :param spec: The name of the format to use
+        :raises ValueError: If the spec is not one of the supported formats or mixes lowercase and uppercase
+        """
+        ...
+
+    def __add__(self, other: "Color | int | Channels") -> "Color": ...
+    def __radd__(self, other: "Color | int | Channels") -> "Color": ...
+    def __sub__(self, other: "Color | int | Channels") -> "Color": ...
+    def __mul__(self, factor: "Color | float | Factors") -> "Color":
+        """
+        Multiplies the channels by the factor, a color multiplies every channel by the matching one while a
+        tuple or list of 3 or 4 factors scales the channels individually, so ``color * (1.0, 0.5, 0.5)``
+        halves green and blue. The alpha channel is only scaled by a single float or a 4 factor tuple. Tuples and
+        lists of any other length raise a ValueError, the same goes for the other arithmetic operators
+        """
+        ...
+
+    def __rmul__(self, factor: "Color | float | Factors") -> "Color": ...
     def __truediv__(self, factor: float) -> "Color": ...
     def __floordiv__(self, factor: int) -> "Color": ...
+    def __iadd__(self, other: "Color | int | Channels") -> "Color": ...
+    def __isub__(self, other: "Color | int | Channels") -> "Color": ...
+    def __imul__(self, factor: "Color | float | Factors") -> "Color": ...
+    def __itruediv__(self, factor: float) -> "Color": ...
+    def __int__(self) -> int:
+        """
+        The same packed 32-bit RRGGBBAA integer as [to_int] without premultiplying, ``__index__`` returns
+        it as well, so ``hex(color)`` gives ``0xRRGGBBAA`` and colors can be used wherever an integer is expected.
+        The arithmetic operators treat a sequence as channel values though, so ``[1, 2] * color`` raises an error
+        rather than repeating the list
+        """
+        ...
+
+    def __index__(self) -> int: ...
     def __hash__(self) -> int: ...
     def __nonzero__(self) -> int: ...
     def __neg__(self) -> "Color": ...
@@ -574,9 +1637,19 @@ class Color:
     def __repr__(self) -> str: ...
     def __pow__(self, color: "Color", base: float) -> "Color": ...
     def __rpow__(self, color: "Color", base: float) -> "Color": ...
+    def __len__(self) -> int: ...
+    def __iter__(self) -> Iterator[int]: ...
+    @overload
     def __getitem__(self, a: str | int) -> int: ...
-    def __setitem__(self, a: str | int, b: int) -> int: ...
+    @overload
+    def __getitem__(self, a: slice) -> list[int]: ...
+    @overload
+    def __setitem__(self, a: str | int, b: int) -> None: ...
+    @overload
+    def __setitem__(self, a: slice, b: Sequence[int]) -> None: ...
     def __rshift__(self, places: int) -> "Color": ...
     def __lshift__(self, places: int) -> "Color": ...
     def __copy__(self) -> "Color": ...
+    def __deepcopy__(self, memo: dict) -> "Color": ...
+    def __reduce__(self) -> tuple: ...
     def __sizeof__(self) -> int: ...
@@ -0,0 +1,131 @@
+from ._color import Color
+
+class ColorSpace:
+    """
+    ColorSpace defines an RGB color space of its own, out of its primaries and white point or out of the matrix
+    from its linear channels to XYZ, together with the transfer function that gamma encodes those channels.
+    Once registered, its name can be passed to every function that takes a color space, such as
+    ``ColorArray.to_components``, ``Color.mix``, ``Gradient`` and the conversions of ``tincture.batch``.
+    Conversions back into sRGB bring the colors outside of its gamut into it by reducing their chroma
+
+    The transfer function is one of "linear", "srgb", "rec2020" (also "rec709", which shares it) or a number,
+    which is a pure gamma such as 2.2 for Adobe RGB. Spaces loaded from ICC profiles use the curves of the profile
+    """
+
+    @staticmethod
+    def from_primaries(
+        name: str,
+        red: tuple[float, float],
+        green: tuple[float, float],
+        blue: tuple[float, float],
+        white: str | tuple[float, float] = "D65",
+        transfer: str | float = "srgb",
+    ) -> "ColorSpace":
+        """
+        Defines a color space by the xy chromaticities of its primaries and its white point. A white point other
+        than D65 is adapted over to the D65 white of sRGB with the Bradford transform
+
+        :param name: The name the space is registered under, case-insensitive and without whitespace
+        :param red: The xy chromaticity of the red primary
+        :param green: The xy chromaticity of the green primary
+        :param blue: The xy chromaticity of the blue primary
+        :param white: The white point, either one of the standard illuminants "A", "C", "D50", "D55", "D65",
+            "D75", "E", "F2", "F7" and "F11" or the (x, y) chromaticity of a custom one, by default "D65"
+        :param transfer: The transfer function, by default "srgb"
+
+        :raises ValueError: If the name is taken by a built-in color space or contains whitespace, if the primaries
+            lie on one line or the white point or transfer function is unknown
+        """
+        ...
+
+    @staticmethod
+    def from_matrix(
+        name: str,
+        to_xyz: list[list[float]],
+        white: str | tuple[float, float] = "D65",
+        transfer: str | float = "linear",
+    ) -> "ColorSpace":
+        """
+        Defines a color space by the matrix that converts its linear channels into XYZ, where white has a
+        luminance (Y) of 1.0 rather than 100.0
+
+        :param name: The name the space is registered under, case-insensitive and without whitespace
+        :param to_xyz: The 3x3 matrix in row major order, its columns are the XYZ values of the primaries
+        :param white: The white point the XYZ values are relative to, either one of the standard illuminants or the
+            (x, y) chromaticity of a custom one, by default "D65"
+        :param transfer: The transfer function, by default "linear"
+
+        :raises ValueError: If the name is taken by a built-in color space or contains whitespace, if the matrix
+            cannot be inverted or the white point or transfer function is unknown
+        """
+        ...
+
+    @staticmethod
+    def from_icc(data_or_path: bytes | str, name: str | None = None) -> "ColorSpace":
+        """
+        Defines a color space by a matrix/TRC ICC profile of version 2 or 4, the kind that displays and RGB working
+        spaces use. The colorants of the profile make up the matrix to XYZ while its tone reproduction curves,
+        either sampled or parametric, become the transfer function of each channel. Profiles built out of lookup
+        tables, which printers and most scanners use, are not supported
+
+        :param data_or_path: The bytes of the profile or the path to the file holding it
+        :param name: The name the space is registered under, by default the description of the profile with its
+            whitespace replaced by underscores. It has to be given when the profile has no description or when the
+            description is the name of a built-in color space
+
+        :raises ValueError: If the data is not a matrix/TRC profile of an RGB device, one of its curves is
+            malformed (such as a zero gamma), or if it has no description and no name was given
+        """
+        ...
+
+    @staticmethod
+    def registered() -> list[str]:
+        """The names of the registered color spaces, in the order they were registered"""
+        ...
+
+    @staticmethod
+    def unregister(name: str) -> bool:
+        """
+        Removes the registered color space with the name, gradients already using it keep working
+
+        :param name: The name of the space, case-insensitive
+
+        :return: Whenever a space with the name was registered
+        """
+        ...
+
+    def register(self) -> None:
+        """
+        Makes the color space available by its name, a space registered under the same name before is replaced
+        """
+        ...
+
+    @property
+    def name(self) -> str:
+        """The name of the color space"""
+        ...
+
+    @property
+    def matrix(self) -> list[list[float]]:
+        """The matrix from the linear channels into XYZ relative to D65, in row major order"""
+        ...
+
+    def to_components(self, color: Color) -> tuple[float, float, float]:
+        """
+        Converts the color into the gamma encoded channels of the color space, which range from [0.0, 1.0] for
+        the colors inside its gamut
+
+        :param color: The color to convert
+        """
+        ...
+
+    def from_components(self, components: tuple[float, float, float], transparency: float = 1.0) -> Color:
+        """
+        Converts the gamma encoded channels of the color space into a color
+
+        :param components: The red, green and blue channels of the color space
+        :param transparency: The transparency value that ranges from [0.0, 1.0]
+
+        :raises ValueError: If the transparency is out of range
+        """
+        ...
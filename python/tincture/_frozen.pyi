@@ -0,0 +1,75 @@
+from typing import Iterator, overload
+
+from ._color import Color
+
+class FrozenColor:
+    __match_args__ = ("r", "g", "b", "a")
+
+    def __init__(self, r: int, g: int, b: int, a: int = 255) -> None:
+        """
+        FrozenColor is an immutable counterpart of [Color]. Its channels can be read but never assigned, which
+        makes it safe to use as a dict key or inside a set, whereas mutating a [Color] that is already used as a
+        key silently breaks the lookup. A frozen color is equal to and hashes the same as the color with the same
+        channels, so both can be used to look up the same entry
+
+        :param r: The red channel, in range [0, 255]
+        :param g: The green channel, in range [0, 255]
+        :param b: The blue channel, in range [0, 255]
+        :param a: The alpha channel, in range [0, 255]. By default, it's fully opaque
+        """
+        ...
+
+    @staticmethod
+    def from_color(color: Color) -> "FrozenColor":
+        """
+        Creates a frozen copy of the color, this is the same as calling [Color.freeze]
+
+        :param color: The color to freeze
+        """
+        ...
+
+    def replace(
+        self, r: int | None = None, g: int | None = None, b: int | None = None, a: int | None = None
+    ) -> "FrozenColor":
+        """
+        Creates a new frozen color where the given channels are replaced and the omitted ones are kept
+
+        :param r: The new red channel, by default the red channel is kept
+        :param g: The new green channel, by default the green channel is kept
+        :param b: The new blue channel, by default the blue channel is kept
+        :param a: The new alpha channel, by default the alpha channel is kept
+        """
+        ...
+
+    def thaw(self) -> Color:
+        """
+        Creates a mutable [Color] with the same channels, changing it does not affect this frozen color
+        """
+        ...
+
+    @property
+    def r(self) -> int: ...
+    @property
+    def g(self) -> int: ...
+    @property
+    def b(self) -> int: ...
+    @property
+    def a(self) -> int: ...
+
+    def __eq__(self, other: object) -> bool: ...
+    def __ne__(self, other: object) -> bool: ...
+    def __hash__(self) -> int: ...
+    def __int__(self) -> int: ...
+    def __index__(self) -> int: ...
+    def __len__(self) -> int: ...
+    def __iter__(self) -> Iterator[int]: ...
+    @overload
+    def __getitem__(self, a: str | int) -> int: ...
+    @overload
+    def __getitem__(self, a: slice) -> list[int]: ...
+    def __format__(self, spec: str) -> str: ...
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+    def __copy__(self) -> "FrozenColor": ...
+    def __deepcopy__(self, memo: dict) -> "FrozenColor": ...
+    def __reduce__(self) -> tuple: ...
@@ -0,0 +1,293 @@
+from os import PathLike
+
+from ._array import ColorArray
+from ._color import Color
+
+class Gradient:
+    def __init__(
+            self,
+            stops: list[tuple[float, Color]],
+            space: str = "oklab",
+            hue_strategy: str = "shorter",
+            interpolation: str = "linear"
+    ) -> None:
+        """
+        Gradient stores multiple colors (also known as color stops) that are placed on a position ranging
+        from [0.0, 1.0]. Sampling the gradient picks the two stops surrounding the position and interpolates
+        between them inside the gradient's color space, just like :func:`Color.lerp() <tincture.Color.lerp>`.
+        The stops do not have to be supplied in order, they are sorted by their position
+
+        :param stops: The (position, color) pairs of the gradient, there has to be at least one stop
+        :param space: The color space the stops are interpolated in, by default "oklab"
+        :param hue_strategy: Which way around the color wheel the hue is interpolated for cylindrical color
+        spaces, it can be "shorter", "longer", "increasing" or "decreasing". By default, it is set to "shorter"
+        :param interpolation: How the stops are connected, "linear" interpolates every pair of neighbouring stops
+        on its own while "catmull_rom" fits a Catmull-Rom spline that smoothly passes through every stop without the
+        visible kinks of linear segments. By default, it is set to "linear"
+        """
+        ...
+
+    @staticmethod
+    def from_colors(
+            colors: list[Color],
+            space: str = "oklab",
+            hue_strategy: str = "shorter",
+            interpolation: str = "linear"
+    ) -> "Gradient":
+        """
+        Construct a new gradient where the supplied colors are evenly spread out from 0.0 to 1.0
+
+        :param colors: The colors of the gradient, there has to be at least one color
+        :param space: The color space the stops are interpolated in, by default "oklab"
+        :param hue_strategy: The hue interpolation strategy for cylindrical color spaces, by default "shorter"
+        :param interpolation: Either "linear" or "catmull_rom", by default "linear"
+        """
+        ...
+
+    @staticmethod
+    def cubehelix(start: float = 0.5, rotations: float = -1.5, hue: float = 1.0, gamma: float = 1.0) -> "Gradient":
+        """
+        Constructs a gradient from black to white that follows Dave Green's cubehelix color scheme. The colors spiral
+        around the RGB cube while their perceived brightness keeps increasing monotonically, which is why it is
+        well suited for scientific imaging and prints well in grayscale. The defaults match the original scheme
+
+        :param start: The starting hue direction, where 1.0 is red, 2.0 is green and 3.0 is blue. By default 0.5
+        :param rotations: The amount of rotations around the hue wheel from black to white, by default -1.5
+        :param hue: The saturation of the colors, 0.0 results in a pure grayscale gradient. By default 1.0
+        :param gamma: Emphasizes either the darker (above 1.0) or the brighter (below 1.0) colors, by default 1.0
+
+        :raises ValueError: If any of the arguments is NaN or infinite, gamma is not above zero or hue is negative
+        """
+        ...
+
+    @staticmethod
+    def duotone(dark: Color, light: Color, mid: Color | None = None, space: str = "oklab") -> "Gradient":
+        """
+        Constructs the gradient of a duotone, running from the dark color at 0.0 to the light color at 1.0. Supplying
+        a midtone color places it at 0.5, which makes it a tritone. Sampling it at the grayscale value of a pixel
+        gives the same result as :func:`Color.duotone() <tincture.Color.duotone>`
+
+        :param dark: The color of the shadows
+        :param light: The color of the highlights
+        :param mid: The color of the midtones, by default there is none
+        :param space: The color space the stops are interpolated in, by default "oklab"
+
+        :raises ValueError: If the space is unknown
+        """
+        ...
+
+    @property
+    def stops(self) -> list[tuple[float, Color]]:
+        """The (position, color) pairs of the gradient sorted by their position"""
+        ...
+
+    @property
+    def space(self) -> str:
+        """The name of the color space the stops are interpolated in"""
+        ...
+
+    @property
+    def hue_strategy(self) -> str:
+        """The hue interpolation strategy used for cylindrical color spaces"""
+        ...
+
+    @property
+    def interpolation(self) -> str:
+        """The way the stops are connected, "linear" or "catmull_rom" (see the constructor)"""
+        ...
+
+    def sample(self, t: float, wrap: str = "clamp") -> Color:
+        """
+        Samples the color of the gradient at the supplied position. Positions before the first stop
+        result in the first stop's color while positions after the last stop result in the last stop's color.
+        The wrap mode decides what happens to positions outside the [0.0, 1.0] range, which is useful for
+        animated looping gradients and procedural textures
+
+        :param t: The position to sample the gradient at
+        :param wrap: Either "clamp" which sticks to the ends of the gradient, "repeat" which tiles the gradient
+        or "mirror" which goes back and forth (ping-pong) between the ends. By default, it is set to "clamp"
+
+        :raises ValueError: If the wrap mode is unknown or the position is NaN or infinite
+        """
+        ...
+
+    def sample_many(self, n: int) -> bytes:
+        """
+        Samples the gradient at n evenly spaced positions from 0.0 to 1.0 and returns the colors packed as
+        RGBA pixels, 4 bytes per sample. The sampling is done without holding the GIL, which makes it much
+        faster than calling :func:`sample() <tincture.Gradient.sample>` in a loop when rendering gradient strips
+
+        :param n: The number of samples to take
+
+        :raises MemoryError: If the pixels of that many samples cannot be allocated
+        """
+        ...
+
+    def sample_array(self, n: int) -> ColorArray:
+        """
+        Samples the gradient the same way as :func:`sample_many() <tincture.Gradient.sample_many>`, but returns
+        the samples as a :class:`ColorArray`. Its buffer has the shape (n, 4), so ``numpy.asarray`` turns it
+        straight into an image row
+
+        :param n: The number of samples to take
+
+        :raises MemoryError: If the pixels of that many samples cannot be allocated
+        """
+        ...
+
+    def sample_into(self, width: int) -> bytearray:
+        """
+        Samples the gradient into a pixel row of the supplied width, the result is a mutable bytearray of RGBA
+        pixels (4 bytes per pixel) which can be handed over directly to image libraries.
+        Works the same way as :func:`sample_many() <tincture.Gradient.sample_many>`
+
+        :param width: The width of the pixel row in pixels
+
+        :raises MemoryError: If the pixels of that many samples cannot be allocated
+        """
+        ...
+
+    def to_unity_gradient_json(self) -> str:
+        """
+        Exports the gradient into the JSON representation of Unity's Gradient, as produced by JsonUtility.
+        The color keys hold the sRGB colors of the stops while the alpha keys hold their transparency.
+        Unity always blends the keys in its own way, so gradients interpolated in other color spaces may look
+        slightly different inside the engine
+
+        :raises ValueError: If the gradient has more than 8 stops, which is the limit of Unity's gradients
+        """
+        ...
+
+    def to_glsl(self, name: str = "gradient") -> str:
+        """
+        Exports the gradient as a GLSL function which takes the position as a float and returns the
+        sRGB color as a vec4, so gradients designed in Python can be used inside fragment shaders (e.g. Shadertoy).
+        The function mixes the colors piecewise, gradients that are not interpolated in "srgb" get extra samples
+        in between their stops so that the shader approximates their color space closely
+
+        :param name: The name of the GLSL function, by default "gradient"
+
+        :raises ValueError: If the name is not a valid GLSL identifier
+        """
+        ...
+
+    def to_css(self, kind: str = "linear", angle: float = 90.0) -> str:
+        """
+        Exports the gradient into a CSS gradient string such as ``linear-gradient(90deg, #ff0000 0%, #0000ff 100%)``.
+        Browsers interpolate the stops in sRGB, so gradients that use another color space or the "catmull_rom"
+        interpolation get extra stops in between to closely match how they look in tincture. Transparent stops
+        are written as 8 digit hex colors
+
+        :param kind: The kind of the CSS gradient, it can be "linear", "radial" or "conic". By default "linear"
+        :param angle: The direction of linear gradients or the starting angle of conic gradients in degrees,
+        radial gradients ignore it. By default 90 degrees
+
+        :raises ValueError: If the kind of the gradient is unknown
+        """
+        ...
+
+    def to_cube(self, path: str | PathLike | None = None, size: int = 33) -> str | None:
+        """
+        Exports the gradient as a gradient map inside a .cube 3D LUT, which can be loaded into color grading
+        software such as DaVinci Resolve or Premiere. Every input color is mapped onto the gradient by its Rec. 709
+        luma, so black inputs result in the gradient's start while white inputs result in its end
+
+        :param path: Where to write the .cube file, if it is not supplied then the text of the LUT is returned instead
+        :param size: The amount of grid points per channel, it ranges from [2, 256]. By default 33
+
+        :raises ValueError: If the size is out of range
+        """
+        ...
+
+    def to_json(self, indent: int | None = None) -> str:
+        """
+        Serializes the gradient into JSON with a stable schema that other languages can read as well, for example::
+
+            {"space": "oklab", "hue_strategy": "shorter", "interpolation": "linear",
+             "stops": [{"position": 0.0, "color": "#ff0000"}, {"position": 1.0, "color": "#0000ff80"}]}
+
+        The alpha channel of a stop is only written when its color is not fully opaque. The JSON can be turned
+        back into a gradient with [from_json]
+
+        :param indent: The indentation of the JSON, by default everything is written in a single line
+        """
+        ...
+
+    @staticmethod
+    def from_json(text: str) -> "Gradient":
+        """
+        Reads a gradient from the JSON produced by [to_json]. Missing settings fall back to the same defaults
+        as the constructor of the gradient, while the stops are required
+
+        :param text: The JSON text
+
+        :raises ValueError: If the JSON is not an object, has no stops or a setting or color is invalid
+        """
+        ...
+
+    def to_text(self) -> str:
+        """
+        Exports the gradient into a line-oriented text format that is meant to be stored inside version control.
+        The first lines hold the settings of the gradient, followed by one "position hex" line per stop sorted by
+        position, so changing a single stop results in a single changed line inside the diff. For example::
+
+            # tincture gradient
+            space oklab
+            hue_strategy shorter
+            interpolation linear
+            0.0 #ff0000ff
+            1.0 #0000ffff
+
+        The text can be turned back into a gradient with :func:`from_text() <tincture.Gradient.from_text>`
+        """
+        ...
+
+    @staticmethod
+    def from_text(text: str) -> "Gradient":
+        """
+        Parses a gradient from the text format produced by :func:`to_text() <tincture.Gradient.to_text>`.
+        Empty lines and lines starting with "#" are ignored, while missing settings fall back to the same defaults
+        as the constructor of the gradient
+
+        :param text: The text to parse
+
+        :raises ValueError: If a line cannot be interpreted or the gradient has no valid stops
+        """
+        ...
+
+    def to_svg(self, width: float = 480.0, height: float = 48.0, labels: bool = False) -> str:
+        """
+        Renders the gradient as a standalone SVG document holding a horizontal strip, useful for documentation
+        and design handoff. Like [to_css], extra stops are added so the SVG matches the gradient's own color space
+        and interpolation
+
+        :param width: The width of the image, by default 480
+        :param height: The height of the image, by default 48
+        :param labels: Whether the hex value of every stop is written at its position, by default False
+
+        :raises ValueError: If the width or the height is not positive
+        """
+        ...
+
+    def fingerprint(self) -> str:
+        """
+        A stable hash of the stops and settings of the gradient as 16 hex digits, which lets caching layers and
+        asset pipelines detect changed gradients cheaply. The stop positions are quantized to 16 bits beforehand,
+        so rounding noise far below what can be seen does not change the hash. The hash stays the same between
+        runs and platforms
+        """
+        ...
+
+    def _repr_html_(self) -> str:
+        """
+        Renders the gradient as a horizontal strip built from [to_css], Jupyter notebooks use this to display
+        gradients. Transparent parts are drawn over a checkerboard
+        """
+        ...
+
+    def __len__(self) -> int: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __repr__(self) -> str: ...
+    def __copy__(self) -> "Gradient": ...
+    def __deepcopy__(self, memo: dict) -> "Gradient": ...
+    def __reduce__(self) -> tuple: ...
@@ -0,0 +1,24 @@
+from os import PathLike
+from typing import Callable
+
+from ._color import Color
+
+def export_lut(
+        transform: Callable[[Color], Color],
+        size: int = 33,
+        path: str | PathLike | None = None,
+        title: str = "tincture"
+) -> str | None:
+    """
+    Exports any color transform as a .cube 3D LUT, so grading operations built with tincture can be loaded into
+    color grading software such as DaVinci Resolve or Premiere. The transform is called once for every point of
+    the LUT's grid with the input color and has to return the output color, for example ``lambda c: c.inverse()``
+
+    :param transform: The function that maps an input color onto an output color
+    :param size: The amount of grid points per channel, it ranges from [2, 256]. By default 33
+    :param path: Where to write the .cube file, if it is not supplied then the text of the LUT is returned instead
+    :param title: The title stored inside the .cube file, by default "tincture"
+
+    :raises ValueError: If the size is out of range
+    """
+    ...
@@ -0,0 +1,96 @@
+from ._color import Color
+
+class ColorMatrix:
+    def __init__(self, values: list[float]) -> None:
+        """
+        ColorMatrix is a 4x5 color filter, the same kind as SVG's feColorMatrix and Android's ColorMatrix. The
+        values are the 4 rows of 5 in row major order, the rows produce the red, green, blue and alpha channels
+        out of the weighted sum of the original channels plus the offset found in the fifth column. The channels
+        are scaled to [0.0, 1.0] (so the offsets are too) and the results are clamped back into the channel range.
+        Many filters (saturation, sepia, inversion, hue rotation and so on) are such matrices, so they can be
+        chained into one matrix and then applied in a single pass
+
+        :param values: The 20 values of the matrix, in row major order
+
+        :raises ValueError: If there are not exactly 20 values
+        """
+        ...
+
+    @staticmethod
+    def identity() -> "ColorMatrix":
+        """The matrix that leaves every color unchanged"""
+        ...
+
+    @staticmethod
+    def saturation(amount: float) -> "ColorMatrix":
+        """
+        The matrix that scales the saturation of colors, the same way as CSS's ``saturate()`` filter
+
+        :param amount: 1.0 leaves the colors unchanged, 0.0 turns them into gray, values above 1.0 oversaturate them
+        """
+        ...
+
+    @staticmethod
+    def sepia(amount: float = 1.0) -> "ColorMatrix":
+        """
+        The matrix that gives colors a sepia tone, the same way as CSS's ``sepia()`` filter
+
+        :param amount: How strong the effect is from 0.0 (unchanged) to 1.0 (fully sepia), by default 1.0.
+        It is clamped to that range
+
+        :raises ValueError: If the amount is NaN or infinite
+        """
+        ...
+
+    @staticmethod
+    def hue_rotate(degrees: float) -> "ColorMatrix":
+        """
+        The matrix that rotates the hue of colors, the same way as CSS's ``hue-rotate()`` filter
+
+        :param degrees: The angle to rotate the hues by
+        """
+        ...
+
+    @staticmethod
+    def invert() -> "ColorMatrix":
+        """The matrix that inverts the red, green and blue channels, the alpha is left unchanged"""
+        ...
+
+    @property
+    def values(self) -> list[float]:
+        """The 20 values of the matrix, in row major order"""
+        ...
+
+    def apply(self, color: Color) -> Color:
+        """
+        Applies the matrix to the color
+
+        :param color: The color to filter
+
+        :return: The filtered color
+        """
+        ...
+
+    def then(self, other: "ColorMatrix") -> "ColorMatrix":
+        """
+        Chains another matrix after this one, applying the result is the same as applying this matrix and then
+        the other one, except that the channels are not clamped in between
+
+        :param other: The matrix to apply afterward
+        """
+        ...
+
+    def __matmul__(self, other: "ColorMatrix") -> "ColorMatrix":
+        """
+        The matrix product, like in math ``a @ b`` applies ``b`` first and ``a`` afterward. It is
+        the same as ``b.then(a)``
+        """
+        ...
+
+    def __call__(self, color: Color) -> Color: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __ne__(self, other: object) -> bool: ...
+    def __repr__(self) -> str: ...
+    def __copy__(self) -> "ColorMatrix": ...
+    def __deepcopy__(self, memo: dict) -> "ColorMatrix": ...
+    def __reduce__(self) -> tuple: ...
@@ -0,0 +1,472 @@
+from os import PathLike
+from typing import Iterator, overload
+
+from ._color import Color
+
+class Palette:
+    name: str | None
+    """The name of the palette, it is kept when reading and writing palette files"""
+    columns: int | None
+    """A hint of how many columns the palette should be displayed in, used by the GIMP palette format"""
+
+    def __init__(self, colors: list[Color] | None = None, name: str | None = None) -> None:
+        """
+        Palette is an ordered collection of colors that behaves like a Python sequence, it supports indexing
+        (including negative indices), slicing, iteration, membership tests and equality. Slicing a palette
+        results in a new palette. Every color can optionally carry a name (see [names])
+
+        :param colors: The colors of the palette, by default the palette starts out empty
+        :param name: The name of the palette, by default None
+        """
+        ...
+
+    @staticmethod
+    def random(
+            n: int,
+            seed: int | None = None,
+            style: str | None = None,
+            hue_strategy: str = "golden_ratio"
+    ) -> "Palette":
+        """
+        Generates a palette of n random colors which are spaced out in hue and drawn inside OKLCH, so the colors
+        share a perceptual lightness and chroma range instead of looking arbitrary. Colors outside the sRGB gamut
+        have their chroma reduced until they fit, which keeps their hue. The supported styles are:
+
+        - "pastel" for light colors with a low chroma
+        - "vivid" for medium light colors with a high chroma
+        - "dark" for dark colors with a moderate chroma
+        - None for colors drawn from a broad lightness and chroma range
+
+        The hue strategies are "golden_ratio" (every hue is the previous one rotated by the golden angle, which
+        never lands close to an earlier hue), "even" (the hues are evenly spaced around the wheel) and "random"
+
+        :param n: The amount of colors to generate
+        :param seed: The seed of the random number generator, the same seed always results in the same palette.
+            By default, a random seed is used
+        :param style: The lightness and chroma constraints of the colors, by default None
+        :param hue_strategy: How the hues are spaced apart, by default "golden_ratio"
+
+        :raises ValueError: If the style or the hue strategy is unknown
+        """
+        ...
+
+    @staticmethod
+    def brewer(name: str, n: int | None = None) -> "Palette":
+        """
+        Looks up a ColorBrewer palette, such as ``Palette.brewer("RdYlBu", 9)``. The schemes and their largest
+        palettes are also available as the constants of :mod:`tincture.brewer`
+
+        :param name: The name of the scheme, the case is ignored
+        :param n: The amount of colors, every scheme offers at least 3 and at most 9 (sequential), 11 (diverging)
+            or between 8 and 12 (qualitative) colors. By default the largest palette of the scheme
+
+        :return: The palette named after its scheme
+
+        :raises ValueError: If the scheme is unknown or it has no palette with n colors
+        """
+        ...
+
+    @staticmethod
+    def web_safe() -> "Palette":
+        """
+        Creates the 216 color web-safe palette, every channel is one of 0, 51, 102, 153, 204 or 255. Combined with
+        [nearest] this finds the perceptually closest web-safe color
+
+        :return: The web-safe palette ordered by red, then green, then blue
+        """
+        ...
+
+    def to_web_safe(self) -> "Palette":
+        """
+        Returns a new palette where every color has been snapped to the web-safe cube channel by channel, see
+        ``Color.to_web_safe``. The names and groups of the colors are kept
+        """
+        ...
+
+    @staticmethod
+    def from_gpl(text_or_path: str | PathLike) -> "Palette":
+        """
+        Reads a GIMP palette (.gpl), the lingua franca of pixel-art palettes. The name of the palette, its column
+        hint and the name of every color are kept. The format has no alpha channel, so all colors are opaque
+
+        :param text_or_path: Either the contents of the palette (a string starting with "GIMP Palette") or the path
+        of the file to read
+
+        :raises ValueError: If the header is missing or a line is not a valid color entry
+        """
+        ...
+
+    def to_gpl(self, name: str | None = None, path: str | PathLike | None = None) -> str | None:
+        """
+        Writes the palette in the GIMP palette format (.gpl) including the color names and the [columns] hint.
+        The alpha channel is dropped since the format cannot hold it
+
+        :param name: The name written into the header, by default the [name] of the palette or "Untitled"
+        :param path: The file to write the palette into, when omitted the text is returned instead
+
+        :return: The palette as text, or None when it was written into a file
+        """
+        ...
+
+    @staticmethod
+    def from_json(text: str) -> "Palette":
+        """
+        Reads a palette from the JSON produced by [to_json]. A bare JSON list of colors is accepted as well, every
+        color may either be a hex string or an object holding the hex string under "color" alongside an optional
+        "name" and "group"
+
+        :param text: The JSON text
+
+        :raises ValueError: If the JSON is invalid, a color entry is missing or is not a valid hex string
+        """
+        ...
+
+    def to_json(self, indent: int | None = None) -> str:
+        """
+        Serializes the palette into JSON of the shape ``{"name": ..., "columns": ..., "colors": [...]}``, where
+        every color is an object holding its hex string under "color" as well as its "name" and "group" when it has
+        them. The alpha channel is only written for colors which are not fully opaque
+
+        :param indent: The indentation of the JSON, by default everything is written in a single line
+
+        :return: The JSON text
+        """
+        ...
+
+    @staticmethod
+    def from_hex_text(text: str) -> "Palette":
+        """
+        Reads a palette from text holding one hex color per line (such as "#ff8800" or "ff8800cc"). Blank lines
+        and comments starting with ";" or "//" are skipped
+
+        :param text: The text containing the hex colors
+
+        :raises ValueError: If a line is not a valid hex color
+        """
+        ...
+
+    def to_text(self) -> str:
+        """
+        Exports the palette into a line-oriented text format that is meant to be stored inside version control.
+        The first lines hold the [name] and [columns] of the palette (when set), followed by one "position hex name"
+        line per color sorted by position, so changing a single color results in a single changed line inside the
+        diff. A "group" line starts every run of colors that share a [groups] entry and a bare "group" line ends
+        it. For example::
+
+            # tincture palette
+            name Brand
+            0 #ff0000ff Primary
+            group Blues
+            1 #0000ffff Sky
+            2 #000080ff
+
+        The text can be turned back into a palette with :func:`from_text() <tincture.Palette.from_text>`
+        """
+        ...
+
+    @staticmethod
+    def from_text(text: str) -> "Palette":
+        """
+        Parses a palette from the text format produced by :func:`to_text() <tincture.Palette.to_text>`. The colors
+        are ordered by their positions, empty lines and lines starting with "#" are ignored
+
+        :param text: The text to parse
+
+        :raises ValueError: If a line cannot be interpreted or two colors share a position
+        """
+        ...
+
+    def to_hex_text(self, include_transparency: bool = False) -> str:
+        """
+        Writes the palette as one hex color per line, the names and groups of the colors are dropped
+
+        :param include_transparency: Whether the alpha channel is appended to every hex color, by default False
+
+        :return: The text containing the hex colors
+        """
+        ...
+
+    @staticmethod
+    def from_ase(data_or_path: bytes | bytearray | memoryview | str | PathLike) -> "Palette":
+        """
+        Reads an Adobe Swatch Exchange file (.ase). The colors inside a group remember the name of the group
+        (see [groups]) and every color keeps its name. Colors stored as CMYK, Lab (relative to D50) or Gray are
+        converted into sRGB, the format has no alpha channel so all colors are opaque
+
+        :param data_or_path: Either the raw bytes of the file or the path of the file to read
+
+        :raises ValueError: If the data is not a valid swatch exchange file or uses an unsupported color model
+        """
+        ...
+
+    def to_ase(self, path: str | PathLike | None = None) -> bytes | None:
+        """
+        Writes the palette as an Adobe Swatch Exchange file (.ase), consecutive colors that share a [groups] entry
+        are written inside the same group. The colors are written as RGB and the alpha channel is dropped
+
+        :param path: The file to write the palette into, when omitted the bytes are returned instead
+
+        :return: The contents of the file, or None when it was written into a file
+
+        :raises ValueError: If a name or group is longer than the 65534 UTF-16 code units the format can hold
+        """
+        ...
+
+    @staticmethod
+    def from_aco(data_or_path: bytes | bytearray | memoryview | str | PathLike) -> "Palette":
+        """
+        Reads a Photoshop swatch file (.aco), the color names of the version 2 section are used when it is present.
+        Colors stored as HSB, CMYK, Lab (relative to D50) or Grayscale are converted into sRGB. The format neither
+        has groups nor an alpha channel, so all colors are opaque
+
+        :param data_or_path: Either the raw bytes of the file or the path of the file to read
+
+        :raises ValueError: If the data is not a valid Photoshop swatch file or uses an unsupported color space
+        """
+        ...
+
+    def to_aco(self, path: str | PathLike | None = None) -> bytes | None:
+        """
+        Writes the palette as a Photoshop swatch file (.aco) containing both the version 1 section and the named
+        version 2 section. The colors are written as RGB, the alpha channel and the [groups] are dropped
+
+        :param path: The file to write the palette into, when omitted the bytes are returned instead
+
+        :return: The contents of the file, or None when it was written into a file
+
+        :raises ValueError: If the palette has more than the 65535 colors the format can hold
+        """
+        ...
+
+    def to_pdf(self, path: str | PathLike | None = None, layout: str = "grid") -> bytes | None:
+        """
+        Renders the palette as a printable A4 swatch sheet, so print designers can proof generated palettes on
+        paper. The sheet starts with the [name] of the palette and every swatch is labelled with its name, hex
+        value, CMYK percentages and CIELAB values relative to D50 (the white point of print). Colors that are not
+        opaque are shown on top of white, as paper has no transparency. Palettes that do not fit onto a single page
+        continue on the next one
+
+        :param path: The file to write the PDF into, when omitted the bytes are returned instead
+        :param layout: Either "grid" which places the labels below the swatches, using the [columns] of the palette
+            (by default 4, at most 5), or "list" which places one swatch per row with the labels next to it. By
+            default, it is set to "grid"
+
+        :return: The contents of the file, or None when it was written into a file
+
+        :raises ValueError: If the layout is unknown
+        """
+        ...
+
+    @staticmethod
+    def from_colors_median_cut(colors_or_buffer: list[Color] | bytes | bytearray | memoryview, count: int) -> "Palette":
+        """
+        Reduces the supplied colors into a palette of at most count colors via Heckbert's median cut. All colors are
+        placed into a box which is repeatedly split at the median of its widest channel, until there are count
+        boxes. The palette contains the average color of every box. The quantization runs without holding the GIL
+
+        :param colors_or_buffer: Either a list of colors or a buffer of tightly packed RGBA pixels (4 bytes per pixel)
+        such as the raw pixel data of an image
+        :param count: The maximum amount of colors inside the palette, fewer colors are returned if there are not
+        enough distinct colors
+
+        :raises ValueError: If count is zero, there are no colors or the buffer length is not a multiple of 4
+        """
+        ...
+
+    @staticmethod
+    def from_pixels(
+            buffer: bytes | bytearray | memoryview,
+            stride: int | None = None,
+            sample_rate: int = 1
+    ) -> "Palette":
+        """
+        Builds a palette out of every distinct color of a raw pixel buffer, ordered from the most to the least
+        common color. Any object implementing the buffer protocol with unsigned bytes is accepted, such as a numpy
+        array of uint8 or the result of PIL's ``Image.tobytes()``. Counting the colors runs without holding the GIL
+
+        :param buffer: The pixel data, with the channels of every pixel in RGBA order
+        :param stride: The distance in bytes from the start of one pixel to the next, by default 4 (tightly packed
+            RGBA pixels). A stride of 3 reads tightly packed RGB pixels, which are all opaque
+        :param sample_rate: Only every sample_rate-th pixel is counted, which speeds up large images, by default 1
+
+        :raises ValueError: If the stride is below 3, the sample rate is zero or the buffer length is not a
+            multiple of the stride
+        """
+        ...
+
+    @staticmethod
+    def octree(colors_or_buffer: list[Color] | bytes | bytearray | memoryview, count: int) -> "Palette":
+        """
+        Reduces the supplied colors into a palette of at most count colors via octree quantization. Every distinct
+        color is inserted into an RGB octree, afterward the least populated branches of the deepest level get merged
+        until there are count leaves left. It is much faster than [kmeans] for large images and usually keeps the
+        exact colors of flat-color art. The quantization runs without holding the GIL
+
+        :param colors_or_buffer: Either a list of colors or a buffer of tightly packed RGBA pixels (4 bytes per pixel)
+        :param count: The maximum amount of colors inside the palette, merging whole branches may result in fewer
+
+        :raises ValueError: If count is zero, there are no colors or the buffer length is not a multiple of 4
+        """
+        ...
+
+    @staticmethod
+    def kmeans(
+            colors_or_buffer: list[Color] | bytes | bytearray | memoryview,
+            k: int,
+            max_iter: int = 100,
+            seed: int | None = None
+    ) -> "Palette":
+        """
+        Extracts the k dominant colors via k-means clustering, which is useful for pulling a palette out of a
+        screenshot or photo. The clusters are seeded with k-means++ and measured inside Oklab, the resulting palette
+        holds the average color of every cluster ordered from the most to the least common one. The clustering runs
+        without holding the GIL
+
+        :param colors_or_buffer: Either a list of colors or a buffer of tightly packed RGBA pixels (4 bytes per pixel)
+        :param k: The amount of clusters, fewer colors are returned if there are not enough distinct colors
+        :param max_iter: The maximum amount of iterations before the clustering stops, by default 100
+        :param seed: The seed of the random number generator, supplying it makes the result reproducible
+
+        :raises ValueError: If k is zero, there are no colors or the buffer length is not a multiple of 4
+        """
+        ...
+
+    @property
+    def colors(self) -> list[Color]:
+        """A copy of the colors inside the palette"""
+        ...
+
+    @property
+    def names(self) -> list[str | None]:
+        """A copy of the name of every color, None for the unnamed colors"""
+        ...
+
+    @property
+    def groups(self) -> list[str | None]:
+        """A copy of the group every color belongs to, None for the colors outside any group"""
+        ...
+
+    def append(self, color: Color, name: str | None = None, group: str | None = None) -> None:
+        """
+        Adds a color to the end of the palette
+
+        :param color: The color to add
+        :param name: The name of the color, by default None
+        :param group: The group the color belongs to, by default None
+        """
+        ...
+
+    def extend(self, colors: list[Color]) -> None:
+        """
+        Adds multiple colors to the end of the palette
+
+        :param colors: The colors to add
+        """
+        ...
+
+    def nearest(self, color: Color, metric: str = "oklab") -> Color:
+        """
+        Finds the color of the palette that is closest to the supplied color. The distance is the euclidean
+        distance inside the metric's color space, the lookup is backed by a KD-tree which is built once per metric
+        and reused until the palette is modified, which makes repeated lookups much faster than a linear scan
+
+        :param color: The color to find the closest palette color of
+        :param metric: The color space the distance is measured in, it can be "oklab", "lab", "srgb", "linear"
+        or "xyz". By default, it is set to "oklab"
+
+        :raises ValueError: If the palette is empty or the metric is a cylindrical color space
+        """
+        ...
+
+    def nearest_many(self, colors: list[Color], metric: str = "oklab") -> list[Color]:
+        """
+        Finds the closest palette color for every supplied color at once, this is done without holding
+        the GIL. Works the same way as :func:`nearest() <tincture.Palette.nearest>`
+
+        :param colors: The colors to find the closest palette colors of
+        :param metric: The color space the distance is measured in, by default "oklab"
+
+        :raises ValueError: If the palette is empty or the metric is a cylindrical color space
+        """
+        ...
+
+    def sorted(self, by: str = "hue", reverse: bool = False) -> "Palette":
+        """
+        Returns a new palette with the colors sorted, the names and groups of the colors move along with them.
+        The sorting itself runs without holding the GIL. The supported orders are:
+
+        - "hue" sorts by the HSV hue, ties are broken by the luminance
+        - "luminance" sorts by the relative luminance (the Y of CIEXYZ), from dark to light
+        - "saturation" sorts by the HSV saturation, from gray to fully saturated
+        - "step" splits the hues into 8 bands and sorts each band by luminance, alternating the direction of every
+          other band. This avoids the noisy look pure hue sorting has on palettes with varied lightness
+
+        :param by: The order the colors are sorted by, by default "hue"
+        :param reverse: Whether the order is reversed, by default False
+
+        :raises ValueError: If the sort order is unknown
+        """
+        ...
+
+    def dedup(self, tolerance: float = 2.0, metric: str = "ciede2000", merge: bool = False) -> "Palette":
+        """
+        Collapses the colors that are perceptually closer than the tolerance, returning a new palette.
+        The colors are visited in order, every color joins the first kept color it is close enough to,
+        otherwise it is kept itself. Alpha is not taken into account while measuring the distances
+
+        The supported metrics are "ciede2000", "cie76" (the euclidean distance inside CIELAB) and
+        "oklab" (the euclidean distance inside Oklab scaled by 100, so the tolerances are comparable)
+
+        :param tolerance: The distance below which two colors are considered duplicates, by default 2.0
+        :param metric: The color difference formula used, by default "ciede2000"
+        :param merge: Whether every group of duplicates is replaced by its average (in Oklab) instead
+            of the first color of the group, by default False
+
+        :raises ValueError: If the tolerance is negative or the metric is unknown
+        """
+        ...
+
+    def to_svg(self, width: float = 480.0, height: float = 120.0, labels: bool = True) -> str:
+        """
+        Renders the palette as a standalone SVG document, useful for documentation and design handoff. The
+        swatches are laid out in a single row, or in a grid when [columns] is set
+
+        :param width: The width of the whole image, by default 480
+        :param height: The height of the whole image, by default 120
+        :param labels: Whether every swatch is labelled with its hex value (and its name when it has one) in a
+            color readable on top of it, by default True
+
+        :raises ValueError: If the width or the height is not positive
+        """
+        ...
+
+    def fingerprint(self) -> str:
+        """
+        A stable hash of the colors inside the palette as 16 hex digits, which lets caching layers and asset
+        pipelines detect changed palettes without comparing them color by color. Like equality, only the colors
+        and their order count, names and groups do not. The hash stays the same between runs and platforms
+        """
+        ...
+
+    def _repr_html_(self) -> str:
+        """
+        Renders the palette as a row of swatches (or a grid when [columns] is set) below its name, Jupyter
+        notebooks use this to display palettes. Hovering a swatch shows the name and hex value of the color
+        """
+        ...
+
+    def __len__(self) -> int: ...
+    @overload
+    def __getitem__(self, index: int) -> Color: ...
+    @overload
+    def __getitem__(self, index: slice) -> "Palette": ...
+    def __setitem__(self, index: int, color: Color) -> None: ...
+    def __iter__(self) -> Iterator[Color]: ...
+    def __contains__(self, color: Color) -> bool: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __repr__(self) -> str: ...
+    def __copy__(self) -> "Palette": ...
+    def __deepcopy__(self, memo: dict) -> "Palette": ...
+    def __reduce__(self) -> tuple: ...
+    def __getstate__(self) -> tuple[list[str | None], list[str | None], int | None]: ...
+    def __setstate__(self, state: tuple[list[str | None], list[str | None], int | None]) -> None: ...
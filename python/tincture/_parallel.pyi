@@ -0,0 +1,19 @@
+def set_num_threads(count: int | None = None) -> None:
+    """
+    Sets the amount of threads used by large batch operations, such as the math of a :class:`ColorArray`, the
+    conversions of :mod:`tincture.batch` or k-means quantization. Operations only get split across the threads once
+    they cover more than 16384 colors, smaller ones stay on the calling thread. The threads are separate from the
+    ones of Python and run without holding the GIL
+
+    :param count: The amount of threads, if it is not supplied then one thread per CPU core is used (the default)
+
+    :raises ValueError: If the amount of threads is 0
+    """
+    ...
+
+def get_num_threads() -> int:
+    """
+    Gets the amount of threads used by large batch operations, as configured by
+    :func:`set_num_threads() <tincture.set_num_threads>`
+    """
+    ...
@@ -0,0 +1,16 @@
+from ._color import Color
+
+def pick(initial: Color | None = None) -> Color | None:
+    """
+    Opens an interactive color picker inside the terminal and blocks until a color is picked. The picker
+    consists of hue, saturation and lightness sliders together with a truecolor preview of the current color,
+    so the terminal has to support 24-bit colors for the preview to be accurate
+
+    The up / down arrow keys select a slider, left / right adjust it (holding shift moves faster), enter
+    accepts the color and escape (or "q") cancels the picker. If stdin / stdout are not attached to a terminal,
+    then it throws an OSError
+
+    :param initial: The color the picker starts from, its alpha channel is kept on the picked color. By default, red
+    :return: The picked color, or None when the picker was cancelled
+    """
+    ...
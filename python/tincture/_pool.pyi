@@ -0,0 +1,86 @@
+from typing import Optional
+
+from ._color import Color
+
+class ColorPool:
+    def __init__(self, colors: Optional[list[Color]] = None) -> None:
+        """
+        ColorPool interns colors and hands out lightweight handles for them, a handle is simply the index of the
+        color inside the pool. Interning the same color twice results in the same handle, so a theming engine
+        can store hundreds of thousands of small integers that reference a few hundred unique colors instead
+        of storing a color object for every styled node
+
+        :param colors: The colors to intern right away, by default the pool starts out empty
+        """
+        ...
+
+    def intern(self, color: Color) -> int:
+        """
+        Interns the color and returns its handle, colors that are already inside the pool keep their handle
+
+        :param color: The color to intern
+        """
+        ...
+
+    def intern_many(self, colors: list[Color]) -> list[int]:
+        """
+        Interns multiple colors at once and returns their handles in the same order
+
+        :param colors: The colors to intern
+        """
+        ...
+
+    def handle_of(self, color: Color) -> Optional[int]:
+        """
+        Looks up the handle of the color without interning it, if the color is not inside the pool it returns None
+
+        :param color: The color to look up
+        """
+        ...
+
+    def get(self, handle: int) -> Color:
+        """
+        Returns the color the handle refers to
+
+        :param handle: The handle of the color
+
+        :raises IndexError: If the handle does not belong to the pool
+        """
+        ...
+
+    def resolve(self, handles: list[int]) -> list[Color]:
+        """
+        Converts a list of handles back into their colors in bulk
+
+        :param handles: The handles to convert
+
+        :raises IndexError: If any of the handles does not belong to the pool
+        """
+        ...
+
+    def resolve_rgba(self, handles: list[int]) -> bytes:
+        """
+        Converts a list of handles into packed RGBA pixels (4 bytes per handle) in bulk, the conversion
+        is done without holding the GIL
+
+        :param handles: The handles to convert
+
+        :raises IndexError: If any of the handles does not belong to the pool
+        """
+        ...
+
+    @property
+    def colors(self) -> list[Color]:
+        """All the interned colors, ordered by their handle"""
+        ...
+
+    def clear(self) -> None:
+        """Removes every color from the pool, all the previously handed out handles become invalid"""
+        ...
+
+    def __getitem__(self, handle: int) -> Color: ...
+    def __contains__(self, color: Color) -> bool: ...
+    def __len__(self) -> int: ...
+    def __repr__(self) -> str: ...
+    def __copy__(self) -> "ColorPool": ...
+    def __deepcopy__(self, memo: dict) -> "ColorPool": ...
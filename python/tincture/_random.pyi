@@ -0,0 +1,101 @@
+from typing import Optional
+
+from ._color import Color
+
+class RandomColorGenerator:
+    def __init__(self, seed: Optional[int] = None) -> None:
+        """
+        RandomColorGenerator holds its own random number generator, so that procedural generation can be made
+        reproducible. Two generators created with the same seed produce the same sequence of colors, independent
+        of anything else drawing random numbers in the meantime
+
+        :param seed: The seed of the generator, by default a random seed is picked and the sequence differs every run
+        """
+        ...
+
+    @property
+    def seed(self) -> Optional[int]:
+        """The seed the generator was created or last reseeded with, None when it was picked at random"""
+        ...
+
+    def reseed(self, seed: Optional[int] = None) -> None:
+        """
+        Restarts the generator from the seed, which replays the same sequence of colors as a new generator would
+
+        :param seed: The new seed of the generator, by default a random seed is picked
+        """
+        ...
+
+    def color(self, random_alpha: bool = False) -> Color:
+        """
+        Draws a color whose channels are uniformly random
+
+        :param random_alpha: Whenever to randomize the alpha too, by default the color is opaque
+        """
+        ...
+
+    def colors(self, n: int, random_alpha: bool = False) -> list[Color]:
+        """
+        Draws multiple colors at once, the same way as calling :func:`color() <tincture.RandomColorGenerator.color>`
+        n times
+
+        :param n: The amount of colors to draw
+        :param random_alpha: Whenever to randomize the alpha too, by default the colors are opaque
+        """
+        ...
+
+    def random(
+            self,
+            space: str = "oklch",
+            l: tuple[float, float] | None = (0.7, 0.9),
+            c: tuple[float, float] | None = (0.05, 0.12),
+            h: tuple[float, float] | None = None
+    ) -> Color:
+        """
+        Draws a random color whose components lie inside the ranges of a cylindrical color space, the same way as
+        :func:`Color.random() <tincture.Color.random>` but drawing from this generator
+
+        :param space: The space the components are drawn in, either "oklch", "lch", "hsl" or "hsv". By default "oklch"
+        :param l: The (start, end) range of the lightness, by default (0.7, 0.9)
+        :param c: The (start, end) range of the chroma or saturation, by default (0.05, 0.12)
+        :param h: The (start, end) range of the hue in degrees, by default the whole color wheel
+
+        :raises ValueError: If the space is not one of the cylindrical spaces, or a range is not finite or its start
+            lies above its end
+        """
+        ...
+
+    def jitter(self, color: Color, sigma: float = 5.0, channels: str | None = None, space: str = "rgb") -> Color:
+        """
+        Adds Gaussian noise to the channels of the color, the same way as
+        :func:`Color.jitter() <tincture.Color.jitter>` but drawing from this generator
+
+        :param color: The color to jitter
+        :param sigma: The standard deviation of the noise on a scale of 0 to 255, by default 5.0
+        :param channels: The names of the channels to jitter, by default every channel except the alpha
+        :param space: The space the noise is added in, either "rgb" or "oklch". By default "rgb"
+
+        :raises ValueError: If the sigma is negative or not finite, the space is unknown or a channel does not
+            belong to the space
+        """
+        ...
+
+    # noinspection PyDefaultArgument
+    def randomise(
+            self,
+            color: Color,
+            start: list[int | None] = [0, 0, 0, 0],
+            end: list[int | None] = [255, 255, 255, 255]
+    ) -> Color:
+        """
+        Randomizes the channels of the color within the ranges, the same way as
+        :func:`Color.randomise() <tincture.Color.randomise>` but drawing from this generator
+
+        :param color: The color whose channels are kept where both ends of the range are None
+        :param start: The starting range for the randomization
+        :param end: The ending range for the randomization
+
+        :raises IndexError: If a starting range is not below its ending range
+        :raises ValueError: If only one end of a range is None
+        """
+        ...
@@ -0,0 +1,78 @@
+from typing import Callable, Sequence, Union, overload
+
+from ._color import Color
+
+ChangeCallback = Callable[[Color, Color], None]
+
+class ReactiveColor:
+    def __init__(self, color: Color) -> None:
+        """
+        ReactiveColor wraps a color and notifies the registered callbacks whenever one of its channels is mutated,
+        either through the channel properties, through item assignment or by replacing the whole color. This lets
+        GUI bindings react to edits made from Python without having to poll the color for changes
+
+        :param color: The initial color
+        """
+        ...
+
+    def on_change(self, callback: ChangeCallback) -> ChangeCallback:
+        """
+        Registers a callback that is called with the old and the new color every time the color changes,
+        assignments that leave the color as is do not call the callbacks. The callback itself is returned,
+        so this method can also be used as a decorator
+
+        :param callback: The function to call once the color changes
+        """
+        ...
+
+    def remove_on_change(self, callback: ChangeCallback) -> None:
+        """
+        Unregisters a callback that was previously registered via [on_change]
+
+        :param callback: The callback to unregister
+
+        :raises ValueError: If the callback is not registered on this color
+        """
+        ...
+
+    @property
+    def color(self) -> Color:
+        """A copy of the wrapped color, assigning a new color notifies the callbacks"""
+        ...
+
+    @color.setter
+    def color(self, value: Color) -> None: ...
+
+    @property
+    def r(self) -> int: ...
+
+    @r.setter
+    def r(self, value: int) -> None: ...
+
+    @property
+    def g(self) -> int: ...
+
+    @g.setter
+    def g(self, value: int) -> None: ...
+
+    @property
+    def b(self) -> int: ...
+
+    @b.setter
+    def b(self, value: int) -> None: ...
+
+    @property
+    def a(self) -> int: ...
+
+    @a.setter
+    def a(self, value: int) -> None: ...
+
+    @overload
+    def __getitem__(self, access_code: Union[int, str]) -> int: ...
+    @overload
+    def __getitem__(self, access_code: slice) -> list[int]: ...
+    @overload
+    def __setitem__(self, access_code: Union[int, str], new_value: int) -> None: ...
+    @overload
+    def __setitem__(self, access_code: slice, new_value: Sequence[int]) -> None: ...
+    def __repr__(self) -> str: ...
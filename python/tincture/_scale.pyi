@@ -0,0 +1,59 @@
+from ._color import Color
+from ._gradient import Gradient
+
+class ColorScale:
+    def __init__(self, gradient: Gradient | list[Color], domain: list[float] | None = None, clamp: bool = True) -> None:
+        """
+        ColorScale maps numeric data values onto the colors of a gradient, much like chroma.js's scales. The domain
+        describes which data values correspond to the start and the end of the gradient, so there is no need to
+        normalize the data by hand. Supplying more than two values results in a piecewise domain, where each piece
+        takes up an equal share of the gradient. For example the domain [0, 10, 100] maps 0 to 10 onto the first
+        half of the gradient and 10 to 100 onto the second half
+
+        :param gradient: The gradient to map the values onto, a list of colors is turned into an evenly spaced
+        gradient that is interpolated in "oklab"
+        :param domain: The strictly increasing or strictly decreasing data values, by default [0.0, 1.0]
+        :param clamp: Whenever values outside the domain get clamped to the ends of the gradient, otherwise
+        they are mapped to None. By default, it is set to True
+
+        :raises ValueError: If the domain has less than two values or is not strictly monotonic
+        """
+        ...
+
+    @property
+    def gradient(self) -> Gradient:
+        """The gradient the values are mapped onto"""
+        ...
+
+    @property
+    def domain(self) -> list[float]:
+        """The data values that correspond to the gradient"""
+        ...
+
+    @property
+    def clamp(self) -> bool:
+        """Whenever values outside the domain get clamped to the ends of the gradient"""
+        ...
+
+    def scale(self, value: float) -> Color | None:
+        """
+        Maps the value onto the color of the gradient, NaN values and (when clamping is disabled) values
+        outside the domain result in None
+
+        :param value: The data value to map
+        """
+        ...
+
+    def scale_many(self, values: list[float]) -> list[Color | None]:
+        """
+        Maps multiple values onto their colors at once, this is done without holding the GIL.
+        Works the same way as :func:`scale() <tincture.ColorScale.scale>`
+
+        :param values: The data values to map
+        """
+        ...
+
+    def __call__(self, value: float) -> Color | None: ...
+    def __repr__(self) -> str: ...
+    def __copy__(self) -> "ColorScale": ...
+    def __deepcopy__(self, memo: dict) -> "ColorScale": ...
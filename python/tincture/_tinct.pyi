@@ -0,0 +1,24 @@
+from os import PathLike
+from typing import Any
+
+from ._color import Color
+
+def dump(obj: Any, path: str | PathLike) -> None:
+    """
+    Writes a tincture object into a ".tinct" file. A tinct file is a JSON container that stores the format
+    name together with a format version, so files written by older versions of tincture can still be read
+    by newer versions. It can store colors, palettes, gradients and (nested) lists of them
+
+    :param obj: The object to be stored inside the tinct file
+    :param path: The path of the file to write into, it will be overwritten if it already exists
+    """
+    ...
+
+def load(path: str | PathLike) -> Any:
+    """
+    Reads a ".tinct" file written by :func:`dump` and reconstructs the stored object. If the file is not
+    a tinct file or it was written with a newer format version than the one supported, then it throws an error
+
+    :param path: The path of the tinct file to read from
+    """
+    ...
@@ -0,0 +1,517 @@
+"""
+Conversions of whole buffers of colors at once, such as the pixels of video frames. Every function copies the buffer
+and then converts it in Rust without holding the GIL, so worker threads can convert frames while the main thread
+keeps running. Pixels are always tightly packed RGBA bytes (bytes, bytearray, :class:`ColorArray <tincture.ColorArray>`
+or a ``uint8`` NumPy array), while the components are packed as native endian 32-bit floats, 4 per color holding the
+three components followed by the alpha from 0.0 to 1.0. This way ``numpy.frombuffer(data, dtype=numpy.float32)``
+reads the components, and ``float32`` NumPy arrays can be passed straight back in
+
+The adjustments (:func:`brightness() <tincture.batch.brightness>` and the others) work the same way as their
+:class:`Color <tincture.Color>` counterparts, but on every pixel of the buffer. They return the adjusted pixels as new
+bytes, or with ``in_place=True`` write them back into the buffer (such as a bytearray or a writable NumPy array)
+and return None
+"""
+
+from ._array import ColorArray
+from ._color import Color
+from ._matrix import ColorMatrix
+
+def to_components(buffer: bytes | bytearray | memoryview | ColorArray, space: str = "oklab") -> bytes:
+    """
+    Converts RGBA pixels into the components of a color space
+
+    :param buffer: The tightly packed RGBA pixels, 4 bytes per color
+    :param space: The color space to convert into, by default "oklab"
+
+    :return: The three components of every color followed by its alpha, packed as 32-bit floats
+
+    :raises ValueError: If the buffer length is not a multiple of 4 or the color space is unknown
+    """
+    ...
+
+def from_components(buffer: bytes | bytearray | memoryview, space: str = "oklab") -> bytes:
+    """
+    Converts the components of a color space back into RGBA pixels, the reverse of
+    :func:`to_components() <tincture.batch.to_components>`. Components outside the sRGB gamut are brought into it
+    the same way as :func:`Color.clamp_to_srgb() <tincture.Color.clamp_to_srgb>` does
+
+    :param buffer: The 32-bit floats, 4 per color holding the three components followed by the alpha
+    :param space: The color space the components belong to, by default "oklab"
+
+    :return: The tightly packed RGBA pixels, 4 bytes per color
+
+    :raises ValueError: If the buffer length is not a multiple of 4 or the color space is unknown
+    """
+    ...
+
+def to_linear(buffer: bytes | bytearray | memoryview | ColorArray) -> bytes:
+    """
+    Converts RGBA pixels into linear sRGB as described by :func:`to_components() <tincture.batch.to_components>`
+
+    :param buffer: The tightly packed RGBA pixels, 4 bytes per color
+
+    :raises ValueError: If the buffer length is not a multiple of 4
+    """
+    ...
+
+def from_linear(buffer: bytes | bytearray | memoryview) -> bytes:
+    """
+    Converts linear sRGB back into RGBA pixels as described by :func:`from_components() <tincture.batch.from_components>`
+
+    :param buffer: The 32-bit floats, 4 per color
+
+    :raises ValueError: If the buffer length is not a multiple of 4
+    """
+    ...
+
+def to_oklab(buffer: bytes | bytearray | memoryview | ColorArray) -> bytes:
+    """
+    Converts RGBA pixels into Oklab as described by :func:`to_components() <tincture.batch.to_components>`
+
+    :param buffer: The tightly packed RGBA pixels, 4 bytes per color
+
+    :raises ValueError: If the buffer length is not a multiple of 4
+    """
+    ...
+
+def from_oklab(buffer: bytes | bytearray | memoryview) -> bytes:
+    """
+    Converts Oklab back into RGBA pixels as described by :func:`from_components() <tincture.batch.from_components>`
+
+    :param buffer: The 32-bit floats, 4 per color
+
+    :raises ValueError: If the buffer length is not a multiple of 4
+    """
+    ...
+
+def to_oklch(buffer: bytes | bytearray | memoryview | ColorArray) -> bytes:
+    """
+    Converts RGBA pixels into OKLCH as described by :func:`to_components() <tincture.batch.to_components>`
+
+    :param buffer: The tightly packed RGBA pixels, 4 bytes per color
+
+    :raises ValueError: If the buffer length is not a multiple of 4
+    """
+    ...
+
+def from_oklch(buffer: bytes | bytearray | memoryview) -> bytes:
+    """
+    Converts OKLCH back into RGBA pixels as described by :func:`from_components() <tincture.batch.from_components>`
+
+    :param buffer: The 32-bit floats, 4 per color
+
+    :raises ValueError: If the buffer length is not a multiple of 4
+    """
+    ...
+
+def to_hsv(buffer: bytes | bytearray | memoryview | ColorArray) -> bytes:
+    """
+    Converts RGBA pixels into HSV as described by :func:`to_components() <tincture.batch.to_components>`
+
+    :param buffer: The tightly packed RGBA pixels, 4 bytes per color
+
+    :raises ValueError: If the buffer length is not a multiple of 4
+    """
+    ...
+
+def from_hsv(buffer: bytes | bytearray | memoryview) -> bytes:
+    """
+    Converts HSV back into RGBA pixels as described by :func:`from_components() <tincture.batch.from_components>`
+
+    :param buffer: The 32-bit floats, 4 per color
+
+    :raises ValueError: If the buffer length is not a multiple of 4
+    """
+    ...
+
+def to_hsl(buffer: bytes | bytearray | memoryview | ColorArray) -> bytes:
+    """
+    Converts RGBA pixels into HSL as described by :func:`to_components() <tincture.batch.to_components>`
+
+    :param buffer: The tightly packed RGBA pixels, 4 bytes per color
+
+    :raises ValueError: If the buffer length is not a multiple of 4
+    """
+    ...
+
+def from_hsl(buffer: bytes | bytearray | memoryview) -> bytes:
+    """
+    Converts HSL back into RGBA pixels as described by :func:`from_components() <tincture.batch.from_components>`
+
+    :param buffer: The 32-bit floats, 4 per color
+
+    :raises ValueError: If the buffer length is not a multiple of 4
+    """
+    ...
+
+def to_lab(buffer: bytes | bytearray | memoryview | ColorArray) -> bytes:
+    """
+    Converts RGBA pixels into CIELAB as described by :func:`to_components() <tincture.batch.to_components>`
+
+    :param buffer: The tightly packed RGBA pixels, 4 bytes per color
+
+    :raises ValueError: If the buffer length is not a multiple of 4
+    """
+    ...
+
+def from_lab(buffer: bytes | bytearray | memoryview) -> bytes:
+    """
+    Converts CIELAB back into RGBA pixels as described by :func:`from_components() <tincture.batch.from_components>`
+
+    :param buffer: The 32-bit floats, 4 per color
+
+    :raises ValueError: If the buffer length is not a multiple of 4
+    """
+    ...
+
+def to_xyz(buffer: bytes | bytearray | memoryview | ColorArray) -> bytes:
+    """
+    Converts RGBA pixels into CIE XYZ as described by :func:`to_components() <tincture.batch.to_components>`
+
+    :param buffer: The tightly packed RGBA pixels, 4 bytes per color
+
+    :raises ValueError: If the buffer length is not a multiple of 4
+    """
+    ...
+
+def from_xyz(buffer: bytes | bytearray | memoryview) -> bytes:
+    """
+    Converts CIE XYZ back into RGBA pixels as described by :func:`from_components() <tincture.batch.from_components>`
+
+    :param buffer: The 32-bit floats, 4 per color
+
+    :raises ValueError: If the buffer length is not a multiple of 4
+    """
+    ...
+
+def brightness(buffer: bytes | bytearray | memoryview | ColorArray, factor: float, in_place: bool = False) -> bytes | None:
+    """
+    Brightens or darkens every pixel, the same way as :func:`Color.brightness() <tincture.Color.brightness>`
+
+    :param buffer: The tightly packed RGBA pixels, 4 bytes per color
+    :param factor: The brightness factor, negative values darken the pixels
+    :param in_place: Whenever to write the adjusted pixels back into the buffer instead of returning them
+
+    :raises ValueError: If the buffer length is not a multiple of 4, it is read-only when adjusting in place
+    or the factor is NaN or infinite
+    """
+    ...
+
+def contrast(buffer: bytes | bytearray | memoryview | ColorArray, factor: float, in_place: bool = False) -> bytes | None:
+    """
+    Increases or reduces the contrast of every pixel, the same way as :func:`Color.contrast() <tincture.Color.contrast>`
+
+    :param buffer: The tightly packed RGBA pixels, 4 bytes per color
+    :param factor: The contrast factor, negative values reduce the contrast
+    :param in_place: Whenever to write the adjusted pixels back into the buffer instead of returning them
+
+    :raises ValueError: If the buffer length is not a multiple of 4, it is read-only when adjusting in place
+    or the factor is NaN or infinite
+    """
+    ...
+
+def saturate(buffer: bytes | bytearray | memoryview | ColorArray, factor: float, in_place: bool = False) -> bytes | None:
+    """
+    Scales the HSV saturation of every pixel, the same way as :func:`Color.saturate() <tincture.Color.saturate>`.
+    The saturation is clamped to [0.0, 1.0]
+
+    :param buffer: The tightly packed RGBA pixels, 4 bytes per color
+    :param factor: The saturation factor, negative values desaturate the pixels
+    :param in_place: Whenever to write the adjusted pixels back into the buffer instead of returning them
+
+    :raises ValueError: If the buffer length is not a multiple of 4, it is read-only when adjusting in place
+    or the factor is NaN or infinite
+    """
+    ...
+
+def vibrance(buffer: bytes | bytearray | memoryview | ColorArray, factor: float, in_place: bool = False) -> bytes | None:
+    """
+    Boosts the saturation of the muted pixels more than of the vivid ones, the same way as
+    :func:`Color.vibrance() <tincture.Color.vibrance>`
+
+    :param buffer: The tightly packed RGBA pixels, 4 bytes per color
+    :param factor: The vibrance factor, negative values desaturate the pixels
+    :param in_place: Whenever to write the adjusted pixels back into the buffer instead of returning them
+
+    :raises ValueError: If the buffer length is not a multiple of 4, it is read-only when adjusting in place
+    or the factor is NaN or infinite
+    """
+    ...
+
+def hue_rotate(
+        buffer: bytes | bytearray | memoryview | ColorArray,
+        degrees: float,
+        space: str = "hsl",
+        in_place: bool = False
+) -> bytes | None:
+    """
+    Rotates the hue of every pixel, the same way as :func:`Color.hue_rotate() <tincture.Color.hue_rotate>`
+
+    :param buffer: The tightly packed RGBA pixels, 4 bytes per color
+    :param degrees: The angle to rotate the hues by, may be negative or fractional
+    :param space: The color space to rotate the hues in, either "hsl", "oklch" or "matrix". By default "hsl"
+    :param in_place: Whenever to write the adjusted pixels back into the buffer instead of returning them
+
+    :raises ValueError: If the buffer length is not a multiple of 4, it is read-only when adjusting in place,
+    the space is unknown or the degrees are NaN or infinite
+    """
+    ...
+
+def grayscale(buffer: bytes | bytearray | memoryview | ColorArray, in_place: bool = False) -> bytes | None:
+    """
+    Turns every pixel into its gray equivalent, the same way as :func:`Color.grayscale() <tincture.Color.grayscale>`
+
+    :param buffer: The tightly packed RGBA pixels, 4 bytes per color
+    :param in_place: Whenever to write the adjusted pixels back into the buffer instead of returning them
+
+    :raises ValueError: If the buffer length is not a multiple of 4, or it is read-only when adjusting in place
+    """
+    ...
+
+def duotone(
+        buffer: bytes | bytearray | memoryview | ColorArray,
+        dark: Color,
+        light: Color,
+        mid: Color | None = None,
+        space: str = "oklab",
+        in_place: bool = False
+) -> bytes | None:
+    """
+    Maps every pixel through two (or three) anchor colors based on its gray value, the same gray value as
+    :func:`Color.grayscale() <tincture.Color.grayscale>` produces. Each pixel becomes the result of
+    :func:`Color.duotone() <tincture.Color.duotone>` while keeping its own alpha
+
+    :param buffer: The tightly packed RGBA pixels, 4 bytes per color
+    :param dark: The color of the shadows
+    :param light: The color of the highlights
+    :param mid: The color of the midtones, by default there is none
+    :param space: The color space the anchors are interpolated in, by default "oklab"
+    :param in_place: Whenever to write the adjusted pixels back into the buffer instead of returning them
+
+    :raises ValueError: If the buffer length is not a multiple of 4, it is read-only when adjusting in place or the
+        space is unknown
+    """
+    ...
+
+def jitter(
+        buffer: bytes | bytearray | memoryview | ColorArray,
+        sigma: float = 5.0,
+        channels: str | None = None,
+        space: str = "rgb",
+        seed: int | None = None,
+        in_place: bool = False
+) -> bytes | None:
+    """
+    Adds Gaussian noise to every pixel, the same way as :func:`Color.jitter() <tincture.Color.jitter>`. Every pixel
+    draws its noise from a generator of its own, so a seeded jitter results in the same pixels no matter how many
+    threads convert the buffer
+
+    :param buffer: The tightly packed RGBA pixels, 4 bytes per color
+    :param sigma: The standard deviation of the noise on a scale of 0 to 255, by default 5.0
+    :param channels: The names of the channels to jitter, by default every channel except the alpha
+    :param space: The space the noise is added in, either "rgb" or "oklch". By default "rgb"
+    :param seed: The seed of the noise which makes the result reproducible, by default a new random seed is picked
+        every call
+    :param in_place: Whenever to write the adjusted pixels back into the buffer instead of returning them
+
+    :raises ValueError: If the buffer length is not a multiple of 4, it is read-only when adjusting in place, the
+        sigma is negative or not finite, the space is unknown or a channel does not belong to the space
+    """
+    ...
+
+def exposure(buffer: bytes | bytearray | memoryview | ColorArray, stops: float, in_place: bool = False) -> bytes | None:
+    """
+    Adjusts the exposure of every pixel by photographic stops, the same way as
+    :func:`Color.exposure() <tincture.Color.exposure>`
+
+    :param buffer: The tightly packed RGBA pixels, 4 bytes per color
+    :param stops: The number of stops to adjust the exposure by, may be negative or fractional
+    :param in_place: Whenever to write the adjusted pixels back into the buffer instead of returning them
+
+    :raises ValueError: If the buffer length is not a multiple of 4, it is read-only when adjusting in place
+    or the number of stops is NaN or infinite
+    """
+    ...
+
+def gamma(buffer: bytes | bytearray | memoryview | ColorArray, gamma: float, in_place: bool = False) -> bytes | None:
+    """
+    Raises the channels of every pixel to the power of the gamma, the same way as
+    :func:`Color.gamma() <tincture.Color.gamma>`
+
+    :param buffer: The tightly packed RGBA pixels, 4 bytes per color
+    :param gamma: The exponent to raise the channels to
+    :param in_place: Whenever to write the adjusted pixels back into the buffer instead of returning them
+
+    :raises ValueError: If the buffer length is not a multiple of 4, it is read-only when adjusting in place
+    or the gamma is not a positive number
+    """
+    ...
+
+def levels(
+        buffer: bytes | bytearray | memoryview | ColorArray,
+        in_black: int,
+        in_white: int,
+        gamma: float = 1.0,
+        out_black: int = 0,
+        out_white: int = 255,
+        luminance: bool = False,
+        in_place: bool = False
+) -> bytes | None:
+    """
+    Performs a levels adjustment on every pixel, the same way as :func:`Color.levels() <tincture.Color.levels>`
+
+    :param buffer: The tightly packed RGBA pixels, 4 bytes per color
+    :param in_black: The input value that turns into the output black point
+    :param in_white: The input value that turns into the output white point
+    :param gamma: The midtone gamma, by default 1.0 (linear)
+    :param out_black: The darkest output value, by default 0
+    :param out_white: The brightest output value, by default 255
+    :param luminance: Whenever to adjust only the gray value of the pixels instead of every channel on its own
+    :param in_place: Whenever to write the adjusted pixels back into the buffer instead of returning them
+
+    :raises ValueError: If the buffer length is not a multiple of 4, it is read-only when adjusting in place,
+    the input black point is not below the input white point or the gamma is not a positive number
+    """
+    ...
+
+def white_balance(
+        buffer: bytes | bytearray | memoryview | ColorArray,
+        reference_white: Color | None = None,
+        in_place: bool = False
+) -> bytes | None:
+    """
+    White balances every pixel, the same way as :func:`Color.white_balance() <tincture.Color.white_balance>`.
+    Without a reference white the gray world assumption is used instead, that is the average of all pixels in
+    linear light (skipping the fully transparent ones) is taken to be neutral
+
+    :param buffer: The tightly packed RGBA pixels, 4 bytes per color
+    :param reference_white: The color that is supposed to be neutral, by default the average of the pixels
+    :param in_place: Whenever to write the adjusted pixels back into the buffer instead of returning them
+
+    :raises ValueError: If the buffer length is not a multiple of 4, it is read-only when adjusting in place
+    or any of the channels of the reference white (or the average) is zero
+    """
+    ...
+
+def swizzle(buffer: bytes | bytearray | memoryview | ColorArray, pattern: str, in_place: bool = False) -> bytes | None:
+    """
+    Rearranges the channels of every pixel, the same way as :func:`Color.swizzle() <tincture.Color.swizzle>`.
+    For example "bgra" converts BGRA pixels into RGBA ones and the other way around
+
+    :param buffer: The tightly packed RGBA pixels, 4 bytes per color
+    :param pattern: The 3 or 4 letters out of "r", "g", "b", "a", "0" and "1"
+    :param in_place: Whenever to write the adjusted pixels back into the buffer instead of returning them
+
+    :raises ValueError: If the buffer length is not a multiple of 4, it is read-only when adjusting in place
+    or the pattern is invalid
+    """
+    ...
+
+def sepia(buffer: bytes | bytearray | memoryview | ColorArray, amount: float = 1.0, in_place: bool = False) -> bytes | None:
+    """
+    Gives every pixel a sepia tone, the same way as :func:`Color.sepia() <tincture.Color.sepia>`
+
+    :param buffer: The tightly packed RGBA pixels, 4 bytes per color
+    :param amount: How strong the effect is from 0.0 (unchanged) to 1.0 (fully sepia), by default 1.0
+    :param in_place: Whenever to write the adjusted pixels back into the buffer instead of returning them
+
+    :raises ValueError: If the buffer length is not a multiple of 4, it is read-only when adjusting in place
+    or the amount is NaN or infinite
+    """
+    ...
+
+def posterize(buffer: bytes | bytearray | memoryview | ColorArray, levels: int, in_place: bool = False) -> bytes | None:
+    """
+    Reduces the channels of every pixel to evenly spaced levels, the same way as
+    :func:`Color.posterize() <tincture.Color.posterize>`
+
+    :param buffer: The tightly packed RGBA pixels, 4 bytes per color
+    :param levels: The number of levels per channel in the range of [2, 255]
+    :param in_place: Whenever to write the adjusted pixels back into the buffer instead of returning them
+
+    :raises ValueError: If the buffer length is not a multiple of 4, it is read-only when adjusting in place
+    or there are less than 2 levels
+    """
+    ...
+
+def solarize(buffer: bytes | bytearray | memoryview | ColorArray, threshold: int = 128, in_place: bool = False) -> bytes | None:
+    """
+    Inverts the channels of every pixel at or above the threshold, the same way as
+    :func:`Color.solarize() <tincture.Color.solarize>`
+
+    :param buffer: The tightly packed RGBA pixels, 4 bytes per color
+    :param threshold: The channel value from which on the channels are inverted, by default 128
+    :param in_place: Whenever to write the adjusted pixels back into the buffer instead of returning them
+
+    :raises ValueError: If the buffer length is not a multiple of 4, or it is read-only when adjusting in place
+    """
+    ...
+
+def threshold(buffer: bytes | bytearray | memoryview | ColorArray, value: int = 128, in_place: bool = False) -> bytes | None:
+    """
+    Turns every pixel white or black depending on its gray value, the same way as
+    :func:`Color.threshold() <tincture.Color.threshold>`
+
+    :param buffer: The tightly packed RGBA pixels, 4 bytes per color
+    :param value: The gray value from which on the pixels turn white, by default 128
+    :param in_place: Whenever to write the adjusted pixels back into the buffer instead of returning them
+
+    :raises ValueError: If the buffer length is not a multiple of 4, or it is read-only when adjusting in place
+    """
+    ...
+
+def color_matrix(buffer: bytes | bytearray | memoryview | ColorArray, matrix: ColorMatrix, in_place: bool = False) -> bytes | None:
+    """
+    Filters every pixel through the color matrix, the same way as :func:`ColorMatrix.apply() <tincture.ColorMatrix.apply>`.
+    Chaining multiple filters into one matrix beforehand applies all of them in a single pass
+
+    :param buffer: The tightly packed RGBA pixels, 4 bytes per color
+    :param matrix: The matrix to filter the pixels through
+    :param in_place: Whenever to write the adjusted pixels back into the buffer instead of returning them
+
+    :raises ValueError: If the buffer length is not a multiple of 4, or it is read-only when adjusting in place
+    """
+    ...
+
+def dedup(
+        colors: list[Color] | bytes | bytearray | memoryview | ColorArray,
+        tolerance: float = 2.0,
+        metric: str = "ciede2000",
+        merge: bool = False
+) -> ColorArray:
+    """
+    Removes the near-duplicates of large color sets, such as colors scraped from websites. It works the same way as
+    :func:`Palette.dedup() <tincture.Palette.dedup>`, every color is compared with the colors kept so far and is
+    dropped when it lies closer than the tolerance to one of them. Kept colors are bucketed by their lightness, so
+    each color is only compared with the kept colors of a similar lightness instead of all of them
+
+    :param colors: Either a list of colors or a buffer of tightly packed RGBA pixels
+    :param tolerance: Colors closer than this distance count as duplicates, by default 2.0
+    :param metric: The distance metric, either "ciede2000" (the default), "cie76" or "oklab"
+    :param merge: Whenever to replace every kept color with the Oklab average of its duplicates, by default False
+
+    :return: The kept colors in their original order
+
+    :raises ValueError: If the tolerance is negative, the metric is unknown or the buffer length is not a
+        multiple of 4
+    """
+    ...
+
+def approx_contains(
+        haystack: list[Color] | bytes | bytearray | memoryview | ColorArray,
+        needle: Color,
+        tolerance: float = 2.0,
+        metric: str = "ciede2000"
+) -> bool:
+    """
+    Checks whenever any of the colors lies closer than the tolerance to the needle
+
+    :param haystack: Either a list of colors or a buffer of tightly packed RGBA pixels
+    :param needle: The color to look for
+    :param tolerance: The distance below which colors count as equal, by default 2.0
+    :param metric: The distance metric, either "ciede2000" (the default), "cie76" or "oklab"
+
+    :raises ValueError: If the tolerance is negative, the metric is unknown or the buffer length is not a
+        multiple of 4
+    """
+    ...
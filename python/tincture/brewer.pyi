@@ -0,0 +1,125 @@
+"""
+The ColorBrewer palettes by Cynthia Brewer, designed for maps and other data visualizations. There are sequential
+schemes for ordered data, diverging schemes for data around a critical middle value and qualitative schemes for
+categories. Every constant holds the largest palette of its scheme (9 colors for the sequential and 11 for the
+diverging schemes), palettes of other sizes come from :func:`Palette.brewer() <tincture.Palette.brewer>`. The sizes
+of the sequential and diverging schemes are separate designs rather than slices of the largest palette
+"""
+
+from ._palette import Palette
+
+def names(kind: str | None = None) -> list[str]:
+    """
+    :param kind: Either "sequential", "diverging" or "qualitative" to only list the schemes of that kind, by default
+        every scheme is listed
+
+    :return: The names of the ColorBrewer schemes, which are the names of the constants of this module
+
+    :raises ValueError: If the kind is unknown
+    """
+    ...
+
+YlGn: Palette
+"""Sequential, from light yellow to dark green"""
+
+YlGnBu: Palette
+"""Sequential, from light yellow over green to dark blue"""
+
+GnBu: Palette
+"""Sequential, from light green to dark blue"""
+
+BuGn: Palette
+"""Sequential, from light blue to dark green"""
+
+PuBuGn: Palette
+"""Sequential, from white over blue to dark green"""
+
+PuBu: Palette
+"""Sequential, from white over purple to dark blue"""
+
+BuPu: Palette
+"""Sequential, from light blue to dark purple"""
+
+RdPu: Palette
+"""Sequential, from light pink to dark purple"""
+
+PuRd: Palette
+"""Sequential, from light purple to dark red"""
+
+OrRd: Palette
+"""Sequential, from light orange to dark red"""
+
+YlOrRd: Palette
+"""Sequential, from light yellow over orange to dark red"""
+
+YlOrBr: Palette
+"""Sequential, from light yellow over orange to dark brown"""
+
+Purples: Palette
+"""Sequential, from white to dark purple"""
+
+Blues: Palette
+"""Sequential, from white to dark blue"""
+
+Greens: Palette
+"""Sequential, from white to dark green"""
+
+Oranges: Palette
+"""Sequential, from white to dark orange"""
+
+Reds: Palette
+"""Sequential, from white to dark red"""
+
+Greys: Palette
+"""Sequential, from white to black"""
+
+PuOr: Palette
+"""Diverging, from orange over white to purple"""
+
+BrBG: Palette
+"""Diverging, from brown over white to blue green"""
+
+PRGn: Palette
+"""Diverging, from purple over white to green"""
+
+PiYG: Palette
+"""Diverging, from pink over white to yellow green"""
+
+RdBu: Palette
+"""Diverging, from red over white to blue"""
+
+RdGy: Palette
+"""Diverging, from red over white to grey"""
+
+RdYlBu: Palette
+"""Diverging, from red over yellow to blue"""
+
+Spectral: Palette
+"""Diverging, from red over yellow to purple blue"""
+
+RdYlGn: Palette
+"""Diverging, from red over yellow to green"""
+
+Accent: Palette
+"""Qualitative, 8 colors"""
+
+Dark2: Palette
+"""Qualitative, 8 dark colors"""
+
+Paired: Palette
+"""Qualitative, 12 colors in light and dark pairs"""
+
+Pastel1: Palette
+"""Qualitative, 9 pastel colors"""
+
+Pastel2: Palette
+"""Qualitative, 8 pastel colors"""
+
+Set1: Palette
+"""Qualitative, 9 strong colors"""
+
+Set2: Palette
+"""Qualitative, 8 muted colors"""
+
+Set3: Palette
+"""Qualitative, 12 light colors"""
@@ -0,0 +1,25 @@
+"""
+Preconstructed gradients of the popular scientific colormaps, they are perceptually uniform (with the exception of
+turbo) and can be sampled like any other gradient without depending on matplotlib. Each gradient is built from
+10 evenly spaced control points of the original colormap, which are interpolated in sRGB
+"""
+
+from ._gradient import Gradient
+
+viridis: Gradient
+"""The default colormap of matplotlib, goes from dark purple over teal to yellow"""
+
+magma: Gradient
+"""Goes from black over purple and coral to pale yellow"""
+
+inferno: Gradient
+"""Goes from black over purple, red and orange to pale yellow"""
+
+plasma: Gradient
+"""Goes from dark blue over magenta and orange to yellow"""
+
+cividis: Gradient
+"""A variant of viridis that is optimized for viewers with color vision deficiency, goes from dark blue to yellow"""
+
+turbo: Gradient
+"""An improved rainbow colormap, goes from dark blue over cyan, green and yellow to dark red"""
@@ -0,0 +1,187 @@
+"""
+Keeps the type stubs inside python/tincture in sync with the compiled extension module.
+
+The stubs are written by hand and are not generated. The signatures pyo3 exposes at runtime only carry the
+parameter names and defaults, so stubs generated from them would type every parameter and return value as
+``Any``, while the hand written ones carry the types, the overloads (such as indexing a color by a channel
+name, index or slice) and the documentation. Instead, every class, method, property and function that the
+Rust code exposes has to be declared inside them with the same parameters as the pyo3 signature. Running
+this script against a freshly built module lists everything that drifted apart (the test suite does the
+same), ``--skeleton`` additionally prints ready to paste declarations for the members which have no stub
+yet::
+
+    python scripts/stubgen.py --skeleton
+"""
+
+from __future__ import annotations
+
+import argparse
+import ast
+import inspect
+import sys
+from pathlib import Path
+from types import ModuleType
+
+STUB_DIRECTORY = Path(__file__).resolve().parent.parent / "python" / "tincture"
+SUBMODULES = ("batch", "brewer", "colormaps")
+
+# The special methods follow Python's protocols, pyo3 names their parameters generically, so only their
+# presence is checked. Comparison operators and reflected operators are generated by pyo3 on its own
+IGNORED_SPECIAL_METHODS = {
+    "__lt__", "__le__", "__eq__", "__ne__", "__gt__", "__ge__", "__bool__", "__delitem__",
+    "__radd__", "__rsub__", "__rmul__", "__rtruediv__", "__rfloordiv__", "__rlshift__", "__rrshift__",
+    "__rmatmul__", "__repr__", "__int__",
+}
+# Attributes every class carries, or that only exist for pyo3's own bookkeeping
+IGNORED_ATTRIBUTES = set(vars(object)) | {"__module__", "__doc__", "__match_args__"}
+
+
+def _parameters(function: ast.FunctionDef) -> list[str]:
+    """The parameter names of the declaration, with a trailing "=" on the ones that have a default"""
+    arguments: ast.arguments = function.args
+    positional: list[ast.arg] = arguments.posonlyargs + arguments.args
+    required: int = len(positional) - len(arguments.defaults)
+    names: list[str] = [
+        argument.arg + ("=" if index >= required else "") for index, argument in enumerate(positional)
+    ]
+    if names and names[0] in ("self", "cls"):
+        names = names[1:]
+    if arguments.vararg is not None:
+        names.append("*" + arguments.vararg.arg)
+    names.extend(
+        argument.arg + ("=" if default is not None else "")
+        for argument, default in zip(arguments.kwonlyargs, arguments.kw_defaults)
+    )
+    return names
+
+
+def load_stubs(directory: Path = STUB_DIRECTORY) -> dict[tuple, list[list[str]]]:
+    """
+    Reads every declaration of the stubs, keyed by (submodule, class, member) where the submodule is empty
+    for the top level package and the class is None for module level functions and constants. Every
+    overload of a member contributes its own parameter list
+    """
+    declarations: dict[tuple, list[list[str]]] = {}
+    for path in sorted(directory.glob("*.py*")):
+        submodule: str = path.stem if path.stem in SUBMODULES else ""
+        for node in ast.parse(path.read_text()).body:
+            if isinstance(node, ast.ClassDef):
+                declarations.setdefault((submodule, node.name, None), [])
+                for item in node.body:
+                    if isinstance(item, ast.FunctionDef):
+                        declarations.setdefault((submodule, node.name, item.name), []).append(_parameters(item))
+                    elif isinstance(item, ast.AnnAssign) and isinstance(item.target, ast.Name):
+                        declarations.setdefault((submodule, node.name, item.target.id), [])
+                    elif isinstance(item, ast.Assign):
+                        for target in item.targets:
+                            if isinstance(target, ast.Name):
+                                declarations.setdefault((submodule, node.name, target.id), [])
+            elif isinstance(node, ast.FunctionDef):
+                declarations.setdefault((submodule, None, node.name), []).append(_parameters(node))
+            elif isinstance(node, ast.AnnAssign) and isinstance(node.target, ast.Name):
+                declarations.setdefault((submodule, None, node.target.id), [])
+    return declarations
+
+
+def _runtime_parameters(member: object) -> list[str] | None:
+    try:
+        signature: inspect.Signature = inspect.signature(member)
+    except (TypeError, ValueError):
+        return None
+    return [
+        ("*" if parameter.kind == parameter.VAR_POSITIONAL else "")
+        + parameter.name
+        + ("=" if parameter.default is not parameter.empty else "")
+        for parameter in signature.parameters.values()
+        if parameter.name not in ("self", "cls")
+    ]
+
+
+def runtime_members(module: ModuleType):
+    """
+    Yields (submodule, class, member, object) for everything the compiled module exposes, the class is None for
+    module level functions and constants while the member is None for the class itself
+    """
+    for submodule, namespace in [("", module)] + [(name, getattr(module, name)) for name in SUBMODULES]:
+        for name in dir(namespace):
+            value: object = getattr(namespace, name)
+            if name.startswith("_") or isinstance(value, ModuleType):
+                continue
+            if not isinstance(value, type):
+                yield submodule, None, name, value
+                continue
+            yield submodule, value, None, value
+            if value.__text_signature__ is not None:
+                yield submodule, value, "__init__", value
+            for member in vars(value):
+                if member.startswith("_") and not (member.startswith("__") and member.endswith("__")):
+                    continue
+                if member in IGNORED_SPECIAL_METHODS or member in IGNORED_ATTRIBUTES:
+                    continue
+                yield submodule, value, member, getattr(value, member)
+
+
+def _qualified_name(submodule: str, owner: type | None, member: str | None) -> str:
+    owner_name: str | None = owner.__name__ if owner is not None else None
+    return ".".join(part for part in ("tincture", submodule, owner_name, member) if part)
+
+
+def _key(submodule: str, owner: type | None, member: str | None) -> tuple:
+    return submodule, owner.__name__ if owner is not None else None, member
+
+
+def find_problems(module: ModuleType, directory: Path = STUB_DIRECTORY) -> list[str]:
+    """Lists every member of the compiled module that has no stub or whose stub has other parameters"""
+    declarations: dict[tuple, list[list[str]]] = load_stubs(directory)
+    problems: list[str] = []
+    for submodule, owner, member, value in runtime_members(module):
+        name: str = _qualified_name(submodule, owner, member)
+        if _key(submodule, owner, member) not in declarations:
+            problems.append(f"{name} has no stub")
+            continue
+        special: bool = member is not None and member.startswith("__") and member != "__init__"
+        if member is None or special or not callable(value):
+            continue
+        parameters: list[str] | None = _runtime_parameters(value)
+        if parameters is not None and parameters not in declarations[_key(submodule, owner, member)]:
+            expected: str = ", ".join(parameters)
+            problems.append(f"{name} takes ({expected}) but its stub declares different parameters")
+    return problems
+
+
+def skeleton(owner: type | None, member: str, value: object) -> str:
+    """A stub declaration derived from the pyo3 signature, the types are left for the author to fill in"""
+    if owner is not None and not callable(value):
+        return f"    @property\n    def {member}(self): ...\n"
+    try:
+        signature: str = str(inspect.signature(value)).replace("/, ", "").replace(", /", "")
+    except (TypeError, ValueError):
+        signature = "(self, *args, **kwargs)"
+    if owner is None:
+        return f"def {member}{signature}: ...\n"
+    if isinstance(inspect.getattr_static(owner, member), staticmethod):
+        return f"    @staticmethod\n    def {member}{signature}: ...\n"
+    return f"    def {member}{signature}: ...\n"
+
+
+def main() -> int:
+    parser = argparse.ArgumentParser(description=__doc__, formatter_class=argparse.RawDescriptionHelpFormatter)
+    parser.add_argument("--skeleton", action="store_true", help="print declarations for the members without stubs")
+    arguments = parser.parse_args()
+
+    import tincture
+
+    problems: list[str] = find_problems(tincture)
+    for problem in problems:
+        print(problem)
+    if arguments.skeleton:
+        declarations: dict[tuple, list[list[str]]] = load_stubs()
+        for submodule, owner, member, value in runtime_members(tincture):
+            if _key(submodule, owner, member) not in declarations and member is not None:
+                print(f"\n# {_qualified_name(submodule, owner, None)}")
+                print(skeleton(owner, member, value), end="")
+    return 1 if problems else 0
+
+
+if __name__ == "__main__":
+    sys.exit(main())
@@ -0,0 +1,190 @@
+use crate::color::consts::{D65_WHITE, WHITE_POINTS};
+use crate::color::spaces::multiply_matrix;
+use pyo3::exceptions::PyValueError;
+use pyo3::{FromPyObject, PyResult};
+
+/// A white point, either the name of a standard illuminant or the xy chromaticity of a custom one
+#[derive(Clone, Debug, FromPyObject)]
+pub enum Illuminant {
+    #[pyo3(transparent, annotation = "str")]
+    Name(String),
+    #[pyo3(transparent, annotation = "tuple[float, float]")]
+    Chromaticity((f32, f32)),
+}
+
+impl Illuminant {
+    pub(crate) fn named(name: &str) -> Illuminant {
+        Illuminant::Name(name.to_string())
+    }
+
+    /// The white point as XYZ with the luminance normalized to 1
+    pub(crate) fn white_point(&self) -> PyResult<[f32; 3]> {
+        match self {
+            Illuminant::Name(name) => white_point(name),
+            Illuminant::Chromaticity((x, y)) => {
+                if *y <= 0.0 || *x < 0.0 || x + y > 1.0 {
+                    return Err(PyValueError::new_err(format!(
+                        "The chromaticity ({}, {}) is not a valid white point",
+                        x, y
+                    )));
+                }
+                Ok([x / y, 1.0, (1.0 - x - y) / y])
+            }
+        }
+    }
+}
+
+/// The color temperature that the sRGB white (D65) is closest to on the Planckian locus
+pub(crate) const REFERENCE_KELVIN: f32 = 6504.0;
+
+/// The temperatures the approximation of the Planckian locus covers
+pub(crate) const MIN_KELVIN: f32 = 1667.0;
+pub(crate) const MAX_KELVIN: f32 = 25000.0;
+
+/// The xy chromaticity of a black body radiator at the temperature, through the cubic spline
+/// approximation of Kim et al. which covers [MIN_KELVIN] up to [MAX_KELVIN] (the temperature is
+/// clamped)
+pub(crate) fn planckian_chromaticity(kelvin: f32) -> (f32, f32) {
+    let kelvin: f32 = kelvin.clamp(MIN_KELVIN, MAX_KELVIN);
+    let (t, t2, t3): (f32, f32, f32) = (kelvin, kelvin * kelvin, kelvin * kelvin * kelvin);
+    let x: f32 = if kelvin <= 4000.0 {
+        -0.266_123_9e9 / t3 - 0.234_358_9e6 / t2 + 0.877_695_6e3 / t + 0.179_910
+    } else {
+        -3.025_846_9e9 / t3 + 2.107_038e6 / t2 + 0.222_634_7e3 / t + 0.240_390
+    };
+    let (x2, x3): (f32, f32) = (x * x, x * x * x);
+    let y: f32 = if kelvin <= 2222.0 {
+        -1.106_381_4 * x3 - 1.348_110_2 * x2 + 2.185_558_3 * x - 0.202_196_83
+    } else if kelvin <= 4000.0 {
+        -0.954_947_6 * x3 - 1.374_185_9 * x2 + 2.091_37 * x - 0.167_488_67
+    } else {
+        3.081_758 * x3 - 5.873_387 * x2 + 3.751_13 * x - 0.370_014_83
+    };
+    (x, y)
+}
+
+/// Adapts XYZ relative to D65 into XYZ relative to the white point with the Bradford transform,
+/// the same way as ICC profiles move colors to their D50 connection space
+pub(crate) fn from_d65(xyz: [f32; 3], white: [f32; 3]) -> [f32; 3] {
+    if white == D65_WHITE {
+        return xyz;
+    }
+    AdaptationMethod::Bradford.adapt(xyz, D65_WHITE, white)
+}
+
+/// The reverse of [from_d65]
+pub(crate) fn to_d65(xyz: [f32; 3], white: [f32; 3]) -> [f32; 3] {
+    if white == D65_WHITE {
+        return xyz;
+    }
+    AdaptationMethod::Bradford.adapt(xyz, white, D65_WHITE)
+}
+
+/// The transform that moves XYZ into the cone response space where the adaptation happens,
+/// followed by its inverse
+type ConeResponse = ([[f32; 3]; 3], [[f32; 3]; 3]);
+
+const BRADFORD: ConeResponse = (
+    [
+        [0.8951, 0.2664, -0.1614],
+        [-0.7502, 1.7135, 0.0367],
+        [0.0389, -0.0685, 1.0296],
+    ],
+    [
+        [0.986_993, -0.147_054_3, 0.159_962_7],
+        [0.432_305_3, 0.518_360_3, 0.049_291_2],
+        [-0.008_528_7, 0.040_042_8, 0.968_486_7],
+    ],
+);
+
+pub(crate) const CAT16: ConeResponse = (
+    [
+        [0.401_288, 0.650_173, -0.051_461],
+        [-0.250_268, 1.204_414, 0.045_854],
+        [-0.002_079, 0.048_952, 0.953_127],
+    ],
+    [
+        [1.862_068, -1.011_254_6, 0.149_186_8],
+        [0.387_526_5, 0.621_447_4, -0.008_974],
+        [-0.015_841_5, -0.034_122_9, 1.049_964_4],
+    ],
+);
+
+const VON_KRIES: ConeResponse = (
+    [
+        [0.400_24, 0.7076, -0.080_81],
+        [-0.2263, 1.165_32, 0.0457],
+        [0.0, 0.0, 0.918_22],
+    ],
+    [
+        [1.859_936_4, -1.129_381_6, 0.219_897_4],
+        [0.361_191_4, 0.638_812_5, -0.000_006_4],
+        [0.0, 0.0, 1.089_063_6],
+    ],
+);
+
+const IDENTITY: [[f32; 3]; 3] = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum AdaptationMethod {
+    Bradford,
+    Cat16,
+    VonKries,
+    XyzScaling,
+}
+
+impl AdaptationMethod {
+    pub(crate) fn from_name(name: &str) -> PyResult<AdaptationMethod> {
+        match name.to_lowercase().as_str() {
+            "bradford" => Ok(AdaptationMethod::Bradford),
+            "cat16" => Ok(AdaptationMethod::Cat16),
+            "von_kries" | "von-kries" => Ok(AdaptationMethod::VonKries),
+            "xyz_scaling" | "xyz-scaling" => Ok(AdaptationMethod::XyzScaling),
+            _ => Err(PyValueError::new_err(format!(
+                "Unknown chromatic adaptation method \"{}\", expected bradford, cat16, von_kries or xyz_scaling",
+                name
+            ))),
+        }
+    }
+
+    fn cone_response(&self) -> ConeResponse {
+        match self {
+            AdaptationMethod::Bradford => BRADFORD,
+            AdaptationMethod::Cat16 => CAT16,
+            AdaptationMethod::VonKries => VON_KRIES,
+            AdaptationMethod::XyzScaling => (IDENTITY, IDENTITY),
+        }
+    }
+
+    /// Moves the XYZ color seen under the source white to the one that looks the same under
+    /// the destination white, by scaling the cone responses by the ratio of both whites
+    pub(crate) fn adapt(&self, xyz: [f32; 3], source: [f32; 3], destination: [f32; 3]) -> [f32; 3] {
+        let (forward, backward): ConeResponse = self.cone_response();
+        let source: [f32; 3] = multiply_matrix(&forward, source);
+        let destination: [f32; 3] = multiply_matrix(&forward, destination);
+        let cones: [f32; 3] = multiply_matrix(&forward, xyz);
+        multiply_matrix(
+            &backward,
+            std::array::from_fn(|index| cones[index] * destination[index] / source[index]),
+        )
+    }
+}
+
+/// Looks up the white point of a standard illuminant by its name
+fn white_point(name: &str) -> PyResult<[f32; 3]> {
+    WHITE_POINTS
+        .iter()
+        .find(|(illuminant, _)| illuminant.eq_ignore_ascii_case(name))
+        .map(|(_, white)| *white)
+        .ok_or_else(|| {
+            let names: Vec<&str> = WHITE_POINTS
+                .iter()
+                .map(|(illuminant, _)| *illuminant)
+                .collect();
+            PyValueError::new_err(format!(
+                "Unknown illuminant \"{}\", expected one of {}",
+                name,
+                names.join(", ")
+            ))
+        })
+}
@@ -0,0 +1,94 @@
+use crate::color::Color;
+
+/// The default xterm values of the 16 basic terminal colors, terminals let users redefine
+/// these, so the 256 color mapping only ever picks from the cube and the grayscale ramp
+const ANSI16: [(u8, u8, u8); 16] = [
+    (0, 0, 0),
+    (205, 0, 0),
+    (0, 205, 0),
+    (205, 205, 0),
+    (0, 0, 238),
+    (205, 0, 205),
+    (0, 205, 205),
+    (229, 229, 229),
+    (127, 127, 127),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (92, 92, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255),
+];
+
+/// The channel levels of the 6x6x6 color cube occupying the indices 16 to 231
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+fn squared_distance(color: Color, other: (u8, u8, u8)) -> i32 {
+    let difference = |first: u8, second: u8| (first as i32) - (second as i32);
+    difference(color.r, other.0).pow(2)
+        + difference(color.g, other.1).pow(2)
+        + difference(color.b, other.2).pow(2)
+}
+
+fn cube_level(channel: u8) -> usize {
+    match channel {
+        0..48 => 0,
+        48..115 => 1,
+        _ => ((channel as usize) - 35) / 40,
+    }
+}
+
+pub(crate) fn ansi256_color(index: u8) -> Color {
+    let (r, g, b): (u8, u8, u8) = match index {
+        0..16 => ANSI16[index as usize],
+        16..232 => {
+            let offset: usize = (index - 16) as usize;
+            (
+                CUBE_LEVELS[offset / 36],
+                CUBE_LEVELS[(offset / 6) % 6],
+                CUBE_LEVELS[offset % 6],
+            )
+        }
+        _ => {
+            let level: u8 = 8 + (index - 232) * 10;
+            (level, level, level)
+        }
+    };
+    Color::new(r, g, b, 255)
+}
+
+/// Picks the closest entry of either the color cube or the grayscale ramp, as xterm and tmux do
+pub(crate) fn to_ansi256(color: Color) -> u8 {
+    let (r, g, b): (usize, usize, usize) = (
+        cube_level(color.r),
+        cube_level(color.g),
+        cube_level(color.b),
+    );
+    let cube: (u8, u8, u8) = (CUBE_LEVELS[r], CUBE_LEVELS[g], CUBE_LEVELS[b]);
+    let average: i32 = ((color.r as i32) + (color.g as i32) + (color.b as i32)) / 3;
+    let gray_index: i32 = ((average - 3) / 10).clamp(0, 23);
+    let gray_level: u8 = (8 + gray_index * 10) as u8;
+    if squared_distance(color, (gray_level, gray_level, gray_level)) < squared_distance(color, cube)
+    {
+        232 + gray_index as u8
+    } else {
+        (16 + 36 * r + 6 * g + b) as u8
+    }
+}
+
+pub(crate) fn to_ansi16(color: Color) -> u8 {
+    (0..16u8)
+        .min_by_key(|index| squared_distance(color, ANSI16[*index as usize]))
+        .unwrap()
+}
+
+pub(crate) const ANSI_RESET: &str = "\x1b[0m";
+
+pub(crate) fn foreground_escape(color: Color) -> String {
+    format!("\x1b[38;2;{};{};{}m", color.r, color.g, color.b)
+}
+
+pub(crate) fn background_escape(color: Color) -> String {
+    format!("\x1b[48;2;{};{};{}m", color.r, color.g, color.b)
+}
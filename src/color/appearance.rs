@@ -0,0 +1,298 @@
+use crate::color::adaptation::{Illuminant, CAT16};
+use crate::color::spaces::multiply_matrix;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use std::f32::consts::PI;
+
+/// The surround of the viewing field, how bright the area around the observed scene is
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum Surround {
+    Average,
+    Dim,
+    Dark,
+}
+
+impl Surround {
+    pub(crate) fn from_name(name: &str) -> PyResult<Surround> {
+        match name.to_lowercase().as_str() {
+            "average" => Ok(Surround::Average),
+            "dim" => Ok(Surround::Dim),
+            "dark" => Ok(Surround::Dark),
+            _ => Err(PyValueError::new_err(format!(
+                "Unknown surround \"{}\", expected average, dim or dark",
+                name
+            ))),
+        }
+    }
+
+    pub(crate) fn name(&self) -> &'static str {
+        match self {
+            Surround::Average => "average",
+            Surround::Dim => "dim",
+            Surround::Dark => "dark",
+        }
+    }
+
+    /// The degree of adaptation factor F, the impact c of the surround and the chromatic
+    /// induction factor Nc
+    fn parameters(&self) -> (f32, f32, f32) {
+        match self {
+            Surround::Average => (1.0, 0.69, 1.0),
+            Surround::Dim => (0.9, 0.59, 0.9),
+            Surround::Dark => (0.8, 0.525, 0.8),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[pyclass(module = "tincture", frozen)]
+pub struct ViewingConditions {
+    adapting_luminance: f32,
+    background_luminance: f32,
+    surround: Surround,
+    white: [f32; 3],
+    discounting: bool,
+}
+
+#[pymethods]
+impl ViewingConditions {
+    #[new]
+    #[pyo3(signature = (
+        adapting_luminance=64.0 / PI * 0.2,
+        background_luminance=20.0,
+        surround="average",
+        white=Illuminant::named("D65"),
+        discounting=false
+    ))]
+    fn new(
+        adapting_luminance: f32,
+        background_luminance: f32,
+        surround: &str,
+        white: Illuminant,
+        discounting: bool,
+    ) -> PyResult<Self> {
+        if !(adapting_luminance > 0.0 && adapting_luminance.is_finite()) {
+            return Err(PyValueError::new_err(
+                "The adapting luminance must be a positive number",
+            ));
+        } else if !(background_luminance > 0.0 && background_luminance <= 100.0) {
+            return Err(PyValueError::new_err(
+                "The background luminance must be above 0.0 and at most 100.0",
+            ));
+        }
+        Ok(ViewingConditions {
+            adapting_luminance,
+            background_luminance,
+            surround: Surround::from_name(surround)?,
+            white: white.white_point()?,
+            discounting,
+        })
+    }
+
+    /// A display in a dark room, where the screen is the only thing to adapt to
+    #[staticmethod]
+    pub fn dark_room() -> ViewingConditions {
+        ViewingConditions {
+            adapting_luminance: 20.0,
+            background_luminance: 20.0,
+            surround: Surround::Dark,
+            white: crate::color::consts::D65_WHITE,
+            discounting: false,
+        }
+    }
+
+    /// A display (or print) viewed outdoors under direct sunlight
+    #[staticmethod]
+    pub fn sunlight() -> ViewingConditions {
+        ViewingConditions {
+            adapting_luminance: 2000.0,
+            background_luminance: 20.0,
+            surround: Surround::Average,
+            white: crate::color::consts::D65_WHITE,
+            discounting: false,
+        }
+    }
+
+    #[getter]
+    pub fn get_adapting_luminance(&self) -> f32 {
+        self.adapting_luminance
+    }
+
+    #[getter]
+    pub fn get_background_luminance(&self) -> f32 {
+        self.background_luminance
+    }
+
+    #[getter]
+    pub fn get_surround(&self) -> &'static str {
+        self.surround.name()
+    }
+
+    #[getter]
+    pub fn get_white(&self) -> (f32, f32, f32) {
+        (self.white[0], self.white[1], self.white[2])
+    }
+
+    #[getter]
+    pub fn get_discounting(&self) -> bool {
+        self.discounting
+    }
+
+    pub fn __eq__(&self, other: &ViewingConditions) -> bool {
+        self == other
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!(
+            "ViewingConditions(adapting_luminance={:?}, background_luminance={:?}, surround=\"{}\", discounting={})",
+            self.adapting_luminance,
+            self.background_luminance,
+            self.surround.name(),
+            if self.discounting { "True" } else { "False" }
+        )
+    }
+}
+
+/// The CAM16 color appearance model under one set of viewing conditions, with everything that
+/// only depends on the conditions computed up front
+pub(crate) struct Cam16 {
+    white_gains: [f32; 3],
+    luminance_adaptation: f32,
+    background_ratio: f32,
+    exponent: f32,
+    induction: f32,
+    surround_impact: f32,
+    chromatic_induction: f32,
+    achromatic_white: f32,
+}
+
+impl Cam16 {
+    pub(crate) fn new(conditions: &ViewingConditions) -> Cam16 {
+        let (factor, surround_impact, chromatic_induction): (f32, f32, f32) =
+            conditions.surround.parameters();
+        let white: [f32; 3] = conditions.white.map(|value| value * 100.0);
+        let white_cones: [f32; 3] = multiply_matrix(&CAT16.0, white);
+        let degree: f32 = if conditions.discounting {
+            1.0
+        } else {
+            (factor * (1.0 - (1.0 / 3.6) * ((-conditions.adapting_luminance - 42.0) / 92.0).exp()))
+                .clamp(0.0, 1.0)
+        };
+        let white_gains: [f32; 3] = white_cones.map(|cone| degree * white[1] / cone + 1.0 - degree);
+
+        let k: f32 = 1.0 / (5.0 * conditions.adapting_luminance + 1.0);
+        let k4: f32 = k.powi(4);
+        let luminance_adaptation: f32 = 0.2 * k4 * (5.0 * conditions.adapting_luminance)
+            + 0.1 * (1.0 - k4).powi(2) * (5.0 * conditions.adapting_luminance).cbrt();
+        let background_ratio: f32 = conditions.background_luminance / white[1];
+        let induction: f32 = 0.725 * background_ratio.powf(-0.2);
+
+        let mut model: Cam16 = Cam16 {
+            white_gains,
+            luminance_adaptation,
+            background_ratio,
+            exponent: 1.48 + background_ratio.sqrt(),
+            induction,
+            surround_impact,
+            chromatic_induction,
+            achromatic_white: 0.0,
+        };
+        let white_response: [f32; 3] = model.compress(white_cones);
+        model.achromatic_white = model.achromatic(white_response);
+        model
+    }
+
+    /// The post-adaptation cone responses of the cone responses
+    fn compress(&self, cones: [f32; 3]) -> [f32; 3] {
+        std::array::from_fn(|index| {
+            let adapted: f32 = self.white_gains[index] * cones[index];
+            let scaled: f32 = (self.luminance_adaptation * adapted.abs() / 100.0).powf(0.42);
+            400.0 * adapted.signum() * scaled / (scaled + 27.13) + 0.1
+        })
+    }
+
+    fn decompress(&self, responses: [f32; 3]) -> [f32; 3] {
+        std::array::from_fn(|index| {
+            let response: f32 = responses[index] - 0.1;
+            let magnitude: f32 = response.abs().min(399.99);
+            let adapted: f32 = response.signum() * 100.0 / self.luminance_adaptation
+                * (27.13 * magnitude / (400.0 - magnitude)).powf(1.0 / 0.42);
+            adapted / self.white_gains[index]
+        })
+    }
+
+    fn achromatic(&self, responses: [f32; 3]) -> f32 {
+        (2.0 * responses[0] + responses[1] + 0.05 * responses[2] - 0.305) * self.induction
+    }
+
+    fn eccentricity(hue: f32) -> f32 {
+        0.25 * ((hue.to_radians() + 2.0).cos() + 3.8)
+    }
+
+    fn chroma_scale(&self) -> f32 {
+        (1.64 - 0.29f32.powf(self.background_ratio)).powf(0.73)
+    }
+
+    /// The lightness J, chroma C and hue h of the XYZ values (scaled to 100)
+    pub(crate) fn to_jch(&self, xyz: [f32; 3]) -> [f32; 3] {
+        let responses: [f32; 3] = self.compress(multiply_matrix(&CAT16.0, xyz));
+        let a: f32 = responses[0] - 12.0 * responses[1] / 11.0 + responses[2] / 11.0;
+        let b: f32 = (responses[0] + responses[1] - 2.0 * responses[2]) / 9.0;
+        let hue: f32 = b.atan2(a).to_degrees().rem_euclid(360.0);
+
+        let achromatic: f32 = self.achromatic(responses).max(0.0);
+        let lightness: f32 =
+            100.0 * (achromatic / self.achromatic_white).powf(self.surround_impact * self.exponent);
+        let t: f32 = (50000.0 / 13.0
+            * self.chromatic_induction
+            * self.induction
+            * Cam16::eccentricity(hue)
+            * a.hypot(b))
+            / (responses[0] + responses[1] + 21.0 / 20.0 * responses[2]);
+        let chroma: f32 = t.max(0.0).powf(0.9) * (lightness / 100.0).sqrt() * self.chroma_scale();
+        [lightness, chroma, hue]
+    }
+
+    /// The XYZ values (scaled to 100) of the lightness J, chroma C and hue h
+    pub(crate) fn from_jch(&self, jch: [f32; 3]) -> [f32; 3] {
+        let [lightness, chroma, hue]: [f32; 3] = jch;
+        let lightness: f32 = lightness.max(0.0);
+        let t: f32 = if lightness == 0.0 {
+            0.0
+        } else {
+            (chroma / ((lightness / 100.0).sqrt() * self.chroma_scale())).powf(1.0 / 0.9)
+        };
+        let achromatic: f32 = self.achromatic_white
+            * (lightness / 100.0).powf(1.0 / (self.surround_impact * self.exponent));
+        let p2: f32 = achromatic / self.induction + 0.305;
+
+        let (sin, cos): (f32, f32) = hue.to_radians().sin_cos();
+        let (a, b): (f32, f32) = if t <= 0.0 {
+            (0.0, 0.0)
+        } else {
+            let p1: f32 = 50000.0 / 13.0
+                * self.chromatic_induction
+                * self.induction
+                * Cam16::eccentricity(hue)
+                / t;
+            let p3: f32 = 21.0 / 20.0;
+            if sin.abs() >= cos.abs() {
+                let b: f32 = p2 * (2.0 + p3) * (460.0 / 1403.0)
+                    / (p1 / sin + (2.0 + p3) * (220.0 / 1403.0) * (cos / sin) - 27.0 / 1403.0
+                        + p3 * (6300.0 / 1403.0));
+                (b * cos / sin, b)
+            } else {
+                let a: f32 = p2 * (2.0 + p3) * (460.0 / 1403.0)
+                    / (p1 / cos + (2.0 + p3) * (220.0 / 1403.0)
+                        - (27.0 / 1403.0 - p3 * (6300.0 / 1403.0)) * (sin / cos));
+                (a, a * sin / cos)
+            }
+        };
+        let responses: [f32; 3] = [
+            (460.0 * p2 + 451.0 * a + 288.0 * b) / 1403.0,
+            (460.0 * p2 - 891.0 * a - 261.0 * b) / 1403.0,
+            (460.0 * p2 - 220.0 * a - 6300.0 * b) / 1403.0,
+        ];
+        multiply_matrix(&CAT16.1, self.decompress(responses))
+    }
+}
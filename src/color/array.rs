@@ -0,0 +1,363 @@
+use crate::color::difference::DistanceMetric;
+use crate::color::palette::{ColorsOrBuffer, PaletteIndex};
+use crate::color::parallel::map_indices;
+use crate::color::rules::{apply_rules, Rule};
+use crate::color::spaces::{interpolate_in_space, ColorSpace, HueStrategy};
+use crate::color::utils::{color_add_color, color_sub_color};
+use crate::color::Color;
+use pyo3::exceptions::{PyBufferError, PyIndexError, PyValueError};
+use pyo3::ffi;
+use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyDict, PyIterator, PyList, PyType};
+use std::ffi::c_void;
+use std::os::raw::c_int;
+use std::ptr;
+
+/// The amount of colors listed by the representation before it gets abbreviated
+const REPR_LIMIT: usize = 6;
+
+/// The struct format of the exported buffer, a single unsigned byte per item
+const BUFFER_FORMAT: &std::ffi::CStr = c"B";
+
+#[derive(FromPyObject)]
+pub enum ColorOrArray<'py> {
+    #[pyo3(transparent, annotation = "Color")]
+    Color(Color),
+    #[pyo3(transparent, annotation = "ColorArray")]
+    Array(PyRef<'py, ColorArray>),
+}
+
+#[derive(FromPyObject)]
+pub enum ScaleFactor {
+    #[pyo3(transparent, annotation = "Color")]
+    Color(Color),
+    #[pyo3(transparent, annotation = "float")]
+    Float(f32),
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+#[pyclass(module = "tincture")]
+pub struct ColorArray {
+    pub(crate) data: Vec<u8>,
+}
+
+impl ColorArray {
+    pub(crate) fn from_colors(colors: impl IntoIterator<Item = Color>) -> ColorArray {
+        ColorArray {
+            data: colors
+                .into_iter()
+                .flat_map(|color| [color.r, color.g, color.b, color.a])
+                .collect(),
+        }
+    }
+
+    pub(crate) fn colors(&self) -> impl Iterator<Item = Color> + '_ {
+        self.data
+            .chunks_exact(4)
+            .map(|pixel| Color::new(pixel[0], pixel[1], pixel[2], pixel[3]))
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.data.len() / 4
+    }
+
+    fn color_at(&self, index: usize) -> Color {
+        let pixel: &[u8] = &self.data[index * 4..index * 4 + 4];
+        Color::new(pixel[0], pixel[1], pixel[2], pixel[3])
+    }
+
+    /// Turns a (possibly negative) Python index into a position inside the array
+    fn resolve_index(&self, index: isize) -> PyResult<usize> {
+        let length: isize = self.len() as isize;
+        let resolved: isize = if index < 0 { index + length } else { index };
+        if resolved < 0 || resolved >= length {
+            return Err(PyIndexError::new_err("ColorArray index out of range"));
+        }
+        Ok(resolved as usize)
+    }
+
+    /// Applies the operation to every color paired with either the same color or the color
+    /// at the same position of another array, which has to be of the same length
+    fn zip_with(
+        &self,
+        python: Python<'_>,
+        other: ColorOrArray,
+        operation: impl Fn(Color, Color) -> Color + Send + Sync,
+    ) -> PyResult<ColorArray> {
+        match other {
+            ColorOrArray::Color(other) => Ok(python.allow_threads(|| {
+                ColorArray::from_colors(map_indices(self.len(), |index| {
+                    operation(self.color_at(index), other)
+                }))
+            })),
+            ColorOrArray::Array(other) => {
+                if other.len() != self.len() {
+                    return Err(PyValueError::new_err(format!(
+                        "Cannot combine color arrays of the lengths {} and {}",
+                        self.len(),
+                        other.len()
+                    )));
+                }
+                let other: &ColorArray = &other;
+                Ok(python.allow_threads(|| {
+                    ColorArray::from_colors(map_indices(self.len(), |index| {
+                        operation(self.color_at(index), other.color_at(index))
+                    }))
+                }))
+            }
+        }
+    }
+}
+
+#[pymethods]
+impl ColorArray {
+    #[new]
+    #[pyo3(signature = (colors_or_buffer=None))]
+    fn new(python: Python<'_>, colors_or_buffer: Option<ColorsOrBuffer>) -> PyResult<Self> {
+        match colors_or_buffer {
+            Some(ColorsOrBuffer::Buffer(buffer)) => {
+                let data: Vec<u8> = buffer.to_vec(python)?;
+                if data.len() % 4 != 0 {
+                    return Err(PyValueError::new_err(
+                        "The buffer length must be a multiple of 4 (RGBA pixels)",
+                    ));
+                }
+                Ok(ColorArray { data })
+            }
+            Some(colors_or_buffer) => Ok(ColorArray::from_colors(
+                colors_or_buffer.into_colors(python)?,
+            )),
+            None => Ok(ColorArray::default()),
+        }
+    }
+
+    /// Exports the colors as a read-only buffer of the shape (len, 4), which lets NumPy
+    /// and memoryview access them without copying. The shape and the strides are kept
+    /// alive inside the internal field of the view until it is released
+    unsafe fn __getbuffer__(
+        slf: Bound<'_, Self>,
+        view: *mut ffi::Py_buffer,
+        flags: c_int,
+    ) -> PyResult<()> {
+        if view.is_null() {
+            return Err(PyBufferError::new_err("The buffer view is null"));
+        }
+        if (flags & ffi::PyBUF_WRITABLE) == ffi::PyBUF_WRITABLE {
+            return Err(PyBufferError::new_err("ColorArray buffers are read-only"));
+        }
+        let array: PyRef<'_, ColorArray> = slf.borrow();
+        let layout: Box<[ffi::Py_ssize_t; 4]> = Box::new([array.len() as ffi::Py_ssize_t, 4, 4, 1]);
+        (*view).buf = array.data.as_ptr() as *mut c_void;
+        (*view).len = array.data.len() as ffi::Py_ssize_t;
+        (*view).readonly = 1;
+        (*view).itemsize = 1;
+        (*view).format = if (flags & ffi::PyBUF_FORMAT) == ffi::PyBUF_FORMAT {
+            BUFFER_FORMAT.as_ptr() as *mut _
+        } else {
+            ptr::null_mut()
+        };
+        (*view).ndim = 2;
+        let layout: *mut ffi::Py_ssize_t = Box::into_raw(layout) as *mut ffi::Py_ssize_t;
+        (*view).shape = if (flags & ffi::PyBUF_ND) == ffi::PyBUF_ND {
+            layout
+        } else {
+            ptr::null_mut()
+        };
+        (*view).strides = if (flags & ffi::PyBUF_STRIDES) == ffi::PyBUF_STRIDES {
+            layout.add(2)
+        } else {
+            ptr::null_mut()
+        };
+        (*view).suboffsets = ptr::null_mut();
+        (*view).internal = layout as *mut c_void;
+        drop(array);
+        (*view).obj = slf.into_any().into_ptr();
+        Ok(())
+    }
+
+    unsafe fn __releasebuffer__(&self, view: *mut ffi::Py_buffer) {
+        drop(Box::from_raw((*view).internal as *mut [ffi::Py_ssize_t; 4]));
+    }
+
+    #[staticmethod]
+    #[pyo3(signature = (components, space="oklab"))]
+    pub fn from_components(
+        python: Python<'_>,
+        components: Vec<(f32, f32, f32, f32)>,
+        space: &str,
+    ) -> PyResult<ColorArray> {
+        let space: ColorSpace = ColorSpace::from_name(space)?;
+        Ok(python.allow_threads(|| {
+            ColorArray::from_colors(map_indices(components.len(), |index| {
+                let (first, second, third, alpha) = components[index];
+                space.from_components([first, second, third], alpha)
+            }))
+        }))
+    }
+
+    #[pyo3(signature = (space="oklab"))]
+    pub fn to_components(
+        &self,
+        python: Python<'_>,
+        space: &str,
+    ) -> PyResult<Vec<(f32, f32, f32, f32)>> {
+        let space: ColorSpace = ColorSpace::from_name(space)?;
+        Ok(python.allow_threads(|| {
+            map_indices(self.len(), |index| {
+                let color: Color = self.color_at(index);
+                let components: [f32; 3] = space.to_components(color);
+                (
+                    components[0],
+                    components[1],
+                    components[2],
+                    (color.a as f32) / 255.0,
+                )
+            })
+        }))
+    }
+
+    pub fn to_list(&self) -> Vec<Color> {
+        self.colors().collect()
+    }
+
+    pub fn to_bytes<'py>(&self, python: Python<'py>) -> Bound<'py, PyBytes> {
+        PyBytes::new_bound(python, &self.data)
+    }
+
+    #[pyo3(signature = (other, t, space="srgb", hue_strategy="shorter"))]
+    pub fn lerp(
+        &self,
+        python: Python<'_>,
+        other: ColorOrArray,
+        t: f32,
+        space: &str,
+        hue_strategy: &str,
+    ) -> PyResult<ColorArray> {
+        let space: ColorSpace = ColorSpace::from_name(space)?;
+        let hue_strategy: HueStrategy = HueStrategy::from_name(hue_strategy)?;
+        self.zip_with(python, other, |color, other| {
+            interpolate_in_space(color, other, t, space, hue_strategy)
+        })
+    }
+
+    #[pyo3(signature = (reference, metric="ciede2000"))]
+    pub fn delta_e(
+        &self,
+        python: Python<'_>,
+        reference: Color,
+        metric: &str,
+    ) -> PyResult<Vec<f32>> {
+        let metric: DistanceMetric = DistanceMetric::from_name(metric)?;
+        Ok(python.allow_threads(|| {
+            map_indices(self.len(), |index| {
+                metric.distance(self.color_at(index), reference)
+            })
+        }))
+    }
+
+    pub fn apply_rules(
+        &self,
+        python: Python<'_>,
+        rules: Vec<Bound<'_, PyDict>>,
+    ) -> PyResult<ColorArray> {
+        let rules: Vec<Rule> = rules
+            .iter()
+            .map(Rule::compile)
+            .collect::<PyResult<Vec<Rule>>>()?;
+        Ok(python.allow_threads(|| {
+            ColorArray::from_colors(map_indices(self.len(), |index| {
+                apply_rules(&rules, self.color_at(index))
+            }))
+        }))
+    }
+
+    pub fn __add__(&self, python: Python<'_>, other: ColorOrArray) -> PyResult<ColorArray> {
+        self.zip_with(python, other, |color, other| {
+            color_add_color(&color, &other, false)
+        })
+    }
+
+    pub fn __sub__(&self, python: Python<'_>, other: ColorOrArray) -> PyResult<ColorArray> {
+        self.zip_with(python, other, |color, other| {
+            color_sub_color(&color, &other, false)
+        })
+    }
+
+    pub fn __mul__(&self, python: Python<'_>, factor: ScaleFactor) -> ColorArray {
+        python.allow_threads(|| {
+            ColorArray::from_colors(map_indices(self.len(), |index| {
+                let mut color: Color = self.color_at(index);
+                match factor {
+                    ScaleFactor::Float(factor) => color.mul(factor, false, false),
+                    ScaleFactor::Color(other) => color.tensor(other, false),
+                }
+            }))
+        })
+    }
+
+    pub fn __len__(&self) -> usize {
+        self.len()
+    }
+
+    pub fn __getitem__(&self, python: Python<'_>, index: PaletteIndex) -> PyResult<PyObject> {
+        match index {
+            PaletteIndex::Integer(index) => {
+                Ok(self.color_at(self.resolve_index(index)?).into_py(python))
+            }
+            PaletteIndex::Slice(slice) => {
+                let indices = slice.indices(self.len() as isize)?;
+                let colors = (0..indices.slicelength).map(|offset| {
+                    self.color_at((indices.start + (offset as isize) * indices.step) as usize)
+                });
+                Ok(ColorArray::from_colors(colors).into_py(python))
+            }
+        }
+    }
+
+    pub fn __setitem__(&mut self, index: isize, color: Color) -> PyResult<()> {
+        let index: usize = self.resolve_index(index)?;
+        self.data[index * 4..index * 4 + 4].copy_from_slice(&[color.r, color.g, color.b, color.a]);
+        Ok(())
+    }
+
+    pub fn __iter__<'py>(&self, python: Python<'py>) -> PyResult<Bound<'py, PyIterator>> {
+        let colors: Vec<PyObject> = self.colors().map(|color| color.into_py(python)).collect();
+        PyList::new_bound(python, colors).as_any().iter()
+    }
+
+    pub fn __eq__(&self, other: &ColorArray) -> bool {
+        self.data == other.data
+    }
+
+    pub fn __copy__(&self) -> ColorArray {
+        self.clone()
+    }
+
+    pub fn __deepcopy__(&self, _memo: Bound<'_, PyDict>) -> ColorArray {
+        self.clone()
+    }
+
+    pub fn __reduce__<'py>(
+        &self,
+        python: Python<'py>,
+    ) -> (Bound<'py, PyType>, (Bound<'py, PyBytes>,)) {
+        (
+            python.get_type_bound::<ColorArray>(),
+            (self.to_bytes(python),),
+        )
+    }
+
+    pub fn __repr__(&self) -> String {
+        let describe =
+            |color: Color| format!("Color({}, {}, {}, {})", color.r, color.g, color.b, color.a);
+        let colors: Vec<String> = if self.len() <= REPR_LIMIT {
+            self.colors().map(describe).collect()
+        } else {
+            let half: usize = REPR_LIMIT / 2;
+            let head = (0..half).map(|index| describe(self.color_at(index)));
+            let tail = (self.len() - half..self.len()).map(|index| describe(self.color_at(index)));
+            head.chain(["...".to_string()]).chain(tail).collect()
+        };
+        format!("ColorArray([{}])", colors.join(", "))
+    }
+}
@@ -0,0 +1,661 @@
+use crate::color::array::ColorArray;
+use crate::color::difference::{check_tolerance, group_similar, DistanceMetric};
+use crate::color::gradient::Gradient;
+use crate::color::matrix::{ColorMatrix, HueRotation};
+use crate::color::palette::ColorsOrBuffer;
+use crate::color::parallel::map_indices;
+use crate::color::random::{Jitter, PixelRng};
+use crate::color::spaces::{average_in_space, decimal_rgba_to_color, ColorSpace, GamutMapping};
+use crate::color::transfer::{decode_rgb, encode_slice};
+use crate::color::utils::{
+    check_finite, check_gamma, check_posterize_levels, gamma_color, grayscale_color, parse_swizzle,
+    posterize_color, saturate_color, scale_linear, solarize_color, swizzle_color, threshold_color,
+    vibrance_color, white_balance_gains, Levels, SwizzleSource,
+};
+use crate::color::Color;
+use pyo3::buffer::PyBuffer;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+
+fn check_pixel_length(length: usize) -> PyResult<()> {
+    if length % 4 != 0 {
+        return Err(PyValueError::new_err(
+            "The buffer length must be a multiple of 4 (RGBA pixels)",
+        ));
+    }
+    Ok(())
+}
+
+/// Converts the RGBA pixels into the components of the color space followed by the alpha,
+/// packed as native endian 32-bit floats
+fn pixels_to_components(pixels: &[u8], space: ColorSpace) -> Vec<u8> {
+    let values: Vec<[f32; 4]> = map_indices(pixels.len() / 4, |index| {
+        let pixel: &[u8] = &pixels[index * 4..index * 4 + 4];
+        let components: [f32; 3] =
+            space.to_components(Color::new(pixel[0], pixel[1], pixel[2], pixel[3]));
+        [
+            components[0],
+            components[1],
+            components[2],
+            (pixel[3] as f32) / 255.0,
+        ]
+    });
+    values
+        .iter()
+        .flatten()
+        .flat_map(|value| value.to_ne_bytes())
+        .collect()
+}
+
+/// How many pixels [components_to_pixels] gamma encodes together, the 12 channels of four
+/// pixels fill exactly three vectors of the transfer function
+const ENCODE_GROUP: usize = 4;
+
+/// The reverse of [pixels_to_components], components outside the sRGB gamut are mapped into
+/// it the same way as [ColorSpace::from_components] does
+fn components_to_pixels(values: &[f32], space: ColorSpace) -> Vec<u8> {
+    let count: usize = values.len() / 4;
+    if space.is_gamma_encoded() {
+        let pixels: Vec<[u8; 4]> = map_indices(count, |index| {
+            let value: &[f32] = &values[index * 4..index * 4 + 4];
+            let color: Color = space.from_components([value[0], value[1], value[2]], value[3]);
+            [color.r, color.g, color.b, color.a]
+        });
+        return pixels.concat();
+    }
+    let mapping: GamutMapping = space.gamut_mapping();
+    let groups: Vec<[[u8; 4]; ENCODE_GROUP]> = map_indices(count.div_ceil(ENCODE_GROUP), |group| {
+        let start: usize = group * ENCODE_GROUP;
+        let group_values: &[f32] = &values[start * 4..count.min(start + ENCODE_GROUP) * 4];
+        let mut rgb: [f32; ENCODE_GROUP * 3] = [0.0; ENCODE_GROUP * 3];
+        for (linear, value) in rgb.chunks_exact_mut(3).zip(group_values.chunks_exact(4)) {
+            linear.copy_from_slice(&space.to_linear_srgb([value[0], value[1], value[2]]));
+        }
+        encode_slice(&mut rgb);
+        let mut pixels: [[u8; 4]; ENCODE_GROUP] = [[0; 4]; ENCODE_GROUP];
+        for ((pixel, decimal), value) in pixels
+            .iter_mut()
+            .zip(rgb.chunks_exact(3))
+            .zip(group_values.chunks_exact(4))
+        {
+            let decimal: [f32; 3] = mapping.apply([decimal[0], decimal[1], decimal[2]]);
+            let color: Color = decimal_rgba_to_color(decimal[0], decimal[1], decimal[2], value[3]);
+            *pixel = [color.r, color.g, color.b, color.a];
+        }
+        pixels
+    });
+    groups
+        .iter()
+        .flatten()
+        .take(count)
+        .flatten()
+        .copied()
+        .collect()
+}
+
+fn convert_to<'py>(
+    python: Python<'py>,
+    buffer: PyBuffer<u8>,
+    space: ColorSpace,
+) -> PyResult<Bound<'py, PyBytes>> {
+    let pixels: Vec<u8> = buffer.to_vec(python)?;
+    check_pixel_length(pixels.len())?;
+    let values: Vec<u8> = python.allow_threads(|| pixels_to_components(&pixels, space));
+    Ok(PyBytes::new_bound(python, &values))
+}
+
+fn convert_from<'py>(
+    python: Python<'py>,
+    buffer: PyBuffer<f32>,
+    space: ColorSpace,
+) -> PyResult<Bound<'py, PyBytes>> {
+    let values: Vec<f32> = buffer.to_vec(python)?;
+    check_pixel_length(values.len())?;
+    let pixels: Vec<u8> = python.allow_threads(|| components_to_pixels(&values, space));
+    Ok(PyBytes::new_bound(python, &pixels))
+}
+
+/// Copies the pixels out of a buffer that is about to be adjusted
+fn read_pixels(python: Python<'_>, buffer: &PyBuffer<u8>, in_place: bool) -> PyResult<Vec<u8>> {
+    if in_place && buffer.readonly() {
+        return Err(PyValueError::new_err(
+            "Cannot adjust a read-only buffer in place",
+        ));
+    }
+    let pixels: Vec<u8> = buffer.to_vec(python)?;
+    check_pixel_length(pixels.len())?;
+    Ok(pixels)
+}
+
+/// Applies the adjustment to every pixel, either writing the result back into the buffer
+/// or returning it as new bytes
+fn adjust_pixels<'py>(
+    python: Python<'py>,
+    buffer: PyBuffer<u8>,
+    in_place: bool,
+    adjustment: impl Fn(Color) -> Color + Send + Sync,
+) -> PyResult<Option<Bound<'py, PyBytes>>> {
+    let pixels: Vec<u8> = read_pixels(python, &buffer, in_place)?;
+    adjust_read_pixels(python, buffer, pixels, in_place, adjustment)
+}
+
+/// The second half of [adjust_pixels], for adjustments that need to look at the pixels first
+fn adjust_read_pixels<'py>(
+    python: Python<'py>,
+    buffer: PyBuffer<u8>,
+    pixels: Vec<u8>,
+    in_place: bool,
+    adjustment: impl Fn(Color) -> Color + Send + Sync,
+) -> PyResult<Option<Bound<'py, PyBytes>>> {
+    adjust_indexed_pixels(python, buffer, pixels, in_place, |_, color| {
+        adjustment(color)
+    })
+}
+
+/// Like [adjust_read_pixels], but the adjustment also receives the index of the pixel
+fn adjust_indexed_pixels<'py>(
+    python: Python<'py>,
+    buffer: PyBuffer<u8>,
+    mut pixels: Vec<u8>,
+    in_place: bool,
+    adjustment: impl Fn(usize, Color) -> Color + Send + Sync,
+) -> PyResult<Option<Bound<'py, PyBytes>>> {
+    pixels = python.allow_threads(|| {
+        let adjusted: Vec<[u8; 4]> = map_indices(pixels.len() / 4, |index| {
+            let pixel: &[u8] = &pixels[index * 4..index * 4 + 4];
+            let color: Color = Color::new(pixel[0], pixel[1], pixel[2], pixel[3]);
+            let color: Color = adjustment(index, color);
+            [color.r, color.g, color.b, color.a]
+        });
+        adjusted.concat()
+    });
+    if in_place {
+        buffer.copy_from_slice(python, &pixels)?;
+        return Ok(None);
+    }
+    Ok(Some(PyBytes::new_bound(python, &pixels)))
+}
+
+/// The average linear light of the pixels, fully transparent pixels carry no color and are skipped
+fn average_linear(pixels: &[u8]) -> [f32; 3] {
+    let mut sums: [f64; 3] = [0.0; 3];
+    let mut count: usize = 0;
+    for pixel in pixels.chunks_exact(4).filter(|pixel| pixel[3] != 0) {
+        let rgb: [f32; 3] = decode_rgb(Color::new(pixel[0], pixel[1], pixel[2], pixel[3]));
+        for (sum, value) in sums.iter_mut().zip(rgb) {
+            *sum += value as f64;
+        }
+        count += 1;
+    }
+    sums.map(|sum| (sum / (count.max(1) as f64)) as f32)
+}
+
+/// Applies an adjustment that treats every color channel on its own the same way, which
+/// makes it a lookup into a table of all 256 channel values. The alpha is left untouched
+fn adjust_channels<'py>(
+    python: Python<'py>,
+    buffer: PyBuffer<u8>,
+    in_place: bool,
+    adjustment: impl Fn(Color) -> Color,
+) -> PyResult<Option<Bound<'py, PyBytes>>> {
+    let table: [u8; 256] =
+        std::array::from_fn(|value| adjustment(Color::new(value as u8, 0, 0, 255)).r);
+    adjust_pixels(python, buffer, in_place, |color| {
+        Color::new(
+            table[color.r as usize],
+            table[color.g as usize],
+            table[color.b as usize],
+            color.a,
+        )
+    })
+}
+
+#[pyfunction]
+#[pyo3(signature = (buffer, factor, in_place=false))]
+pub fn brightness<'py>(
+    python: Python<'py>,
+    buffer: PyBuffer<u8>,
+    factor: f32,
+    in_place: bool,
+) -> PyResult<Option<Bound<'py, PyBytes>>> {
+    check_finite(factor, "brightness factor")?;
+    adjust_channels(python, buffer, in_place, |color| color.brightness(factor))
+}
+
+#[pyfunction]
+#[pyo3(signature = (buffer, factor, in_place=false))]
+pub fn contrast<'py>(
+    python: Python<'py>,
+    buffer: PyBuffer<u8>,
+    factor: f32,
+    in_place: bool,
+) -> PyResult<Option<Bound<'py, PyBytes>>> {
+    check_finite(factor, "contrast factor")?;
+    adjust_channels(python, buffer, in_place, |mut color| {
+        color.contrast(factor);
+        color
+    })
+}
+
+#[pyfunction]
+#[pyo3(signature = (buffer, factor, in_place=false))]
+pub fn saturate<'py>(
+    python: Python<'py>,
+    buffer: PyBuffer<u8>,
+    factor: f32,
+    in_place: bool,
+) -> PyResult<Option<Bound<'py, PyBytes>>> {
+    check_finite(factor, "saturation factor")?;
+    adjust_pixels(python, buffer, in_place, |color| {
+        saturate_color(color, factor)
+    })
+}
+
+#[pyfunction]
+#[pyo3(signature = (buffer, factor, in_place=false))]
+pub fn vibrance<'py>(
+    python: Python<'py>,
+    buffer: PyBuffer<u8>,
+    factor: f32,
+    in_place: bool,
+) -> PyResult<Option<Bound<'py, PyBytes>>> {
+    check_finite(factor, "vibrance factor")?;
+    adjust_pixels(python, buffer, in_place, |color| {
+        vibrance_color(color, factor)
+    })
+}
+
+#[pyfunction]
+#[pyo3(signature = (buffer, degrees, space="hsl", in_place=false))]
+pub fn hue_rotate<'py>(
+    python: Python<'py>,
+    buffer: PyBuffer<u8>,
+    degrees: f32,
+    space: &str,
+    in_place: bool,
+) -> PyResult<Option<Bound<'py, PyBytes>>> {
+    let rotation: HueRotation = HueRotation::new(space, degrees)?;
+    adjust_pixels(python, buffer, in_place, |color| rotation.rotate(color))
+}
+
+#[pyfunction]
+#[pyo3(signature = (buffer, stops, in_place=false))]
+pub fn exposure<'py>(
+    python: Python<'py>,
+    buffer: PyBuffer<u8>,
+    stops: f32,
+    in_place: bool,
+) -> PyResult<Option<Bound<'py, PyBytes>>> {
+    check_finite(stops, "exposure")?;
+    let scale: f32 = stops.exp2();
+    adjust_channels(python, buffer, in_place, |color| {
+        scale_linear(color, [scale; 3])
+    })
+}
+
+#[pyfunction]
+#[pyo3(signature = (buffer, gamma, in_place=false))]
+pub fn gamma<'py>(
+    python: Python<'py>,
+    buffer: PyBuffer<u8>,
+    gamma: f32,
+    in_place: bool,
+) -> PyResult<Option<Bound<'py, PyBytes>>> {
+    check_gamma(gamma)?;
+    adjust_channels(python, buffer, in_place, |color| gamma_color(color, gamma))
+}
+
+#[pyfunction]
+#[pyo3(signature = (buffer, in_black, in_white, gamma=1.0, out_black=0, out_white=255, luminance=false, in_place=false))]
+#[allow(clippy::too_many_arguments)]
+pub fn levels<'py>(
+    python: Python<'py>,
+    buffer: PyBuffer<u8>,
+    in_black: u8,
+    in_white: u8,
+    gamma: f32,
+    out_black: u8,
+    out_white: u8,
+    luminance: bool,
+    in_place: bool,
+) -> PyResult<Option<Bound<'py, PyBytes>>> {
+    let levels: Levels = Levels::new(in_black, in_white, gamma, out_black, out_white)?;
+    if luminance {
+        return adjust_pixels(python, buffer, in_place, |color| levels.apply(color, true));
+    }
+    adjust_channels(python, buffer, in_place, |color| levels.apply(color, false))
+}
+
+#[pyfunction]
+#[pyo3(signature = (buffer, reference_white=None, in_place=false))]
+pub fn white_balance<'py>(
+    python: Python<'py>,
+    buffer: PyBuffer<u8>,
+    reference_white: Option<Color>,
+    in_place: bool,
+) -> PyResult<Option<Bound<'py, PyBytes>>> {
+    let pixels: Vec<u8> = read_pixels(python, &buffer, in_place)?;
+    let reference: [f32; 3] = match reference_white {
+        Some(color) => decode_rgb(color),
+        None => python.allow_threads(|| average_linear(&pixels)),
+    };
+    let gains: [f32; 3] = white_balance_gains(reference)?;
+    adjust_read_pixels(python, buffer, pixels, in_place, |color| {
+        scale_linear(color, gains)
+    })
+}
+
+#[pyfunction]
+#[pyo3(signature = (buffer, pattern, in_place=false))]
+pub fn swizzle<'py>(
+    python: Python<'py>,
+    buffer: PyBuffer<u8>,
+    pattern: &str,
+    in_place: bool,
+) -> PyResult<Option<Bound<'py, PyBytes>>> {
+    let sources: [SwizzleSource; 4] = parse_swizzle(pattern)?;
+    adjust_pixels(python, buffer, in_place, |color| {
+        swizzle_color(color, &sources)
+    })
+}
+
+#[pyfunction]
+#[pyo3(signature = (buffer, amount=1.0, in_place=false))]
+pub fn sepia<'py>(
+    python: Python<'py>,
+    buffer: PyBuffer<u8>,
+    amount: f32,
+    in_place: bool,
+) -> PyResult<Option<Bound<'py, PyBytes>>> {
+    let matrix: ColorMatrix = ColorMatrix::sepia(amount)?;
+    adjust_pixels(python, buffer, in_place, |color| matrix.transform(color))
+}
+
+#[pyfunction]
+#[pyo3(signature = (buffer, levels, in_place=false))]
+pub fn posterize<'py>(
+    python: Python<'py>,
+    buffer: PyBuffer<u8>,
+    levels: u8,
+    in_place: bool,
+) -> PyResult<Option<Bound<'py, PyBytes>>> {
+    check_posterize_levels(levels)?;
+    adjust_channels(python, buffer, in_place, |color| {
+        posterize_color(color, levels)
+    })
+}
+
+#[pyfunction]
+#[pyo3(signature = (buffer, threshold=128, in_place=false))]
+pub fn solarize<'py>(
+    python: Python<'py>,
+    buffer: PyBuffer<u8>,
+    threshold: u8,
+    in_place: bool,
+) -> PyResult<Option<Bound<'py, PyBytes>>> {
+    adjust_channels(python, buffer, in_place, |color| {
+        solarize_color(color, threshold)
+    })
+}
+
+#[pyfunction]
+#[pyo3(signature = (buffer, value=128, in_place=false))]
+pub fn threshold<'py>(
+    python: Python<'py>,
+    buffer: PyBuffer<u8>,
+    value: u8,
+    in_place: bool,
+) -> PyResult<Option<Bound<'py, PyBytes>>> {
+    adjust_pixels(python, buffer, in_place, |color| {
+        threshold_color(color, value)
+    })
+}
+
+#[pyfunction]
+#[pyo3(signature = (buffer, matrix, in_place=false))]
+pub fn color_matrix<'py>(
+    python: Python<'py>,
+    buffer: PyBuffer<u8>,
+    matrix: ColorMatrix,
+    in_place: bool,
+) -> PyResult<Option<Bound<'py, PyBytes>>> {
+    adjust_pixels(python, buffer, in_place, |color| matrix.transform(color))
+}
+
+#[pyfunction]
+#[pyo3(signature = (buffer, in_place=false))]
+pub fn grayscale<'py>(
+    python: Python<'py>,
+    buffer: PyBuffer<u8>,
+    in_place: bool,
+) -> PyResult<Option<Bound<'py, PyBytes>>> {
+    adjust_pixels(python, buffer, in_place, grayscale_color)
+}
+
+#[pyfunction]
+#[pyo3(signature = (buffer, dark, light, mid=None, space="oklab", in_place=false))]
+pub fn duotone<'py>(
+    python: Python<'py>,
+    buffer: PyBuffer<u8>,
+    dark: Color,
+    light: Color,
+    mid: Option<Color>,
+    space: &str,
+    in_place: bool,
+) -> PyResult<Option<Bound<'py, PyBytes>>> {
+    let gradient: Gradient = Gradient::duotone(dark, light, mid, space)?;
+    let table: [Color; 256] =
+        std::array::from_fn(|value| gradient.sample_color((value as f32) / 255.0));
+    adjust_pixels(python, buffer, in_place, |color| {
+        let mapped: Color = table[grayscale_color(color).r as usize];
+        Color::new(mapped.r, mapped.g, mapped.b, color.a)
+    })
+}
+
+/// Adds noise to every pixel the same way as [Color::jitter], each pixel draws from its own
+/// generator so that a seeded jitter is reproducible even though the pixels run in parallel
+#[pyfunction]
+#[pyo3(signature = (buffer, sigma=5.0, channels=None, space="rgb", seed=None, in_place=false))]
+pub fn jitter<'py>(
+    python: Python<'py>,
+    buffer: PyBuffer<u8>,
+    sigma: f32,
+    channels: Option<&str>,
+    space: &str,
+    seed: Option<u64>,
+    in_place: bool,
+) -> PyResult<Option<Bound<'py, PyBytes>>> {
+    let jitter: Jitter = Jitter::new(sigma, channels, space)?;
+    let seed: u64 = seed.unwrap_or_else(rand::random);
+    let pixels: Vec<u8> = read_pixels(python, &buffer, in_place)?;
+    adjust_indexed_pixels(python, buffer, pixels, in_place, |index, color| {
+        jitter.apply(color, &mut PixelRng::new(seed, index))
+    })
+}
+
+#[pyfunction]
+#[pyo3(signature = (buffer, space="oklab"))]
+pub fn to_components<'py>(
+    python: Python<'py>,
+    buffer: PyBuffer<u8>,
+    space: &str,
+) -> PyResult<Bound<'py, PyBytes>> {
+    convert_to(python, buffer, ColorSpace::from_name(space)?)
+}
+
+#[pyfunction]
+#[pyo3(signature = (buffer, space="oklab"))]
+pub fn from_components<'py>(
+    python: Python<'py>,
+    buffer: PyBuffer<f32>,
+    space: &str,
+) -> PyResult<Bound<'py, PyBytes>> {
+    convert_from(python, buffer, ColorSpace::from_name(space)?)
+}
+
+#[pyfunction]
+pub fn to_linear<'py>(python: Python<'py>, buffer: PyBuffer<u8>) -> PyResult<Bound<'py, PyBytes>> {
+    convert_to(python, buffer, ColorSpace::LinearSrgb)
+}
+
+#[pyfunction]
+pub fn from_linear<'py>(
+    python: Python<'py>,
+    buffer: PyBuffer<f32>,
+) -> PyResult<Bound<'py, PyBytes>> {
+    convert_from(python, buffer, ColorSpace::LinearSrgb)
+}
+
+#[pyfunction]
+pub fn to_oklab<'py>(python: Python<'py>, buffer: PyBuffer<u8>) -> PyResult<Bound<'py, PyBytes>> {
+    convert_to(python, buffer, ColorSpace::Oklab)
+}
+
+#[pyfunction]
+pub fn from_oklab<'py>(
+    python: Python<'py>,
+    buffer: PyBuffer<f32>,
+) -> PyResult<Bound<'py, PyBytes>> {
+    convert_from(python, buffer, ColorSpace::Oklab)
+}
+
+#[pyfunction]
+pub fn to_oklch<'py>(python: Python<'py>, buffer: PyBuffer<u8>) -> PyResult<Bound<'py, PyBytes>> {
+    convert_to(python, buffer, ColorSpace::Oklch)
+}
+
+#[pyfunction]
+pub fn from_oklch<'py>(
+    python: Python<'py>,
+    buffer: PyBuffer<f32>,
+) -> PyResult<Bound<'py, PyBytes>> {
+    convert_from(python, buffer, ColorSpace::Oklch)
+}
+
+#[pyfunction]
+pub fn to_hsv<'py>(python: Python<'py>, buffer: PyBuffer<u8>) -> PyResult<Bound<'py, PyBytes>> {
+    convert_to(python, buffer, ColorSpace::Hsv)
+}
+
+#[pyfunction]
+pub fn from_hsv<'py>(python: Python<'py>, buffer: PyBuffer<f32>) -> PyResult<Bound<'py, PyBytes>> {
+    convert_from(python, buffer, ColorSpace::Hsv)
+}
+
+#[pyfunction]
+pub fn to_hsl<'py>(python: Python<'py>, buffer: PyBuffer<u8>) -> PyResult<Bound<'py, PyBytes>> {
+    convert_to(python, buffer, ColorSpace::Hsl)
+}
+
+#[pyfunction]
+pub fn from_hsl<'py>(python: Python<'py>, buffer: PyBuffer<f32>) -> PyResult<Bound<'py, PyBytes>> {
+    convert_from(python, buffer, ColorSpace::Hsl)
+}
+
+#[pyfunction]
+pub fn to_lab<'py>(python: Python<'py>, buffer: PyBuffer<u8>) -> PyResult<Bound<'py, PyBytes>> {
+    convert_to(python, buffer, ColorSpace::Lab)
+}
+
+#[pyfunction]
+pub fn from_lab<'py>(python: Python<'py>, buffer: PyBuffer<f32>) -> PyResult<Bound<'py, PyBytes>> {
+    convert_from(python, buffer, ColorSpace::Lab)
+}
+
+#[pyfunction]
+pub fn to_xyz<'py>(python: Python<'py>, buffer: PyBuffer<u8>) -> PyResult<Bound<'py, PyBytes>> {
+    convert_to(python, buffer, ColorSpace::Xyz)
+}
+
+#[pyfunction]
+pub fn from_xyz<'py>(python: Python<'py>, buffer: PyBuffer<f32>) -> PyResult<Bound<'py, PyBytes>> {
+    convert_from(python, buffer, ColorSpace::Xyz)
+}
+
+#[pyfunction]
+#[pyo3(signature = (colors, tolerance=2.0, metric="ciede2000", merge=false))]
+pub fn dedup(
+    python: Python<'_>,
+    colors: ColorsOrBuffer,
+    tolerance: f32,
+    metric: &str,
+    merge: bool,
+) -> PyResult<ColorArray> {
+    let metric: DistanceMetric = DistanceMetric::from_name(metric)?;
+    check_tolerance(tolerance)?;
+    let colors: Vec<Color> = colors.into_colors(python)?;
+    Ok(python.allow_threads(|| {
+        let groups: Vec<Vec<usize>> = group_similar(&colors, tolerance, metric);
+        ColorArray::from_colors(groups.iter().map(|group| {
+            if merge && group.len() > 1 {
+                let members: Vec<Color> = group.iter().map(|index| colors[*index]).collect();
+                average_in_space(&members, &vec![1.0; members.len()], ColorSpace::Oklab)
+            } else {
+                colors[group[0]]
+            }
+        }))
+    }))
+}
+
+#[pyfunction]
+#[pyo3(signature = (haystack, needle, tolerance=2.0, metric="ciede2000"))]
+pub fn approx_contains(
+    python: Python<'_>,
+    haystack: ColorsOrBuffer,
+    needle: Color,
+    tolerance: f32,
+    metric: &str,
+) -> PyResult<bool> {
+    let metric: DistanceMetric = DistanceMetric::from_name(metric)?;
+    check_tolerance(tolerance)?;
+    let haystack: Vec<Color> = haystack.into_colors(python)?;
+    Ok(python.allow_threads(|| {
+        let space: ColorSpace = metric.space();
+        let target: [f32; 3] = space.to_components(needle);
+        haystack
+            .iter()
+            .any(|color| metric.measure(space.to_components(*color), target) < tolerance)
+    }))
+}
+
+pub(crate) fn register(parent: &Bound<'_, PyModule>) -> PyResult<()> {
+    let module: Bound<PyModule> = PyModule::new_bound(parent.py(), "batch")?;
+    module.add_function(wrap_pyfunction!(to_components, &module)?)?;
+    module.add_function(wrap_pyfunction!(from_components, &module)?)?;
+    module.add_function(wrap_pyfunction!(to_linear, &module)?)?;
+    module.add_function(wrap_pyfunction!(from_linear, &module)?)?;
+    module.add_function(wrap_pyfunction!(to_oklab, &module)?)?;
+    module.add_function(wrap_pyfunction!(from_oklab, &module)?)?;
+    module.add_function(wrap_pyfunction!(to_oklch, &module)?)?;
+    module.add_function(wrap_pyfunction!(from_oklch, &module)?)?;
+    module.add_function(wrap_pyfunction!(to_hsv, &module)?)?;
+    module.add_function(wrap_pyfunction!(from_hsv, &module)?)?;
+    module.add_function(wrap_pyfunction!(to_hsl, &module)?)?;
+    module.add_function(wrap_pyfunction!(from_hsl, &module)?)?;
+    module.add_function(wrap_pyfunction!(to_lab, &module)?)?;
+    module.add_function(wrap_pyfunction!(from_lab, &module)?)?;
+    module.add_function(wrap_pyfunction!(to_xyz, &module)?)?;
+    module.add_function(wrap_pyfunction!(from_xyz, &module)?)?;
+    module.add_function(wrap_pyfunction!(brightness, &module)?)?;
+    module.add_function(wrap_pyfunction!(contrast, &module)?)?;
+    module.add_function(wrap_pyfunction!(saturate, &module)?)?;
+    module.add_function(wrap_pyfunction!(vibrance, &module)?)?;
+    module.add_function(wrap_pyfunction!(hue_rotate, &module)?)?;
+    module.add_function(wrap_pyfunction!(grayscale, &module)?)?;
+    module.add_function(wrap_pyfunction!(duotone, &module)?)?;
+    module.add_function(wrap_pyfunction!(jitter, &module)?)?;
+    module.add_function(wrap_pyfunction!(exposure, &module)?)?;
+    module.add_function(wrap_pyfunction!(gamma, &module)?)?;
+    module.add_function(wrap_pyfunction!(levels, &module)?)?;
+    module.add_function(wrap_pyfunction!(white_balance, &module)?)?;
+    module.add_function(wrap_pyfunction!(swizzle, &module)?)?;
+    module.add_function(wrap_pyfunction!(sepia, &module)?)?;
+    module.add_function(wrap_pyfunction!(posterize, &module)?)?;
+    module.add_function(wrap_pyfunction!(solarize, &module)?)?;
+    module.add_function(wrap_pyfunction!(threshold, &module)?)?;
+    module.add_function(wrap_pyfunction!(color_matrix, &module)?)?;
+    module.add_function(wrap_pyfunction!(dedup, &module)?)?;
+    module.add_function(wrap_pyfunction!(approx_contains, &module)?)?;
+    parent.add_submodule(&module)
+}
@@ -1,7 +1,7 @@
 use crate::color::*;
 use pyo3::pyclass;
 
-#[pyclass(eq, eq_int)]
+#[pyclass(module = "tincture", eq, eq_int)]
 #[derive(Clone, PartialEq)]
 pub enum BlendingMode {
     Darken,
@@ -0,0 +1,96 @@
+use crate::color::spaces::{linear_srgb_to_xyz, xyz_to_lab, ColorSpace};
+use crate::color::utils::srgb_to_linear;
+use crate::color::Color;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+const MINIMUM_RAMP_STEPS: usize = 3;
+
+#[derive(Clone, Debug)]
+#[pyclass(module = "tincture")]
+pub struct GrayRampReport {
+    #[pyo3(get)]
+    pub gamma: f32,
+    #[pyo3(get)]
+    pub cast: (f32, f32),
+    #[pyo3(get)]
+    pub delta_e: Vec<f32>,
+}
+
+#[pymethods]
+impl GrayRampReport {
+    #[getter]
+    pub fn average_delta_e(&self) -> f32 {
+        self.delta_e.iter().sum::<f32>() / (self.delta_e.len() as f32)
+    }
+
+    #[getter]
+    pub fn max_delta_e(&self) -> f32 {
+        self.delta_e.iter().copied().fold(0.0, f32::max)
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!(
+            "GrayRampReport(gamma={:.3}, cast=({:.3}, {:.3}), average_delta_e={:.3}, max_delta_e={:.3})",
+            self.gamma,
+            self.cast.0,
+            self.cast.1,
+            self.average_delta_e(),
+            self.max_delta_e()
+        )
+    }
+}
+
+fn ideal_gray_lab(level: f32) -> [f32; 3] {
+    xyz_to_lab(linear_srgb_to_xyz([srgb_to_linear(level); 3]))
+}
+
+pub(crate) fn evaluate_ramp(measured_colors: &[Color]) -> GrayRampReport {
+    let divisor: f32 = (measured_colors.len() - 1) as f32;
+    let mut log_products: f32 = 0.0;
+    let mut log_squares: f32 = 0.0;
+    let mut cast_a: f32 = 0.0;
+    let mut cast_b: f32 = 0.0;
+    let mut delta_e: Vec<f32> = Vec::with_capacity(measured_colors.len());
+    for (index, color) in measured_colors.iter().enumerate() {
+        let level: f32 = (index as f32) / divisor;
+        let measured: [f32; 3] = ColorSpace::Lab.to_components(*color);
+        let ideal: [f32; 3] = ideal_gray_lab(level);
+        delta_e.push(
+            measured
+                .iter()
+                .zip(ideal.iter())
+                .map(|(first, second)| (first - second).powi(2))
+                .sum::<f32>()
+                .sqrt(),
+        );
+        cast_a += measured[1];
+        cast_b += measured[2];
+        let luminance: f32 = ColorSpace::Xyz.to_components(*color)[1];
+        if level > 0.0 && level < 1.0 && luminance > 0.0 {
+            log_products += level.ln() * luminance.ln();
+            log_squares += level.ln().powi(2);
+        }
+    }
+    let count: f32 = measured_colors.len() as f32;
+    GrayRampReport {
+        gamma: if log_squares > 0.0 {
+            log_products / log_squares
+        } else {
+            f32::NAN
+        },
+        cast: (cast_a / count, cast_b / count),
+        delta_e,
+    }
+}
+
+#[pyfunction]
+pub fn evaluate_gray_ramp(measured_colors: Vec<Color>) -> PyResult<GrayRampReport> {
+    if measured_colors.len() < MINIMUM_RAMP_STEPS {
+        return Err(PyValueError::new_err(format!(
+            "A gray ramp needs at least {} measured steps",
+            MINIMUM_RAMP_STEPS
+        )));
+    }
+    Ok(evaluate_ramp(&measured_colors))
+}
@@ -0,0 +1,154 @@
+use crate::color::gradient::{Gradient, Interpolation};
+use crate::color::spaces::{ColorSpace, HueStrategy};
+use crate::color::Color;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+const VIRIDIS: [[u8; 3]; 10] = [
+    [0x44, 0x01, 0x54],
+    [0x48, 0x28, 0x78],
+    [0x3E, 0x4A, 0x89],
+    [0x31, 0x68, 0x8E],
+    [0x26, 0x82, 0x8E],
+    [0x1F, 0x9E, 0x89],
+    [0x35, 0xB7, 0x79],
+    [0x6D, 0xCD, 0x59],
+    [0xB4, 0xDE, 0x2C],
+    [0xFD, 0xE7, 0x25],
+];
+
+const MAGMA: [[u8; 3]; 10] = [
+    [0x00, 0x00, 0x04],
+    [0x18, 0x0F, 0x3E],
+    [0x45, 0x10, 0x77],
+    [0x72, 0x1F, 0x81],
+    [0x9F, 0x2F, 0x7F],
+    [0xCD, 0x40, 0x71],
+    [0xF1, 0x60, 0x5D],
+    [0xFD, 0x95, 0x67],
+    [0xFE, 0xC9, 0x8D],
+    [0xFC, 0xFD, 0xBF],
+];
+
+const INFERNO: [[u8; 3]; 10] = [
+    [0x00, 0x00, 0x04],
+    [0x1B, 0x0C, 0x42],
+    [0x4B, 0x0C, 0x6B],
+    [0x78, 0x1C, 0x6D],
+    [0xA5, 0x2C, 0x60],
+    [0xCF, 0x44, 0x46],
+    [0xED, 0x69, 0x25],
+    [0xFB, 0x9A, 0x06],
+    [0xF7, 0xD0, 0x3C],
+    [0xFC, 0xFF, 0xA4],
+];
+
+const PLASMA: [[u8; 3]; 10] = [
+    [0x0D, 0x08, 0x87],
+    [0x47, 0x03, 0x9F],
+    [0x73, 0x01, 0xA8],
+    [0x9C, 0x17, 0x9E],
+    [0xBD, 0x37, 0x86],
+    [0xD8, 0x57, 0x6B],
+    [0xED, 0x79, 0x53],
+    [0xFA, 0x9E, 0x3B],
+    [0xFD, 0xC9, 0x26],
+    [0xF0, 0xF9, 0x21],
+];
+
+const CIVIDIS: [[u8; 3]; 10] = [
+    [0x00, 0x20, 0x4D],
+    [0x00, 0x33, 0x6F],
+    [0x39, 0x48, 0x6B],
+    [0x57, 0x5C, 0x6D],
+    [0x70, 0x71, 0x73],
+    [0x8A, 0x87, 0x79],
+    [0xA6, 0x9D, 0x75],
+    [0xC4, 0xB5, 0x6C],
+    [0xE4, 0xCF, 0x5B],
+    [0xFF, 0xEA, 0x46],
+];
+
+const TURBO: [[u8; 3]; 10] = [
+    [0x30, 0x12, 0x3B],
+    [0x46, 0x62, 0xD7],
+    [0x36, 0xAA, 0xF9],
+    [0x1A, 0xE4, 0xB6],
+    [0x72, 0xFE, 0x5E],
+    [0xC7, 0xEF, 0x34],
+    [0xFA, 0xBA, 0x39],
+    [0xF6, 0x6B, 0x19],
+    [0xCB, 0x2A, 0x04],
+    [0x7A, 0x04, 0x03],
+];
+
+pub(crate) const COLORMAPS: [(&str, [[u8; 3]; 10]); 6] = [
+    ("viridis", VIRIDIS),
+    ("magma", MAGMA),
+    ("inferno", INFERNO),
+    ("plasma", PLASMA),
+    ("cividis", CIVIDIS),
+    ("turbo", TURBO),
+];
+
+/// The stops of matplotlib's "hot" colormap, which glows from black over red and yellow to
+/// white the way a heated black body does
+const BLACKBODY: [(f32, [u8; 3]); 4] = [
+    (0.0, [0x0B, 0x00, 0x00]),
+    (0.365_079, [0xFF, 0x00, 0x00]),
+    (0.746_032, [0xFF, 0xFF, 0x00]),
+    (1.0, [0xFF, 0xFF, 0xFF]),
+];
+
+pub(crate) fn colormap(data: &[[u8; 3]]) -> PyResult<Gradient> {
+    let divisor: f32 = (data.len() - 1) as f32;
+    let stops: Vec<(f32, Color)> = data
+        .iter()
+        .enumerate()
+        .map(|(index, rgb)| {
+            (
+                (index as f32) / divisor,
+                Color::new(rgb[0], rgb[1], rgb[2], 255),
+            )
+        })
+        .collect();
+    Gradient::create(
+        stops,
+        ColorSpace::Srgb,
+        HueStrategy::Shorter,
+        Interpolation::Linear,
+    )
+}
+
+/// Looks up the colormap that heat values get mapped through, either one of [COLORMAPS] or the
+/// black body ramp
+pub(crate) fn heat_scheme(name: &str) -> PyResult<Gradient> {
+    let name: String = name.to_lowercase();
+    if name == "blackbody" {
+        let stops: Vec<(f32, Color)> = BLACKBODY
+            .iter()
+            .map(|(position, rgb)| (*position, Color::new(rgb[0], rgb[1], rgb[2], 255)))
+            .collect();
+        return Gradient::create(
+            stops,
+            ColorSpace::Srgb,
+            HueStrategy::Shorter,
+            Interpolation::Linear,
+        );
+    }
+    match COLORMAPS.iter().find(|(scheme, _)| *scheme == name) {
+        Some((_, data)) => colormap(data),
+        None => Err(PyValueError::new_err(format!(
+            "Unknown heat scheme \"{}\", expected viridis, magma, inferno, plasma, cividis, turbo or blackbody",
+            name
+        ))),
+    }
+}
+
+pub(crate) fn register(parent: &Bound<'_, PyModule>) -> PyResult<()> {
+    let module: Bound<PyModule> = PyModule::new_bound(parent.py(), "colormaps")?;
+    for (name, data) in COLORMAPS {
+        module.add(name, colormap(&data)?)?;
+    }
+    parent.add_submodule(&module)
+}
@@ -218,4 +218,21 @@ pub const DARK_TEAL: color::Color = color::Color {
     g: 125,
     b: 93,
     a: 255,
-};
\ No newline at end of file
+};
+/// The white point of sRGB and every color space built upon it
+pub const D65_WHITE: [f32; 3] = [0.950_47, 1.0, 1.088_83];
+
+/// The white points of the standard illuminants for the CIE 1931 2° observer, as XYZ with
+/// the luminance normalized to 1
+pub const WHITE_POINTS: [(&str, [f32; 3]); 10] = [
+    ("A", [1.098_5, 1.0, 0.355_85]),
+    ("C", [0.980_74, 1.0, 1.182_32]),
+    ("D50", [0.964_22, 1.0, 0.825_21]),
+    ("D55", [0.956_82, 1.0, 0.921_49]),
+    ("D65", D65_WHITE),
+    ("D75", [0.949_72, 1.0, 1.226_38]),
+    ("E", [1.0, 1.0, 1.0]),
+    ("F2", [0.991_87, 1.0, 0.673_95]),
+    ("F7", [0.950_44, 1.0, 1.087_55]),
+    ("F11", [1.009_66, 1.0, 0.643_7]),
+];
@@ -0,0 +1,367 @@
+use crate::color::adaptation::{to_d65, Illuminant};
+use crate::color::icc::{parse_icc, IccProfile};
+use crate::color::spaces::{
+    decimal_rgba_to_color, invert_matrix, linear_srgb_to_xyz, linear_to_rec2020, multiply_matrix,
+    rec2020_to_linear, xyz_to_linear_srgb, ColorSpace, GamutMapping,
+};
+use crate::color::swatches::BytesOrPath;
+use crate::color::transfer::{decode_rgb, encode_rgb};
+use crate::color::utils::{
+    check_gamma, find_invalid_percentage_range, linear_to_srgb, srgb_to_linear,
+};
+use crate::color::Color;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use std::sync::RwLock;
+
+/// Every registered color space. The definitions are leaked so that [ColorSpace] can refer to
+/// them while staying [Copy], conversions then never have to lock the registry
+static REGISTRY: RwLock<Vec<&'static CustomSpace>> = RwLock::new(Vec::new());
+
+/// The transfer function that gamma encodes the linear channels of a custom color space
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum TransferFunction {
+    Linear,
+    Srgb,
+    Rec2020,
+    Gamma(f32),
+    /// The parametric curve of ICC profiles with the parameters g, a, b, c, d, e and f, which
+    /// is (a * x + b) ^ g + e from d onward and c * x + f below it
+    Parametric([f32; 7]),
+    /// Evenly spaced samples of the curve over [0.0, 1.0], linearly interpolated between
+    Table(Vec<f32>),
+}
+
+#[derive(FromPyObject)]
+pub enum TransferArgument {
+    #[pyo3(transparent, annotation = "str")]
+    Name(String),
+    #[pyo3(transparent, annotation = "float")]
+    Gamma(f32),
+}
+
+impl TransferFunction {
+    fn from_argument(argument: TransferArgument) -> PyResult<TransferFunction> {
+        match argument {
+            TransferArgument::Gamma(gamma) => {
+                check_gamma(gamma)?;
+                Ok(TransferFunction::Gamma(gamma))
+            }
+            TransferArgument::Name(name) => match name.to_lowercase().as_str() {
+                "linear" => Ok(TransferFunction::Linear),
+                "srgb" => Ok(TransferFunction::Srgb),
+                "rec2020" | "rec709" => Ok(TransferFunction::Rec2020),
+                _ => Err(PyValueError::new_err(format!(
+                    "Unknown transfer function \"{}\", expected linear, srgb, rec2020 or a gamma",
+                    name
+                ))),
+            },
+        }
+    }
+
+    /// Encodes a linear channel, mirrored onto negative values for the colors outside the gamut
+    fn encode(&self, value: f32) -> f32 {
+        match self {
+            TransferFunction::Linear => value,
+            TransferFunction::Srgb => linear_to_srgb(value),
+            TransferFunction::Rec2020 => linear_to_rec2020(value),
+            TransferFunction::Gamma(gamma) => value.signum() * value.abs().powf(1.0 / gamma),
+            TransferFunction::Parametric([g, a, b, c, d, e, f]) => {
+                let magnitude: f32 = value.abs();
+                let encoded: f32 = if magnitude >= c * d + f {
+                    ((magnitude - e).max(0.0).powf(1.0 / g) - b) / a
+                } else if *c != 0.0 {
+                    (magnitude - f) / c
+                } else {
+                    0.0
+                };
+                value.signum() * encoded
+            }
+            TransferFunction::Table(samples) => {
+                let magnitude: f32 = value.abs();
+                let index: usize = samples.partition_point(|sample| *sample < magnitude);
+                let position: f32 = if index == 0 {
+                    0.0
+                } else if index == samples.len() {
+                    (samples.len() - 1) as f32
+                } else {
+                    let (low, high): (f32, f32) = (samples[index - 1], samples[index]);
+                    (index - 1) as f32 + (magnitude - low) / (high - low)
+                };
+                value.signum() * position / (samples.len() - 1) as f32
+            }
+        }
+    }
+
+    fn decode(&self, value: f32) -> f32 {
+        match self {
+            TransferFunction::Linear => value,
+            TransferFunction::Srgb => srgb_to_linear(value),
+            TransferFunction::Rec2020 => rec2020_to_linear(value),
+            TransferFunction::Gamma(gamma) => value.signum() * value.abs().powf(*gamma),
+            TransferFunction::Parametric([g, a, b, c, d, e, f]) => {
+                let magnitude: f32 = value.abs();
+                let decoded: f32 = if magnitude >= *d {
+                    (a * magnitude + b).max(0.0).powf(*g) + e
+                } else {
+                    c * magnitude + f
+                };
+                value.signum() * decoded
+            }
+            TransferFunction::Table(samples) => {
+                let position: f32 = value.abs().min(1.0) * (samples.len() - 1) as f32;
+                let index: usize = (position as usize).min(samples.len() - 2);
+                let fraction: f32 = position - index as f32;
+                let decoded: f32 =
+                    samples[index] + (samples[index + 1] - samples[index]) * fraction;
+                value.signum() * decoded
+            }
+        }
+    }
+}
+
+/// An RGB color space defined by the matrix from its linear channels to XYZ relative to D65
+/// (the XYZ every other space converts through) and by the transfer function of each channel
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct CustomSpace {
+    pub(crate) name: String,
+    to_xyz: [[f32; 3]; 3],
+    from_xyz: [[f32; 3]; 3],
+    transfer: [TransferFunction; 3],
+}
+
+impl CustomSpace {
+    /// Builds the space out of a matrix to XYZ relative to the white point, which is adapted
+    /// over to D65 with the Bradford transform
+    pub(crate) fn new(
+        name: &str,
+        to_xyz: [[f32; 3]; 3],
+        white: [f32; 3],
+        transfer: [TransferFunction; 3],
+    ) -> PyResult<CustomSpace> {
+        if name.is_empty() || name.contains(char::is_whitespace) {
+            return Err(PyValueError::new_err(format!(
+                "The color space name \"{}\" cannot be empty or contain whitespace",
+                name
+            )));
+        } else if matches!(ColorSpace::from_name(name), Ok(space) if !matches!(space, ColorSpace::Custom(_)))
+        {
+            return Err(PyValueError::new_err(format!(
+                "The name \"{}\" belongs to a built-in color space",
+                name
+            )));
+        }
+        let columns: [[f32; 3]; 3] = std::array::from_fn(|column| {
+            to_d65(std::array::from_fn(|row| to_xyz[row][column]), white)
+        });
+        let to_xyz: [[f32; 3]; 3] =
+            std::array::from_fn(|row| std::array::from_fn(|column| columns[column][row]));
+        let from_xyz: [[f32; 3]; 3] = invert_matrix(&to_xyz).ok_or_else(|| {
+            PyValueError::new_err("The matrix to XYZ of a color space must be invertible")
+        })?;
+        Ok(CustomSpace {
+            name: name.to_string(),
+            to_xyz,
+            from_xyz,
+            transfer,
+        })
+    }
+
+    /// The matrix to XYZ of the primaries given by their xy chromaticities, scaled so that
+    /// the channels at their maximum add up to the white point
+    fn primaries_matrix(primaries: [(f32, f32); 3], white: [f32; 3]) -> PyResult<[[f32; 3]; 3]> {
+        if primaries.iter().any(|(_, y)| *y <= 0.0) {
+            return Err(PyValueError::new_err(
+                "The y chromaticity of the primaries must be above 0",
+            ));
+        }
+        let columns: [[f32; 3]; 3] = primaries.map(|(x, y)| [x / y, 1.0, (1.0 - x - y) / y]);
+        let matrix: [[f32; 3]; 3] =
+            std::array::from_fn(|row| std::array::from_fn(|column| columns[column][row]));
+        let scale: [f32; 3] = multiply_matrix(
+            &invert_matrix(&matrix)
+                .ok_or_else(|| PyValueError::new_err("The primaries cannot lie on one line"))?,
+            white,
+        );
+        Ok(std::array::from_fn(|row| {
+            std::array::from_fn(|column| matrix[row][column] * scale[column])
+        }))
+    }
+
+    /// The gamma encoded components of the XYZ (relative to D65) values
+    pub(crate) fn encode(&self, xyz: [f32; 3]) -> [f32; 3] {
+        let linear: [f32; 3] = multiply_matrix(&self.from_xyz, xyz);
+        std::array::from_fn(|index| self.transfer[index].encode(linear[index]))
+    }
+
+    /// The XYZ (relative to D65) values of the gamma encoded components
+    pub(crate) fn decode(&self, components: [f32; 3]) -> [f32; 3] {
+        multiply_matrix(
+            &self.to_xyz,
+            std::array::from_fn(|index| self.transfer[index].decode(components[index])),
+        )
+    }
+}
+
+/// Finds the registered color space with the name, ignoring the case
+pub(crate) fn lookup(name: &str) -> Option<&'static CustomSpace> {
+    REGISTRY
+        .read()
+        .unwrap_or_else(|error| error.into_inner())
+        .iter()
+        .find(|space| space.name.eq_ignore_ascii_case(name))
+        .copied()
+}
+
+#[derive(Clone)]
+#[pyclass(module = "tincture", name = "ColorSpace", frozen)]
+pub struct ColorSpaceDefinition {
+    space: CustomSpace,
+}
+
+#[pymethods]
+impl ColorSpaceDefinition {
+    #[staticmethod]
+    #[pyo3(signature = (
+        name,
+        red,
+        green,
+        blue,
+        white=Illuminant::named("D65"),
+        transfer=TransferArgument::Name(String::from("srgb"))
+    ))]
+    pub fn from_primaries(
+        name: &str,
+        red: (f32, f32),
+        green: (f32, f32),
+        blue: (f32, f32),
+        white: Illuminant,
+        transfer: TransferArgument,
+    ) -> PyResult<ColorSpaceDefinition> {
+        let white: [f32; 3] = white.white_point()?;
+        let matrix: [[f32; 3]; 3] = CustomSpace::primaries_matrix([red, green, blue], white)?;
+        let transfer: TransferFunction = TransferFunction::from_argument(transfer)?;
+        Ok(ColorSpaceDefinition {
+            space: CustomSpace::new(name, matrix, white, [(); 3].map(|_| transfer.clone()))?,
+        })
+    }
+
+    #[staticmethod]
+    #[pyo3(signature = (
+        name,
+        to_xyz,
+        white=Illuminant::named("D65"),
+        transfer=TransferArgument::Name(String::from("linear"))
+    ))]
+    pub fn from_matrix(
+        name: &str,
+        to_xyz: [[f32; 3]; 3],
+        white: Illuminant,
+        transfer: TransferArgument,
+    ) -> PyResult<ColorSpaceDefinition> {
+        let white: [f32; 3] = white.white_point()?;
+        let transfer: TransferFunction = TransferFunction::from_argument(transfer)?;
+        Ok(ColorSpaceDefinition {
+            space: CustomSpace::new(name, to_xyz, white, [(); 3].map(|_| transfer.clone()))?,
+        })
+    }
+
+    /// Profiles are named after their description unless a name is given, with the whitespace
+    /// replaced by underscores
+    #[staticmethod]
+    #[pyo3(signature = (data_or_path, name=None))]
+    pub fn from_icc(
+        python: Python<'_>,
+        data_or_path: BytesOrPath,
+        name: Option<String>,
+    ) -> PyResult<ColorSpaceDefinition> {
+        let profile: IccProfile = parse_icc(&data_or_path.into_bytes(python)?)?;
+        let name: String = match name {
+            Some(name) => name,
+            None => profile
+                .description
+                .map(|description| {
+                    description
+                        .split_whitespace()
+                        .collect::<Vec<&str>>()
+                        .join("_")
+                })
+                .ok_or_else(|| {
+                    PyValueError::new_err("The ICC profile has no description, a name is needed")
+                })?,
+        };
+        Ok(ColorSpaceDefinition {
+            space: CustomSpace::new(&name, profile.to_xyz, profile.illuminant, profile.curves)?,
+        })
+    }
+
+    /// Names of the registered color spaces, in the order they were registered
+    #[staticmethod]
+    pub fn registered() -> Vec<String> {
+        REGISTRY
+            .read()
+            .unwrap_or_else(|error| error.into_inner())
+            .iter()
+            .map(|space| space.name.clone())
+            .collect()
+    }
+
+    #[staticmethod]
+    pub fn unregister(name: &str) -> bool {
+        let mut registry = REGISTRY.write().unwrap_or_else(|error| error.into_inner());
+        let length: usize = registry.len();
+        registry.retain(|space| !space.name.eq_ignore_ascii_case(name));
+        registry.len() != length
+    }
+
+    /// Makes the space available by its name to every function taking a color space, a
+    /// previous registration under the same name is replaced
+    pub fn register(&self) {
+        let mut registry = REGISTRY.write().unwrap_or_else(|error| error.into_inner());
+        if let Some(index) = registry
+            .iter()
+            .position(|space| space.name.eq_ignore_ascii_case(&self.space.name))
+        {
+            if *registry[index] != self.space {
+                registry[index] = Box::leak(Box::new(self.space.clone()));
+            }
+            return;
+        }
+        registry.push(Box::leak(Box::new(self.space.clone())));
+    }
+
+    #[getter]
+    pub fn get_name(&self) -> String {
+        self.space.name.clone()
+    }
+
+    #[getter]
+    pub fn get_matrix(&self) -> [[f32; 3]; 3] {
+        self.space.to_xyz
+    }
+
+    pub fn to_components(&self, color: Color) -> (f32, f32, f32) {
+        let components: [f32; 3] = self.space.encode(linear_srgb_to_xyz(decode_rgb(color)));
+        (components[0], components[1], components[2])
+    }
+
+    /// Same as the conversions of the registered space, colors outside of sRGB are brought
+    /// into it by reducing their chroma
+    #[pyo3(signature = (components, transparency=1.0))]
+    pub fn from_components(
+        &self,
+        components: (f32, f32, f32),
+        transparency: f32,
+    ) -> PyResult<Color> {
+        find_invalid_percentage_range(transparency, "Transparency")?;
+        let xyz: [f32; 3] = self
+            .space
+            .decode([components.0, components.1, components.2]);
+        let rgb: [f32; 3] = GamutMapping::OklchChroma.apply(encode_rgb(xyz_to_linear_srgb(xyz)));
+        Ok(decimal_rgba_to_color(rgb[0], rgb[1], rgb[2], transparency))
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!("ColorSpace(\"{}\")", self.space.name)
+    }
+}
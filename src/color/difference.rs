@@ -0,0 +1,174 @@
+use crate::color::parallel::map_indices;
+use crate::color::spaces::ColorSpace;
+use crate::color::Color;
+use pyo3::exceptions::PyValueError;
+use pyo3::PyResult;
+use std::collections::HashMap;
+
+/// The largest value the lightness weight of CIEDE2000 takes (at a lightness of 0 or 100)
+const CIEDE2000_MAXIMUM_LIGHTNESS_WEIGHT: f32 = 1.75;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum DistanceMetric {
+    Ciede2000,
+    Cie76,
+    Oklab,
+}
+
+impl DistanceMetric {
+    pub(crate) fn from_name(name: &str) -> PyResult<DistanceMetric> {
+        match name.to_lowercase().as_str() {
+            "ciede2000" | "de2000" => Ok(DistanceMetric::Ciede2000),
+            "cie76" | "de76" => Ok(DistanceMetric::Cie76),
+            "oklab" => Ok(DistanceMetric::Oklab),
+            _ => Err(PyValueError::new_err(format!(
+                "Unknown distance metric \"{}\", expected \"ciede2000\", \"cie76\" or \"oklab\"",
+                name
+            ))),
+        }
+    }
+
+    /// The color space the metric measures the colors in
+    pub(crate) fn space(&self) -> ColorSpace {
+        match self {
+            DistanceMetric::Ciede2000 | DistanceMetric::Cie76 => ColorSpace::Lab,
+            DistanceMetric::Oklab => ColorSpace::Oklab,
+        }
+    }
+
+    /// The distance between two colors that are already converted into the space of the metric
+    pub(crate) fn measure(&self, first: [f32; 3], second: [f32; 3]) -> f32 {
+        match self {
+            DistanceMetric::Ciede2000 => ciede2000(first, second),
+            DistanceMetric::Cie76 => euclidean(first, second),
+            DistanceMetric::Oklab => euclidean(first, second) * 100.0,
+        }
+    }
+
+    /// The perceptual distance between two colors, the Oklab distance is scaled by 100
+    /// so that all the metrics roughly share the same "just noticeable difference" of ~1-2
+    pub(crate) fn distance(&self, first: Color, second: Color) -> f32 {
+        let space: ColorSpace = self.space();
+        self.measure(space.to_components(first), space.to_components(second))
+    }
+
+    /// Scales the lightness (the first component) so that the distance between two colors
+    /// is never smaller than the difference of their scaled lightness
+    fn lightness_scale(&self) -> f32 {
+        match self {
+            DistanceMetric::Ciede2000 => 1.0 / CIEDE2000_MAXIMUM_LIGHTNESS_WEIGHT,
+            DistanceMetric::Cie76 => 1.0,
+            DistanceMetric::Oklab => 100.0,
+        }
+    }
+}
+
+pub(crate) fn check_tolerance(tolerance: f32) -> PyResult<()> {
+    if tolerance.is_nan() || tolerance < 0.0 {
+        return Err(PyValueError::new_err("The tolerance cannot be negative"));
+    }
+    Ok(())
+}
+
+/// Groups the colors in their order, every color joins the first group whose first color is
+/// closer than the tolerance and otherwise starts a new group. Groups are bucketed by their
+/// scaled lightness, so only the groups of the neighbouring buckets have to be measured
+pub(crate) fn group_similar(
+    colors: &[Color],
+    tolerance: f32,
+    metric: DistanceMetric,
+) -> Vec<Vec<usize>> {
+    let space: ColorSpace = metric.space();
+    let points: Vec<[f32; 3]> =
+        map_indices(colors.len(), |index| space.to_components(colors[index]));
+    // Any width of at least the tolerance works, the lower limit keeps the keys finite
+    let width: f32 = tolerance.max(0.01) / metric.lightness_scale();
+    let mut groups: Vec<Vec<usize>> = Vec::new();
+    let mut buckets: HashMap<i64, Vec<usize>> = HashMap::new();
+    for (index, point) in points.iter().enumerate() {
+        let bucket: i64 = (point[0] / width).floor() as i64;
+        let found: Option<usize> = (bucket - 1..=bucket + 1)
+            .filter_map(|key| buckets.get(&key))
+            .flatten()
+            .copied()
+            .filter(|group| metric.measure(points[groups[*group][0]], *point) < tolerance)
+            .min();
+        match found {
+            Some(group) => groups[group].push(index),
+            None => {
+                buckets.entry(bucket).or_default().push(groups.len());
+                groups.push(vec![index]);
+            }
+        }
+    }
+    groups
+}
+
+fn euclidean(first: [f32; 3], second: [f32; 3]) -> f32 {
+    ((first[0] - second[0]).powi(2)
+        + (first[1] - second[1]).powi(2)
+        + (first[2] - second[2]).powi(2))
+    .sqrt()
+}
+
+/// The CIEDE2000 color difference (Sharma, Wu and Dalal's formulation) between two CIELAB colors
+pub(crate) fn ciede2000(first: [f32; 3], second: [f32; 3]) -> f32 {
+    let [l1, a1, b1]: [f64; 3] = first.map(|value| value as f64);
+    let [l2, a2, b2]: [f64; 3] = second.map(|value| value as f64);
+    let c_average: f64 = ((a1.hypot(b1)) + (a2.hypot(b2))) / 2.0;
+    let g: f64 = 0.5 * (1.0 - (c_average.powi(7) / (c_average.powi(7) + 25f64.powi(7))).sqrt());
+    let a1_prime: f64 = a1 * (1.0 + g);
+    let a2_prime: f64 = a2 * (1.0 + g);
+    let c1_prime: f64 = a1_prime.hypot(b1);
+    let c2_prime: f64 = a2_prime.hypot(b2);
+    let hue = |b: f64, a: f64| -> f64 {
+        if a == 0.0 && b == 0.0 {
+            0.0
+        } else {
+            b.atan2(a).to_degrees().rem_euclid(360.0)
+        }
+    };
+    let h1_prime: f64 = hue(b1, a1_prime);
+    let h2_prime: f64 = hue(b2, a2_prime);
+    let delta_l: f64 = l2 - l1;
+    let delta_c: f64 = c2_prime - c1_prime;
+    let delta_h_angle: f64 = if c1_prime * c2_prime == 0.0 {
+        0.0
+    } else if (h2_prime - h1_prime).abs() <= 180.0 {
+        h2_prime - h1_prime
+    } else if h2_prime - h1_prime > 180.0 {
+        h2_prime - h1_prime - 360.0
+    } else {
+        h2_prime - h1_prime + 360.0
+    };
+    let delta_h: f64 =
+        2.0 * (c1_prime * c2_prime).sqrt() * (delta_h_angle.to_radians() / 2.0).sin();
+    let l_average: f64 = (l1 + l2) / 2.0;
+    let c_prime_average: f64 = (c1_prime + c2_prime) / 2.0;
+    let h_average: f64 = if c1_prime * c2_prime == 0.0 {
+        h1_prime + h2_prime
+    } else if (h1_prime - h2_prime).abs() <= 180.0 {
+        (h1_prime + h2_prime) / 2.0
+    } else if h1_prime + h2_prime < 360.0 {
+        (h1_prime + h2_prime + 360.0) / 2.0
+    } else {
+        (h1_prime + h2_prime - 360.0) / 2.0
+    };
+    let t: f64 = 1.0 - 0.17 * (h_average - 30.0).to_radians().cos()
+        + 0.24 * (2.0 * h_average).to_radians().cos()
+        + 0.32 * (3.0 * h_average + 6.0).to_radians().cos()
+        - 0.20 * (4.0 * h_average - 63.0).to_radians().cos();
+    let delta_theta: f64 = 30.0 * (-((h_average - 275.0) / 25.0).powi(2)).exp();
+    let r_c: f64 =
+        2.0 * (c_prime_average.powi(7) / (c_prime_average.powi(7) + 25f64.powi(7))).sqrt();
+    let s_l: f64 =
+        1.0 + (0.015 * (l_average - 50.0).powi(2)) / (20.0 + (l_average - 50.0).powi(2)).sqrt();
+    let s_c: f64 = 1.0 + 0.045 * c_prime_average;
+    let s_h: f64 = 1.0 + 0.015 * c_prime_average * t;
+    let r_t: f64 = -(2.0 * delta_theta).to_radians().sin() * r_c;
+    ((delta_l / s_l).powi(2)
+        + (delta_c / s_c).powi(2)
+        + (delta_h / s_h).powi(2)
+        + r_t * (delta_c / s_c) * (delta_h / s_h))
+        .sqrt() as f32
+}
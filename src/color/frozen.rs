@@ -0,0 +1,154 @@
+use crate::color::{Color, ColorAccessCode};
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyIterator, PyType};
+
+#[derive(FromPyObject)]
+pub enum AnyColor {
+    #[pyo3(transparent, annotation = "Color")]
+    Color(Color),
+    #[pyo3(transparent, annotation = "FrozenColor")]
+    Frozen(FrozenColor),
+}
+
+impl AnyColor {
+    fn color(&self) -> Color {
+        match self {
+            AnyColor::Color(color) => *color,
+            AnyColor::Frozen(frozen) => frozen.color,
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+#[pyclass(module = "tincture", frozen)]
+pub struct FrozenColor {
+    color: Color,
+}
+
+impl From<Color> for FrozenColor {
+    fn from(color: Color) -> Self {
+        FrozenColor { color }
+    }
+}
+
+#[pymethods]
+impl FrozenColor {
+    #[new]
+    #[pyo3(signature = (r, g, b, a=255))]
+    fn new(r: u8, g: u8, b: u8, a: u8) -> Self {
+        FrozenColor::from(Color { r, g, b, a })
+    }
+
+    #[classattr]
+    pub fn __match_args__() -> (&'static str, &'static str, &'static str, &'static str) {
+        ("r", "g", "b", "a")
+    }
+
+    #[staticmethod]
+    pub fn from_color(color: Color) -> FrozenColor {
+        FrozenColor::from(color)
+    }
+
+    #[pyo3(signature = (r=None, g=None, b=None, a=None))]
+    pub fn replace(
+        &self,
+        r: Option<u8>,
+        g: Option<u8>,
+        b: Option<u8>,
+        a: Option<u8>,
+    ) -> FrozenColor {
+        FrozenColor::from(self.color.replace(r, g, b, a))
+    }
+
+    pub fn thaw(&self) -> Color {
+        self.color
+    }
+
+    #[getter]
+    pub fn get_r(&self) -> u8 {
+        self.color.r
+    }
+
+    #[getter]
+    pub fn get_g(&self) -> u8 {
+        self.color.g
+    }
+
+    #[getter]
+    pub fn get_b(&self) -> u8 {
+        self.color.b
+    }
+
+    #[getter]
+    pub fn get_a(&self) -> u8 {
+        self.color.a
+    }
+
+    pub fn __eq__(&self, other: AnyColor) -> bool {
+        self.color == other.color()
+    }
+
+    pub fn __ne__(&self, other: AnyColor) -> bool {
+        self.color != other.color()
+    }
+
+    /// Matches the hash of the equal [Color], so both can be looked up in the same dict
+    pub fn __hash__(&self, python: Python<'_>) -> u64 {
+        self.color.__hash__(python)
+    }
+
+    pub fn __int__(&self) -> u32 {
+        self.color.to_int(false)
+    }
+
+    pub fn __index__(&self) -> u32 {
+        self.color.to_int(false)
+    }
+
+    pub fn __len__(&self) -> usize {
+        4
+    }
+
+    pub fn __iter__<'py>(&self, python: Python<'py>) -> PyResult<Bound<'py, PyIterator>> {
+        self.color.__iter__(python)
+    }
+
+    pub fn __getitem__(
+        &self,
+        python: Python<'_>,
+        access_code: ColorAccessCode,
+    ) -> PyResult<PyObject> {
+        self.color.__getitem__(python, access_code)
+    }
+
+    pub fn __format__(&self, python: Python<'_>, spec: &str) -> PyResult<String> {
+        self.color.__format__(python, spec)
+    }
+
+    pub fn __str__(&self, python: Python<'_>) -> String {
+        self.color.__str__(python)
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!(
+            "FrozenColor({}, {}, {}, {})",
+            self.color.r, self.color.g, self.color.b, self.color.a
+        )
+    }
+
+    /// Frozen colors cannot change, so copies of them can simply share the same object
+    pub fn __copy__(slf: Py<Self>) -> Py<Self> {
+        slf
+    }
+
+    pub fn __deepcopy__(slf: Py<Self>, _memo: Bound<'_, PyDict>) -> Py<Self> {
+        slf
+    }
+
+    pub fn __reduce__<'py>(&self, python: Python<'py>) -> (Bound<'py, PyType>, (u8, u8, u8, u8)) {
+        (
+            python.get_type_bound::<FrozenColor>(),
+            (self.color.r, self.color.g, self.color.b, self.color.a),
+        )
+    }
+}
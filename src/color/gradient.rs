@@ -0,0 +1,742 @@
+use crate::color::array::ColorArray;
+use crate::color::html::gradient_html;
+use crate::color::lut::{build_cube, check_lut_size, write_or_return};
+use crate::color::spaces::{
+    catmull_rom, catmull_rom_components, decimal_rgba_to_color, interpolate_components, ColorSpace,
+    HueStrategy,
+};
+use crate::color::svg::{
+    check_svg_size, contrasting_text, svg_document, svg_number, svg_paint, svg_text,
+};
+use crate::color::utils::{
+    check_finite, color_to_decimal_rgb, find_invalid_percentage_range, fingerprint,
+};
+use crate::color::Color;
+use pyo3::exceptions::{PyMemoryError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::{PyByteArray, PyBytes, PyDict, PyType};
+use std::f32::consts::PI;
+use std::path::PathBuf;
+
+const UNITY_MAXIMUM_KEYS: usize = 8;
+const GLSL_SAMPLES_PER_SEGMENT: usize = 8;
+const CUBEHELIX_STOPS: usize = 64;
+const TEXT_HEADER: &str = "# tincture gradient";
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum Interpolation {
+    Linear,
+    CatmullRom,
+}
+
+impl Interpolation {
+    pub(crate) fn from_name(name: &str) -> PyResult<Interpolation> {
+        match name.to_lowercase().as_str() {
+            "linear" => Ok(Interpolation::Linear),
+            "catmull_rom" | "catmull-rom" => Ok(Interpolation::CatmullRom),
+            _ => Err(PyValueError::new_err(format!(
+                "Unknown interpolation \"{}\", expected \"linear\" or \"catmull_rom\"",
+                name
+            ))),
+        }
+    }
+
+    pub(crate) fn name(&self) -> &'static str {
+        match self {
+            Interpolation::Linear => "linear",
+            Interpolation::CatmullRom => "catmull_rom",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum WrapMode {
+    Clamp,
+    Repeat,
+    Mirror,
+}
+
+impl WrapMode {
+    pub(crate) fn from_name(name: &str) -> PyResult<WrapMode> {
+        match name.to_lowercase().as_str() {
+            "clamp" => Ok(WrapMode::Clamp),
+            "repeat" => Ok(WrapMode::Repeat),
+            "mirror" => Ok(WrapMode::Mirror),
+            _ => Err(PyValueError::new_err(format!(
+                "Unknown wrap mode \"{}\", expected \"clamp\", \"repeat\" or \"mirror\"",
+                name
+            ))),
+        }
+    }
+
+    /// Folds the position back into the [0, 1] range, positions that are already
+    /// inside the range are left as is for every mode. Infinite and NaN positions have
+    /// no place inside the range (repeating or mirroring them results in NaN)
+    pub(crate) fn wrap(&self, t: f32) -> PyResult<f32> {
+        if !t.is_finite() {
+            return Err(PyValueError::new_err(format!(
+                "The sample position has to be a finite number, got {}",
+                t
+            )));
+        } else if (0.0..=1.0).contains(&t) {
+            return Ok(t);
+        }
+        Ok(match self {
+            WrapMode::Clamp => t,
+            WrapMode::Repeat => t.rem_euclid(1.0),
+            WrapMode::Mirror => {
+                let folded: f32 = t.rem_euclid(2.0);
+                if folded > 1.0 {
+                    2.0 - folded
+                } else {
+                    folded
+                }
+            }
+        })
+    }
+}
+
+/// The constructor arguments a gradient is pickled as
+type GradientArguments = (Vec<(f32, Color)>, &'static str, &'static str, &'static str);
+
+#[derive(Clone, Debug)]
+#[pyclass(module = "tincture")]
+pub struct Gradient {
+    pub(crate) stops: Vec<(f32, Color)>,
+    pub(crate) space: ColorSpace,
+    pub(crate) hue_strategy: HueStrategy,
+    pub(crate) interpolation: Interpolation,
+    components: Vec<[f32; 3]>,
+}
+
+impl Gradient {
+    pub(crate) fn create(
+        mut stops: Vec<(f32, Color)>,
+        space: ColorSpace,
+        hue_strategy: HueStrategy,
+        interpolation: Interpolation,
+    ) -> PyResult<Gradient> {
+        if stops.is_empty() {
+            return Err(PyValueError::new_err(
+                "A gradient needs at least one color stop",
+            ));
+        }
+        for (position, _) in &stops {
+            find_invalid_percentage_range(*position, "Stop position")?;
+        }
+        stops.sort_by(|first, second| first.0.total_cmp(&second.0));
+        let components: Vec<[f32; 3]> = stops
+            .iter()
+            .map(|(_, color)| space.to_components(*color))
+            .collect();
+        Ok(Gradient {
+            stops,
+            space,
+            hue_strategy,
+            interpolation,
+            components,
+        })
+    }
+
+    /// Samples the gradient at the position, positions past the outer stops (including the
+    /// infinities) take the color of the closest stop. NaN has no position and takes the color of
+    /// the first stop, it would otherwise land in front of every stop
+    pub(crate) fn sample_color(&self, t: f32) -> Color {
+        let last: usize = self.stops.len() - 1;
+        if t >= self.stops[last].0 {
+            return self.stops[last].1;
+        } else if t < self.stops[0].0 || t.is_nan() {
+            return self.stops[0].1;
+        }
+        let index: usize = self.stops.partition_point(|(position, _)| *position <= t);
+        let (start_position, start) = self.stops[index - 1];
+        let (end_position, end) = self.stops[index];
+        let local_t: f32 = (t - start_position) / (end_position - start_position);
+        if self.interpolation == Interpolation::CatmullRom {
+            return self.sample_catmull_rom(index, local_t);
+        }
+        let components: [f32; 3] = interpolate_components(
+            self.space,
+            self.components[index - 1],
+            self.components[index],
+            local_t,
+            self.hue_strategy,
+        );
+        let alpha: f32 = ((start.a as f32) + ((end.a as f32) - (start.a as f32)) * local_t) / 255.0;
+        self.space.from_components(components, alpha)
+    }
+
+    /// Samples the spline segment ending at the supplied stop, the outer control points are
+    /// duplicates of the segment's own stops at the ends of the gradient and at hard stops
+    fn sample_catmull_rom(&self, end_index: usize, t: f32) -> Color {
+        let start_index: usize = end_index - 1;
+        let before: usize =
+            if start_index > 0 && self.stops[start_index - 1].0 < self.stops[start_index].0 {
+                start_index - 1
+            } else {
+                start_index
+            };
+        let after: usize = if end_index + 1 < self.stops.len()
+            && self.stops[end_index + 1].0 > self.stops[end_index].0
+        {
+            end_index + 1
+        } else {
+            end_index
+        };
+        let indices: [usize; 4] = [before, start_index, end_index, after];
+        let components: [f32; 3] = catmull_rom_components(
+            self.space,
+            indices.map(|index| self.components[index]),
+            t,
+            self.hue_strategy,
+        );
+        let alpha: f32 = catmull_rom(indices.map(|index| self.stops[index].1.a as f32), t) / 255.0;
+        self.space.from_components(components, alpha)
+    }
+
+    /// Samples the gradient evenly into packed RGBA bytes. A count too large to allocate raises a
+    /// MemoryError, since the release build aborts on panics instead of unwinding into Python
+    pub(crate) fn sample_rgba(&self, count: usize) -> PyResult<Vec<u8>> {
+        let too_large =
+            || PyMemoryError::new_err(format!("Cannot allocate the pixels of {} samples", count));
+        let length: usize = count.checked_mul(4).ok_or_else(too_large)?;
+        let mut pixels: Vec<u8> = Vec::new();
+        pixels.try_reserve_exact(length).map_err(|_| too_large())?;
+        let divisor: f32 = (count.max(2) - 1) as f32;
+        for index in 0..count {
+            let color: Color = self.sample_color((index as f32) / divisor);
+            pixels.extend_from_slice(&[color.r, color.g, color.b, color.a]);
+        }
+        Ok(pixels)
+    }
+
+    /// The stops of the gradient with extra samples in between, so that linearly mixing them
+    /// in sRGB (the way shaders and CSS do) approximates the gradient's own color space and interpolation
+    fn srgb_keys(&self) -> Vec<(f32, Color)> {
+        let mut keys: Vec<(f32, Color)> = Vec::new();
+        for (index, (position, color)) in self.stops.iter().enumerate() {
+            keys.push((*position, *color));
+            let Some((next_position, _)) = self.stops.get(index + 1) else {
+                continue;
+            };
+            if (self.space == ColorSpace::Srgb && self.interpolation == Interpolation::Linear)
+                || *next_position <= *position
+            {
+                continue;
+            }
+            for sample in 1..GLSL_SAMPLES_PER_SEGMENT {
+                let t: f32 = position
+                    + (next_position - position) * (sample as f32)
+                        / (GLSL_SAMPLES_PER_SEGMENT as f32);
+                keys.push((t, self.sample_color(t)));
+            }
+        }
+        keys
+    }
+}
+
+fn glsl_vec4(color: Color) -> String {
+    let rgb: (f32, f32, f32) = color_to_decimal_rgb(color);
+    format!(
+        "vec4({:.4}, {:.4}, {:.4}, {:.4})",
+        rgb.0,
+        rgb.1,
+        rgb.2,
+        (color.a as f32) / 255.0
+    )
+}
+
+fn css_percentage(position: f32) -> String {
+    let formatted: String = format!("{:.2}", position * 100.0);
+    let trimmed: &str = formatted.trim_end_matches('0').trim_end_matches('.');
+    format!("{}%", trimmed)
+}
+
+fn is_glsl_identifier(name: &str) -> bool {
+    let mut characters = name.chars();
+    match characters.next() {
+        Some(first) if first.is_ascii_alphabetic() || first == '_' => {
+            characters.all(|character| character.is_ascii_alphanumeric() || character == '_')
+        }
+        _ => false,
+    }
+}
+
+/// The JSON object a gradient is stored as, shared by [Gradient::to_json] and the tinct container
+pub(crate) fn gradient_to_json<'py>(
+    python: Python<'py>,
+    gradient: &Gradient,
+) -> PyResult<Bound<'py, PyDict>> {
+    let mut stops: Vec<Bound<'py, PyDict>> = Vec::with_capacity(gradient.stops.len());
+    for (position, color) in &gradient.stops {
+        let stop: Bound<'py, PyDict> = PyDict::new_bound(python);
+        stop.set_item("position", position)?;
+        stop.set_item("color", color.to_hex(color.a != 255))?;
+        stops.push(stop);
+    }
+    let object: Bound<'py, PyDict> = PyDict::new_bound(python);
+    object.set_item("space", gradient.space.name())?;
+    object.set_item("hue_strategy", gradient.hue_strategy.name())?;
+    object.set_item("interpolation", gradient.interpolation.name())?;
+    object.set_item("stops", stops)?;
+    Ok(object)
+}
+
+pub(crate) fn gradient_from_json(object: &Bound<'_, PyAny>) -> PyResult<Gradient> {
+    let object: &Bound<PyDict> = object
+        .downcast::<PyDict>()
+        .map_err(|_| PyValueError::new_err("The gradient JSON has to be an object"))?;
+    let setting = |key: &str, default: &'static str| -> PyResult<String> {
+        match object.get_item(key)? {
+            Some(value) => value.extract(),
+            None => Ok(default.to_string()),
+        }
+    };
+    let mut stops: Vec<(f32, Color)> = Vec::new();
+    for stop in object
+        .get_item("stops")?
+        .ok_or_else(|| PyValueError::new_err("The gradient JSON is missing \"stops\""))?
+        .iter()?
+    {
+        let stop: Bound<PyAny> = stop?;
+        let position: f32 = stop.get_item("position")?.extract()?;
+        let hex: String = stop.get_item("color")?.extract()?;
+        stops.push((position, Color::from_hex(&hex)?));
+    }
+    Gradient::create(
+        stops,
+        ColorSpace::from_name(&setting("space", "oklab")?)?,
+        HueStrategy::from_name(&setting("hue_strategy", "shorter")?)?,
+        Interpolation::from_name(&setting("interpolation", "linear")?)?,
+    )
+}
+
+#[pymethods]
+impl Gradient {
+    #[new]
+    #[pyo3(signature = (stops, space="oklab", hue_strategy="shorter", interpolation="linear"))]
+    fn new(
+        stops: Vec<(f32, Color)>,
+        space: &str,
+        hue_strategy: &str,
+        interpolation: &str,
+    ) -> PyResult<Self> {
+        Gradient::create(
+            stops,
+            ColorSpace::from_name(space)?,
+            HueStrategy::from_name(hue_strategy)?,
+            Interpolation::from_name(interpolation)?,
+        )
+    }
+
+    #[staticmethod]
+    #[pyo3(signature = (colors, space="oklab", hue_strategy="shorter", interpolation="linear"))]
+    pub fn from_colors(
+        colors: Vec<Color>,
+        space: &str,
+        hue_strategy: &str,
+        interpolation: &str,
+    ) -> PyResult<Gradient> {
+        let divisor: f32 = (colors.len().max(2) - 1) as f32;
+        let stops: Vec<(f32, Color)> = colors
+            .into_iter()
+            .enumerate()
+            .map(|(index, color)| ((index as f32) / divisor, color))
+            .collect();
+        Gradient::new(stops, space, hue_strategy, interpolation)
+    }
+
+    #[staticmethod]
+    #[pyo3(signature = (start=0.5, rotations=-1.5, hue=1.0, gamma=1.0))]
+    pub fn cubehelix(start: f32, rotations: f32, hue: f32, gamma: f32) -> PyResult<Gradient> {
+        check_finite(start, "start")?;
+        check_finite(rotations, "amount of rotations")?;
+        check_finite(hue, "hue")?;
+        check_finite(gamma, "gamma")?;
+        if gamma <= 0.0 {
+            return Err(PyValueError::new_err("Gamma must be above 0"));
+        } else if hue < 0.0 {
+            return Err(PyValueError::new_err("Hue cannot be negative"));
+        }
+        let stops: Vec<(f32, Color)> = (0..CUBEHELIX_STOPS)
+            .map(|index| {
+                let fraction: f32 = (index as f32) / ((CUBEHELIX_STOPS - 1) as f32);
+                let lightness: f32 = fraction.powf(gamma);
+                let amplitude: f32 = hue * lightness * (1.0 - lightness) / 2.0;
+                let angle: f32 = 2.0 * PI * (start / 3.0 + rotations * fraction);
+                let (sin, cos): (f32, f32) = angle.sin_cos();
+                let color: Color = decimal_rgba_to_color(
+                    lightness + amplitude * (-0.14861 * cos + 1.78277 * sin),
+                    lightness + amplitude * (-0.29227 * cos - 0.90649 * sin),
+                    lightness + amplitude * (1.97294 * cos),
+                    1.0,
+                );
+                (fraction, color)
+            })
+            .collect();
+        Gradient::create(
+            stops,
+            ColorSpace::Srgb,
+            HueStrategy::Shorter,
+            Interpolation::Linear,
+        )
+    }
+
+    #[staticmethod]
+    #[pyo3(signature = (dark, light, mid=None, space="oklab"))]
+    pub fn duotone(
+        dark: Color,
+        light: Color,
+        mid: Option<Color>,
+        space: &str,
+    ) -> PyResult<Gradient> {
+        let mut stops: Vec<(f32, Color)> = vec![(0.0, dark), (1.0, light)];
+        if let Some(mid) = mid {
+            stops.insert(1, (0.5, mid));
+        }
+        Gradient::create(
+            stops,
+            ColorSpace::from_name(space)?,
+            HueStrategy::Shorter,
+            Interpolation::Linear,
+        )
+    }
+
+    #[getter]
+    pub fn stops(&self) -> Vec<(f32, Color)> {
+        self.stops.clone()
+    }
+
+    #[getter]
+    pub fn space(&self) -> &'static str {
+        self.space.name()
+    }
+
+    #[getter]
+    pub fn hue_strategy(&self) -> &'static str {
+        self.hue_strategy.name()
+    }
+
+    #[getter]
+    pub fn interpolation(&self) -> &'static str {
+        self.interpolation.name()
+    }
+
+    #[pyo3(signature = (t, wrap="clamp"))]
+    pub fn sample(&self, t: f32, wrap: &str) -> PyResult<Color> {
+        let wrap: WrapMode = WrapMode::from_name(wrap)?;
+        Ok(self.sample_color(wrap.wrap(t)?))
+    }
+
+    pub fn sample_many<'py>(&self, python: Python<'py>, n: usize) -> PyResult<Bound<'py, PyBytes>> {
+        let pixels: Vec<u8> = python.allow_threads(|| self.sample_rgba(n))?;
+        Ok(PyBytes::new_bound(python, &pixels))
+    }
+
+    pub fn sample_array(&self, python: Python<'_>, n: usize) -> PyResult<ColorArray> {
+        Ok(ColorArray {
+            data: python.allow_threads(|| self.sample_rgba(n))?,
+        })
+    }
+
+    pub fn sample_into<'py>(
+        &self,
+        python: Python<'py>,
+        width: usize,
+    ) -> PyResult<Bound<'py, PyByteArray>> {
+        let pixels: Vec<u8> = python.allow_threads(|| self.sample_rgba(width))?;
+        Ok(PyByteArray::new_bound(python, &pixels))
+    }
+
+    pub fn to_unity_gradient_json(&self, python: Python<'_>) -> PyResult<String> {
+        if self.stops.len() > UNITY_MAXIMUM_KEYS {
+            return Err(PyValueError::new_err(format!(
+                "Unity gradients support at most {} keys, but the gradient has {} stops",
+                UNITY_MAXIMUM_KEYS,
+                self.stops.len()
+            )));
+        }
+        let mut color_keys: Vec<Bound<PyDict>> = Vec::with_capacity(self.stops.len());
+        let mut alpha_keys: Vec<Bound<PyDict>> = Vec::with_capacity(self.stops.len());
+        for (position, color) in &self.stops {
+            let rgb: (f32, f32, f32) = color_to_decimal_rgb(*color);
+            let unity_color: Bound<PyDict> = PyDict::new_bound(python);
+            unity_color.set_item("r", rgb.0)?;
+            unity_color.set_item("g", rgb.1)?;
+            unity_color.set_item("b", rgb.2)?;
+            unity_color.set_item("a", 1.0)?;
+            let color_key: Bound<PyDict> = PyDict::new_bound(python);
+            color_key.set_item("color", unity_color)?;
+            color_key.set_item("time", position)?;
+            color_keys.push(color_key);
+            let alpha_key: Bound<PyDict> = PyDict::new_bound(python);
+            alpha_key.set_item("alpha", (color.a as f32) / 255.0)?;
+            alpha_key.set_item("time", position)?;
+            alpha_keys.push(alpha_key);
+        }
+        let gradient: Bound<PyDict> = PyDict::new_bound(python);
+        gradient.set_item("colorKeys", color_keys)?;
+        gradient.set_item("alphaKeys", alpha_keys)?;
+        gradient.set_item("mode", 0)?;
+        python
+            .import_bound("json")?
+            .call_method1("dumps", (gradient,))?
+            .extract()
+    }
+
+    #[pyo3(signature = (name="gradient"))]
+    pub fn to_glsl(&self, name: &str) -> PyResult<String> {
+        if !is_glsl_identifier(name) {
+            return Err(PyValueError::new_err(format!(
+                "\"{}\" is not a valid GLSL function name",
+                name
+            )));
+        }
+        let keys: Vec<(f32, Color)> = self.srgb_keys();
+        let (first_position, first_color) = keys[0];
+        let mut lines: Vec<String> = vec![
+            format!("vec4 {}(float t) {{", name),
+            format!(
+                "    if (t <= {:.6}) return {};",
+                first_position,
+                glsl_vec4(first_color)
+            ),
+        ];
+        for pair in keys.windows(2) {
+            let ((start_position, start), (end_position, end)) = (pair[0], pair[1]);
+            if end_position <= start_position {
+                continue;
+            }
+            lines.push(format!(
+                "    if (t < {:.6}) return mix({}, {}, (t - {:.6}) / {:.6});",
+                end_position,
+                glsl_vec4(start),
+                glsl_vec4(end),
+                start_position,
+                end_position - start_position
+            ));
+        }
+        lines.push(format!("    return {};", glsl_vec4(keys[keys.len() - 1].1)));
+        lines.push("}".to_string());
+        Ok(lines.join("\n") + "\n")
+    }
+
+    #[pyo3(signature = (kind="linear", angle=90.0))]
+    pub fn to_css(&self, kind: &str, angle: f32) -> PyResult<String> {
+        let prefix: String = match kind.to_lowercase().as_str() {
+            "linear" => format!("linear-gradient({}deg, ", angle),
+            "radial" => "radial-gradient(circle, ".to_string(),
+            "conic" => format!("conic-gradient(from {}deg, ", angle),
+            _ => {
+                return Err(PyValueError::new_err(format!(
+                    "Unknown CSS gradient kind \"{}\"",
+                    kind
+                )))
+            }
+        };
+        let stops: Vec<String> = self
+            .srgb_keys()
+            .iter()
+            .map(|(position, color)| {
+                format!(
+                    "{} {}",
+                    color.to_hex(color.a != 255),
+                    css_percentage(*position)
+                )
+            })
+            .collect();
+        Ok(format!("{}{})", prefix, stops.join(", ")))
+    }
+
+    #[pyo3(signature = (path=None, size=33))]
+    pub fn to_cube(&self, path: Option<PathBuf>, size: usize) -> PyResult<Option<String>> {
+        check_lut_size(size)?;
+        let text: String = build_cube("tincture gradient map", size, |color| {
+            let rgb: (f32, f32, f32) = color_to_decimal_rgb(color);
+            Ok(self.sample_color(0.2126 * rgb.0 + 0.7152 * rgb.1 + 0.0722 * rgb.2))
+        })?;
+        write_or_return(text, path)
+    }
+
+    pub fn to_text(&self) -> String {
+        let mut lines: Vec<String> = vec![
+            TEXT_HEADER.to_string(),
+            format!("space {}", self.space.name()),
+            format!("hue_strategy {}", self.hue_strategy.name()),
+            format!("interpolation {}", self.interpolation.name()),
+        ];
+        for (position, color) in &self.stops {
+            lines.push(format!("{:?} {}", position, color.to_hex(true)));
+        }
+        lines.join("\n") + "\n"
+    }
+
+    #[staticmethod]
+    pub fn from_text(text: &str) -> PyResult<Gradient> {
+        let mut space: ColorSpace = ColorSpace::Oklab;
+        let mut hue_strategy: HueStrategy = HueStrategy::Shorter;
+        let mut interpolation: Interpolation = Interpolation::Linear;
+        let mut stops: Vec<(f32, Color)> = Vec::new();
+        for (number, line) in text.lines().enumerate() {
+            let line: &str = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let Some((key, value)) = line.split_once(char::is_whitespace) else {
+                return Err(PyValueError::new_err(format!(
+                    "Line {} is missing a value",
+                    number + 1
+                )));
+            };
+            let value: &str = value.trim();
+            match key {
+                "space" => space = ColorSpace::from_name(value)?,
+                "hue_strategy" => hue_strategy = HueStrategy::from_name(value)?,
+                "interpolation" => interpolation = Interpolation::from_name(value)?,
+                _ => {
+                    let position: f32 = key.parse().map_err(|_| {
+                        PyValueError::new_err(format!(
+                            "Line {} is neither a setting nor a color stop",
+                            number + 1
+                        ))
+                    })?;
+                    stops.push((position, Color::from_hex(value)?));
+                }
+            }
+        }
+        Gradient::create(stops, space, hue_strategy, interpolation)
+    }
+
+    #[pyo3(signature = (indent=None))]
+    pub fn to_json(&self, python: Python<'_>, indent: Option<usize>) -> PyResult<String> {
+        let object: Bound<PyDict> = gradient_to_json(python, self)?;
+        let options: Bound<PyDict> = PyDict::new_bound(python);
+        options.set_item("indent", indent)?;
+        python
+            .import_bound("json")?
+            .call_method("dumps", (object,), Some(&options))?
+            .extract()
+    }
+
+    #[staticmethod]
+    pub fn from_json(python: Python<'_>, text: &str) -> PyResult<Gradient> {
+        let object: Bound<PyAny> = python
+            .import_bound("json")?
+            .call_method1("loads", (text,))?;
+        gradient_from_json(&object)
+    }
+
+    pub fn fingerprint(&self) -> String {
+        let settings: String = format!(
+            "{} {} {}",
+            self.space.name(),
+            self.hue_strategy.name(),
+            self.interpolation.name()
+        );
+        let mut bytes: Vec<u8> = settings.into_bytes();
+        for (position, color) in &self.stops {
+            // Positions are quantized to 16 bits, so float noise far below that does not count as a change
+            let position: u16 = (position * 65535.0).round() as u16;
+            bytes.extend_from_slice(&position.to_be_bytes());
+            bytes.extend_from_slice(&[color.r, color.g, color.b, color.a]);
+        }
+        fingerprint(&bytes)
+    }
+
+    pub fn __len__(&self) -> usize {
+        self.stops.len()
+    }
+
+    pub fn __eq__(&self, other: &Gradient) -> bool {
+        self.stops == other.stops
+            && self.space == other.space
+            && self.hue_strategy == other.hue_strategy
+            && self.interpolation == other.interpolation
+    }
+
+    pub fn __copy__(&self) -> Gradient {
+        self.clone()
+    }
+
+    pub fn __deepcopy__(&self, _memo: Bound<'_, PyDict>) -> Gradient {
+        self.clone()
+    }
+
+    pub fn __reduce__<'py>(&self, python: Python<'py>) -> (Bound<'py, PyType>, GradientArguments) {
+        (
+            python.get_type_bound::<Gradient>(),
+            (
+                self.stops.clone(),
+                self.space.name(),
+                self.hue_strategy.name(),
+                self.interpolation.name(),
+            ),
+        )
+    }
+
+    #[pyo3(signature = (width=480.0, height=48.0, labels=false))]
+    pub fn to_svg(&self, width: f32, height: f32, labels: bool) -> PyResult<String> {
+        check_svg_size(width, height)?;
+        let stops: String = self
+            .srgb_keys()
+            .iter()
+            .map(|(position, color)| {
+                format!(
+                    "      <stop offset=\"{}\" {}/>\n",
+                    css_percentage(*position),
+                    svg_paint("stop-color", "stop-opacity", *color)
+                )
+            })
+            .collect();
+        let mut body: String = format!(
+            "  <defs>\n    <linearGradient id=\"gradient\" x1=\"0\" y1=\"0\" x2=\"1\" y2=\"0\">\n{}    </linearGradient>\n  </defs>\n  <rect width=\"{}\" height=\"{}\" fill=\"url(#gradient)\"/>\n",
+            stops,
+            svg_number(width),
+            svg_number(height)
+        );
+        if labels {
+            let font_size: f32 = (height / 4.0).min(12.0);
+            for (position, color) in &self.stops {
+                let anchor: &str = match position {
+                    position if *position <= 0.0 => "start",
+                    position if *position >= 1.0 => "end",
+                    _ => "middle",
+                };
+                body += &svg_text(
+                    position.clamp(0.0, 1.0) * width,
+                    height - font_size * 0.75,
+                    font_size,
+                    anchor,
+                    contrasting_text(*color),
+                    &color.to_hex(color.a != 255),
+                );
+            }
+        }
+        Ok(svg_document(width, height, &body))
+    }
+
+    pub fn _repr_html_(&self) -> PyResult<String> {
+        let css: String = self.to_css("linear", 90.0)?;
+        Ok(gradient_html(&css, self.stops.len()))
+    }
+
+    pub fn __repr__(&self) -> String {
+        let stops: Vec<String> = self
+            .stops
+            .iter()
+            .map(|(position, color)| {
+                format!(
+                    "({:?}, Color({}, {}, {}, {}))",
+                    position, color.r, color.g, color.b, color.a
+                )
+            })
+            .collect();
+        format!(
+            "Gradient([{}], space=\"{}\", hue_strategy=\"{}\", interpolation=\"{}\")",
+            stops.join(", "),
+            self.space.name(),
+            self.hue_strategy.name(),
+            self.interpolation.name()
+        )
+    }
+}
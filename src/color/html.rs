@@ -0,0 +1,84 @@
+use crate::color::palette::Palette;
+use crate::color::Color;
+
+/// Drawn underneath every swatch, so that (semi) transparent colors remain recognizable
+const CHECKERBOARD: &str = "repeating-conic-gradient(#ccc 0% 25%, #fff 0% 50%) 50% / 8px 8px";
+
+pub(crate) fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// A box filled with the CSS image (drawn on top of the checkerboard)
+fn swatch(image: &str, title: &str, width: usize, height: usize) -> String {
+    format!(
+        "<span title=\"{}\" style=\"display:inline-block;width:{}px;height:{}px;\
+         border:1px solid #888;background:{}, {}\"></span>",
+        escape_html(title),
+        width,
+        height,
+        image,
+        CHECKERBOARD
+    )
+}
+
+fn solid(color: Color) -> String {
+    let hex: String = color.to_hex(color.a != 255);
+    format!("linear-gradient({}, {})", hex, hex)
+}
+
+fn color_title(color: Color, name: Option<&String>) -> String {
+    let hex: String = color.to_hex(color.a != 255);
+    match name {
+        Some(name) => format!("{} {}", name, hex),
+        None => hex,
+    }
+}
+
+pub(crate) fn color_html(color: Color) -> String {
+    let hex: String = color.to_hex(color.a != 255);
+    format!(
+        "<div style=\"display:inline-flex;align-items:center;gap:6px;font-family:monospace\">\
+         {}<span>{}</span></div>",
+        swatch(&solid(color), &hex, 20, 20),
+        hex
+    )
+}
+
+pub(crate) fn palette_html(palette: &Palette) -> String {
+    let swatches: Vec<String> = palette
+        .colors
+        .iter()
+        .zip(&palette.names)
+        .map(|(color, name)| swatch(&solid(*color), &color_title(*color, name.as_ref()), 24, 24))
+        .collect();
+    let title: String = match &palette.name {
+        Some(name) => format!("{} ({} colors)", escape_html(name), palette.colors.len()),
+        None => format!("Palette ({} colors)", palette.colors.len()),
+    };
+    let layout: String = match palette.columns {
+        Some(columns) => format!(
+            "display:inline-grid;grid-template-columns:repeat({}, 26px)",
+            columns
+        ),
+        None => "display:flex;flex-wrap:wrap;max-width:560px".to_string(),
+    };
+    format!(
+        "<div style=\"font-family:monospace\"><div>{}</div>\
+         <div style=\"{};gap:2px\">{}</div></div>",
+        title,
+        layout,
+        swatches.join("")
+    )
+}
+
+pub(crate) fn gradient_html(css: &str, stops: usize) -> String {
+    format!(
+        "<div style=\"font-family:monospace\"><div>Gradient ({} stops)</div>\
+         {}</div>",
+        stops,
+        swatch(css, "", 256, 24)
+    )
+}
@@ -0,0 +1,206 @@
+use crate::color::custom_space::TransferFunction;
+use pyo3::exceptions::PyValueError;
+use pyo3::PyResult;
+
+const HEADER_SIZE: usize = 128;
+const PROFILE_SIGNATURE: &[u8; 4] = b"acsp";
+
+/// What a matrix/TRC profile holds, the colorants and the illuminant are the XYZ values of the
+/// profile connection space, which are relative to the illuminant (D50 for every real profile)
+pub(crate) struct IccProfile {
+    pub(crate) description: Option<String>,
+    pub(crate) illuminant: [f32; 3],
+    pub(crate) to_xyz: [[f32; 3]; 3],
+    pub(crate) curves: [TransferFunction; 3],
+}
+
+fn slice(data: &[u8], offset: usize, length: usize) -> PyResult<&[u8]> {
+    data.get(offset..offset.saturating_add(length))
+        .ok_or_else(|| PyValueError::new_err("The ICC profile ended unexpectedly"))
+}
+
+fn u16_at(data: &[u8], offset: usize) -> PyResult<u16> {
+    Ok(u16::from_be_bytes(
+        slice(data, offset, 2)?.try_into().unwrap(),
+    ))
+}
+
+fn u32_at(data: &[u8], offset: usize) -> PyResult<u32> {
+    Ok(u32::from_be_bytes(
+        slice(data, offset, 4)?.try_into().unwrap(),
+    ))
+}
+
+/// A signed 15.16 fixed point number
+fn fixed_at(data: &[u8], offset: usize) -> PyResult<f32> {
+    Ok((u32_at(data, offset)? as i32 as f64 / 65536.0) as f32)
+}
+
+fn xyz_at(data: &[u8], offset: usize) -> PyResult<[f32; 3]> {
+    Ok([
+        fixed_at(data, offset)?,
+        fixed_at(data, offset + 4)?,
+        fixed_at(data, offset + 8)?,
+    ])
+}
+
+/// Parses a matrix/TRC profile of version 2 or 4, the kind displays and working spaces use.
+/// Profiles built out of lookup tables (printers and most scanners) are refused
+pub(crate) fn parse_icc(data: &[u8]) -> PyResult<IccProfile> {
+    if slice(data, 36, 4).ok() != Some(PROFILE_SIGNATURE.as_slice()) {
+        return Err(PyValueError::new_err(
+            "The data is not an ICC profile, its signature is missing",
+        ));
+    } else if slice(data, 16, 4)? != b"RGB " || slice(data, 20, 4)? != b"XYZ " {
+        return Err(PyValueError::new_err(
+            "Only RGB profiles with an XYZ connection space are supported",
+        ));
+    }
+    let illuminant: [f32; 3] = xyz_at(data, 68)?;
+    let count: usize = u32_at(data, HEADER_SIZE)? as usize;
+    let mut tags: Vec<(&[u8], &[u8])> = Vec::new();
+    for index in 0..count {
+        let entry: usize = HEADER_SIZE + 4 + index * 12;
+        let offset: usize = u32_at(data, entry + 4)? as usize;
+        let length: usize = u32_at(data, entry + 8)? as usize;
+        tags.push((slice(data, entry, 4)?, slice(data, offset, length)?));
+    }
+    let tag = |signature: &[u8; 4]| -> PyResult<&[u8]> {
+        tags.iter()
+            .find(|(name, _)| *name == signature)
+            .map(|(_, tag)| *tag)
+            .ok_or_else(|| {
+                PyValueError::new_err(format!(
+                    "The ICC profile has no \"{}\" tag, only matrix/TRC profiles are supported",
+                    String::from_utf8_lossy(signature).trim()
+                ))
+            })
+    };
+
+    let colorants: [[f32; 3]; 3] = [
+        parse_xyz(tag(b"rXYZ")?)?,
+        parse_xyz(tag(b"gXYZ")?)?,
+        parse_xyz(tag(b"bXYZ")?)?,
+    ];
+    Ok(IccProfile {
+        description: tag(b"desc").ok().and_then(parse_text),
+        illuminant,
+        to_xyz: std::array::from_fn(|row| std::array::from_fn(|column| colorants[column][row])),
+        curves: [
+            parse_curve(tag(b"rTRC")?)?,
+            parse_curve(tag(b"gTRC")?)?,
+            parse_curve(tag(b"bTRC")?)?,
+        ],
+    })
+}
+
+fn parse_xyz(tag: &[u8]) -> PyResult<[f32; 3]> {
+    if slice(tag, 0, 4)? != b"XYZ " {
+        return Err(PyValueError::new_err(
+            "The colorant tags of the ICC profile have to be of the XYZ type",
+        ));
+    }
+    xyz_at(tag, 8)
+}
+
+/// Reads a tone reproduction curve, either sampled ("curv") or parametric ("para")
+fn parse_curve(tag: &[u8]) -> PyResult<TransferFunction> {
+    match slice(tag, 0, 4)? {
+        b"curv" => {
+            let count: usize = u32_at(tag, 8)? as usize;
+            match count {
+                0 => Ok(TransferFunction::Linear),
+                // A single entry is the gamma as an unsigned 8.8 fixed point number
+                1 => {
+                    let gamma: f32 = (u16_at(tag, 12)? as f32) / 256.0;
+                    check_curve_gamma(gamma)?;
+                    Ok(TransferFunction::Gamma(gamma))
+                }
+                _ => Ok(TransferFunction::Table(
+                    (0..count)
+                        .map(|index| Ok((u16_at(tag, 12 + index * 2)? as f32) / 65535.0))
+                        .collect::<PyResult<Vec<f32>>>()?,
+                )),
+            }
+        }
+        b"para" => {
+            let kind: u16 = u16_at(tag, 8)?;
+            let length: usize = match kind {
+                0 => 1,
+                1 => 3,
+                2 => 4,
+                3 => 5,
+                4 => 7,
+                _ => {
+                    return Err(PyValueError::new_err(format!(
+                        "Unknown parametric curve type {} in the ICC profile",
+                        kind
+                    )))
+                }
+            };
+            let mut values: [f32; 7] = [0.0; 7];
+            for (index, value) in values.iter_mut().take(length).enumerate() {
+                *value = fixed_at(tag, 12 + index * 4)?;
+            }
+            let [g, a, b, c, d, e, f]: [f32; 7] = values;
+            if values.iter().any(|value| !value.is_finite()) {
+                return Err(PyValueError::new_err(
+                    "The parametric curve of the ICC profile has non-finite parameters",
+                ));
+            }
+            check_curve_gamma(g)?;
+            // Encoding divides by a, which the types past the plain gamma have to leave non-zero
+            if kind != 0 && a == 0.0 {
+                return Err(PyValueError::new_err(
+                    "The parametric curve of the ICC profile cannot have a zero slope",
+                ));
+            }
+            // Every type is a special case of the last one: (a * x + b) ^ g + e above d and
+            // c * x + f below it
+            Ok(TransferFunction::Parametric(match kind {
+                0 => [g, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+                1 => [g, a, b, 0.0, -b / a, 0.0, 0.0],
+                2 => [g, a, b, 0.0, -b / a, c, c],
+                _ => [g, a, b, c, d, e, f],
+            }))
+        }
+        kind => Err(PyValueError::new_err(format!(
+            "Unsupported tone reproduction curve type \"{}\" in the ICC profile",
+            String::from_utf8_lossy(kind).trim()
+        ))),
+    }
+}
+
+fn check_curve_gamma(gamma: f32) -> PyResult<()> {
+    if !(gamma.is_finite() && gamma > 0.0) {
+        return Err(PyValueError::new_err(format!(
+            "The gamma of a tone reproduction curve in the ICC profile has to be above 0, got {}",
+            gamma
+        )));
+    }
+    Ok(())
+}
+
+/// Reads the description, stored as ASCII in version 2 profiles and as localized UTF-16
+/// strings in version 4 ones, where the first localization is picked
+fn parse_text(tag: &[u8]) -> Option<String> {
+    let text: String = match slice(tag, 0, 4).ok()? {
+        b"desc" => {
+            let length: usize = u32_at(tag, 8).ok()? as usize;
+            String::from_utf8_lossy(slice(tag, 12, length).ok()?).to_string()
+        }
+        b"mluc" => {
+            let length: usize = u32_at(tag, 20).ok()? as usize;
+            let offset: usize = u32_at(tag, 24).ok()? as usize;
+            let units: Vec<u16> = slice(tag, offset, length)
+                .ok()?
+                .chunks_exact(2)
+                .map(|unit| u16::from_be_bytes([unit[0], unit[1]]))
+                .collect();
+            String::from_utf16_lossy(&units)
+        }
+        _ => return None,
+    };
+    let text: &str = text.trim_end_matches('\0').trim();
+    Some(text.to_string()).filter(|text| !text.is_empty())
+}
@@ -0,0 +1,96 @@
+/// A 3-dimensional KD-tree over color components, it is built once and answers
+/// nearest neighbour queries in logarithmic time on average
+#[derive(Clone, Debug)]
+pub(crate) struct KdTree {
+    nodes: Vec<KdNode>,
+    root: Option<usize>,
+}
+
+#[derive(Clone, Debug)]
+struct KdNode {
+    point: [f32; 3],
+    index: usize,
+    axis: usize,
+    left: Option<usize>,
+    right: Option<usize>,
+}
+
+fn squared_distance(first: [f32; 3], second: [f32; 3]) -> f32 {
+    (first[0] - second[0]).powi(2) + (first[1] - second[1]).powi(2) + (first[2] - second[2]).powi(2)
+}
+
+impl KdTree {
+    pub(crate) fn new(points: &[[f32; 3]]) -> KdTree {
+        let mut entries: Vec<([f32; 3], usize)> = points
+            .iter()
+            .enumerate()
+            .map(|(index, point)| (*point, index))
+            .collect();
+        let mut tree: KdTree = KdTree {
+            nodes: Vec::with_capacity(points.len()),
+            root: None,
+        };
+        tree.root = tree.build(&mut entries, 0);
+        tree
+    }
+
+    fn build(&mut self, entries: &mut [([f32; 3], usize)], depth: usize) -> Option<usize> {
+        if entries.is_empty() {
+            return None;
+        }
+        let axis: usize = depth % 3;
+        entries.sort_by(|first, second| first.0[axis].total_cmp(&second.0[axis]));
+        let middle: usize = entries.len() / 2;
+        let (point, index) = entries[middle];
+        let node: usize = self.nodes.len();
+        self.nodes.push(KdNode {
+            point,
+            index,
+            axis,
+            left: None,
+            right: None,
+        });
+        let (lower, upper) = entries.split_at_mut(middle);
+        self.nodes[node].left = self.build(lower, depth + 1);
+        self.nodes[node].right = self.build(&mut upper[1..], depth + 1);
+        Some(node)
+    }
+
+    /// Returns the index (in the original points) of the point closest to the target,
+    /// ties are resolved in favour of the lowest index
+    pub(crate) fn nearest(&self, target: [f32; 3]) -> Option<usize> {
+        let mut best: Option<(f32, usize)> = None;
+        self.search(self.root, target, &mut best);
+        best.map(|(_, index)| index)
+    }
+
+    fn search(&self, node: Option<usize>, target: [f32; 3], best: &mut Option<(f32, usize)>) {
+        let Some(node) = node else {
+            return;
+        };
+        let current: &KdNode = &self.nodes[node];
+        let distance: f32 = squared_distance(current.point, target);
+        let improves: bool = match best {
+            None => true,
+            Some((best_distance, best_index)) => {
+                distance < *best_distance
+                    || (distance == *best_distance && current.index < *best_index)
+            }
+        };
+        if improves {
+            *best = Some((distance, current.index));
+        }
+        let difference: f32 = target[current.axis] - current.point[current.axis];
+        let (near, far) = if difference < 0.0 {
+            (current.left, current.right)
+        } else {
+            (current.right, current.left)
+        };
+        self.search(near, target, best);
+        if let Some((best_distance, _)) = best {
+            if difference * difference <= *best_distance {
+                self.search(far, target, best);
+            }
+        }
+    }
+}
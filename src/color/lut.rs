@@ -0,0 +1,75 @@
+use crate::color::spaces::decimal_rgba_to_color;
+use crate::color::utils::color_to_decimal_rgb;
+use crate::color::Color;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use std::fs;
+use std::path::PathBuf;
+
+pub(crate) const MINIMUM_LUT_SIZE: usize = 2;
+pub(crate) const MAXIMUM_LUT_SIZE: usize = 256;
+
+pub(crate) fn check_lut_size(size: usize) -> PyResult<()> {
+    if !(MINIMUM_LUT_SIZE..=MAXIMUM_LUT_SIZE).contains(&size) {
+        return Err(PyValueError::new_err(format!(
+            "The LUT size must be between {} and {}",
+            MINIMUM_LUT_SIZE, MAXIMUM_LUT_SIZE
+        )));
+    }
+    Ok(())
+}
+
+/// Builds the text of a .cube 3D LUT, where the red channel changes the fastest
+/// followed by the green channel and then the blue channel, as the format requires
+pub(crate) fn build_cube(
+    title: &str,
+    size: usize,
+    mut transform: impl FnMut(Color) -> PyResult<Color>,
+) -> PyResult<String> {
+    let mut lines: Vec<String> = vec![
+        format!("TITLE \"{}\"", title.replace('"', "'")),
+        format!("LUT_3D_SIZE {}", size),
+        "DOMAIN_MIN 0.0 0.0 0.0".to_string(),
+        "DOMAIN_MAX 1.0 1.0 1.0".to_string(),
+    ];
+    let divisor: f32 = (size - 1) as f32;
+    for blue in 0..size {
+        for green in 0..size {
+            for red in 0..size {
+                let input: Color = decimal_rgba_to_color(
+                    (red as f32) / divisor,
+                    (green as f32) / divisor,
+                    (blue as f32) / divisor,
+                    1.0,
+                );
+                let output: (f32, f32, f32) = color_to_decimal_rgb(transform(input)?);
+                lines.push(format!("{:.6} {:.6} {:.6}", output.0, output.1, output.2));
+            }
+        }
+    }
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Writes the LUT into the path when one is supplied, otherwise hands back the text itself
+pub(crate) fn write_or_return(text: String, path: Option<PathBuf>) -> PyResult<Option<String>> {
+    match path {
+        Some(path) => {
+            fs::write(path, text)?;
+            Ok(None)
+        }
+        None => Ok(Some(text)),
+    }
+}
+
+#[pyfunction]
+#[pyo3(signature = (transform, size=33, path=None, title="tincture"))]
+pub fn export_lut(
+    transform: Bound<'_, PyAny>,
+    size: usize,
+    path: Option<PathBuf>,
+    title: &str,
+) -> PyResult<Option<String>> {
+    check_lut_size(size)?;
+    let text: String = build_cube(title, size, |color| transform.call1((color,))?.extract())?;
+    write_or_return(text, path)
+}
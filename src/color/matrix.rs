@@ -0,0 +1,231 @@
+use crate::color::spaces::ColorSpace;
+use crate::color::utils::check_finite;
+use crate::color::Color;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyType};
+
+/// The weights of the luminance that the saturation and sepia presets are built upon,
+/// the same ones as the filter effects of SVG and CSS use
+const LUMINANCE: [f32; 3] = [0.213, 0.715, 0.072];
+
+/// How the hue of colors gets rotated, either in one of the cylindrical spaces or through the
+/// hue rotation matrix of CSS, which is cheaper but does not preserve the lightness
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum HueRotation {
+    Space(ColorSpace, f32),
+    Matrix(ColorMatrix),
+}
+
+impl HueRotation {
+    pub(crate) fn new(space: &str, degrees: f32) -> PyResult<HueRotation> {
+        check_finite(degrees, "hue rotation")?;
+        match space.to_lowercase().as_str() {
+            "hsl" => Ok(HueRotation::Space(ColorSpace::Hsl, degrees)),
+            "oklch" => Ok(HueRotation::Space(ColorSpace::Oklch, degrees)),
+            "matrix" => Ok(HueRotation::Matrix(ColorMatrix::hue_rotate(degrees))),
+            _ => Err(PyValueError::new_err(format!(
+                "Unknown hue rotation space \"{}\", expected hsl, oklch or matrix",
+                space
+            ))),
+        }
+    }
+
+    pub(crate) fn rotate(&self, color: Color) -> Color {
+        match self {
+            HueRotation::Space(space, degrees) => {
+                let mut components: [f32; 3] = space.to_components(color);
+                let index: usize = space.hue_index().unwrap_or(0);
+                components[index] = (components[index] + degrees).rem_euclid(360.0);
+                space.from_components(components, (color.a as f32) / 255.0)
+            }
+            HueRotation::Matrix(matrix) => matrix.transform(color),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[pyclass(module = "tincture")]
+pub struct ColorMatrix {
+    values: [f32; 20],
+}
+
+impl ColorMatrix {
+    fn from_rows(rows: [[f32; 3]; 3]) -> ColorMatrix {
+        let mut values: [f32; 20] = [0.0; 20];
+        for (row, weights) in rows.iter().enumerate() {
+            values[row * 5..row * 5 + 3].copy_from_slice(weights);
+        }
+        values[18] = 1.0;
+        ColorMatrix { values }
+    }
+
+    /// Applies the matrix to the channels of the color scaled to [0.0, 1.0], the results are
+    /// clamped back into the channel range
+    pub(crate) fn transform(&self, color: Color) -> Color {
+        let channels: [f32; 4] = [
+            (color.r as f32) / 255.0,
+            (color.g as f32) / 255.0,
+            (color.b as f32) / 255.0,
+            (color.a as f32) / 255.0,
+        ];
+        let result: [u8; 4] = std::array::from_fn(|row| {
+            let weights: &[f32] = &self.values[row * 5..row * 5 + 5];
+            let value: f32 = channels
+                .iter()
+                .zip(weights)
+                .map(|(channel, weight)| channel * weight)
+                .sum::<f32>()
+                + weights[4];
+            (value.clamp(0.0, 1.0) * 255.0).round() as u8
+        });
+        Color::new(result[0], result[1], result[2], result[3])
+    }
+
+    /// The matrix that applies `first` and then `second`, the last row of both is the
+    /// implicit (0, 0, 0, 0, 1) that carries the offsets through
+    fn compose(first: &ColorMatrix, second: &ColorMatrix) -> ColorMatrix {
+        let mut values: [f32; 20] = [0.0; 20];
+        for row in 0..4 {
+            for column in 0..5 {
+                let mut value: f32 = (0..4)
+                    .map(|index| second.values[row * 5 + index] * first.values[index * 5 + column])
+                    .sum();
+                if column == 4 {
+                    value += second.values[row * 5 + 4];
+                }
+                values[row * 5 + column] = value;
+            }
+        }
+        ColorMatrix { values }
+    }
+}
+
+#[pymethods]
+impl ColorMatrix {
+    #[new]
+    fn new(values: Vec<f32>) -> PyResult<Self> {
+        let values: [f32; 20] = values.try_into().map_err(|values: Vec<f32>| {
+            PyValueError::new_err(format!(
+                "A color matrix needs 20 values (4 rows of 5), got {}",
+                values.len()
+            ))
+        })?;
+        Ok(ColorMatrix { values })
+    }
+
+    #[staticmethod]
+    pub fn identity() -> ColorMatrix {
+        ColorMatrix::from_rows([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]])
+    }
+
+    #[staticmethod]
+    pub fn saturation(amount: f32) -> ColorMatrix {
+        ColorMatrix::from_rows(std::array::from_fn(|row| {
+            std::array::from_fn(|column| {
+                let identity: f32 = if row == column { 1.0 } else { 0.0 };
+                LUMINANCE[column] + (identity - LUMINANCE[column]) * amount
+            })
+        }))
+    }
+
+    #[staticmethod]
+    #[pyo3(signature = (amount=1.0))]
+    pub fn sepia(amount: f32) -> PyResult<ColorMatrix> {
+        check_finite(amount, "sepia amount")?;
+        let amount: f32 = amount.clamp(0.0, 1.0);
+        let sepia: [[f32; 3]; 3] = [
+            [0.393, 0.769, 0.189],
+            [0.349, 0.686, 0.168],
+            [0.272, 0.534, 0.131],
+        ];
+        Ok(ColorMatrix::from_rows(std::array::from_fn(|row| {
+            std::array::from_fn(|column| {
+                let identity: f32 = if row == column { 1.0 } else { 0.0 };
+                identity + (sepia[row][column] - identity) * amount
+            })
+        })))
+    }
+
+    #[staticmethod]
+    pub fn hue_rotate(degrees: f32) -> ColorMatrix {
+        let (sin, cos): (f32, f32) = degrees.to_radians().sin_cos();
+        ColorMatrix::from_rows([
+            [
+                0.213 + cos * 0.787 - sin * 0.213,
+                0.715 - cos * 0.715 - sin * 0.715,
+                0.072 - cos * 0.072 + sin * 0.928,
+            ],
+            [
+                0.213 - cos * 0.213 + sin * 0.143,
+                0.715 + cos * 0.285 + sin * 0.140,
+                0.072 - cos * 0.072 - sin * 0.283,
+            ],
+            [
+                0.213 - cos * 0.213 - sin * 0.787,
+                0.715 - cos * 0.715 + sin * 0.715,
+                0.072 + cos * 0.928 + sin * 0.072,
+            ],
+        ])
+    }
+
+    #[staticmethod]
+    pub fn invert() -> ColorMatrix {
+        let mut matrix: ColorMatrix =
+            ColorMatrix::from_rows([[-1.0, 0.0, 0.0], [0.0, -1.0, 0.0], [0.0, 0.0, -1.0]]);
+        for row in 0..3 {
+            matrix.values[row * 5 + 4] = 1.0;
+        }
+        matrix
+    }
+
+    #[getter]
+    pub fn get_values(&self) -> Vec<f32> {
+        self.values.to_vec()
+    }
+
+    pub fn apply(&self, color: Color) -> Color {
+        self.transform(color)
+    }
+
+    pub fn then(&self, other: &ColorMatrix) -> ColorMatrix {
+        ColorMatrix::compose(self, other)
+    }
+
+    pub fn __matmul__(&self, other: &ColorMatrix) -> ColorMatrix {
+        ColorMatrix::compose(other, self)
+    }
+
+    pub fn __call__(&self, color: Color) -> Color {
+        self.transform(color)
+    }
+
+    pub fn __eq__(&self, other: &ColorMatrix) -> bool {
+        self == other
+    }
+
+    pub fn __ne__(&self, other: &ColorMatrix) -> bool {
+        self != other
+    }
+
+    pub fn __copy__(&self) -> ColorMatrix {
+        *self
+    }
+
+    pub fn __deepcopy__(&self, _memo: Bound<'_, PyDict>) -> ColorMatrix {
+        *self
+    }
+
+    pub fn __reduce__<'py>(&self, python: Python<'py>) -> (Bound<'py, PyType>, (Vec<f32>,)) {
+        (python.get_type_bound::<ColorMatrix>(), (self.get_values(),))
+    }
+
+    pub fn __repr__(&self) -> String {
+        let values: Vec<String> = self
+            .values
+            .iter()
+            .map(|value| format!("{value:?}"))
+            .collect();
+        format!("ColorMatrix([{}])", values.join(", "))
+    }
+}
@@ -1,23 +1,58 @@
 use crate::color::utils::*;
-use num_bigint::{BigInt, Sign};
+use num_bigint::BigInt;
 use pyo3::exceptions::{PyIndexError, PyTypeError, PyValueError, PyZeroDivisionError};
 use pyo3::prelude::PyTupleMethods;
-use pyo3::types::{PyAnyMethods, PyList, PyTuple};
-use pyo3::{pyclass, pymethods, Bound, FromPyObject, PyResult, Python};
+use pyo3::types::{
+    PyAny, PyAnyMethods, PyBytes, PyDict, PyDictMethods, PyIterator, PyList, PySequence, PySlice,
+    PySliceIndices, PySliceMethods, PyTuple, PyType,
+};
+use pyo3::{pyclass, pymethods, Bound, FromPyObject, IntoPy, PyErr, PyObject, PyResult, Python};
 use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
 use std::f32;
 use std::f32::consts::PI;
 use std::hash::{Hash, Hasher};
-use rand::rngs::ThreadRng;
 
+mod adaptation;
+mod ansi;
+pub mod appearance;
+pub mod array;
+pub mod batch;
 pub mod blending;
+pub mod brewer;
+pub mod calibration;
+pub mod colormaps;
 pub mod consts;
+pub mod custom_space;
+mod difference;
+pub mod frozen;
+pub mod gradient;
+mod html;
+mod icc;
+mod kdtree;
+pub mod lut;
+pub mod matrix;
+pub mod palette;
+pub mod parallel;
+mod pdf;
+pub mod picker;
+pub mod pool;
+mod quantize;
+pub mod random;
+pub mod reactive;
+mod rules;
+pub mod scale;
+mod spaces;
+mod svg;
+mod swatches;
+pub mod tinct;
+mod transfer;
 mod utils;
 
 
 #[repr(C)]
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
-#[pyclass]
+#[pyclass(module = "tincture")]
 pub struct Color {
     #[pyo3(get, set)]
     pub r: u8,
@@ -30,27 +65,93 @@ pub struct Color {
 }
 
 #[derive(FromPyObject)]
-pub enum ColorAccessCode {
+pub enum ColorAccessCode<'py> {
     #[pyo3(transparent, annotation = "int")]
     Integer(u8),
     #[pyo3(transparent, annotation = "str")]
     String(String),
+    #[pyo3(transparent, annotation = "slice")]
+    Slice(Bound<'py, PySlice>),
 }
 
 #[derive(FromPyObject)]
-pub enum ColorOrScalar {
+pub enum ChannelValues {
     #[pyo3(transparent, annotation = "int")]
-    Integer(BigInt),
+    Integer(u8),
+    #[pyo3(transparent, annotation = "Sequence[int]")]
+    Sequence(Vec<u8>),
+}
+
+/// A value for each of the RGB channels and optionally the alpha channel, as given by a tuple or list
+pub struct ChannelTuple<T> {
+    pub(crate) rgb: [T; 3],
+    pub(crate) alpha: Option<T>,
+}
+
+/// The values of a sequence meant for the channels. Every sequence is claimed, whatever its length
+/// or contents, so that the operators raise an error for it instead of returning NotImplemented.
+/// Otherwise Python reports a misleading TypeError, or for `list * color` repeats the list by the
+/// packed integer that __index__ returns
+pub struct ChannelSequence<T>(Result<Vec<T>, String>);
+
+impl<'py, T: FromPyObject<'py>> FromPyObject<'py> for ChannelSequence<T> {
+    fn extract_bound(object: &Bound<'py, PyAny>) -> PyResult<Self> {
+        object.downcast::<PySequence>()?;
+        Ok(ChannelSequence(
+            object.extract().map_err(|error: PyErr| error.to_string()),
+        ))
+    }
+}
+
+impl<T: Copy> ChannelSequence<T> {
+    pub(crate) fn channels(&self) -> PyResult<ChannelTuple<T>> {
+        let values: &Vec<T> = self.0.as_ref().map_err(|error| {
+            PyTypeError::new_err(format!("Expected numbers for the channels, {}", error))
+        })?;
+        match values[..] {
+            [r, g, b] => Ok(ChannelTuple {
+                rgb: [r, g, b],
+                alpha: None,
+            }),
+            [r, g, b, a] => Ok(ChannelTuple {
+                rgb: [r, g, b],
+                alpha: Some(a),
+            }),
+            _ => Err(PyValueError::new_err(format!(
+                "Expected a value for 3 or 4 channels but got {}",
+                values.len()
+            ))),
+        }
+    }
+}
+
+// A color converts to a number through __index__, so the color variants have to be tried first
+#[derive(FromPyObject)]
+pub enum ColorOrScalar {
     #[pyo3(transparent, annotation = "Color")]
     Color(Color),
+    #[pyo3(transparent, annotation = "int")]
+    Integer(BigInt),
+    #[pyo3(transparent, annotation = "tuple[int, ...]")]
+    Channels(ChannelSequence<i64>),
 }
 
 #[derive(FromPyObject)]
 pub enum ColorOrFloat {
-    #[pyo3(transparent, annotation = "float")]
-    Float(f32),
     #[pyo3(transparent, annotation = "Color")]
     Color(Color),
+    #[pyo3(transparent, annotation = "float")]
+    Float(f32),
+    #[pyo3(transparent, annotation = "tuple[float, ...]")]
+    Factors(ChannelSequence<f32>),
+}
+
+#[derive(FromPyObject)]
+pub enum HexStrings {
+    #[pyo3(transparent, annotation = "str")]
+    Text(String),
+    #[pyo3(transparent, annotation = "list[str]")]
+    List(Vec<String>),
 }
 
 
@@ -62,6 +163,11 @@ impl Color {
         Color { r, g, b, a }
     }
 
+    #[classattr]
+    pub fn __match_args__() -> (&'static str, &'static str, &'static str, &'static str) {
+        ("r", "g", "b", "a")
+    }
+
     #[staticmethod]
     pub fn from_srgb(r: u8, g: u8, b: u8) -> PyResult<Color> {
         Ok(Color { r, g, b, a: 255 })
@@ -92,19 +198,27 @@ impl Color {
     }
 
     #[staticmethod]
-    #[pyo3(signature = (x, y, z, transparency=1.0))]
-    pub fn from_xyz(x: f32, y: f32, z: f32, transparency: f32) -> PyResult<Color> {
-        if !(0.0..=95.047).contains(&x) {
-            return Err(PyValueError::new_err("X must be between 0 and 95"));
-        } else if !(0.0..=100.0).contains(&y) {
-            return Err(PyValueError::new_err("Y must be between 0.0 and 100.0"));
-        } else if !(0.0..=108.883).contains(&z) {
-            return Err(PyValueError::new_err("Z must be between 0.0 and 108.883"));
+    #[pyo3(signature = (x, y, z, transparency=1.0, illuminant=adaptation::Illuminant::named("D65")))]
+    pub fn from_xyz(
+        x: f32,
+        y: f32,
+        z: f32,
+        transparency: f32,
+        illuminant: adaptation::Illuminant,
+    ) -> PyResult<Color> {
+        let white: [f32; 3] = illuminant.white_point()?;
+        for (value, (name, bound)) in [x, y, z].iter().zip(["X", "Y", "Z"].iter().zip(white)) {
+            // The tolerance absorbs the rounding of scaling the white point up to 100
+            if !(0.0..=bound * 100.0 + 1e-3).contains(value) {
+                return Err(PyValueError::new_err(format!(
+                    "{} must be between 0.0 and {:.3}",
+                    name,
+                    bound * 100.0
+                )));
+            }
         }
         find_invalid_percentage_range(transparency, "Transparency")?;
-        let x: f32 = x / 100.0;
-        let y: f32 = y / 100.0;
-        let z: f32 = z / 100.0;
+        let [x, y, z]: [f32; 3] = adaptation::to_d65([x / 100.0, y / 100.0, z / 100.0], white);
 
         let mut r: f32 = x * 3.2406 + y * -1.5372 + z * -0.4986;
         let mut g: f32 = x * -0.9689 + y * 1.8758 + z * 0.0415;
@@ -129,6 +243,23 @@ impl Color {
         Ok(to_unit_rgb(r, g, b, transparency))
     }
 
+    /// CIELAB relative to the white point of the illuminant, colors outside of sRGB are brought
+    /// into it by reducing their chroma
+    #[staticmethod]
+    #[pyo3(signature = (l, a, b, transparency=1.0, illuminant=adaptation::Illuminant::named("D65")))]
+    pub fn from_lab(
+        l: f32,
+        a: f32,
+        b: f32,
+        transparency: f32,
+        illuminant: adaptation::Illuminant,
+    ) -> PyResult<Color> {
+        find_invalid_percentage_range(transparency, "Transparency")?;
+        let white: [f32; 3] = illuminant.white_point()?;
+        let xyz: [f32; 3] = spaces::lab_to_xyz_relative([l, a, b], white);
+        Ok(spaces::ColorSpace::Xyz.from_components(adaptation::to_d65(xyz, white), transparency))
+    }
+
     #[staticmethod]
     pub fn from_lch(l: f32, c: f32, h: i16, transparency: f32) -> PyResult<Color> {
         if !(0.0..=100.0).contains(&l) {
@@ -185,37 +316,60 @@ impl Color {
         Ok(to_unit_rgb(r + m, g + m, b + m, transparency))
     }
 
+    #[staticmethod]
+    pub fn from_ansi256(index: u8) -> Color {
+        ansi::ansi256_color(index)
+    }
+
     #[staticmethod]
     pub fn from_hex(hex_string: &str) -> PyResult<Color> {
-        let mut adjusted_str: String = hex_string.to_string();
-        if hex_string.starts_with("#") {
-            adjusted_str = hex_string.strip_prefix("#").unwrap().to_string();
-        }
-        if adjusted_str.len() != 6 || adjusted_str.len() != 8 {
-            return Err(PyValueError::new_err("Invalid Hex String Length"));
-        }
-        let r: Result<u8, String> = interpret_to_hex(&adjusted_str, 0..2);
-        let g: Result<u8, String> = interpret_to_hex(&adjusted_str, 2..4);
-        let b: Result<u8, String> = interpret_to_hex(&adjusted_str, 4..6);
-        let mut a: Result<u8, String> = Ok(255);
-        if adjusted_str.len() == 8 {
-            a = interpret_to_hex(&adjusted_str, 4..6);
-        }
-        match (r, g, b, a) {
-            (Ok(r), Ok(g), Ok(b), Ok(a)) => Ok(Color::new(r, g, b, a)),
-            (Err(_), _, _, _) => Err(PyValueError::new_err(
-                "Cannot Interpret The First Hexadecimal Part",
-            )),
-            (_, Err(_), _, _) => Err(PyValueError::new_err(
-                "Cannot Interpret The Second Hexadecimal Part",
-            )),
-            (_, _, Err(_), _) => Err(PyValueError::new_err(
-                "Cannot Interpret The Third Hexadecimal Part",
-            )),
-            (_, _, _, Err(_)) => Err(PyValueError::new_err(
-                "Cannot Interpret The Fourth Hexadecimal Part",
-            )),
-        }
+        parse_hex(hex_string).map_err(PyValueError::new_err)
+    }
+
+    #[staticmethod]
+    pub fn parse_hex_many(python: Python<'_>, hex_strings: HexStrings) -> PyResult<Vec<Color>> {
+        python.allow_threads(|| {
+            let parse = |(index, hex_string): (usize, &str)| {
+                parse_hex(hex_string.trim()).map_err(|error| {
+                    PyValueError::new_err(format!(
+                        "{} (\"{}\" at index {})",
+                        error,
+                        hex_string.trim(),
+                        index
+                    ))
+                })
+            };
+            match &hex_strings {
+                HexStrings::Text(text) => text
+                    .lines()
+                    .filter(|line| !line.trim().is_empty())
+                    .enumerate()
+                    .map(parse)
+                    .collect(),
+                HexStrings::List(list) => list
+                    .iter()
+                    .map(String::as_str)
+                    .enumerate()
+                    .map(parse)
+                    .collect(),
+            }
+        })
+    }
+
+    #[staticmethod]
+    #[pyo3(signature = (colors, include_transparency=false))]
+    pub fn format_hex_many(
+        python: Python<'_>,
+        colors: palette::ColorsOrBuffer,
+        include_transparency: bool,
+    ) -> PyResult<Vec<String>> {
+        let colors: Vec<Color> = colors.into_colors(python)?;
+        Ok(python.allow_threads(|| {
+            colors
+                .iter()
+                .map(|color| color.to_hex(include_transparency))
+                .collect()
+        }))
     }
 
     #[staticmethod]
@@ -237,9 +391,20 @@ impl Color {
     }
 
     #[staticmethod]
-    pub fn mlerp(start: Color, end: Color, t: f32) -> PyResult<Color> {
+    #[pyo3(signature = (start, end, t, linear=false))]
+    pub fn mlerp(start: Color, end: Color, t: f32, linear: bool) -> PyResult<Color> {
         find_invalid_percentage_range(t, "t")?;
         let t_inverted: f32 = 1.0 - t;
+        if linear {
+            let rgba1: (f32, f32, f32, f32) = color_to_linear_rgba(start);
+            let rgba2: (f32, f32, f32, f32) = color_to_linear_rgba(end);
+            return Ok(linear_to_unit_rgb(
+                (t_inverted * rgba1.0) + t * rgba2.0,
+                (t_inverted * rgba1.1) + t * rgba2.1,
+                (t_inverted * rgba1.2) + t * rgba2.2,
+                (t_inverted * rgba1.3) + t * rgba2.3,
+            ));
+        }
         Ok(Color {
             r: ((t_inverted * start.r as f32) + t * (end.r as f32)).floor() as u8,
             g: ((t_inverted * start.g as f32) + t * (end.g as f32)).floor() as u8,
@@ -249,28 +414,65 @@ impl Color {
     }
 
     #[staticmethod]
-    pub fn clerp(start: Color, end: Color, t: f32) -> PyResult<Color> {
+    #[pyo3(signature = (start, end, t, hue_strategy="shorter"))]
+    pub fn clerp(start: Color, end: Color, t: f32, hue_strategy: &str) -> PyResult<Color> {
         find_invalid_percentage_range(t, "t")?;
-        let lch_start: (f32, f32, u16) = color_to_lch(start);
-        let lch_end: (f32, f32, u16) = color_to_lch(end);
-        let one_minus_t: f32 = 1.0 - t;
-        let new_values: (f32, f32, f32, f32) = (
-            (one_minus_t * lch_start.0) + (t * lch_end.0),
-            (one_minus_t * lch_start.1) + (t * lch_end.1),
-            (one_minus_t * (lch_start.2 as f32)) + (t * (lch_end.2 as f32)),
-            (one_minus_t * (start.a as f32)) + (t * (end.a as f32)),
-        );
-        Color::from_lch(
-            new_values.0,
-            new_values.1,
-            new_values.2.floor() as i16,
-            new_values.3 / 255.0,
-        )
+        Ok(spaces::interpolate_in_space(
+            start,
+            end,
+            t,
+            spaces::ColorSpace::Oklch,
+            spaces::HueStrategy::from_name(hue_strategy)?,
+        ))
+    }
+
+    #[staticmethod]
+    #[pyo3(signature = (start, end, t, space="srgb", hue_strategy="shorter"))]
+    pub fn lerp(
+        start: Color,
+        end: Color,
+        t: f32,
+        space: &str,
+        hue_strategy: &str,
+    ) -> PyResult<Color> {
+        find_invalid_percentage_range(t, "t")?;
+        Ok(spaces::interpolate_in_space(
+            start,
+            end,
+            t,
+            spaces::ColorSpace::from_name(space)?,
+            spaces::HueStrategy::from_name(hue_strategy)?,
+        ))
+    }
+
+    #[staticmethod]
+    #[pyo3(signature = (c00, c10, c01, c11, u, v, space="srgb", hue_strategy="shorter"))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn bilerp(
+        c00: Color,
+        c10: Color,
+        c01: Color,
+        c11: Color,
+        u: f32,
+        v: f32,
+        space: &str,
+        hue_strategy: &str,
+    ) -> PyResult<Color> {
+        find_invalid_percentage_range(u, "u")?;
+        find_invalid_percentage_range(v, "v")?;
+        Ok(spaces::bilinear_in_space(
+            [c00, c10, c01, c11],
+            u,
+            v,
+            spaces::ColorSpace::from_name(space)?,
+            spaces::HueStrategy::from_name(hue_strategy)?,
+        ))
     }
 
-    pub fn mlerp_inplace(&mut self, end: Color, t: f32) -> PyResult<()> {
+    #[pyo3(signature = (end, t, linear=false))]
+    pub fn mlerp_inplace(&mut self, end: Color, t: f32, linear: bool) -> PyResult<()> {
         find_invalid_percentage_range(t, "t")?;
-        let result: Color = Color::mlerp(*self, end, t)?;
+        let result: Color = Color::mlerp(*self, end, t, linear)?;
         self.r = result.r;
         self.g = result.g;
         self.b = result.b;
@@ -278,8 +480,9 @@ impl Color {
         Ok(())
     }
 
-    pub fn clerp_inplace(&mut self, end: Color, t: f32) -> PyResult<()> {
-        let result: Color = Color::clerp(*self, end, t)?;
+    #[pyo3(signature = (end, t, hue_strategy="shorter"))]
+    pub fn clerp_inplace(&mut self, end: Color, t: f32, hue_strategy: &str) -> PyResult<()> {
+        let result: Color = Color::clerp(*self, end, t, hue_strategy)?;
         self.r = result.r;
         self.g = result.g;
         self.b = result.b;
@@ -288,8 +491,141 @@ impl Color {
     }
 
     #[staticmethod]
-    #[pyo3(signature = (blend_mode, *args))]
-    pub fn blend(blend_mode: blending::BlendingMode, args: Bound<'_, PyTuple>) -> PyResult<Color> {
+    #[pyo3(signature = (colors, weights=None, space="oklab"))]
+    pub fn average(colors: Vec<Color>, weights: Option<Vec<f32>>, space: &str) -> PyResult<Color> {
+        let space: spaces::ColorSpace = spaces::ColorSpace::from_name(space)?;
+        if colors.is_empty() {
+            return Err(PyValueError::new_err(
+                "Cannot average an empty list of colors",
+            ));
+        }
+        let weights: Vec<f32> = weights.unwrap_or_else(|| vec![1.0; colors.len()]);
+        if weights.len() != colors.len() {
+            return Err(PyValueError::new_err(
+                "The amount of weights must match the amount of colors",
+            ));
+        } else if weights.iter().any(|weight| !weight.is_finite()) {
+            return Err(PyValueError::new_err("Weights have to be finite numbers"));
+        } else if weights.iter().any(|weight| *weight < 0.0) {
+            return Err(PyValueError::new_err("Weights cannot be negative"));
+        }
+        let total: f32 = weights.iter().sum();
+        if total <= 0.0 {
+            return Err(PyValueError::new_err(
+                "The sum of the weights must be above 0",
+            ));
+        } else if !total.is_finite() {
+            return Err(PyValueError::new_err("The sum of the weights is too large"));
+        }
+        Ok(spaces::average_in_space(&colors, &weights, space))
+    }
+
+    #[staticmethod]
+    #[pyo3(signature = (colors, t, space="oklab"))]
+    pub fn bezier(colors: Vec<Color>, t: f32, space: &str) -> PyResult<Color> {
+        let space: spaces::ColorSpace = spaces::ColorSpace::from_name(space)?;
+        find_invalid_percentage_range(t, "t")?;
+        if colors.is_empty() {
+            return Err(PyValueError::new_err(
+                "A bezier curve needs at least one control color",
+            ));
+        }
+        Ok(spaces::bezier_in_space(&colors, t, space))
+    }
+
+    #[staticmethod]
+    #[pyo3(signature = (components, space="oklch", gamut="srgb"))]
+    pub fn is_in_gamut(components: (f32, f32, f32), space: &str, gamut: &str) -> PyResult<bool> {
+        let space: spaces::ColorSpace = spaces::ColorSpace::from_name(space)?;
+        let gamut: spaces::Gamut = spaces::Gamut::from_name(gamut)?;
+        Ok(gamut.contains(space, [components.0, components.1, components.2]))
+    }
+
+    #[staticmethod]
+    #[pyo3(signature = (components, space="oklch", method="oklch_chroma", transparency=1.0))]
+    pub fn clamp_to_srgb(
+        components: (f32, f32, f32),
+        space: &str,
+        method: &str,
+        transparency: f32,
+    ) -> PyResult<Color> {
+        let space: spaces::ColorSpace = spaces::ColorSpace::from_name(space)?;
+        let mapping: spaces::GamutMapping = spaces::GamutMapping::from_name(method)?;
+        find_invalid_percentage_range(transparency, "Transparency")?;
+        Ok(space.from_components_mapped(
+            [components.0, components.1, components.2],
+            transparency,
+            mapping,
+        ))
+    }
+
+    #[pyo3(signature = (other, weight=0.5, space="oklab", hue="shorter"))]
+    pub fn mix(&self, other: Color, weight: f32, space: &str, hue: &str) -> PyResult<Color> {
+        find_invalid_percentage_range(weight, "Weight")?;
+        Ok(spaces::interpolate_in_space(
+            *self,
+            other,
+            weight,
+            spaces::ColorSpace::from_name(space)?,
+            spaces::HueStrategy::from_name(hue)?,
+        ))
+    }
+
+    #[pyo3(signature = (amount, space="srgb"))]
+    pub fn tint_with_white(&self, amount: f32, space: &str) -> PyResult<Color> {
+        self.mix(Color::new(255, 255, 255, self.a), amount, space, "shorter")
+    }
+
+    #[pyo3(signature = (amount, space="srgb"))]
+    pub fn shade_with_black(&self, amount: f32, space: &str) -> PyResult<Color> {
+        self.mix(Color::new(0, 0, 0, self.a), amount, space, "shorter")
+    }
+
+    #[pyo3(signature = (amount, space="srgb"))]
+    pub fn tone_with_gray(&self, amount: f32, space: &str) -> PyResult<Color> {
+        self.mix(Color::new(128, 128, 128, self.a), amount, space, "shorter")
+    }
+
+    #[staticmethod]
+    #[pyo3(signature = (value, min=0.0, max=1.0, scheme="turbo"))]
+    pub fn from_heat(value: f32, min: f32, max: f32, scheme: &str) -> PyResult<Color> {
+        if value.is_nan() {
+            return Err(PyValueError::new_err("The heat value cannot be NaN"));
+        } else if !min.is_finite() || !max.is_finite() || min >= max {
+            return Err(PyValueError::new_err(format!(
+                "The heat range needs a finite minimum below the maximum, got {} to {}",
+                min, max
+            )));
+        }
+        let t: f32 = ((value - min) / (max - min)).clamp(0.0, 1.0);
+        Ok(colormaps::heat_scheme(scheme)?.sample_color(t))
+    }
+
+    #[staticmethod]
+    #[pyo3(signature = (value, dark, light, mid=None, space="oklab"))]
+    pub fn duotone(
+        value: f32,
+        dark: Color,
+        light: Color,
+        mid: Option<Color>,
+        space: &str,
+    ) -> PyResult<Color> {
+        find_invalid_percentage_range(value, "Value")?;
+        Ok(gradient::Gradient::duotone(dark, light, mid, space)?.sample_color(value))
+    }
+
+    pub fn neutralize(&self, amount: f32) -> PyResult<Color> {
+        find_invalid_percentage_range(amount, "Neutralize")?;
+        Ok(neutralize_color(*self, amount))
+    }
+
+    #[staticmethod]
+    #[pyo3(signature = (blend_mode, *args, linear=false))]
+    pub fn blend(
+        blend_mode: blending::BlendingMode,
+        args: Bound<'_, PyTuple>,
+        linear: bool,
+    ) -> PyResult<Color> {
         let mut iterator = args.iter();
         let first_color: PyResult<Color> = iterator
             .next()
@@ -299,15 +635,32 @@ impl Color {
         for arg in iterator {
             match arg.extract::<Color>() {
                 Ok(color) => {
-                    let rgba1 = (
-                        (blended_color.0 as f32) / 255.0,
-                        (blended_color.1 as f32) / 255.0,
-                        (blended_color.2 as f32) / 255.0,
-                        (blended_color.3 as f32) / 255.0,
+                    let previous: Color = Color::new(
+                        blended_color.0,
+                        blended_color.1,
+                        blended_color.2,
+                        blended_color.3,
                     );
-                    let rgb2 = color_to_decimal_rgb(color);
-                    let blended = blending::compute_blend(&blend_mode, rgba1, rgb2);
-                    let result = to_unit_rgb(blended.0, blended.1, blended.2, blended.3);
+                    let result = if linear {
+                        let rgba1 = color_to_linear_rgba(previous);
+                        let rgba2 = color_to_linear_rgba(color);
+                        let blended = blending::compute_blend(
+                            &blend_mode,
+                            rgba1,
+                            (rgba2.0, rgba2.1, rgba2.2),
+                        );
+                        linear_to_unit_rgb(blended.0, blended.1, blended.2, blended.3)
+                    } else {
+                        let rgba1 = (
+                            (blended_color.0 as f32) / 255.0,
+                            (blended_color.1 as f32) / 255.0,
+                            (blended_color.2 as f32) / 255.0,
+                            (blended_color.3 as f32) / 255.0,
+                        );
+                        let rgb2 = color_to_decimal_rgb(color);
+                        let blended = blending::compute_blend(&blend_mode, rgba1, rgb2);
+                        to_unit_rgb(blended.0, blended.1, blended.2, blended.3)
+                    };
                     blended_color = unwrap_color(result);
                 }
                 Err(_) => {
@@ -319,33 +672,66 @@ impl Color {
         }
         Ok(Color::new(
             blended_color.0,
-            blended_color.0,
+            blended_color.1,
             blended_color.2,
             blended_color.3,
         ))
     }
 
-    #[pyo3(signature = (other, include_transparency=false))]
-    pub fn add(&mut self, other: ColorOrScalar, include_transparency: bool) -> Color {
-        match other {
-            ColorOrScalar::Color(c) => color_add_color(self, &c, include_transparency),
-            ColorOrScalar::Integer(i) => color_add_scalar(self, i, include_transparency),
-        }
+    #[pyo3(signature = (other, include_transparency=false, linear=false))]
+    pub fn add(
+        &mut self,
+        other: ColorOrScalar,
+        include_transparency: bool,
+        linear: bool,
+    ) -> PyResult<Color> {
+        Ok(match (other, linear) {
+            (ColorOrScalar::Color(c), true) => {
+                color_add_color_linear(self, &c, include_transparency)
+            }
+            (ColorOrScalar::Color(c), false) => color_add_color(self, &c, include_transparency),
+            (ColorOrScalar::Integer(i), true) => {
+                color_add_scalar_linear(self, i, include_transparency)
+            }
+            (ColorOrScalar::Integer(i), false) => color_add_scalar(self, i, include_transparency),
+            (ColorOrScalar::Channels(channels), linear) => {
+                color_add_channels(self, &channels.channels()?, include_transparency, linear)
+            }
+        })
     }
 
     #[pyo3(signature = (other, include_transparency=false))]
-    pub fn sub(&mut self, other: ColorOrScalar, include_transparency: bool) -> Color {
-        match other {
+    pub fn sub(&mut self, other: ColorOrScalar, include_transparency: bool) -> PyResult<Color> {
+        Ok(match other {
             ColorOrScalar::Color(c) => color_sub_color(self, &c, include_transparency),
             ColorOrScalar::Integer(i) => color_sub_scalar(self, i, include_transparency),
-        }
+            ColorOrScalar::Channels(channels) => {
+                let channels: ChannelTuple<i64> = channels.channels()?;
+                let negated: ChannelTuple<i64> = ChannelTuple {
+                    rgb: channels.rgb.map(|value| value.saturating_neg()),
+                    alpha: channels.alpha.map(|value| value.saturating_neg()),
+                };
+                color_add_channels(self, &negated, include_transparency, false)
+            }
+        })
     }
 
-    #[pyo3(signature = (scalar, include_transparency=false))]
-    pub fn mul(&mut self, scalar: f32, include_transparency: bool) -> Color {
+    #[pyo3(signature = (scalar, include_transparency=false, linear=false))]
+    pub fn mul(&mut self, scalar: f32, include_transparency: bool, linear: bool) -> Color {
         if scalar <= 0.0 {
             return Color::new(0, 0, 0, if include_transparency { 0 } else { self.a });
         }
+        if linear {
+            let rgba: (f32, f32, f32, f32) = color_to_linear_rgba(*self);
+            let mut result: Color =
+                linear_to_unit_rgb(rgba.0 * scalar, rgba.1 * scalar, rgba.2 * scalar, 0.0);
+            result.a = if include_transparency {
+                ((self.a as f32) * scalar).clamp(0.0, 255.0).floor() as u8
+            } else {
+                self.a
+            };
+            return result;
+        }
         Color {
             r: ((self.r as f32) * scalar).clamp(0.0, 255.0).floor() as u8,
             g: ((self.g as f32) * scalar).clamp(0.0, 255.0).floor() as u8,
@@ -380,6 +766,43 @@ impl Color {
         })
     }
 
+    #[pyo3(signature = (other, include_transparency=false, linear=false))]
+    pub fn add_inplace(
+        &mut self,
+        other: ColorOrScalar,
+        include_transparency: bool,
+        linear: bool,
+    ) -> PyResult<()> {
+        *self = self.add(other, include_transparency, linear)?;
+        Ok(())
+    }
+
+    #[pyo3(signature = (other, include_transparency=false))]
+    pub fn sub_inplace(
+        &mut self,
+        other: ColorOrScalar,
+        include_transparency: bool,
+    ) -> PyResult<()> {
+        *self = self.sub(other, include_transparency)?;
+        Ok(())
+    }
+
+    #[pyo3(signature = (scalar, include_transparency=false, linear=false))]
+    pub fn mul_inplace(&mut self, scalar: f32, include_transparency: bool, linear: bool) {
+        *self = self.mul(scalar, include_transparency, linear);
+    }
+
+    #[pyo3(signature = (scalar, include_transparency=false))]
+    pub fn div_inplace(
+        &mut self,
+        python: Python,
+        scalar: f32,
+        include_transparency: bool,
+    ) -> PyResult<()> {
+        *self = self.div(python, scalar, include_transparency)?;
+        Ok(())
+    }
+
     #[pyo3(signature = (other, include_transparency=false))]
     pub fn tensor(&self, other: Color, include_transparency: bool) -> Color {
         Color {
@@ -460,15 +883,104 @@ impl Color {
         }
     }
 
+    pub fn swizzle(&self, pattern: &str) -> PyResult<Color> {
+        Ok(swizzle_color(*self, &parse_swizzle(pattern)?))
+    }
+
     pub fn grayscale(&self, _python: Python) -> Color {
-        let value =
-            (0.299 * self.r as f32 + 0.587 * self.g as f32 + 0.114 * self.b as f32).round() as u8;
-        Color {
-            r: value,
-            g: value,
-            b: value,
-            a: self.a,
-        }
+        grayscale_color(*self)
+    }
+
+    pub fn exposure(&self, stops: f32) -> PyResult<Color> {
+        check_finite(stops, "exposure")?;
+        Ok(scale_linear(*self, [stops.exp2(); 3]))
+    }
+
+    pub fn gamma(&self, gamma: f32) -> PyResult<Color> {
+        check_gamma(gamma)?;
+        Ok(gamma_color(*self, gamma))
+    }
+
+    #[pyo3(signature = (in_black, in_white, gamma=1.0, out_black=0, out_white=255, luminance=false))]
+    pub fn levels(
+        &self,
+        in_black: u8,
+        in_white: u8,
+        gamma: f32,
+        out_black: u8,
+        out_white: u8,
+        luminance: bool,
+    ) -> PyResult<Color> {
+        let levels: Levels = Levels::new(in_black, in_white, gamma, out_black, out_white)?;
+        Ok(levels.apply(*self, luminance))
+    }
+
+    #[pyo3(signature = (
+        src_white=adaptation::Illuminant::named("D65"),
+        dst_white=adaptation::Illuminant::named("D50"),
+        method="bradford"
+    ))]
+    pub fn adapt(
+        &self,
+        src_white: adaptation::Illuminant,
+        dst_white: adaptation::Illuminant,
+        method: &str,
+    ) -> PyResult<Color> {
+        let method: adaptation::AdaptationMethod = adaptation::AdaptationMethod::from_name(method)?;
+        let xyz: [f32; 3] = spaces::ColorSpace::Xyz.to_components(*self);
+        let adapted: [f32; 3] =
+            method.adapt(xyz, src_white.white_point()?, dst_white.white_point()?);
+        // Bright colors land above the sRGB gamut where reducing the chroma would wash out the
+        // very tint the adaptation is after, so the channels are clipped instead
+        Ok(spaces::ColorSpace::Xyz.from_components_mapped(
+            adapted,
+            (self.a as f32) / 255.0,
+            spaces::GamutMapping::Clip,
+        ))
+    }
+
+    pub fn adapt_appearance(
+        &self,
+        from_conditions: appearance::ViewingConditions,
+        to_conditions: appearance::ViewingConditions,
+    ) -> Color {
+        let xyz: [f32; 3] = spaces::ColorSpace::Xyz
+            .to_components(*self)
+            .map(|value| value * 100.0);
+        let jch: [f32; 3] = appearance::Cam16::new(&from_conditions).to_jch(xyz);
+        let xyz: [f32; 3] = appearance::Cam16::new(&to_conditions).from_jch(jch);
+        // Clipped for the same reason as in [Color::adapt], a change of white would otherwise
+        // wash out of the brightest colors
+        spaces::ColorSpace::Xyz.from_components_mapped(
+            xyz.map(|value| value / 100.0),
+            (self.a as f32) / 255.0,
+            spaces::GamutMapping::Clip,
+        )
+    }
+
+    pub fn white_balance(&self, reference_white: Color) -> PyResult<Color> {
+        let gains: [f32; 3] = white_balance_gains(transfer::decode_rgb(reference_white))?;
+        Ok(scale_linear(*self, gains))
+    }
+
+    #[pyo3(signature = (amount=1.0))]
+    pub fn sepia(&self, amount: f32) -> PyResult<Color> {
+        Ok(matrix::ColorMatrix::sepia(amount)?.transform(*self))
+    }
+
+    pub fn posterize(&self, levels: u8) -> PyResult<Color> {
+        check_posterize_levels(levels)?;
+        Ok(posterize_color(*self, levels))
+    }
+
+    #[pyo3(signature = (threshold=128))]
+    pub fn solarize(&self, threshold: u8) -> Color {
+        solarize_color(*self, threshold)
+    }
+
+    #[pyo3(signature = (value=128))]
+    pub fn threshold(&self, value: u8) -> Color {
+        threshold_color(*self, value)
     }
 
     pub fn triadic_colors(&self, python: Python<'_>) -> [Color; 2] {
@@ -481,22 +993,32 @@ impl Color {
         ]
     }
 
-    pub fn adjust_temperature(&mut self, temperature: BigInt) {
-        if temperature == BigInt::ZERO {
-            return;
+    /// Planckian white points on both ends, so that no change leaves the color as it is even
+    /// though D65 itself sits slightly off the Planckian locus
+    pub fn adjust_temperature(&self, delta_kelvin: f32) -> PyResult<Color> {
+        if delta_kelvin == 0.0 {
+            return Ok(*self);
+        } else if !delta_kelvin.is_finite() {
+            return Err(PyValueError::new_err(
+                "The temperature change must be a finite number",
+            ));
         }
-        let adjusted_temp =
-            wrap_around_bigint(if temperature > BigInt::new(Sign::Plus, vec![255]) {
-                BigInt::new(Sign::Plus, vec![255])
-            } else if temperature < BigInt::new(Sign::Minus, vec![255]) {
-                BigInt::new(Sign::Minus, vec![255])
-            } else {
-                temperature
-            })
-            .1 as u16;
-
-        self.r = ((self.r as u16) + adjusted_temp).clamp(0, 255) as u8;
-        self.b = ((self.b as u16) - adjusted_temp).clamp(0, 255) as u8;
+        let target: f32 = adaptation::REFERENCE_KELVIN + delta_kelvin;
+        if !(adaptation::MIN_KELVIN..=adaptation::MAX_KELVIN).contains(&target) {
+            return Err(PyValueError::new_err(format!(
+                "The temperature change has to stay between {} K and {} K, got {} K",
+                adaptation::MIN_KELVIN,
+                adaptation::MAX_KELVIN,
+                target
+            )));
+        }
+        let source: (f32, f32) = adaptation::planckian_chromaticity(adaptation::REFERENCE_KELVIN);
+        let destination: (f32, f32) = adaptation::planckian_chromaticity(target);
+        self.adapt(
+            adaptation::Illuminant::Chromaticity(source),
+            adaptation::Illuminant::Chromaticity(destination),
+            "bradford",
+        )
     }
 
     pub fn contrast(&mut self, factor: f32) {
@@ -531,6 +1053,16 @@ impl Color {
         }
     }
 
+    pub fn lighten(&self, amount: f32) -> PyResult<Color> {
+        check_finite(amount, "lightness amount")?;
+        Ok(lighten_color(*self, amount))
+    }
+
+    pub fn darken(&self, amount: f32) -> PyResult<Color> {
+        check_finite(amount, "lightness amount")?;
+        Ok(lighten_color(*self, -amount))
+    }
+
     pub fn tint(&self, python: Python, degrees: BigInt) -> PyResult<Color> {
         let new_degrees: BigInt = &degrees % BigInt::from(360);
         if new_degrees == BigInt::ZERO {
@@ -542,49 +1074,96 @@ impl Color {
         Color::from_hsl(hue, hsl.1, hsl.2, (self.a as f32) / 255.0)
     }
 
-    pub fn saturate(&self, factor: f32) -> Color {
+    #[pyo3(signature = (degrees, space="hsl"))]
+    pub fn hue_rotate(&self, degrees: f32, space: &str) -> PyResult<Color> {
+        Ok(matrix::HueRotation::new(space, degrees)?.rotate(*self))
+    }
+
+    #[pyo3(signature = (target, strength=0.5))]
+    pub fn harmonize(&self, target: Color, strength: f32) -> PyResult<Color> {
+        find_invalid_percentage_range(strength, "Strength")?;
+        Ok(harmonize_color(*self, target, strength))
+    }
+
+    pub fn rotate_hue_preserve_luminance(&self, degrees: f32) -> PyResult<Color> {
+        check_finite(degrees, "hue rotation")?;
+        Ok(rotate_hue_preserving_luminance(*self, degrees))
+    }
+
+    pub fn saturate(&self, factor: f32) -> PyResult<Color> {
+        check_finite(factor, "saturation factor")?;
         if factor == 0.0 {
-            return *self;
+            return Ok(*self);
         }
-        let mut hsv: (u16, f32, f32) = color_to_hsv(*self);
-        hsv.1 *= factor + 1.0;
-        Color::from_hsv(hsv.0 as i16, hsv.1, hsv.2, (self.a as f32) / 255.0).unwrap()
+        Ok(saturate_color(*self, factor))
+    }
+
+    #[pyo3(signature = (method="value"))]
+    pub fn normalize(&self, method: &str) -> PyResult<Color> {
+        normalize_color(*self, method)
+    }
+
+    pub fn saturate_oklch(&self, amount: f32) -> PyResult<Color> {
+        check_finite(amount, "chroma amount")?;
+        Ok(saturate_oklch_color(*self, amount))
+    }
+
+    pub fn desaturate_oklch(&self, amount: f32) -> PyResult<Color> {
+        check_finite(amount, "chroma amount")?;
+        Ok(saturate_oklch_color(*self, -amount))
     }
 
-    #[pyo3(signature = (start=[Some(0), Some(0), Some(0), Some(0)], end=[Some(255), Some(255), Some(255), Some(255)]))]
+    pub fn vibrance(&self, factor: f32) -> PyResult<Color> {
+        check_finite(factor, "vibrance factor")?;
+        Ok(vibrance_color(*self, factor))
+    }
+
+    #[pyo3(signature = (sigma=5.0, channels=None, space="rgb", seed=None))]
+    pub fn jitter(
+        &self,
+        sigma: f32,
+        channels: Option<&str>,
+        space: &str,
+        seed: Option<u64>,
+    ) -> PyResult<Color> {
+        let jitter: random::Jitter = random::Jitter::new(sigma, channels, space)?;
+        Ok(jitter.apply(*self, &mut random::seeded_rng(seed)))
+    }
+
+    #[staticmethod]
+    #[pyo3(signature = (space="oklch", l=Some((0.7, 0.9)), c=Some((0.05, 0.12)), h=None, seed=None))]
+    pub fn random(
+        space: &str,
+        l: Option<(f32, f32)>,
+        c: Option<(f32, f32)>,
+        h: Option<(f32, f32)>,
+        seed: Option<u64>,
+    ) -> PyResult<Color> {
+        let ranges: random::RandomRanges = random::RandomRanges::new(space, l, c, h)?;
+        Ok(ranges.draw(&mut random::seeded_rng(seed)))
+    }
+
+    #[pyo3(signature = (start=[Some(0), Some(0), Some(0), Some(0)], end=[Some(255), Some(255), Some(255), Some(255)], seed=None))]
     pub fn randomise(
         &self,
         _python: Python,
         start: [Option<u8>; 4],
         end: [Option<u8>; 4],
+        seed: Option<u64>,
     ) -> PyResult<Color> {
-        let mut rng: ThreadRng = rand::thread_rng();
-        Ok(Color {
-            r: randomise_component(self.r, start[0], end[0], &mut rng, "Red")?,
-            g: randomise_component(self.g, start[1], end[1], &mut rng, "Green")?,
-            b: randomise_component(self.b, start[2], end[2], &mut rng, "Blue")?,
-            a: randomise_component(self.a, start[3], end[3], &mut rng, "Transparency")?
-        })
+        match seed {
+            Some(seed) => {
+                random::randomise_color(*self, start, end, &mut random::seeded_rng(Some(seed)))
+            }
+            None => random::randomise_color(*self, start, end, &mut rand::thread_rng()),
+        }
     }
 
-    pub fn get_luminance(&self, python: Python) -> f32 {
-        let mut rgb: (f32, f32, f32) = self.to_decimal_rgb(python);
-        rgb.0 = if rgb.0 <= 0.03928 {
-            rgb.0 / 12.92
-        } else {
-            ((rgb.0 + 0.055) / 1.055).powf(2.4)
-        };
-        rgb.1 = if rgb.1 <= 0.03928 {
-            rgb.1 / 12.92
-        } else {
-            ((rgb.1 + 0.055) / 1.055).powf(2.4)
-        };
-        rgb.2 = if rgb.2 <= 0.03928 {
-            rgb.2 / 12.92
-        } else {
-            ((rgb.2 + 0.055) / 1.055).powf(2.4)
-        };
-        0.2126 * rgb.0 + 0.7152 * rgb.1 + 0.0722 * rgb.2
+    pub fn get_luminance(&self, _python: Python) -> f32 {
+        // The decode table matches the 0.03928 threshold of WCAG too, since no 8-bit
+        // channel value falls between it and 0.04045
+        let rgb: [f32; 3] = transfer::decode_rgb(*self);
+        0.2126 * rgb[0] + 0.7152 * rgb[1] + 0.0722 * rgb[2]
     }
 
     pub fn get_saturation(&self, _python: Python) -> f32 {
@@ -621,6 +1200,42 @@ impl Color {
             && alpha_part
     }
 
+    pub fn with_red(&self, value: u8) -> Color {
+        Color { r: value, ..*self }
+    }
+
+    pub fn with_green(&self, value: u8) -> Color {
+        Color { g: value, ..*self }
+    }
+
+    pub fn with_blue(&self, value: u8) -> Color {
+        Color { b: value, ..*self }
+    }
+
+    pub fn with_alpha(&self, value: u8) -> Color {
+        Color { a: value, ..*self }
+    }
+
+    #[pyo3(signature = (r=None, g=None, b=None, a=None))]
+    pub fn replace(&self, r: Option<u8>, g: Option<u8>, b: Option<u8>, a: Option<u8>) -> Color {
+        Color {
+            r: r.unwrap_or(self.r),
+            g: g.unwrap_or(self.g),
+            b: b.unwrap_or(self.b),
+            a: a.unwrap_or(self.a),
+        }
+    }
+
+    #[pyo3(signature = (by="hue"))]
+    pub fn sort_key(&self, by: &str) -> PyResult<(f32, f32, f32)> {
+        let [first, second, third]: [f32; 3] = palette::SortOrder::from_name(by)?.key(*self);
+        Ok((first, second, third))
+    }
+
+    pub fn freeze(&self) -> frozen::FrozenColor {
+        frozen::FrozenColor::from(*self)
+    }
+
     pub fn copy(&self) -> Color {
         Color {
             r: self.r,
@@ -632,9 +1247,9 @@ impl Color {
 
     #[pyo3(signature = (include_transparency=false))]
     pub fn to_hex(&self, include_transparency: bool) -> String {
-        let hex_str = format!("#{:x?}{:x?}{:x?}", self.r, self.g, self.b);
+        let hex_str = format!("#{:02x}{:02x}{:02x}", self.r, self.g, self.b);
         if include_transparency {
-            hex_str + &format!("{:x?}", self.a)
+            hex_str + &format!("{:02x}", self.a)
         } else {
             hex_str
         }
@@ -667,48 +1282,37 @@ impl Color {
     }
 
     pub fn to_cmyk(&self, _python: Python) -> (f32, f32, f32, f32, f32) {
-        let rgb: (f32, f32, f32) = color_to_decimal_rgb(*self);
-        let k: f32 = 1.0 - rgb.0.max(rgb.1).max(rgb.2);
-        let k_invert: f32 = 1.0 - k;
-
-        if k_invert == 0.0 {
-            return (0.0, 0.0, 0.0, 1.0, (self.a as f32) / 255.0);
-        }
-        let c: f32 = (k_invert - rgb.0) / k_invert;
-        let m: f32 = (k_invert - rgb.1) / k_invert;
-        let y: f32 = (k_invert - rgb.2) / k_invert;
+        let (c, m, y, k): (f32, f32, f32, f32) = color_to_cmyk(*self);
         (c, m, y, k, (self.a as f32) / 255.0)
     }
 
-    pub fn to_xyz(&self, _python: Python) -> (f32, f32, f32, f32) {
-        let mut rgb: (f32, f32, f32) = color_to_decimal_rgb(*self);
-
-        rgb.0 = if rgb.0 > 0.04045 {
-            ((rgb.0 + 0.055) / 1.055).powf(2.4)
-        } else {
-            rgb.0 / 12.92
-        };
-        rgb.1 = if rgb.1 > 0.04045 {
-            ((rgb.1 + 0.055) / 1.055).powf(2.4)
-        } else {
-            rgb.1 / 12.92
-        };
-        rgb.2 = if rgb.2 > 0.04045 {
-            ((rgb.2 + 0.055) / 1.055).powf(2.4)
-        } else {
-            rgb.2 / 12.92
-        };
-
-        rgb.0 *= 100.0;
-        rgb.1 *= 100.0;
-        rgb.2 *= 100.0;
-
-        (
-            rgb.0 * 0.4124 + rgb.1 * 0.3576 + rgb.2 * 0.1805,
-            rgb.0 * 0.2126 + rgb.1 * 0.7152 + rgb.2 * 0.0722,
-            rgb.0 * 0.0193 + rgb.1 * 0.1192 + rgb.2 * 0.9505,
+    #[pyo3(signature = (illuminant=adaptation::Illuminant::named("D65")))]
+    pub fn to_xyz(&self, illuminant: adaptation::Illuminant) -> PyResult<(f32, f32, f32, f32)> {
+        let white: [f32; 3] = illuminant.white_point()?;
+        let rgb: [f32; 3] = transfer::decode_rgb(*self);
+        let xyz: [f32; 3] = adaptation::from_d65(
+            [
+                rgb[0] * 0.4124 + rgb[1] * 0.3576 + rgb[2] * 0.1805,
+                rgb[0] * 0.2126 + rgb[1] * 0.7152 + rgb[2] * 0.0722,
+                rgb[0] * 0.0193 + rgb[1] * 0.1192 + rgb[2] * 0.9505,
+            ],
+            white,
+        );
+        Ok((
+            xyz[0] * 100.0,
+            xyz[1] * 100.0,
+            xyz[2] * 100.0,
             (self.a as f32) / 255.0,
-        )
+        ))
+    }
+
+    #[pyo3(signature = (illuminant=adaptation::Illuminant::named("D65")))]
+    pub fn to_lab(&self, illuminant: adaptation::Illuminant) -> PyResult<(f32, f32, f32, f32)> {
+        let white: [f32; 3] = illuminant.white_point()?;
+        let xyz: [f32; 3] =
+            adaptation::from_d65(spaces::ColorSpace::Xyz.to_components(*self), white);
+        let lab: [f32; 3] = spaces::xyz_to_lab_relative(xyz, white);
+        Ok((lab[0], lab[1], lab[2], (self.a as f32) / 255.0))
     }
 
     pub fn to_oklab(&self, _python: Python) -> (f32, f32, f32, f32) {
@@ -725,6 +1329,70 @@ impl Color {
         PyList::new_bound(python, vec![self.r, self.g, self.b, self.a])
     }
 
+    pub fn to_dict<'a>(&self, python: Python<'a>) -> PyResult<Bound<'a, PyDict>> {
+        let dict: Bound<PyDict> = PyDict::new_bound(python);
+        dict.set_item("r", self.r)?;
+        dict.set_item("g", self.g)?;
+        dict.set_item("b", self.b)?;
+        dict.set_item("a", self.a)?;
+        Ok(dict)
+    }
+
+    #[staticmethod]
+    pub fn from_dict(values: HashMap<String, u8>) -> PyResult<Color> {
+        const NAMES: [&str; 4] = ["red", "green", "blue", "alpha"];
+        let mut channels: [Option<u8>; 4] = [None; 4];
+        for (key, value) in values {
+            let index: usize = match key.to_lowercase().as_str() {
+                "red" | "r" => 0,
+                "green" | "g" => 1,
+                "blue" | "b" => 2,
+                "alpha" | "a" => 3,
+                _ => {
+                    return Err(PyValueError::new_err(format!(
+                        "Unknown color channel \"{}\"",
+                        key
+                    )))
+                }
+            };
+            if channels[index].replace(value).is_some() {
+                return Err(PyValueError::new_err(format!(
+                    "The {} channel is given more than once",
+                    NAMES[index]
+                )));
+            }
+        }
+        match channels {
+            [Some(r), Some(g), Some(b), a] => Ok(Color {
+                r,
+                g,
+                b,
+                a: a.unwrap_or(255),
+            }),
+            _ => Err(PyValueError::new_err(
+                "The red, green and blue channels are required",
+            )),
+        }
+    }
+
+    pub fn to_json(&self, python: Python<'_>) -> PyResult<String> {
+        python
+            .import_bound("json")?
+            .call_method1("dumps", (self.to_dict(python)?,))?
+            .extract()
+    }
+
+    #[staticmethod]
+    pub fn from_json(python: Python<'_>, text: &str) -> PyResult<Color> {
+        let object: Bound<PyAny> = python
+            .import_bound("json")?
+            .call_method1("loads", (text,))?;
+        if let Ok(hex) = object.extract::<String>() {
+            return Color::from_hex(&hex);
+        }
+        Color::from_dict(object.extract()?)
+    }
+
     pub fn to_decimal_rgba_list<'a>(&self, python: Python<'a>) -> Bound<'a, PyList> {
         PyList::new_bound(
             python,
@@ -741,27 +1409,133 @@ impl Color {
         PyTuple::new_bound(python, vec![self.r, self.g, self.b, self.a])
     }
 
+    #[pyo3(signature = (premultiplied=false))]
+    pub fn to_int(&self, premultiplied: bool) -> u32 {
+        let color: Color = if premultiplied {
+            premultiply_color(*self)
+        } else {
+            *self
+        };
+        u32::from_be_bytes([color.r, color.g, color.b, color.a])
+    }
+
+    #[pyo3(signature = (premultiplied=false))]
+    pub fn to_bytes<'a>(&self, python: Python<'a>, premultiplied: bool) -> Bound<'a, PyBytes> {
+        let color: Color = if premultiplied {
+            premultiply_color(*self)
+        } else {
+            *self
+        };
+        PyBytes::new_bound(python, &[color.r, color.g, color.b, color.a])
+    }
+
+    pub fn premultiply(&self) -> Color {
+        premultiply_color(*self)
+    }
+
+    pub fn unpremultiply(&self) -> Color {
+        unpremultiply_color(*self)
+    }
+
+    pub fn to_ansi256(&self) -> u8 {
+        ansi::to_ansi256(*self)
+    }
+
+    pub fn to_ansi16(&self) -> u8 {
+        ansi::to_ansi16(*self)
+    }
+
+    pub fn ansi_fg(&self) -> String {
+        ansi::foreground_escape(*self)
+    }
+
+    pub fn ansi_bg(&self) -> String {
+        ansi::background_escape(*self)
+    }
+
+    #[pyo3(signature = (text, bg=None))]
+    pub fn paint(&self, text: &str, bg: Option<Color>) -> String {
+        let background: String = bg.map(ansi::background_escape).unwrap_or_default();
+        format!(
+            "{}{}{}{}",
+            ansi::foreground_escape(*self),
+            background,
+            text,
+            ansi::ANSI_RESET
+        )
+    }
+
+    pub fn to_web_safe(&self) -> Color {
+        web_safe_color(*self)
+    }
+
+    pub fn to_unity(&self) -> (f32, f32, f32, f32) {
+        color_to_linear_rgba(*self)
+    }
+
+    pub fn to_godot(&self) -> String {
+        let rgb: (f32, f32, f32) = color_to_decimal_rgb(*self);
+        let rgba: (f32, f32, f32, f32) = (rgb.0, rgb.1, rgb.2, (self.a as f32) / 255.0);
+        format!(
+            "Color({:.4}, {:.4}, {:.4}, {:.4})",
+            rgba.0, rgba.1, rgba.2, rgba.3
+        )
+    }
+
+    pub fn to_unreal(&self) -> String {
+        let rgba: (f32, f32, f32, f32) = color_to_linear_rgba(*self);
+        format!(
+            "(R={:.6},G={:.6},B={:.6},A={:.6})",
+            rgba.0, rgba.1, rgba.2, rgba.3
+        )
+    }
+
+    pub fn _repr_html_(&self) -> String {
+        html::color_html(*self)
+    }
+
     pub fn __str__(&self, _python: Python) -> String {
         format!("({} : {} : {} : {})", self.r, self.g, self.b, self.a)
     }
 
+    pub fn __format__(&self, python: Python, spec: &str) -> PyResult<String> {
+        if spec.is_empty() {
+            return Ok(self.__str__(python));
+        }
+        format_color(*self, spec).ok_or_else(|| {
+            PyValueError::new_err(format!(
+                "Unknown format spec \"{}\", expected one of hex, hex8, rgb, rgba, hsl, hsla or css in lowercase or uppercase",
+                spec
+            ))
+        })
+    }
+
     pub fn __repr__(&self, _python: Python) -> String {
         format!("Color({}, {}, {}, {})", self.r, self.g, self.b, self.a)
     }
 
-    pub fn __add__(&mut self, other: ColorOrScalar) -> Color {
-        self.add(other, true)
+    pub fn __add__(&mut self, other: ColorOrScalar) -> PyResult<Color> {
+        self.add(other, true, false)
     }
 
-    pub fn __sub__(&mut self, other: ColorOrScalar) -> Color {
+    pub fn __sub__(&mut self, other: ColorOrScalar) -> PyResult<Color> {
         self.sub(other, true)
     }
 
-    pub fn __mul__(&mut self, other: ColorOrFloat) -> Color {
-        match other {
+    pub fn __mul__(&mut self, other: ColorOrFloat) -> PyResult<Color> {
+        Ok(match other {
             ColorOrFloat::Color(c) => self.tensor(c, true),
-            ColorOrFloat::Float(f) => self.mul(f, true),
-        }
+            ColorOrFloat::Float(f) => self.mul(f, true, false),
+            ColorOrFloat::Factors(factors) => color_mul_channels(self, &factors.channels()?),
+        })
+    }
+
+    pub fn __radd__(&mut self, other: ColorOrScalar) -> PyResult<Color> {
+        self.__add__(other)
+    }
+
+    pub fn __rmul__(&mut self, other: ColorOrFloat) -> PyResult<Color> {
+        self.__mul__(other)
     }
 
     pub fn __truediv__(&mut self, python: Python, other: f32) -> PyResult<Color> {
@@ -772,6 +1546,31 @@ impl Color {
         self.div(python, wrap_around_bigint_as_i16(other) as f32, true)
     }
 
+    pub fn __iadd__(&mut self, other: ColorOrScalar) -> PyResult<()> {
+        self.add_inplace(other, true, false)
+    }
+
+    pub fn __isub__(&mut self, other: ColorOrScalar) -> PyResult<()> {
+        self.sub_inplace(other, true)
+    }
+
+    pub fn __imul__(&mut self, other: ColorOrFloat) -> PyResult<()> {
+        *self = self.__mul__(other)?;
+        Ok(())
+    }
+
+    pub fn __itruediv__(&mut self, python: Python, other: f32) -> PyResult<()> {
+        self.div_inplace(python, other, true)
+    }
+
+    pub fn __int__(&self) -> u32 {
+        self.to_int(false)
+    }
+
+    pub fn __index__(&self) -> u32 {
+        self.to_int(false)
+    }
+
     pub fn __hash__(&self, _python: Python) -> u64 {
         let mut hasher = DefaultHasher::new();
         self.r.hash(&mut hasher);
@@ -813,80 +1612,67 @@ impl Color {
         self.__pow__(color, base)
     }
 
-    pub fn __getitem__(&self, access_code: ColorAccessCode) -> PyResult<u8> {
-        let adjusted_access_code = access_code;
-        if let ColorAccessCode::String(value) = adjusted_access_code {
-            return match value.to_lowercase().as_str() {
-                "red" | "r" => Ok(self.r),
-                "green" | "g" => Ok(self.g),
-                "blue" | "b" => Ok(self.b),
-                "alpha" | "a" => Ok(self.a),
-                _ => Err(PyIndexError::new_err(
-                    "Cannot access a value outside of the color's reach",
-                )),
-            };
-        }
-        match adjusted_access_code {
-            ColorAccessCode::Integer(0) => Ok(self.r),
-            ColorAccessCode::Integer(1) => Ok(self.g),
-            ColorAccessCode::Integer(2) => Ok(self.b),
-            ColorAccessCode::Integer(3) => Ok(self.a),
-            _ => Err(PyIndexError::new_err(
-                "Cannot access a value outside of the color's reach",
-            )),
+    pub fn __len__(&self) -> usize {
+        4
+    }
+
+    pub fn __iter__<'py>(&self, python: Python<'py>) -> PyResult<Bound<'py, PyIterator>> {
+        PyList::new_bound(python, [self.r, self.g, self.b, self.a])
+            .as_any()
+            .iter()
+    }
+
+    pub fn __getitem__(
+        &self,
+        python: Python<'_>,
+        access_code: ColorAccessCode,
+    ) -> PyResult<PyObject> {
+        if let ColorAccessCode::Slice(slice) = access_code {
+            let channels: [u8; 4] = [self.r, self.g, self.b, self.a];
+            let values: Vec<u8> = slice_offsets(&slice)?
+                .into_iter()
+                .map(|index| channels[index])
+                .collect();
+            return Ok(values.into_py(python));
         }
+        let mut color: Color = *self;
+        let channel: u8 = *color.channel_mut(access_code, "access")?;
+        Ok(channel.into_py(python))
     }
 
     pub fn __setitem__(
         &mut self,
         _python: Python,
         access_code: ColorAccessCode,
-        new_value: u8,
+        new_value: ChannelValues,
     ) -> PyResult<()> {
-        let adjusted_access_code = access_code;
-        if let ColorAccessCode::String(value) = adjusted_access_code {
-            return match value.to_lowercase().as_str() {
-                "red" | "r" => {
-                    self.r = new_value;
-                    Ok(())
-                }
-                "green" | "g" => {
-                    self.g = new_value;
-                    Ok(())
+        match (access_code, new_value) {
+            (ColorAccessCode::Slice(slice), ChannelValues::Sequence(values)) => {
+                let offsets: Vec<usize> = slice_offsets(&slice)?;
+                if offsets.len() != values.len() {
+                    return Err(PyValueError::new_err(format!(
+                        "Cannot assign {} values to a slice of {} channels",
+                        values.len(),
+                        offsets.len()
+                    )));
                 }
-                "blue" | "b" => {
-                    self.b = new_value;
-                    Ok(())
+                let mut channels: [u8; 4] = [self.r, self.g, self.b, self.a];
+                for (index, value) in offsets.into_iter().zip(values) {
+                    channels[index] = value;
                 }
-                "alpha" | "a" => {
-                    self.a = new_value;
-                    Ok(())
-                }
-                _ => Err(PyIndexError::new_err(
-                    "Cannot set a value outside of the color's reach",
-                )),
-            };
-        }
-        match adjusted_access_code {
-            ColorAccessCode::Integer(0) => {
-                self.r = new_value;
+                *self = Color::new(channels[0], channels[1], channels[2], channels[3]);
                 Ok(())
             }
-            ColorAccessCode::Integer(1) => {
-                self.g = new_value;
-                Ok(())
-            }
-            ColorAccessCode::Integer(2) => {
-                self.b = new_value;
-                Ok(())
-            }
-            ColorAccessCode::Integer(3) => {
-                self.a = new_value;
+            (ColorAccessCode::Slice(_), ChannelValues::Integer(_)) => Err(PyTypeError::new_err(
+                "Assigning to a slice of channels needs a sequence of values",
+            )),
+            (_, ChannelValues::Sequence(_)) => Err(PyTypeError::new_err(
+                "Assigning to a single channel needs a single value",
+            )),
+            (access_code, ChannelValues::Integer(value)) => {
+                *self.channel_mut(access_code, "set")? = value;
                 Ok(())
             }
-            _ => Err(PyIndexError::new_err(
-                "Cannot set a value outside of the color's reach",
-            )),
         }
     }
 
@@ -928,7 +1714,52 @@ impl Color {
         self.copy()
     }
 
+    pub fn __deepcopy__(&self, _memo: Bound<'_, PyDict>) -> Color {
+        self.copy()
+    }
+
+    pub fn __reduce__<'py>(&self, python: Python<'py>) -> (Bound<'py, PyType>, (u8, u8, u8, u8)) {
+        (
+            python.get_type_bound::<Color>(),
+            (self.r, self.g, self.b, self.a),
+        )
+    }
+
     pub fn __sizeof__(&self) -> usize {
         32
     }
 }
+
+impl Color {
+    /// Resolves a channel by its index or name, the action only goes into the error message
+    fn channel_mut(&mut self, access_code: ColorAccessCode, action: &str) -> PyResult<&mut u8> {
+        let channel: Option<&mut u8> = match access_code {
+            ColorAccessCode::String(value) => match value.to_lowercase().as_str() {
+                "red" | "r" => Some(&mut self.r),
+                "green" | "g" => Some(&mut self.g),
+                "blue" | "b" => Some(&mut self.b),
+                "alpha" | "a" => Some(&mut self.a),
+                _ => None,
+            },
+            ColorAccessCode::Integer(0) => Some(&mut self.r),
+            ColorAccessCode::Integer(1) => Some(&mut self.g),
+            ColorAccessCode::Integer(2) => Some(&mut self.b),
+            ColorAccessCode::Integer(3) => Some(&mut self.a),
+            _ => None,
+        };
+        channel.ok_or_else(|| {
+            PyIndexError::new_err(format!(
+                "Cannot {} a value outside of the color's reach",
+                action
+            ))
+        })
+    }
+}
+
+/// The channel indices a slice covers, in the order it visits them
+fn slice_offsets(slice: &Bound<'_, PySlice>) -> PyResult<Vec<usize>> {
+    let indices: PySliceIndices = slice.indices(4)?;
+    Ok((0..indices.slicelength)
+        .map(|offset| (indices.start + (offset as isize) * indices.step) as usize)
+        .collect())
+}
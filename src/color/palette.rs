@@ -0,0 +1,768 @@
+use crate::color::brewer::brewer_palette;
+use crate::color::difference::{check_tolerance, group_similar, DistanceMetric};
+use crate::color::html::palette_html;
+use crate::color::kdtree::KdTree;
+use crate::color::lut::write_or_return;
+use crate::color::pdf::{write_pdf, PdfLayout};
+use crate::color::quantize::{histogram, kmeans, median_cut, octree};
+use crate::color::spaces::{average_in_space, fit_oklch_to_srgb, ColorSpace};
+use crate::color::svg::{check_svg_size, palette_svg};
+use crate::color::swatches::{
+    palette_from_json, palette_to_json, parse_aco, parse_ase, parse_gpl, parse_hex_text,
+    parse_text, read_text_or_path, write_aco, write_ase, write_bytes_or_return, write_gpl,
+    write_hex_text, write_text, BytesOrPath, GPL_HEADER,
+};
+use crate::color::utils::{fingerprint, web_safe_color};
+use crate::color::Color;
+use pyo3::buffer::PyBuffer;
+use pyo3::exceptions::{PyIndexError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyDict, PyIterator, PyList, PySlice, PyType};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::cmp::{Ordering, Reverse};
+use std::path::PathBuf;
+
+#[derive(FromPyObject)]
+pub enum PaletteIndex<'py> {
+    #[pyo3(transparent, annotation = "int")]
+    Integer(isize),
+    #[pyo3(transparent, annotation = "slice")]
+    Slice(Bound<'py, PySlice>),
+}
+
+#[derive(FromPyObject)]
+pub enum ColorsOrBuffer {
+    #[pyo3(transparent, annotation = "list[Color]")]
+    Colors(Vec<Color>),
+    #[pyo3(transparent, annotation = "bytes")]
+    Buffer(PyBuffer<u8>),
+    #[pyo3(transparent, annotation = "memoryview")]
+    FloatBuffer(PyBuffer<f32>),
+}
+
+impl ColorsOrBuffer {
+    /// Collects the colors, buffers are read as tightly packed RGBA pixels, either as bytes
+    /// or as 32-bit floats from 0.0 to 1.0
+    pub(crate) fn into_colors(self, python: Python<'_>) -> PyResult<Vec<Color>> {
+        match self {
+            ColorsOrBuffer::Colors(colors) => Ok(colors),
+            ColorsOrBuffer::Buffer(buffer) => {
+                let bytes: Vec<u8> = buffer.to_vec(python)?;
+                if bytes.len() % 4 != 0 {
+                    return Err(PyValueError::new_err(
+                        "The buffer length must be a multiple of 4 (RGBA pixels)",
+                    ));
+                }
+                Ok(bytes
+                    .chunks_exact(4)
+                    .map(|pixel| Color::new(pixel[0], pixel[1], pixel[2], pixel[3]))
+                    .collect())
+            }
+            ColorsOrBuffer::FloatBuffer(buffer) => {
+                let values: Vec<f32> = buffer.to_vec(python)?;
+                if values.len() % 4 != 0 {
+                    return Err(PyValueError::new_err(
+                        "The buffer length must be a multiple of 4 (RGBA pixels)",
+                    ));
+                }
+                let encode = |value: f32| (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+                Ok(values
+                    .chunks_exact(4)
+                    .map(|pixel| {
+                        Color::new(
+                            encode(pixel[0]),
+                            encode(pixel[1]),
+                            encode(pixel[2]),
+                            encode(pixel[3]),
+                        )
+                    })
+                    .collect())
+            }
+        }
+    }
+}
+
+/// The amount of bands the hue, luminance and value get split into by the step sort
+const STEP_SORT_BANDS: f32 = 8.0;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum SortOrder {
+    Hue,
+    Luminance,
+    Saturation,
+    Step,
+}
+
+impl SortOrder {
+    pub(crate) fn from_name(name: &str) -> PyResult<SortOrder> {
+        match name.to_lowercase().as_str() {
+            "hue" => Ok(SortOrder::Hue),
+            "luminance" => Ok(SortOrder::Luminance),
+            "saturation" => Ok(SortOrder::Saturation),
+            "step" => Ok(SortOrder::Step),
+            _ => Err(PyValueError::new_err(format!(
+                "Unknown sort order \"{}\", expected \"hue\", \"luminance\", \"saturation\" or \"step\"",
+                name
+            ))),
+        }
+    }
+
+    /// The key colors are ordered by, later components break the ties of earlier ones
+    pub(crate) fn key(&self, color: Color) -> [f32; 3] {
+        let [hue, saturation, value]: [f32; 3] = ColorSpace::Hsv.to_components(color);
+        let luminance: f32 = ColorSpace::Xyz.to_components(color)[1];
+        match self {
+            SortOrder::Hue => [hue, luminance, saturation],
+            SortOrder::Luminance => [luminance, hue, saturation],
+            SortOrder::Saturation => [saturation, hue, luminance],
+            SortOrder::Step => {
+                // Alan Zucconi's step sort, the hues are split into bands and every other band
+                // runs backwards so that the lightness flows smoothly from one band into the next
+                let band: f32 = (hue / 360.0 * STEP_SORT_BANDS).floor();
+                let luminance: f32 = (luminance.sqrt() * STEP_SORT_BANDS).floor();
+                let value: f32 = (value * STEP_SORT_BANDS).floor();
+                if band % 2.0 == 1.0 {
+                    [band, STEP_SORT_BANDS - luminance, STEP_SORT_BANDS - value]
+                } else {
+                    [band, luminance, value]
+                }
+            }
+        }
+    }
+}
+
+/// The golden angle in degrees, stepping the hue by it never lands close to a previous hue
+const GOLDEN_ANGLE: f32 = 137.507_77;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum RandomStyle {
+    Pastel,
+    Vivid,
+    Dark,
+    Any,
+}
+
+impl RandomStyle {
+    pub(crate) fn from_name(name: Option<&str>) -> PyResult<RandomStyle> {
+        let Some(name) = name else {
+            return Ok(RandomStyle::Any);
+        };
+        match name.to_lowercase().as_str() {
+            "pastel" => Ok(RandomStyle::Pastel),
+            "vivid" => Ok(RandomStyle::Vivid),
+            "dark" => Ok(RandomStyle::Dark),
+            _ => Err(PyValueError::new_err(format!(
+                "Unknown palette style \"{}\", expected \"pastel\", \"vivid\", \"dark\" or None",
+                name
+            ))),
+        }
+    }
+
+    /// The ranges of the OKLCH lightness and chroma colors of the style are drawn from
+    pub(crate) fn ranges(&self) -> ((f32, f32), (f32, f32)) {
+        match self {
+            RandomStyle::Pastel => ((0.82, 0.93), (0.04, 0.09)),
+            RandomStyle::Vivid => ((0.6, 0.78), (0.14, 0.22)),
+            RandomStyle::Dark => ((0.25, 0.45), (0.05, 0.14)),
+            RandomStyle::Any => ((0.35, 0.9), (0.02, 0.2)),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum HueSpacing {
+    GoldenRatio,
+    Even,
+    Random,
+}
+
+impl HueSpacing {
+    pub(crate) fn from_name(name: &str) -> PyResult<HueSpacing> {
+        match name.to_lowercase().as_str() {
+            "golden_ratio" | "golden-ratio" => Ok(HueSpacing::GoldenRatio),
+            "even" => Ok(HueSpacing::Even),
+            "random" => Ok(HueSpacing::Random),
+            _ => Err(PyValueError::new_err(format!(
+                "Unknown hue strategy \"{}\", expected \"golden_ratio\", \"even\" or \"random\"",
+                name
+            ))),
+        }
+    }
+}
+
+/// The pickled state of a palette besides its colors and name, which are passed to the constructor
+type PaletteState = (Vec<Option<String>>, Vec<Option<String>>, Option<usize>);
+
+#[derive(Clone, Debug, Default)]
+#[pyclass(module = "tincture")]
+pub struct Palette {
+    pub(crate) colors: Vec<Color>,
+    pub(crate) names: Vec<Option<String>>,
+    pub(crate) groups: Vec<Option<String>>,
+    #[pyo3(get, set)]
+    pub(crate) name: Option<String>,
+    #[pyo3(get, set)]
+    pub(crate) columns: Option<usize>,
+    lookup: Option<(ColorSpace, KdTree)>,
+}
+
+impl Palette {
+    pub(crate) fn from_vec(colors: Vec<Color>) -> Palette {
+        Palette {
+            names: vec![None; colors.len()],
+            groups: vec![None; colors.len()],
+            colors,
+            ..Palette::default()
+        }
+    }
+
+    pub(crate) fn push(&mut self, color: Color, name: Option<String>, group: Option<String>) {
+        self.colors.push(color);
+        self.names.push(name);
+        self.groups.push(group);
+        self.lookup = None;
+    }
+
+    /// Returns the lookup structure for the metric, it is only rebuilt when
+    /// the metric changes or the palette got mutated since the last lookup
+    pub(crate) fn lookup_for(&mut self, metric: &str) -> PyResult<(ColorSpace, &KdTree)> {
+        let space: ColorSpace = ColorSpace::from_name(metric)?;
+        if space.hue_index().is_some() {
+            return Err(PyValueError::new_err(format!(
+                "Cannot measure distances in the cylindrical color space \"{}\"",
+                metric
+            )));
+        } else if self.colors.is_empty() {
+            return Err(PyValueError::new_err(
+                "Cannot look up colors inside an empty palette",
+            ));
+        }
+        if !matches!(&self.lookup, Some((cached, _)) if *cached == space) {
+            let points: Vec<[f32; 3]> = self
+                .colors
+                .iter()
+                .map(|color| space.to_components(*color))
+                .collect();
+            self.lookup = Some((space, KdTree::new(&points)));
+        }
+        let (_, tree) = self.lookup.as_ref().unwrap();
+        Ok((space, tree))
+    }
+
+    /// Turns a (possibly negative) Python index into a position inside the palette
+    fn resolve_index(&self, index: isize) -> PyResult<usize> {
+        let length: isize = self.colors.len() as isize;
+        let resolved: isize = if index < 0 { index + length } else { index };
+        if resolved < 0 || resolved >= length {
+            return Err(PyIndexError::new_err("Palette index out of range"));
+        }
+        Ok(resolved as usize)
+    }
+}
+
+#[pymethods]
+impl Palette {
+    #[new]
+    #[pyo3(signature = (colors=None, name=None))]
+    fn new(colors: Option<Vec<Color>>, name: Option<String>) -> Self {
+        Palette {
+            name,
+            ..Palette::from_vec(colors.unwrap_or_default())
+        }
+    }
+
+    #[staticmethod]
+    pub fn from_colors_median_cut(
+        python: Python<'_>,
+        colors_or_buffer: ColorsOrBuffer,
+        count: usize,
+    ) -> PyResult<Palette> {
+        let colors: Vec<Color> = colors_or_buffer.into_colors(python)?;
+        if count == 0 {
+            return Err(PyValueError::new_err("The color count must be at least 1"));
+        } else if colors.is_empty() {
+            return Err(PyValueError::new_err(
+                "Cannot quantize an empty set of colors",
+            ));
+        }
+        Ok(Palette::from_vec(
+            python.allow_threads(|| median_cut(&colors, count)),
+        ))
+    }
+
+    #[staticmethod]
+    #[pyo3(signature = (buffer, stride=None, sample_rate=1))]
+    pub fn from_pixels(
+        python: Python<'_>,
+        buffer: PyBuffer<u8>,
+        stride: Option<usize>,
+        sample_rate: usize,
+    ) -> PyResult<Palette> {
+        let stride: usize = stride.unwrap_or(4);
+        if stride < 3 {
+            return Err(PyValueError::new_err(
+                "The stride must be at least 3 bytes per pixel",
+            ));
+        } else if sample_rate == 0 {
+            return Err(PyValueError::new_err("The sample rate must be at least 1"));
+        }
+        let bytes: Vec<u8> = buffer.to_vec(python)?;
+        if bytes.len() % stride != 0 {
+            return Err(PyValueError::new_err(format!(
+                "The buffer length must be a multiple of the stride ({} bytes)",
+                stride
+            )));
+        }
+        let colors: Vec<Color> = python.allow_threads(|| {
+            let pixels: Vec<Color> = bytes
+                .chunks_exact(stride)
+                .step_by(sample_rate)
+                .map(|pixel| {
+                    let alpha: u8 = if stride == 3 { 255 } else { pixel[3] };
+                    Color::new(pixel[0], pixel[1], pixel[2], alpha)
+                })
+                .collect();
+            let mut entries: Vec<(Color, u64)> = histogram(&pixels);
+            entries.sort_by_key(|(_, count)| Reverse(*count));
+            entries.into_iter().map(|(color, _)| color).collect()
+        });
+        Ok(Palette::from_vec(colors))
+    }
+
+    #[staticmethod]
+    pub fn octree(
+        python: Python<'_>,
+        colors_or_buffer: ColorsOrBuffer,
+        count: usize,
+    ) -> PyResult<Palette> {
+        let colors: Vec<Color> = colors_or_buffer.into_colors(python)?;
+        if count == 0 {
+            return Err(PyValueError::new_err("The color count must be at least 1"));
+        } else if colors.is_empty() {
+            return Err(PyValueError::new_err(
+                "Cannot quantize an empty set of colors",
+            ));
+        }
+        Ok(Palette::from_vec(
+            python.allow_threads(|| octree(&colors, count)),
+        ))
+    }
+
+    #[staticmethod]
+    #[pyo3(signature = (colors_or_buffer, k, max_iter=100, seed=None))]
+    pub fn kmeans(
+        python: Python<'_>,
+        colors_or_buffer: ColorsOrBuffer,
+        k: usize,
+        max_iter: usize,
+        seed: Option<u64>,
+    ) -> PyResult<Palette> {
+        let colors: Vec<Color> = colors_or_buffer.into_colors(python)?;
+        if k == 0 {
+            return Err(PyValueError::new_err(
+                "The cluster count must be at least 1",
+            ));
+        } else if colors.is_empty() {
+            return Err(PyValueError::new_err(
+                "Cannot cluster an empty set of colors",
+            ));
+        }
+        let mut rng: StdRng = match seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+        Ok(Palette::from_vec(
+            python.allow_threads(|| kmeans(&colors, k, max_iter, &mut rng)),
+        ))
+    }
+
+    #[staticmethod]
+    #[pyo3(signature = (n, seed=None, style=None, hue_strategy="golden_ratio"))]
+    pub fn random(
+        n: usize,
+        seed: Option<u64>,
+        style: Option<&str>,
+        hue_strategy: &str,
+    ) -> PyResult<Palette> {
+        let ((lightness_start, lightness_end), (chroma_start, chroma_end)) =
+            RandomStyle::from_name(style)?.ranges();
+        let spacing: HueSpacing = HueSpacing::from_name(hue_strategy)?;
+        let mut rng: StdRng = match seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+        let start: f32 = rng.gen_range(0.0..360.0);
+        let colors: Vec<Color> = (0..n)
+            .map(|index| {
+                let hue: f32 = match spacing {
+                    HueSpacing::GoldenRatio => start + (index as f32) * GOLDEN_ANGLE,
+                    HueSpacing::Even => start + (index as f32) * 360.0 / (n as f32),
+                    HueSpacing::Random => rng.gen_range(0.0..360.0),
+                };
+                let lch: [f32; 3] = [
+                    rng.gen_range(lightness_start..=lightness_end),
+                    rng.gen_range(chroma_start..=chroma_end),
+                    hue.rem_euclid(360.0),
+                ];
+                ColorSpace::Oklch.from_components(fit_oklch_to_srgb(lch), 1.0)
+            })
+            .collect();
+        Ok(Palette::from_vec(colors))
+    }
+
+    #[getter]
+    pub fn colors(&self) -> Vec<Color> {
+        self.colors.clone()
+    }
+
+    #[getter]
+    pub fn names(&self) -> Vec<Option<String>> {
+        self.names.clone()
+    }
+
+    #[getter]
+    pub fn groups(&self) -> Vec<Option<String>> {
+        self.groups.clone()
+    }
+
+    #[pyo3(signature = (color, name=None, group=None))]
+    pub fn append(&mut self, color: Color, name: Option<String>, group: Option<String>) {
+        self.push(color, name, group);
+    }
+
+    pub fn extend(&mut self, colors: Vec<Color>) {
+        self.names.extend(vec![None; colors.len()]);
+        self.groups.extend(vec![None; colors.len()]);
+        self.colors.extend(colors);
+        self.lookup = None;
+    }
+
+    #[staticmethod]
+    #[pyo3(signature = (name, n=None))]
+    pub fn brewer(name: &str, n: Option<usize>) -> PyResult<Palette> {
+        brewer_palette(name, n)
+    }
+
+    #[staticmethod]
+    pub fn web_safe() -> Palette {
+        let mut colors: Vec<Color> = Vec::with_capacity(216);
+        for r in 0..6u8 {
+            for g in 0..6u8 {
+                for b in 0..6u8 {
+                    colors.push(Color::new(r * 51, g * 51, b * 51, 255));
+                }
+            }
+        }
+        Palette::from_vec(colors)
+    }
+
+    pub fn to_web_safe(&self) -> Palette {
+        Palette {
+            colors: self
+                .colors
+                .iter()
+                .map(|color| web_safe_color(*color))
+                .collect(),
+            names: self.names.clone(),
+            groups: self.groups.clone(),
+            name: self.name.clone(),
+            columns: self.columns,
+            lookup: None,
+        }
+    }
+
+    #[staticmethod]
+    pub fn from_gpl(text_or_path: Bound<'_, PyAny>) -> PyResult<Palette> {
+        parse_gpl(&read_text_or_path(&text_or_path, GPL_HEADER)?)
+    }
+
+    #[pyo3(signature = (name=None, path=None))]
+    pub fn to_gpl(&self, name: Option<&str>, path: Option<PathBuf>) -> PyResult<Option<String>> {
+        let name: &str = name.or(self.name.as_deref()).unwrap_or("Untitled");
+        write_or_return(write_gpl(self, name), path)
+    }
+
+    #[pyo3(signature = (indent=None))]
+    pub fn to_json(&self, python: Python<'_>, indent: Option<usize>) -> PyResult<String> {
+        let object: Bound<PyDict> = palette_to_json(python, self)?;
+        let options: Bound<PyDict> = PyDict::new_bound(python);
+        options.set_item("indent", indent)?;
+        python
+            .import_bound("json")?
+            .call_method("dumps", (object,), Some(&options))?
+            .extract()
+    }
+
+    #[staticmethod]
+    pub fn from_json(python: Python<'_>, text: &str) -> PyResult<Palette> {
+        let object: Bound<PyAny> = python
+            .import_bound("json")?
+            .call_method1("loads", (text,))?;
+        palette_from_json(&object)
+    }
+
+    #[pyo3(signature = (include_transparency=false))]
+    pub fn to_hex_text(&self, include_transparency: bool) -> String {
+        write_hex_text(self, include_transparency)
+    }
+
+    #[staticmethod]
+    pub fn from_hex_text(text: &str) -> PyResult<Palette> {
+        parse_hex_text(text)
+    }
+
+    pub fn to_text(&self) -> String {
+        write_text(self)
+    }
+
+    #[staticmethod]
+    pub fn from_text(text: &str) -> PyResult<Palette> {
+        parse_text(text)
+    }
+
+    #[staticmethod]
+    pub fn from_ase(python: Python<'_>, data_or_path: BytesOrPath) -> PyResult<Palette> {
+        parse_ase(&data_or_path.into_bytes(python)?)
+    }
+
+    #[pyo3(signature = (path=None))]
+    pub fn to_ase<'py>(
+        &self,
+        python: Python<'py>,
+        path: Option<PathBuf>,
+    ) -> PyResult<Option<Bound<'py, PyBytes>>> {
+        write_bytes_or_return(python, write_ase(self)?, path)
+    }
+
+    #[staticmethod]
+    pub fn from_aco(python: Python<'_>, data_or_path: BytesOrPath) -> PyResult<Palette> {
+        parse_aco(&data_or_path.into_bytes(python)?)
+    }
+
+    #[pyo3(signature = (path=None))]
+    pub fn to_aco<'py>(
+        &self,
+        python: Python<'py>,
+        path: Option<PathBuf>,
+    ) -> PyResult<Option<Bound<'py, PyBytes>>> {
+        write_bytes_or_return(python, write_aco(self)?, path)
+    }
+
+    #[pyo3(signature = (path=None, layout="grid"))]
+    pub fn to_pdf<'py>(
+        &self,
+        python: Python<'py>,
+        path: Option<PathBuf>,
+        layout: &str,
+    ) -> PyResult<Option<Bound<'py, PyBytes>>> {
+        let layout: PdfLayout = PdfLayout::from_name(layout)?;
+        write_bytes_or_return(python, write_pdf(self, layout)?, path)
+    }
+
+    #[pyo3(signature = (color, metric="oklab"))]
+    pub fn nearest(&mut self, color: Color, metric: &str) -> PyResult<Color> {
+        let (space, tree) = self.lookup_for(metric)?;
+        let index: usize = tree.nearest(space.to_components(color)).unwrap();
+        Ok(self.colors[index])
+    }
+
+    #[pyo3(signature = (colors, metric="oklab"))]
+    pub fn nearest_many(
+        &mut self,
+        python: Python<'_>,
+        colors: Vec<Color>,
+        metric: &str,
+    ) -> PyResult<Vec<Color>> {
+        self.lookup_for(metric)?;
+        let (space, tree) = self.lookup.as_ref().unwrap();
+        let palette: &[Color] = &self.colors;
+        Ok(python.allow_threads(|| {
+            colors
+                .iter()
+                .map(|color| palette[tree.nearest(space.to_components(*color)).unwrap()])
+                .collect()
+        }))
+    }
+
+    #[pyo3(signature = (tolerance=2.0, metric="ciede2000", merge=false))]
+    pub fn dedup(&self, tolerance: f32, metric: &str, merge: bool) -> PyResult<Palette> {
+        let metric: DistanceMetric = DistanceMetric::from_name(metric)?;
+        check_tolerance(tolerance)?;
+        let groups: Vec<Vec<usize>> = group_similar(&self.colors, tolerance, metric);
+        let mut palette: Palette = Palette {
+            name: self.name.clone(),
+            columns: self.columns,
+            ..Palette::default()
+        };
+        for group in groups {
+            let index: usize = group[0];
+            let color: Color = if merge && group.len() > 1 {
+                let colors: Vec<Color> = group.iter().map(|index| self.colors[*index]).collect();
+                average_in_space(&colors, &vec![1.0; colors.len()], ColorSpace::Oklab)
+            } else {
+                self.colors[index]
+            };
+            palette.push(color, self.names[index].clone(), self.groups[index].clone());
+        }
+        Ok(palette)
+    }
+
+    #[pyo3(signature = (by="hue", reverse=false))]
+    pub fn sorted(&self, python: Python<'_>, by: &str, reverse: bool) -> PyResult<Palette> {
+        let order: SortOrder = SortOrder::from_name(by)?;
+        let colors: &[Color] = &self.colors;
+        let indices: Vec<usize> = python.allow_threads(|| {
+            let mut keyed: Vec<([f32; 3], usize)> = colors
+                .iter()
+                .enumerate()
+                .map(|(index, color)| (order.key(*color), index))
+                .collect();
+            keyed.sort_by(|(first, _), (second, _)| {
+                let ordering: Ordering = first
+                    .iter()
+                    .zip(second)
+                    .map(|(first, second)| first.total_cmp(second))
+                    .find(|ordering| ordering.is_ne())
+                    .unwrap_or(Ordering::Equal);
+                if reverse {
+                    ordering.reverse()
+                } else {
+                    ordering
+                }
+            });
+            keyed.into_iter().map(|(_, index)| index).collect()
+        });
+        let mut palette: Palette = Palette {
+            name: self.name.clone(),
+            columns: self.columns,
+            ..Palette::default()
+        };
+        for index in indices {
+            palette.push(
+                self.colors[index],
+                self.names[index].clone(),
+                self.groups[index].clone(),
+            );
+        }
+        Ok(palette)
+    }
+
+    pub fn fingerprint(&self) -> String {
+        let bytes: Vec<u8> = self
+            .colors
+            .iter()
+            .flat_map(|color| [color.r, color.g, color.b, color.a])
+            .collect();
+        fingerprint(&bytes)
+    }
+
+    pub fn __len__(&self) -> usize {
+        self.colors.len()
+    }
+
+    pub fn __getitem__(&self, python: Python<'_>, index: PaletteIndex) -> PyResult<PyObject> {
+        match index {
+            PaletteIndex::Integer(index) => {
+                Ok(self.colors[self.resolve_index(index)?].into_py(python))
+            }
+            PaletteIndex::Slice(slice) => {
+                let indices = slice.indices(self.colors.len() as isize)?;
+                let mut palette: Palette = Palette::default();
+                for offset in 0..indices.slicelength {
+                    let index: usize = (indices.start + (offset as isize) * indices.step) as usize;
+                    palette.push(
+                        self.colors[index],
+                        self.names[index].clone(),
+                        self.groups[index].clone(),
+                    );
+                }
+                Ok(palette.into_py(python))
+            }
+        }
+    }
+
+    pub fn __setitem__(&mut self, index: isize, color: Color) -> PyResult<()> {
+        let index: usize = self.resolve_index(index)?;
+        self.colors[index] = color;
+        self.names[index] = None;
+        self.lookup = None;
+        Ok(())
+    }
+
+    pub fn __iter__<'py>(&self, python: Python<'py>) -> PyResult<Bound<'py, PyIterator>> {
+        let colors: Vec<PyObject> = self
+            .colors
+            .iter()
+            .map(|color| color.into_py(python))
+            .collect();
+        PyList::new_bound(python, colors).as_any().iter()
+    }
+
+    pub fn __contains__(&self, color: Color) -> bool {
+        self.colors.contains(&color)
+    }
+
+    pub fn __eq__(&self, other: &Palette) -> bool {
+        self.colors == other.colors
+    }
+
+    pub fn __copy__(&self) -> Palette {
+        self.clone()
+    }
+
+    pub fn __deepcopy__(&self, _memo: Bound<'_, PyDict>) -> Palette {
+        self.clone()
+    }
+
+    pub fn __reduce__<'py>(
+        &self,
+        python: Python<'py>,
+    ) -> (
+        Bound<'py, PyType>,
+        (Vec<Color>, Option<String>),
+        PaletteState,
+    ) {
+        (
+            python.get_type_bound::<Palette>(),
+            (self.colors.clone(), self.name.clone()),
+            self.__getstate__(),
+        )
+    }
+
+    pub fn __getstate__(&self) -> PaletteState {
+        (self.names.clone(), self.groups.clone(), self.columns)
+    }
+
+    pub fn __setstate__(&mut self, state: PaletteState) -> PyResult<()> {
+        let (names, groups, columns): PaletteState = state;
+        if names.len() != self.colors.len() || groups.len() != self.colors.len() {
+            return Err(PyValueError::new_err(
+                "The names and groups of the state must match the colors of the palette",
+            ));
+        }
+        self.names = names;
+        self.groups = groups;
+        self.columns = columns;
+        Ok(())
+    }
+
+    #[pyo3(signature = (width=480.0, height=120.0, labels=true))]
+    pub fn to_svg(&self, width: f32, height: f32, labels: bool) -> PyResult<String> {
+        check_svg_size(width, height)?;
+        Ok(palette_svg(self, width, height, labels))
+    }
+
+    pub fn _repr_html_(&self) -> String {
+        palette_html(self)
+    }
+
+    pub fn __repr__(&self) -> String {
+        let colors: Vec<String> = self
+            .colors
+            .iter()
+            .map(|color| format!("Color({}, {}, {}, {})", color.r, color.g, color.b, color.a))
+            .collect();
+        format!("Palette([{}])", colors.join(", "))
+    }
+}
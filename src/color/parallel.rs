@@ -0,0 +1,62 @@
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use rayon::prelude::*;
+use rayon::{ThreadPool, ThreadPoolBuilder};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// Below this many items the work stays on the calling thread, since splitting it up
+/// costs more than it saves
+const PARALLEL_THRESHOLD: usize = 16384;
+
+/// The pool every parallel operation runs on, built lazily so that [set_num_threads]
+/// can still size it before the first large operation
+static THREAD_POOL: Mutex<Option<Arc<ThreadPool>>> = Mutex::new(None);
+
+fn build_pool(threads: usize) -> PyResult<Arc<ThreadPool>> {
+    ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .thread_name(|index| format!("tincture-{}", index))
+        .build()
+        .map(Arc::new)
+        .map_err(|error| PyValueError::new_err(error.to_string()))
+}
+
+fn thread_pool() -> Arc<ThreadPool> {
+    let mut pool: MutexGuard<Option<Arc<ThreadPool>>> = THREAD_POOL
+        .lock()
+        .unwrap_or_else(|error| error.into_inner());
+    pool.get_or_insert_with(|| build_pool(0).expect("The default thread pool could not be created"))
+        .clone()
+}
+
+/// Calls the operation for every index from 0 to the length, spreading the calls across
+/// the thread pool once there are enough of them. The results keep the order of the indices
+pub(crate) fn map_indices<O: Send>(
+    length: usize,
+    operation: impl Fn(usize) -> O + Send + Sync,
+) -> Vec<O> {
+    if length < PARALLEL_THRESHOLD {
+        return (0..length).map(operation).collect();
+    }
+    thread_pool().install(|| (0..length).into_par_iter().map(operation).collect())
+}
+
+#[pyfunction]
+#[pyo3(signature = (count=None))]
+pub fn set_num_threads(count: Option<usize>) -> PyResult<()> {
+    if count == Some(0) {
+        return Err(PyValueError::new_err(
+            "The amount of threads must be at least 1",
+        ));
+    }
+    let pool: Arc<ThreadPool> = build_pool(count.unwrap_or(0))?;
+    *THREAD_POOL
+        .lock()
+        .unwrap_or_else(|error| error.into_inner()) = Some(pool);
+    Ok(())
+}
+
+#[pyfunction]
+pub fn get_num_threads() -> usize {
+    thread_pool().current_num_threads()
+}
@@ -0,0 +1,278 @@
+use crate::color::adaptation::{from_d65, Illuminant};
+use crate::color::palette::Palette;
+use crate::color::spaces::{xyz_to_lab_relative, ColorSpace};
+use crate::color::utils::{color_to_cmyk, color_to_decimal_rgb};
+use crate::color::Color;
+use pyo3::exceptions::PyValueError;
+use pyo3::PyResult;
+
+/// The size of an A4 page in points
+const PAGE_WIDTH: f32 = 595.0;
+const PAGE_HEIGHT: f32 = 842.0;
+const MARGIN: f32 = 40.0;
+const GAP: f32 = 12.0;
+const TITLE_SIZE: f32 = 16.0;
+const LABEL_SIZE: f32 = 7.5;
+const LINE_HEIGHT: f32 = 10.0;
+/// The amount of label lines below (or next to) every swatch
+const LABEL_LINES: usize = 4;
+/// The grid has this many columns when the palette has no column hint, more than the maximum
+/// would leave too little room for the labels
+const DEFAULT_COLUMNS: usize = 4;
+const MAXIMUM_COLUMNS: usize = 5;
+const LIST_SWATCH_SIZE: f32 = 40.0;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum PdfLayout {
+    Grid,
+    List,
+}
+
+impl PdfLayout {
+    pub(crate) fn from_name(name: &str) -> PyResult<PdfLayout> {
+        match name.to_lowercase().as_str() {
+            "grid" => Ok(PdfLayout::Grid),
+            "list" => Ok(PdfLayout::List),
+            _ => Err(PyValueError::new_err(format!(
+                "Unknown swatch sheet layout \"{}\", expected \"grid\" or \"list\"",
+                name
+            ))),
+        }
+    }
+}
+
+/// Formats a number with at most three decimals, dropping the trailing zeros
+fn pdf_number(value: f32) -> String {
+    let formatted: String = format!("{:.3}", value);
+    formatted
+        .trim_end_matches('0')
+        .trim_end_matches('.')
+        .to_string()
+}
+
+/// A literal string of the WinAnsi encoded standard fonts, characters outside of Latin-1 are
+/// replaced with a question mark and everything outside of ASCII is escaped in octal
+fn pdf_string(text: &str) -> String {
+    let mut literal: String = String::from("(");
+    for character in text.chars() {
+        match character {
+            '(' | ')' | '\\' => {
+                literal.push('\\');
+                literal.push(character);
+            }
+            ' '..='~' => literal.push(character),
+            '\u{a0}'..='\u{ff}' => literal += &format!("\\{:03o}", character as u32),
+            _ => literal.push('?'),
+        }
+    }
+    literal.push(')');
+    literal
+}
+
+fn pdf_text(x: f32, y: f32, font: &str, size: f32, text: &str) -> String {
+    format!(
+        "BT /{} {} Tf {} {} Td {} Tj ET\n",
+        font,
+        pdf_number(size),
+        pdf_number(x),
+        pdf_number(y),
+        pdf_string(text)
+    )
+}
+
+/// Paints the swatch with a thin gray outline, so light colors stay visible on paper. Paper has
+/// no transparency, so colors that are not opaque are shown on top of white
+fn pdf_swatch(x: f32, y: f32, width: f32, height: f32, color: Color) -> String {
+    let alpha: f32 = (color.a as f32) / 255.0;
+    let (r, g, b): (f32, f32, f32) = color_to_decimal_rgb(color);
+    let over_white = |channel: f32| channel * alpha + 1.0 - alpha;
+    format!(
+        "q {} {} {} rg 0.6 G 0.5 w {} {} {} {} re B Q\n",
+        pdf_number(over_white(r)),
+        pdf_number(over_white(g)),
+        pdf_number(over_white(b)),
+        pdf_number(x),
+        pdf_number(y),
+        pdf_number(width),
+        pdf_number(height)
+    )
+}
+
+/// Rounds to one decimal without ever printing "-0.0"
+fn one_decimal(value: f32) -> String {
+    format!("{:.1}", (value * 10.0).round() / 10.0 + 0.0)
+}
+
+/// The name of the swatch followed by its hex, CMYK and D50 Lab values, names that do not fit
+/// into the width get shortened
+fn swatch_labels(color: Color, name: Option<&str>, width: f32, white: [f32; 3]) -> Vec<String> {
+    let (c, m, y, k): (f32, f32, f32, f32) = color_to_cmyk(color);
+    let percent = |value: f32| (value * 100.0).round() as u8;
+    let xyz: [f32; 3] = from_d65(ColorSpace::Xyz.to_components(color), white);
+    let lab: [f32; 3] = xyz_to_lab_relative(xyz, white);
+    // Helvetica averages a bit more than half of the font size per character
+    let maximum: usize = ((width / (LABEL_SIZE * 0.55)) as usize).max(4);
+    let name: String = match name {
+        Some(name) if name.chars().count() > maximum => {
+            name.chars().take(maximum - 3).collect::<String>() + "..."
+        }
+        Some(name) => name.to_string(),
+        None => String::new(),
+    };
+    vec![
+        name,
+        color.to_hex(color.a != 255),
+        format!(
+            "CMYK {} {} {} {}",
+            percent(c),
+            percent(m),
+            percent(y),
+            percent(k)
+        ),
+        format!(
+            "Lab {} {} {}",
+            one_decimal(lab[0]),
+            one_decimal(lab[1]),
+            one_decimal(lab[2])
+        ),
+    ]
+}
+
+/// Lays the swatches out over as many A4 pages as needed, the first page starts with the name
+/// of the palette. Every swatch is labelled with its name, hex, CMYK and D50 Lab values, either
+/// below it (grid) or next to it (list)
+pub(crate) fn write_pdf(palette: &Palette, layout: PdfLayout) -> PyResult<Vec<u8>> {
+    let white: [f32; 3] = Illuminant::named("D50").white_point()?;
+    let title: &str = palette.name.as_deref().unwrap_or("Untitled");
+    let content_width: f32 = PAGE_WIDTH - 2.0 * MARGIN;
+    let (columns, swatch_width, swatch_height, cell_width, cell_height): (
+        usize,
+        f32,
+        f32,
+        f32,
+        f32,
+    ) = match layout {
+        PdfLayout::Grid => {
+            let columns: usize = palette
+                .columns
+                .unwrap_or(DEFAULT_COLUMNS)
+                .clamp(1, MAXIMUM_COLUMNS);
+            let cell_width: f32 = (content_width - GAP * ((columns - 1) as f32)) / (columns as f32);
+            let swatch_height: f32 = cell_width * 0.6;
+            let labels_height: f32 = (LABEL_LINES as f32) * LINE_HEIGHT;
+            (
+                columns,
+                cell_width,
+                swatch_height,
+                cell_width,
+                swatch_height + labels_height + GAP,
+            )
+        }
+        PdfLayout::List => (
+            1,
+            LIST_SWATCH_SIZE,
+            LIST_SWATCH_SIZE,
+            content_width,
+            LIST_SWATCH_SIZE + GAP,
+        ),
+    };
+    let mut pages: Vec<String> = Vec::new();
+    let mut content: String = pdf_text(
+        MARGIN,
+        PAGE_HEIGHT - MARGIN - TITLE_SIZE,
+        "F2",
+        TITLE_SIZE,
+        title,
+    );
+    let mut row_top: f32 = PAGE_HEIGHT - MARGIN - TITLE_SIZE - GAP;
+    for (index, (color, name)) in palette.colors.iter().zip(&palette.names).enumerate() {
+        let column: usize = index % columns;
+        if column == 0 && index > 0 {
+            row_top -= cell_height;
+            if row_top - cell_height + GAP < MARGIN {
+                pages.push(std::mem::take(&mut content));
+                row_top = PAGE_HEIGHT - MARGIN;
+            }
+        }
+        let x: f32 = MARGIN + (column as f32) * (cell_width + GAP);
+        content += &pdf_swatch(
+            x,
+            row_top - swatch_height,
+            swatch_width,
+            swatch_height,
+            *color,
+        );
+        let (label_x, label_top, label_width): (f32, f32, f32) = match layout {
+            PdfLayout::Grid => (x, row_top - swatch_height, cell_width),
+            PdfLayout::List => (
+                x + LIST_SWATCH_SIZE + GAP,
+                row_top,
+                cell_width - LIST_SWATCH_SIZE - GAP,
+            ),
+        };
+        let labels: Vec<String> = swatch_labels(*color, name.as_deref(), label_width, white);
+        for (line, label) in labels.iter().enumerate() {
+            if label.is_empty() {
+                continue;
+            }
+            let font: &str = if line == 0 { "F2" } else { "F1" };
+            let y: f32 = label_top - ((line + 1) as f32) * LINE_HEIGHT + 2.0;
+            content += &pdf_text(label_x, y, font, LABEL_SIZE, label);
+        }
+    }
+    pages.push(content);
+    Ok(pdf_document(&pages))
+}
+
+/// Assembles the page contents into a PDF file using the standard Helvetica fonts, which every
+/// PDF reader has to provide so no font gets embedded
+fn pdf_document(pages: &[String]) -> Vec<u8> {
+    let mut objects: Vec<String> = vec![
+        "<< /Type /Catalog /Pages 2 0 R >>".to_string(),
+        // The page tree is filled in once the pages are numbered
+        String::new(),
+        "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica /Encoding /WinAnsiEncoding >>"
+            .to_string(),
+        "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica-Bold /Encoding /WinAnsiEncoding >>"
+            .to_string(),
+    ];
+    let mut kids: Vec<String> = Vec::with_capacity(pages.len());
+    for content in pages {
+        let number: usize = objects.len() + 1;
+        kids.push(format!("{} 0 R", number));
+        objects.push(format!(
+            "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {} {}] /Resources << /Font << /F1 3 0 R /F2 4 0 R >> >> /Contents {} 0 R >>",
+            pdf_number(PAGE_WIDTH),
+            pdf_number(PAGE_HEIGHT),
+            number + 1
+        ));
+        objects.push(format!(
+            "<< /Length {} >>\nstream\n{}endstream",
+            content.len(),
+            content
+        ));
+    }
+    objects[1] = format!(
+        "<< /Type /Pages /Kids [{}] /Count {} >>",
+        kids.join(" "),
+        kids.len()
+    );
+    let mut document: Vec<u8> = b"%PDF-1.4\n%\xe2\xe3\xcf\xd3\n".to_vec();
+    let mut offsets: Vec<usize> = Vec::with_capacity(objects.len());
+    for (index, object) in objects.iter().enumerate() {
+        offsets.push(document.len());
+        document.extend_from_slice(format!("{} 0 obj\n{}\nendobj\n", index + 1, object).as_bytes());
+    }
+    let xref: usize = document.len();
+    let mut trailer: String = format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1);
+    for offset in offsets {
+        trailer += &format!("{:010} 00000 n \n", offset);
+    }
+    trailer += &format!(
+        "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{}\n%%EOF\n",
+        objects.len() + 1,
+        xref
+    );
+    document.extend_from_slice(trailer.as_bytes());
+    document
+}
@@ -0,0 +1,161 @@
+use crate::color::spaces::{decimal_rgba_to_color, hsl_to_rgb, rgb_to_hsl};
+use crate::color::utils::color_to_decimal_rgb;
+use crate::color::{consts, Color};
+use crossterm::cursor::{Hide, MoveTo, Show};
+use crossterm::event::{read, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crossterm::style::{Print, ResetColor, SetBackgroundColor, SetForegroundColor};
+use crossterm::terminal::{
+    disable_raw_mode, enable_raw_mode, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen,
+};
+use crossterm::{execute, queue};
+use pyo3::prelude::*;
+use std::io::{stdout, Stdout, Write};
+
+const SLIDER_WIDTH: u16 = 48;
+const SLIDER_NAMES: [&str; 3] = ["Hue", "Saturation", "Lightness"];
+const SLIDER_MAXIMUMS: [f32; 3] = [360.0, 1.0, 1.0];
+const SLIDER_STEPS: [f32; 3] = [1.0, 0.01, 0.01];
+const SLIDER_LARGE_STEPS: [f32; 3] = [10.0, 0.1, 0.1];
+
+struct TerminalGuard;
+
+impl TerminalGuard {
+    fn new(out: &mut Stdout) -> std::io::Result<TerminalGuard> {
+        enable_raw_mode()?;
+        execute!(out, EnterAlternateScreen, Hide)?;
+        Ok(TerminalGuard)
+    }
+}
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        let _ = execute!(stdout(), ResetColor, Show, LeaveAlternateScreen);
+        let _ = disable_raw_mode();
+    }
+}
+
+fn to_terminal_color(color: Color) -> crossterm::style::Color {
+    crossterm::style::Color::Rgb {
+        r: color.r,
+        g: color.g,
+        b: color.b,
+    }
+}
+
+fn hsl_color(hsl: [f32; 3], alpha: u8) -> Color {
+    let rgb: [f32; 3] = hsl_to_rgb(hsl);
+    let mut color: Color = decimal_rgba_to_color(rgb[0], rgb[1], rgb[2], 1.0);
+    color.a = alpha;
+    color
+}
+
+fn draw(out: &mut Stdout, hsl: [f32; 3], selected: usize, alpha: u8) -> std::io::Result<()> {
+    let color: Color = hsl_color(hsl, alpha);
+    queue!(
+        out,
+        Clear(ClearType::All),
+        MoveTo(2, 1),
+        Print("Tincture color picker")
+    )?;
+    for (index, name) in SLIDER_NAMES.iter().enumerate() {
+        let row: u16 = 3 + (index as u16) * 2;
+        let marker: &str = if index == selected { ">" } else { " " };
+        queue!(
+            out,
+            MoveTo(2, row),
+            Print(format!("{} {:<11}", marker, name))
+        )?;
+        let position: u16 =
+            ((hsl[index] / SLIDER_MAXIMUMS[index]) * ((SLIDER_WIDTH - 1) as f32)).round() as u16;
+        for cell in 0..SLIDER_WIDTH {
+            let mut cell_hsl: [f32; 3] = hsl;
+            cell_hsl[index] = SLIDER_MAXIMUMS[index] * (cell as f32) / ((SLIDER_WIDTH - 1) as f32);
+            let cell_color: Color = hsl_color(cell_hsl, 255);
+            let contrast: crossterm::style::Color = if hsl[2] > 0.6 {
+                crossterm::style::Color::Black
+            } else {
+                crossterm::style::Color::White
+            };
+            queue!(
+                out,
+                SetBackgroundColor(to_terminal_color(cell_color)),
+                SetForegroundColor(contrast),
+                Print(if cell == position { "|" } else { " " })
+            )?;
+        }
+        queue!(out, ResetColor)?;
+        let value: String = if index == 0 {
+            format!(" {:>3.0}°", hsl[index])
+        } else {
+            format!(" {:>3.0}%", hsl[index] * 100.0)
+        };
+        queue!(out, Print(value))?;
+    }
+    for row in 10..14 {
+        queue!(
+            out,
+            MoveTo(2, row),
+            SetBackgroundColor(to_terminal_color(color)),
+            Print(" ".repeat(16)),
+            ResetColor
+        )?;
+    }
+    queue!(
+        out,
+        MoveTo(20, 11),
+        Print(color.to_hex(alpha != 255)),
+        MoveTo(20, 12),
+        Print(format!("rgb({}, {}, {})", color.r, color.g, color.b)),
+        MoveTo(2, 15),
+        Print(
+            "up/down: select   left/right: adjust (shift: faster)   enter: accept   esc/q: cancel"
+        )
+    )?;
+    out.flush()
+}
+
+pub(crate) fn run_picker(initial: Color) -> std::io::Result<Option<Color>> {
+    let rgb: (f32, f32, f32) = color_to_decimal_rgb(initial);
+    let mut hsl: [f32; 3] = rgb_to_hsl([rgb.0, rgb.1, rgb.2]);
+    let mut selected: usize = 0;
+    let mut out: Stdout = stdout();
+    let _guard: TerminalGuard = TerminalGuard::new(&mut out)?;
+    loop {
+        draw(&mut out, hsl, selected, initial.a)?;
+        let Event::Key(KeyEvent {
+            code,
+            modifiers,
+            kind: KeyEventKind::Press,
+            ..
+        }) = read()?
+        else {
+            continue;
+        };
+        let step: [f32; 3] = if modifiers.contains(KeyModifiers::SHIFT) {
+            SLIDER_LARGE_STEPS
+        } else {
+            SLIDER_STEPS
+        };
+        match code {
+            KeyCode::Enter => return Ok(Some(hsl_color(hsl, initial.a))),
+            KeyCode::Esc | KeyCode::Char('q') => return Ok(None),
+            KeyCode::Char('c') if modifiers.contains(KeyModifiers::CONTROL) => return Ok(None),
+            KeyCode::Up => selected = (selected + SLIDER_NAMES.len() - 1) % SLIDER_NAMES.len(),
+            KeyCode::Down | KeyCode::Tab => selected = (selected + 1) % SLIDER_NAMES.len(),
+            KeyCode::Left => {
+                hsl[selected] = (hsl[selected] - step[selected]).max(0.0);
+            }
+            KeyCode::Right => {
+                hsl[selected] = (hsl[selected] + step[selected]).min(SLIDER_MAXIMUMS[selected]);
+            }
+            _ => {}
+        }
+    }
+}
+
+#[pyfunction]
+#[pyo3(signature = (initial=None))]
+pub fn pick(python: Python<'_>, initial: Option<Color>) -> PyResult<Option<Color>> {
+    let initial: Color = initial.unwrap_or(consts::RED);
+    Ok(python.allow_threads(|| run_picker(initial))?)
+}
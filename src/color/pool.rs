@@ -0,0 +1,119 @@
+use crate::color::Color;
+use pyo3::exceptions::PyIndexError;
+use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyDict};
+use std::collections::HashMap;
+
+#[derive(Clone, Debug, Default)]
+#[pyclass(module = "tincture")]
+pub struct ColorPool {
+    colors: Vec<Color>,
+    handles: HashMap<Color, u32>,
+}
+
+impl ColorPool {
+    pub(crate) fn intern_color(&mut self, color: Color) -> u32 {
+        if let Some(handle) = self.handles.get(&color) {
+            return *handle;
+        }
+        let handle: u32 = self.colors.len() as u32;
+        self.colors.push(color);
+        self.handles.insert(color, handle);
+        handle
+    }
+
+    pub(crate) fn resolve_color(&self, handle: u32) -> PyResult<Color> {
+        self.colors.get(handle as usize).copied().ok_or_else(|| {
+            PyIndexError::new_err(format!("Handle {} does not belong to the pool", handle))
+        })
+    }
+}
+
+#[pymethods]
+impl ColorPool {
+    #[new]
+    #[pyo3(signature = (colors=None))]
+    fn new(colors: Option<Vec<Color>>) -> Self {
+        let mut pool: ColorPool = ColorPool::default();
+        for color in colors.unwrap_or_default() {
+            pool.intern_color(color);
+        }
+        pool
+    }
+
+    pub fn intern(&mut self, color: Color) -> u32 {
+        self.intern_color(color)
+    }
+
+    pub fn intern_many(&mut self, colors: Vec<Color>) -> Vec<u32> {
+        colors
+            .into_iter()
+            .map(|color| self.intern_color(color))
+            .collect()
+    }
+
+    pub fn handle_of(&self, color: Color) -> Option<u32> {
+        self.handles.get(&color).copied()
+    }
+
+    pub fn get(&self, handle: u32) -> PyResult<Color> {
+        self.resolve_color(handle)
+    }
+
+    pub fn resolve(&self, handles: Vec<u32>) -> PyResult<Vec<Color>> {
+        handles
+            .into_iter()
+            .map(|handle| self.resolve_color(handle))
+            .collect()
+    }
+
+    pub fn resolve_rgba<'py>(
+        &self,
+        python: Python<'py>,
+        handles: Vec<u32>,
+    ) -> PyResult<Bound<'py, PyBytes>> {
+        let pixels: Vec<u8> = python.allow_threads(|| {
+            let mut pixels: Vec<u8> = Vec::with_capacity(handles.len() * 4);
+            for handle in handles {
+                let color: Color = self.resolve_color(handle)?;
+                pixels.extend_from_slice(&[color.r, color.g, color.b, color.a]);
+            }
+            Ok::<Vec<u8>, PyErr>(pixels)
+        })?;
+        Ok(PyBytes::new_bound(python, &pixels))
+    }
+
+    #[getter]
+    pub fn colors(&self) -> Vec<Color> {
+        self.colors.clone()
+    }
+
+    pub fn clear(&mut self) {
+        self.colors.clear();
+        self.handles.clear();
+    }
+
+    pub fn __getitem__(&self, handle: u32) -> PyResult<Color> {
+        self.resolve_color(handle)
+    }
+
+    pub fn __contains__(&self, color: Color) -> bool {
+        self.handles.contains_key(&color)
+    }
+
+    pub fn __len__(&self) -> usize {
+        self.colors.len()
+    }
+
+    pub fn __copy__(&self) -> ColorPool {
+        self.clone()
+    }
+
+    pub fn __deepcopy__(&self, _memo: Bound<'_, PyDict>) -> ColorPool {
+        self.clone()
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!("ColorPool({} colors)", self.colors.len())
+    }
+}
@@ -0,0 +1,293 @@
+use crate::color::parallel::map_indices;
+use crate::color::spaces::ColorSpace;
+use crate::color::Color;
+use rand::distributions::{Distribution, WeightedIndex};
+use rand::rngs::StdRng;
+use std::cmp::Reverse;
+use std::collections::HashMap;
+
+/// Counts how often each distinct color appears, keeping the order of first appearance
+/// so that every quantization algorithm stays deterministic
+pub(crate) fn histogram(colors: &[Color]) -> Vec<(Color, u64)> {
+    let mut positions: HashMap<Color, usize> = HashMap::new();
+    let mut entries: Vec<(Color, u64)> = Vec::new();
+    for color in colors {
+        match positions.get(color) {
+            Some(position) => entries[*position].1 += 1,
+            None => {
+                positions.insert(*color, entries.len());
+                entries.push((*color, 1));
+            }
+        }
+    }
+    entries
+}
+
+fn channel(color: &Color, index: usize) -> u8 {
+    match index {
+        0 => color.r,
+        1 => color.g,
+        2 => color.b,
+        _ => color.a,
+    }
+}
+
+/// Returns the channel with the widest range inside the box together with that range
+fn widest_channel(entries: &[(Color, u64)]) -> (usize, u8) {
+    (0..4)
+        .map(|index| {
+            let minimum: u8 = entries
+                .iter()
+                .map(|(color, _)| channel(color, index))
+                .min()
+                .unwrap();
+            let maximum: u8 = entries
+                .iter()
+                .map(|(color, _)| channel(color, index))
+                .max()
+                .unwrap();
+            (index, maximum - minimum)
+        })
+        .max_by_key(|(index, range)| (*range, Reverse(*index)))
+        .unwrap()
+}
+
+pub(crate) fn weighted_average(entries: &[(Color, u64)]) -> Color {
+    let mut sums: [u64; 4] = [0; 4];
+    let mut total: u64 = 0;
+    for (color, count) in entries {
+        for (index, sum) in sums.iter_mut().enumerate() {
+            *sum += (channel(color, index) as u64) * count;
+        }
+        total += count;
+    }
+    let average = |sum: u64| ((sum as f64) / (total as f64)).round() as u8;
+    Color {
+        r: average(sums[0]),
+        g: average(sums[1]),
+        b: average(sums[2]),
+        a: average(sums[3]),
+    }
+}
+
+/// Heckbert's median cut, the box with the widest channel range is split at the
+/// population median of that channel until there are enough boxes
+pub(crate) fn median_cut(colors: &[Color], count: usize) -> Vec<Color> {
+    let mut boxes: Vec<Vec<(Color, u64)>> = vec![histogram(colors)];
+    while boxes.len() < count {
+        let Some((target, (axis, _))) = boxes
+            .iter()
+            .enumerate()
+            .filter(|(_, entries)| entries.len() > 1)
+            .map(|(index, entries)| (index, widest_channel(entries)))
+            .max_by_key(|(index, (_, range))| (*range, Reverse(*index)))
+        else {
+            break;
+        };
+        let mut entries: Vec<(Color, u64)> = std::mem::take(&mut boxes[target]);
+        entries.sort_by_key(|(color, _)| channel(color, axis));
+        let population: u64 = entries.iter().map(|(_, count)| count).sum();
+        let mut accumulated: u64 = 0;
+        let mut split: usize = 1;
+        for (index, (_, count)) in entries.iter().enumerate() {
+            accumulated += count;
+            if accumulated * 2 >= population {
+                split = (index + 1).clamp(1, entries.len() - 1);
+                break;
+            }
+        }
+        let upper: Vec<(Color, u64)> = entries.split_off(split);
+        boxes[target] = entries;
+        boxes.push(upper);
+    }
+    boxes
+        .iter()
+        .map(|entries| weighted_average(entries))
+        .collect()
+}
+
+fn squared_distance(first: [f32; 3], second: [f32; 3]) -> f32 {
+    (first[0] - second[0]).powi(2) + (first[1] - second[1]).powi(2) + (first[2] - second[2]).powi(2)
+}
+
+fn closest_center(point: [f32; 3], centers: &[[f32; 3]]) -> usize {
+    let mut closest: usize = 0;
+    let mut closest_distance: f32 = f32::INFINITY;
+    for (index, center) in centers.iter().enumerate() {
+        let distance: f32 = squared_distance(point, *center);
+        if distance < closest_distance {
+            closest = index;
+            closest_distance = distance;
+        }
+    }
+    closest
+}
+
+/// Lloyd's k-means over the distinct colors weighted by their population, seeded via k-means++.
+/// The clusters are measured inside Oklab and returned ordered from the most to the least populated
+pub(crate) fn kmeans(
+    colors: &[Color],
+    k: usize,
+    max_iterations: usize,
+    rng: &mut StdRng,
+) -> Vec<Color> {
+    let entries: Vec<(Color, u64)> = histogram(colors);
+    let points: Vec<[f32; 3]> = map_indices(entries.len(), |index| {
+        ColorSpace::Oklab.to_components(entries[index].0)
+    });
+    let weights: Vec<f64> = entries.iter().map(|(_, count)| *count as f64).collect();
+    let first: usize = WeightedIndex::new(&weights).unwrap().sample(rng);
+    let mut centers: Vec<[f32; 3]> = vec![points[first]];
+    while centers.len() < k.min(points.len()) {
+        let distances: Vec<f64> = map_indices(points.len(), |index| {
+            let center: [f32; 3] = centers[closest_center(points[index], &centers)];
+            (squared_distance(points[index], center) as f64) * weights[index]
+        });
+        let Ok(distribution) = WeightedIndex::new(&distances) else {
+            break;
+        };
+        centers.push(points[distribution.sample(rng)]);
+    }
+    let mut assignments: Vec<usize> = vec![usize::MAX; points.len()];
+    for _ in 0..max_iterations.max(1) {
+        let closest: Vec<usize> = map_indices(points.len(), |index| {
+            closest_center(points[index], &centers)
+        });
+        if closest == assignments {
+            break;
+        }
+        assignments = closest;
+        let mut sums: Vec<([f64; 3], f64)> = vec![([0.0; 3], 0.0); centers.len()];
+        for ((point, weight), assignment) in points.iter().zip(&weights).zip(&assignments) {
+            let (sum, total) = &mut sums[*assignment];
+            for (channel_sum, value) in sum.iter_mut().zip(point) {
+                *channel_sum += (*value as f64) * weight;
+            }
+            *total += weight;
+        }
+        for (center, (sum, total)) in centers.iter_mut().zip(&sums) {
+            if *total > 0.0 {
+                *center = sum.map(|value| (value / total) as f32);
+            }
+        }
+    }
+    let mut clusters: Vec<Vec<(Color, u64)>> = vec![Vec::new(); centers.len()];
+    for (entry, assignment) in entries.iter().zip(&assignments) {
+        clusters[*assignment].push(*entry);
+    }
+    clusters.retain(|cluster| !cluster.is_empty());
+    clusters.sort_by_key(|cluster| Reverse(cluster.iter().map(|(_, count)| count).sum::<u64>()));
+    clusters
+        .iter()
+        .map(|cluster| weighted_average(cluster))
+        .collect()
+}
+
+const OCTREE_DEPTH: usize = 8;
+
+#[derive(Clone, Debug, Default)]
+struct OctreeNode {
+    children: [Option<usize>; 8],
+    sums: [u64; 4],
+    count: u64,
+    leaf: bool,
+}
+
+fn octree_branch(color: &Color, depth: usize) -> usize {
+    let shift: usize = 7 - depth;
+    ((((color.r >> shift) & 1) << 2) | (((color.g >> shift) & 1) << 1) | ((color.b >> shift) & 1))
+        as usize
+}
+
+/// Gervautz and Purgathofer's octree quantization, every distinct color is inserted
+/// into an RGB octree whose deepest nodes get merged until there are few enough leaves
+pub(crate) fn octree(colors: &[Color], count: usize) -> Vec<Color> {
+    let mut nodes: Vec<OctreeNode> = vec![OctreeNode::default()];
+    let mut levels: Vec<Vec<usize>> = vec![Vec::new(); OCTREE_DEPTH];
+    levels[0].push(0);
+    let mut leaves: usize = 0;
+    for (color, population) in histogram(colors) {
+        let mut node: usize = 0;
+        for depth in 0..OCTREE_DEPTH {
+            let branch: usize = octree_branch(&color, depth);
+            node = match nodes[node].children[branch] {
+                Some(child) => child,
+                None => {
+                    let child: usize = nodes.len();
+                    nodes.push(OctreeNode {
+                        leaf: depth == OCTREE_DEPTH - 1,
+                        ..OctreeNode::default()
+                    });
+                    nodes[node].children[branch] = Some(child);
+                    if depth == OCTREE_DEPTH - 1 {
+                        leaves += 1;
+                    } else {
+                        levels[depth + 1].push(child);
+                    }
+                    child
+                }
+            };
+        }
+        let leaf: &mut OctreeNode = &mut nodes[node];
+        for (sum, value) in leaf
+            .sums
+            .iter_mut()
+            .zip([color.r, color.g, color.b, color.a])
+        {
+            *sum += (value as u64) * population;
+        }
+        leaf.count += population;
+    }
+    let mut sorted_level: Option<usize> = None;
+    while leaves > count {
+        let Some(level) = levels.iter().rposition(|level| !level.is_empty()) else {
+            break;
+        };
+        if sorted_level != Some(level) {
+            let mut order: Vec<(u64, usize)> = levels[level]
+                .iter()
+                .map(|node| {
+                    let population: u64 = nodes[*node]
+                        .children
+                        .iter()
+                        .flatten()
+                        .map(|child| nodes[*child].count)
+                        .sum();
+                    (population, *node)
+                })
+                .collect();
+            order.sort_by_key(|(population, node)| (Reverse(*population), Reverse(*node)));
+            levels[level] = order.into_iter().map(|(_, node)| node).collect();
+            sorted_level = Some(level);
+        }
+        let node: usize = levels[level].pop().unwrap();
+        let children: Vec<usize> = nodes[node].children.iter().flatten().copied().collect();
+        for child in &children {
+            let (sums, population) = (nodes[*child].sums, nodes[*child].count);
+            for (sum, value) in nodes[node].sums.iter_mut().zip(sums) {
+                *sum += value;
+            }
+            nodes[node].count += population;
+        }
+        nodes[node].children = [None; 8];
+        nodes[node].leaf = true;
+        leaves = leaves + 1 - children.len();
+    }
+    let mut palette: Vec<Color> = Vec::with_capacity(leaves);
+    let mut stack: Vec<usize> = vec![0];
+    while let Some(node) = stack.pop() {
+        let current: &OctreeNode = &nodes[node];
+        if current.leaf {
+            let average = |sum: u64| ((sum as f64) / (current.count as f64)).round() as u8;
+            palette.push(Color {
+                r: average(current.sums[0]),
+                g: average(current.sums[1]),
+                b: average(current.sums[2]),
+                a: average(current.sums[3]),
+            });
+        } else {
+            stack.extend(current.children.iter().rev().flatten());
+        }
+    }
+    palette
+}
@@ -0,0 +1,309 @@
+use crate::color::spaces::ColorSpace;
+use crate::color::utils::randomise_component;
+use crate::color::Color;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use rand::rngs::StdRng;
+use rand::{Rng, RngCore, SeedableRng};
+use std::f32::consts::PI;
+
+/// A generator that starts from the seed, or from the entropy of the operating system when
+/// there is none so that every run differs
+pub(crate) fn seeded_rng(seed: Option<u64>) -> StdRng {
+    match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    }
+}
+
+/// Draws every channel from its range, channels whose range is None on both ends are kept
+pub(crate) fn randomise_color(
+    color: Color,
+    start: [Option<u8>; 4],
+    end: [Option<u8>; 4],
+    rng: &mut impl Rng,
+) -> PyResult<Color> {
+    Ok(Color {
+        r: randomise_component(color.r, start[0], end[0], rng, "Red")?,
+        g: randomise_component(color.g, start[1], end[1], rng, "Green")?,
+        b: randomise_component(color.b, start[2], end[2], rng, "Blue")?,
+        a: randomise_component(color.a, start[3], end[3], rng, "Transparency")?,
+    })
+}
+
+/// The ranges random colors are drawn from inside one of the cylindrical spaces, the lightness
+/// stands for the value of HSV and the chroma for the saturation of HSL and HSV
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct RandomRanges {
+    space: ColorSpace,
+    lightness: (f32, f32),
+    chroma: (f32, f32),
+    hue: Option<(f32, f32)>,
+}
+
+impl RandomRanges {
+    pub(crate) fn new(
+        space: &str,
+        lightness: Option<(f32, f32)>,
+        chroma: Option<(f32, f32)>,
+        hue: Option<(f32, f32)>,
+    ) -> PyResult<RandomRanges> {
+        let space: ColorSpace = ColorSpace::from_name(space)?;
+        // Components without a range span everything the space can reach
+        let (full_lightness, full_chroma): ((f32, f32), (f32, f32)) = match space {
+            ColorSpace::Oklch => ((0.0, 1.0), (0.0, 0.4)),
+            ColorSpace::Lch => ((0.0, 100.0), (0.0, 150.0)),
+            ColorSpace::Hsl | ColorSpace::Hsv => ((0.0, 1.0), (0.0, 1.0)),
+            _ => {
+                return Err(PyValueError::new_err(format!(
+                    "Random colors are drawn in a cylindrical space, expected oklch, lch, hsl or hsv but got \"{}\"",
+                    space.name()
+                )))
+            }
+        };
+        let lightness: (f32, f32) = check_range(lightness.unwrap_or(full_lightness), "lightness")?;
+        let chroma: (f32, f32) = check_range(chroma.unwrap_or(full_chroma), "chroma")?;
+        if let Some((start, end)) = hue {
+            if !start.is_finite() || !end.is_finite() {
+                return Err(PyValueError::new_err("The hue range has to be finite"));
+            }
+        }
+        Ok(RandomRanges {
+            space,
+            lightness,
+            chroma,
+            hue,
+        })
+    }
+
+    /// Draws every component uniformly from its range, colors that land outside of sRGB have
+    /// their chroma reduced. A hue range whose start lies past its end wraps around 360 degrees
+    pub(crate) fn draw(&self, rng: &mut impl Rng) -> Color {
+        let lightness: f32 = rng.gen_range(self.lightness.0..=self.lightness.1);
+        let chroma: f32 = rng.gen_range(self.chroma.0..=self.chroma.1);
+        let hue: f32 = match self.hue {
+            Some((start, end)) => {
+                let span: f32 = if end - start >= 360.0 {
+                    360.0
+                } else {
+                    (end - start).rem_euclid(360.0)
+                };
+                (start + rng.gen_range(0.0..=span)).rem_euclid(360.0)
+            }
+            None => rng.gen_range(0.0..360.0),
+        };
+        let components: [f32; 3] = match self.space {
+            ColorSpace::Hsl | ColorSpace::Hsv => [hue, chroma, lightness],
+            _ => [lightness, chroma, hue],
+        };
+        self.space.from_components(components, 1.0)
+    }
+}
+
+fn check_range(range: (f32, f32), name: &str) -> PyResult<(f32, f32)> {
+    if !range.0.is_finite() || !range.1.is_finite() || range.0 > range.1 {
+        return Err(PyValueError::new_err(format!(
+            "The {} range needs a finite start that is not above its end, got {} to {}",
+            name, range.0, range.1
+        )));
+    }
+    Ok(range)
+}
+
+/// The noise added by a jitter, one standard deviation per component of the space followed by
+/// the alpha. Components that are not jittered have a deviation of zero
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Jitter {
+    space: ColorSpace,
+    deviations: [f32; 4],
+}
+
+impl Jitter {
+    /// The sigma is on the 0 to 255 scale of the RGB channels, the OKLCH components receive the
+    /// same share of their own range (1.0 for the lightness, 0.4 for the chroma, 360 degrees)
+    pub(crate) fn new(sigma: f32, channels: Option<&str>, space: &str) -> PyResult<Jitter> {
+        if !sigma.is_finite() || sigma < 0.0 {
+            return Err(PyValueError::new_err(format!(
+                "Sigma must be a finite number that is not negative, got {}",
+                sigma
+            )));
+        }
+        let (space, names, ranges): (ColorSpace, &str, [f32; 4]) =
+            match space.to_lowercase().as_str() {
+                "rgb" | "srgb" => (ColorSpace::Srgb, "rgba", [255.0; 4]),
+                "oklch" => (ColorSpace::Oklch, "lcha", [1.0, 0.4, 360.0, 255.0]),
+                _ => {
+                    return Err(PyValueError::new_err(format!(
+                        "Unknown jitter space \"{}\", expected rgb or oklch",
+                        space
+                    )))
+                }
+            };
+        let channels: &str = channels.unwrap_or(&names[..3]);
+        let mut deviations: [f32; 4] = [0.0; 4];
+        for channel in channels.to_lowercase().chars() {
+            let index: usize = names.find(channel).ok_or_else(|| {
+                PyValueError::new_err(format!(
+                    "Unknown channel '{}' for jittering in {}, expected any of \"{}\"",
+                    channel,
+                    space.name(),
+                    names
+                ))
+            })?;
+            deviations[index] = sigma / 255.0 * ranges[index];
+        }
+        Ok(Jitter { space, deviations })
+    }
+
+    pub(crate) fn apply(&self, color: Color, rng: &mut impl Rng) -> Color {
+        let mut noise = |index: usize| match self.deviations[index] {
+            0.0 => 0.0,
+            deviation => gaussian(rng) * deviation,
+        };
+        let alpha: u8 = ((color.a as f32) + noise(3)).round().clamp(0.0, 255.0) as u8;
+        if self.space == ColorSpace::Srgb {
+            let channel =
+                |value: u8, offset: f32| ((value as f32) + offset).round().clamp(0.0, 255.0) as u8;
+            let offsets: [f32; 3] = [noise(0), noise(1), noise(2)];
+            return Color::new(
+                channel(color.r, offsets[0]),
+                channel(color.g, offsets[1]),
+                channel(color.b, offsets[2]),
+                alpha,
+            );
+        }
+        let lch: [f32; 3] = self.space.to_components(color);
+        let components: [f32; 3] = [
+            (lch[0] + noise(0)).clamp(0.0, 1.0),
+            (lch[1] + noise(1)).max(0.0),
+            (lch[2] + noise(2)).rem_euclid(360.0),
+        ];
+        self.space
+            .from_components(components, (alpha as f32) / 255.0)
+    }
+}
+
+/// A standard normal sample through the Box-Muller transform
+fn gaussian(rng: &mut impl Rng) -> f32 {
+    let radius: f32 = (-2.0 * (1.0 - rng.gen::<f32>()).ln()).sqrt();
+    radius * (2.0 * PI * rng.gen::<f32>()).cos()
+}
+
+fn split_mix(value: u64) -> u64 {
+    let value: u64 = (value ^ (value >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    let value: u64 = (value ^ (value >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    value ^ (value >> 31)
+}
+
+/// SplitMix64, cheap enough to start one generator per pixel. Every pixel starts from a hash of
+/// the seed and its index, so a jittered buffer stays the same no matter how it is split up
+/// between the threads
+pub(crate) struct PixelRng(u64);
+
+impl PixelRng {
+    pub(crate) fn new(seed: u64, index: usize) -> PixelRng {
+        PixelRng(split_mix(seed ^ split_mix(index as u64)))
+    }
+}
+
+impl RngCore for PixelRng {
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        split_mix(self.0)
+    }
+
+    fn fill_bytes(&mut self, destination: &mut [u8]) {
+        for chunk in destination.chunks_mut(8) {
+            chunk.copy_from_slice(&self.next_u64().to_le_bytes()[..chunk.len()]);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, destination: &mut [u8]) -> Result<(), rand::Error> {
+        self.fill_bytes(destination);
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug)]
+#[pyclass(module = "tincture")]
+pub struct RandomColorGenerator {
+    rng: StdRng,
+    seed: Option<u64>,
+}
+
+#[pymethods]
+impl RandomColorGenerator {
+    #[new]
+    #[pyo3(signature = (seed=None))]
+    fn new(seed: Option<u64>) -> Self {
+        RandomColorGenerator {
+            rng: seeded_rng(seed),
+            seed,
+        }
+    }
+
+    #[getter]
+    pub fn seed(&self) -> Option<u64> {
+        self.seed
+    }
+
+    #[pyo3(signature = (seed=None))]
+    pub fn reseed(&mut self, seed: Option<u64>) {
+        self.rng = seeded_rng(seed);
+        self.seed = seed;
+    }
+
+    #[pyo3(signature = (random_alpha=false))]
+    pub fn color(&mut self, random_alpha: bool) -> Color {
+        let alpha: u8 = if random_alpha { self.rng.gen() } else { 255 };
+        Color::new(self.rng.gen(), self.rng.gen(), self.rng.gen(), alpha)
+    }
+
+    #[pyo3(signature = (n, random_alpha=false))]
+    pub fn colors(&mut self, n: usize, random_alpha: bool) -> Vec<Color> {
+        (0..n).map(|_| self.color(random_alpha)).collect()
+    }
+
+    #[pyo3(signature = (space="oklch", l=Some((0.7, 0.9)), c=Some((0.05, 0.12)), h=None))]
+    pub fn random(
+        &mut self,
+        space: &str,
+        l: Option<(f32, f32)>,
+        c: Option<(f32, f32)>,
+        h: Option<(f32, f32)>,
+    ) -> PyResult<Color> {
+        Ok(RandomRanges::new(space, l, c, h)?.draw(&mut self.rng))
+    }
+
+    #[pyo3(signature = (color, start=[Some(0), Some(0), Some(0), Some(0)], end=[Some(255), Some(255), Some(255), Some(255)]))]
+    pub fn randomise(
+        &mut self,
+        color: Color,
+        start: [Option<u8>; 4],
+        end: [Option<u8>; 4],
+    ) -> PyResult<Color> {
+        randomise_color(color, start, end, &mut self.rng)
+    }
+
+    #[pyo3(signature = (color, sigma=5.0, channels=None, space="rgb"))]
+    pub fn jitter(
+        &mut self,
+        color: Color,
+        sigma: f32,
+        channels: Option<&str>,
+        space: &str,
+    ) -> PyResult<Color> {
+        Ok(Jitter::new(sigma, channels, space)?.apply(color, &mut self.rng))
+    }
+
+    pub fn __repr__(&self) -> String {
+        match self.seed {
+            Some(seed) => format!("RandomColorGenerator(seed={})", seed),
+            None => String::from("RandomColorGenerator()"),
+        }
+    }
+}
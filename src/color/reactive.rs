@@ -0,0 +1,159 @@
+use crate::color::{ChannelValues, Color, ColorAccessCode};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+#[pyclass(module = "tincture")]
+pub struct ReactiveColor {
+    color: Color,
+    callbacks: Vec<PyObject>,
+}
+
+impl ReactiveColor {
+    /// Applies the change to the wrapped color and notifies the callbacks when the color actually
+    /// changed, the borrow is released before the callbacks run so they are free to read or mutate it
+    fn update(
+        slf: &Bound<'_, ReactiveColor>,
+        change: impl FnOnce(&mut Color) -> PyResult<()>,
+    ) -> PyResult<()> {
+        let python: Python = slf.py();
+        let (old, new, callbacks): (Color, Color, Vec<PyObject>) = {
+            let mut this: PyRefMut<ReactiveColor> = slf.borrow_mut();
+            let old: Color = this.color;
+            change(&mut this.color)?;
+            let callbacks: Vec<PyObject> = this
+                .callbacks
+                .iter()
+                .map(|callback| callback.clone_ref(python))
+                .collect();
+            (old, this.color, callbacks)
+        };
+        if old == new {
+            return Ok(());
+        }
+        for callback in callbacks {
+            callback.call1(python, (old, new))?;
+        }
+        Ok(())
+    }
+}
+
+#[pymethods]
+impl ReactiveColor {
+    #[new]
+    fn new(color: Color) -> Self {
+        ReactiveColor {
+            color,
+            callbacks: Vec::new(),
+        }
+    }
+
+    pub fn on_change(&mut self, python: Python<'_>, callback: PyObject) -> PyObject {
+        self.callbacks.push(callback.clone_ref(python));
+        callback
+    }
+
+    pub fn remove_on_change(&mut self, callback: PyObject) -> PyResult<()> {
+        let Some(index) = self
+            .callbacks
+            .iter()
+            .position(|registered| registered.is(&callback))
+        else {
+            return Err(PyValueError::new_err(
+                "The callback is not registered on this color",
+            ));
+        };
+        self.callbacks.remove(index);
+        Ok(())
+    }
+
+    #[getter]
+    pub fn get_color(&self) -> Color {
+        self.color
+    }
+
+    #[setter]
+    pub fn set_color(slf: &Bound<'_, Self>, value: Color) -> PyResult<()> {
+        ReactiveColor::update(slf, |color| {
+            *color = value;
+            Ok(())
+        })
+    }
+
+    #[getter]
+    pub fn get_r(&self) -> u8 {
+        self.color.r
+    }
+
+    #[setter]
+    pub fn set_r(slf: &Bound<'_, Self>, value: u8) -> PyResult<()> {
+        ReactiveColor::update(slf, |color| {
+            color.r = value;
+            Ok(())
+        })
+    }
+
+    #[getter]
+    pub fn get_g(&self) -> u8 {
+        self.color.g
+    }
+
+    #[setter]
+    pub fn set_g(slf: &Bound<'_, Self>, value: u8) -> PyResult<()> {
+        ReactiveColor::update(slf, |color| {
+            color.g = value;
+            Ok(())
+        })
+    }
+
+    #[getter]
+    pub fn get_b(&self) -> u8 {
+        self.color.b
+    }
+
+    #[setter]
+    pub fn set_b(slf: &Bound<'_, Self>, value: u8) -> PyResult<()> {
+        ReactiveColor::update(slf, |color| {
+            color.b = value;
+            Ok(())
+        })
+    }
+
+    #[getter]
+    pub fn get_a(&self) -> u8 {
+        self.color.a
+    }
+
+    #[setter]
+    pub fn set_a(slf: &Bound<'_, Self>, value: u8) -> PyResult<()> {
+        ReactiveColor::update(slf, |color| {
+            color.a = value;
+            Ok(())
+        })
+    }
+
+    pub fn __getitem__(
+        &self,
+        python: Python<'_>,
+        access_code: ColorAccessCode,
+    ) -> PyResult<PyObject> {
+        self.color.__getitem__(python, access_code)
+    }
+
+    pub fn __setitem__(
+        slf: &Bound<'_, Self>,
+        access_code: ColorAccessCode,
+        new_value: ChannelValues,
+    ) -> PyResult<()> {
+        let python: Python = slf.py();
+        ReactiveColor::update(slf, |color| {
+            color.__setitem__(python, access_code, new_value)
+        })
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!(
+            "ReactiveColor(Color({}, {}, {}, {}))",
+            self.color.r, self.color.g, self.color.b, self.color.a
+        )
+    }
+}
@@ -0,0 +1,214 @@
+use crate::color::spaces::ColorSpace;
+use crate::color::transfer::decode_rgb;
+use crate::color::utils::{lighten_color, parse_hex, relative_luminance, saturate_color};
+use crate::color::Color;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+/// The measurable property of a color a rule condition looks at, the hue, saturation and
+/// lightness are those of HSL while the luminance is the relative luminance
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Property {
+    Hue,
+    Saturation,
+    Lightness,
+    Luminance,
+    Alpha,
+}
+
+impl Property {
+    fn from_name(name: &str) -> PyResult<Property> {
+        match name.to_lowercase().as_str() {
+            "hue" => Ok(Property::Hue),
+            "saturation" => Ok(Property::Saturation),
+            "lightness" => Ok(Property::Lightness),
+            "luminance" => Ok(Property::Luminance),
+            "alpha" => Ok(Property::Alpha),
+            _ => Err(PyValueError::new_err(format!(
+                "Unknown rule condition \"{}\", expected hue, saturation, lightness, luminance or alpha",
+                name
+            ))),
+        }
+    }
+
+    fn measure(&self, color: Color) -> f32 {
+        match self {
+            Property::Hue => ColorSpace::Hsl.to_components(color)[0],
+            Property::Saturation => ColorSpace::Hsl.to_components(color)[1],
+            Property::Lightness => ColorSpace::Hsl.to_components(color)[2],
+            Property::Luminance => relative_luminance(decode_rgb(color)),
+            Property::Alpha => (color.a as f32) / 255.0,
+        }
+    }
+}
+
+/// A range a property has to lie inside (both ends included). Hue ranges whose start lies past
+/// their end wrap around 360 degrees, so [330, 30] matches the reds
+#[derive(Clone, Copy, Debug)]
+struct Condition {
+    property: Property,
+    start: f32,
+    end: f32,
+}
+
+impl Condition {
+    fn matches(&self, color: Color) -> bool {
+        let value: f32 = self.property.measure(color);
+        if self.property == Property::Hue && self.start > self.end {
+            return value >= self.start.rem_euclid(360.0) || value <= self.end.rem_euclid(360.0);
+        }
+        value >= self.start && value <= self.end
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Action {
+    ShiftHue(f32),
+    Saturate(f32),
+    Lighten(f32),
+    SetAlpha(f32),
+    Replace(Color),
+}
+
+impl Action {
+    fn from_item(name: &str, value: &Bound<'_, PyAny>) -> PyResult<Action> {
+        let amount = || -> PyResult<f32> {
+            let amount: f32 = value.extract()?;
+            if !amount.is_finite() {
+                return Err(PyValueError::new_err(format!(
+                    "The amount of the rule action \"{}\" has to be a finite number, got {}",
+                    name, amount
+                )));
+            }
+            Ok(amount)
+        };
+        match name.to_lowercase().as_str() {
+            "shift_hue" => Ok(Action::ShiftHue(amount()?)),
+            "saturate" => Ok(Action::Saturate(amount()?)),
+            "desaturate" => Ok(Action::Saturate(-amount()?)),
+            "lighten" => Ok(Action::Lighten(amount()?)),
+            "darken" => Ok(Action::Lighten(-amount()?)),
+            "set_alpha" => {
+                let alpha: f32 = amount()?;
+                if !(0.0..=1.0).contains(&alpha) {
+                    return Err(PyValueError::new_err(format!(
+                        "The alpha of the rule action \"set_alpha\" has to be between 0.0 and 1.0, got {}",
+                        alpha
+                    )));
+                }
+                Ok(Action::SetAlpha(alpha))
+            }
+            "replace" => match value.extract::<Color>() {
+                Ok(color) => Ok(Action::Replace(color)),
+                Err(_) => {
+                    let hex: String = value.extract()?;
+                    Ok(Action::Replace(
+                        parse_hex(hex.trim()).map_err(PyValueError::new_err)?,
+                    ))
+                }
+            },
+            _ => Err(PyValueError::new_err(format!(
+                "Unknown rule action \"{}\", expected shift_hue, saturate, desaturate, lighten, darken, set_alpha or replace",
+                name
+            ))),
+        }
+    }
+
+    fn apply(&self, color: Color) -> Color {
+        match *self {
+            Action::ShiftHue(degrees) => {
+                let hsl: [f32; 3] = ColorSpace::Hsl.to_components(color);
+                ColorSpace::Hsl.from_components(
+                    [(hsl[0] + degrees).rem_euclid(360.0), hsl[1], hsl[2]],
+                    (color.a as f32) / 255.0,
+                )
+            }
+            Action::Saturate(factor) => saturate_color(color, factor),
+            Action::Lighten(amount) => lighten_color(color, amount),
+            Action::SetAlpha(alpha) => Color {
+                a: (alpha * 255.0).round() as u8,
+                ..color
+            },
+            Action::Replace(replacement) => replacement,
+        }
+    }
+}
+
+/// A recolor rule compiled from its declarative form, the actions run in the order they are
+/// listed whenever every condition holds
+#[derive(Clone, Debug)]
+pub(crate) struct Rule {
+    conditions: Vec<Condition>,
+    actions: Vec<Action>,
+}
+
+impl Rule {
+    /// Compiles a rule such as {"if": {"hue": [200, 260]}, "then": {"shift_hue": -30}}, a rule
+    /// without "if" applies to every color
+    pub(crate) fn compile(rule: &Bound<'_, PyDict>) -> PyResult<Rule> {
+        for key in rule.keys() {
+            let key: String = key.extract()?;
+            if key != "if" && key != "then" {
+                return Err(PyValueError::new_err(format!(
+                    "Unknown rule key \"{}\", expected \"if\" and \"then\"",
+                    key
+                )));
+            }
+        }
+        let mut conditions: Vec<Condition> = Vec::new();
+        if let Some(condition) = rule.get_item("if")? {
+            for (name, range) in condition.downcast::<PyDict>()?.iter() {
+                let property: Property = Property::from_name(&name.extract::<String>()?)?;
+                let [start, end]: [f32; 2] = range.extract()?;
+                if !start.is_finite() || !end.is_finite() {
+                    return Err(PyValueError::new_err(format!(
+                        "The range of the rule condition \"{}\" has to be finite, got {} to {}",
+                        name, start, end
+                    )));
+                }
+                if property != Property::Hue && start > end {
+                    return Err(PyValueError::new_err(format!(
+                        "The range of the rule condition \"{}\" cannot start above its end, got {} to {}",
+                        name, start, end
+                    )));
+                }
+                conditions.push(Condition {
+                    property,
+                    start,
+                    end,
+                });
+            }
+        }
+        let actions: Vec<Action> = match rule.get_item("then")? {
+            Some(actions) => actions
+                .downcast::<PyDict>()?
+                .iter()
+                .map(|(name, value)| Action::from_item(&name.extract::<String>()?, &value))
+                .collect::<PyResult<Vec<Action>>>()?,
+            None => return Err(PyValueError::new_err("Every rule needs a \"then\" entry")),
+        };
+        Ok(Rule {
+            conditions,
+            actions,
+        })
+    }
+
+    fn apply(&self, color: Color) -> Color {
+        if !self
+            .conditions
+            .iter()
+            .all(|condition| condition.matches(color))
+        {
+            return color;
+        }
+        self.actions
+            .iter()
+            .fold(color, |color, action| action.apply(color))
+    }
+}
+
+/// Runs every rule in order, each rule sees the color the rules before it produced
+pub(crate) fn apply_rules(rules: &[Rule], color: Color) -> Color {
+    rules.iter().fold(color, |color, rule| rule.apply(color))
+}
@@ -0,0 +1,138 @@
+use crate::color::gradient::{Gradient, Interpolation};
+use crate::color::spaces::{ColorSpace, HueStrategy};
+use crate::color::Color;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+#[derive(FromPyObject)]
+pub enum GradientOrColors {
+    #[pyo3(transparent, annotation = "Gradient")]
+    Gradient(Gradient),
+    #[pyo3(transparent, annotation = "list[Color]")]
+    Colors(Vec<Color>),
+}
+
+#[derive(Clone, Debug)]
+#[pyclass(module = "tincture")]
+pub struct ColorScale {
+    gradient: Gradient,
+    domain: Vec<f64>,
+    clamp: bool,
+}
+
+impl ColorScale {
+    /// Maps a value of the domain onto a position of the gradient, every piece of a
+    /// piecewise domain takes up an equal share of the gradient
+    fn position_of(&self, value: f64) -> Option<f32> {
+        let first: f64 = self.domain[0];
+        let last: f64 = self.domain[self.domain.len() - 1];
+        let (low, high): (f64, f64) = (first.min(last), first.max(last));
+        if value.is_nan() || (!self.clamp && (value < low || value > high)) {
+            return None;
+        }
+        let value: f64 = value.clamp(low, high);
+        let pieces: usize = self.domain.len() - 1;
+        for index in 0..pieces {
+            let (start, end): (f64, f64) = (self.domain[index], self.domain[index + 1]);
+            if value >= start.min(end) && value <= start.max(end) {
+                let local: f64 = (value - start) / (end - start);
+                return Some((((index as f64) + local) / (pieces as f64)) as f32);
+            }
+        }
+        Some(1.0)
+    }
+}
+
+#[pymethods]
+impl ColorScale {
+    #[new]
+    #[pyo3(signature = (gradient, domain=None, clamp=true))]
+    fn new(gradient: GradientOrColors, domain: Option<Vec<f64>>, clamp: bool) -> PyResult<Self> {
+        let gradient: Gradient = match gradient {
+            GradientOrColors::Gradient(gradient) => gradient,
+            GradientOrColors::Colors(colors) => {
+                let divisor: f32 = (colors.len().max(2) - 1) as f32;
+                Gradient::create(
+                    colors
+                        .into_iter()
+                        .enumerate()
+                        .map(|(index, color)| ((index as f32) / divisor, color))
+                        .collect(),
+                    ColorSpace::Oklab,
+                    HueStrategy::Shorter,
+                    Interpolation::Linear,
+                )?
+            }
+        };
+        let domain: Vec<f64> = domain.unwrap_or_else(|| vec![0.0, 1.0]);
+        if domain.len() < 2 {
+            return Err(PyValueError::new_err(
+                "The domain needs at least two values",
+            ));
+        }
+        let increasing: bool = domain[1] > domain[0];
+        let monotonic: bool = domain.windows(2).all(|pair| {
+            if increasing {
+                pair[1] > pair[0]
+            } else {
+                pair[1] < pair[0]
+            }
+        });
+        if !monotonic {
+            return Err(PyValueError::new_err(
+                "The domain has to be strictly increasing or strictly decreasing",
+            ));
+        }
+        Ok(ColorScale {
+            gradient,
+            domain,
+            clamp,
+        })
+    }
+
+    #[getter]
+    pub fn gradient(&self) -> Gradient {
+        self.gradient.clone()
+    }
+
+    #[getter]
+    pub fn domain(&self) -> Vec<f64> {
+        self.domain.clone()
+    }
+
+    #[getter]
+    pub fn clamp(&self) -> bool {
+        self.clamp
+    }
+
+    pub fn scale(&self, value: f64) -> Option<Color> {
+        self.position_of(value)
+            .map(|position| self.gradient.sample_color(position))
+    }
+
+    pub fn scale_many(&self, python: Python<'_>, values: Vec<f64>) -> Vec<Option<Color>> {
+        python.allow_threads(|| values.into_iter().map(|value| self.scale(value)).collect())
+    }
+
+    pub fn __call__(&self, value: f64) -> Option<Color> {
+        self.scale(value)
+    }
+
+    pub fn __copy__(&self) -> ColorScale {
+        self.clone()
+    }
+
+    pub fn __deepcopy__(&self, _memo: Bound<'_, PyDict>) -> ColorScale {
+        self.clone()
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!(
+            "ColorScale({}, domain={:?}, clamp={})",
+            self.gradient.__repr__(),
+            self.domain,
+            if self.clamp { "True" } else { "False" }
+        )
+    }
+}
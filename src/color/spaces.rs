@@ -0,0 +1,771 @@
+use crate::color::consts::D65_WHITE;
+use crate::color::custom_space::{self, CustomSpace};
+use crate::color::transfer::{decode_rgb, encode_rgb};
+use crate::color::utils::*;
+use crate::color::Color;
+use pyo3::exceptions::PyValueError;
+use pyo3::PyResult;
+
+const ACHROMATIC_THRESHOLD: f32 = 1e-4;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum ColorSpace {
+    Srgb,
+    LinearSrgb,
+    Oklab,
+    Oklch,
+    Hsv,
+    Hsl,
+    Lab,
+    Lch,
+    Xyz,
+    DisplayP3,
+    Rec2020,
+    Custom(&'static CustomSpace),
+}
+
+/// How colors outside the sRGB gamut are brought back into it
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum GamutMapping {
+    Clip,
+    OklchChroma,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum HueStrategy {
+    Shorter,
+    Longer,
+    Increasing,
+    Decreasing,
+}
+
+impl ColorSpace {
+    pub(crate) fn from_name(name: &str) -> PyResult<ColorSpace> {
+        match name.to_lowercase().as_str() {
+            "srgb" | "rgb" => Ok(ColorSpace::Srgb),
+            "linear" | "linear_srgb" | "srgb-linear" => Ok(ColorSpace::LinearSrgb),
+            "oklab" => Ok(ColorSpace::Oklab),
+            "oklch" => Ok(ColorSpace::Oklch),
+            "hsv" => Ok(ColorSpace::Hsv),
+            "hsl" => Ok(ColorSpace::Hsl),
+            "lab" => Ok(ColorSpace::Lab),
+            "lch" => Ok(ColorSpace::Lch),
+            "xyz" => Ok(ColorSpace::Xyz),
+            "display_p3" | "display-p3" | "p3" => Ok(ColorSpace::DisplayP3),
+            "rec2020" | "rec-2020" => Ok(ColorSpace::Rec2020),
+            _ => custom_space::lookup(name)
+                .map(ColorSpace::Custom)
+                .ok_or_else(|| PyValueError::new_err(format!("Unknown color space \"{}\"", name))),
+        }
+    }
+
+    pub(crate) fn name(&self) -> &'static str {
+        match self {
+            ColorSpace::Srgb => "srgb",
+            ColorSpace::LinearSrgb => "linear",
+            ColorSpace::Oklab => "oklab",
+            ColorSpace::Oklch => "oklch",
+            ColorSpace::Hsv => "hsv",
+            ColorSpace::Hsl => "hsl",
+            ColorSpace::Lab => "lab",
+            ColorSpace::Lch => "lch",
+            ColorSpace::Xyz => "xyz",
+            ColorSpace::DisplayP3 => "display_p3",
+            ColorSpace::Rec2020 => "rec2020",
+            ColorSpace::Custom(space) => &space.name,
+        }
+    }
+
+    /// Index of the hue component for cylindrical spaces
+    pub(crate) fn hue_index(&self) -> Option<usize> {
+        match self {
+            ColorSpace::Oklch | ColorSpace::Lch => Some(2),
+            ColorSpace::Hsv | ColorSpace::Hsl => Some(0),
+            _ => None,
+        }
+    }
+
+    /// Index of the component that tells how colorful the color is, the hue
+    /// is meaningless (powerless) once this component reaches zero
+    fn chroma_index(&self) -> Option<usize> {
+        match self {
+            ColorSpace::Oklch | ColorSpace::Lch => Some(1),
+            ColorSpace::Hsv | ColorSpace::Hsl => Some(1),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn to_components(&self, color: Color) -> [f32; 3] {
+        let rgb: (f32, f32, f32) = color_to_decimal_rgb(color);
+        let rgb: [f32; 3] = [rgb.0, rgb.1, rgb.2];
+        match self {
+            ColorSpace::Srgb => rgb,
+            ColorSpace::LinearSrgb => decode_rgb(color),
+            ColorSpace::Oklab => linear_srgb_to_oklab(decode_rgb(color)),
+            ColorSpace::Oklch => rectangular_to_polar(linear_srgb_to_oklab(decode_rgb(color))),
+            ColorSpace::Hsv => rgb_to_hsv(rgb),
+            ColorSpace::Hsl => rgb_to_hsl(rgb),
+            ColorSpace::Lab => xyz_to_lab(linear_srgb_to_xyz(decode_rgb(color))),
+            ColorSpace::Lch => {
+                rectangular_to_polar(xyz_to_lab(linear_srgb_to_xyz(decode_rgb(color))))
+            }
+            ColorSpace::Xyz => linear_srgb_to_xyz(decode_rgb(color)),
+            ColorSpace::DisplayP3 => multiply_matrix(
+                &XYZ_TO_LINEAR_DISPLAY_P3,
+                linear_srgb_to_xyz(decode_rgb(color)),
+            )
+            .map(linear_to_srgb),
+            ColorSpace::Rec2020 => multiply_matrix(
+                &XYZ_TO_LINEAR_REC2020,
+                linear_srgb_to_xyz(decode_rgb(color)),
+            )
+            .map(linear_to_rec2020),
+            ColorSpace::Custom(space) => space.encode(linear_srgb_to_xyz(decode_rgb(color))),
+        }
+    }
+
+    /// Converts the components back into (gamma encoded) decimal sRGB, values may
+    /// land outside [0.0, 1.0] when the components are outside the sRGB gamut
+    pub(crate) fn to_decimal_rgb(&self, components: [f32; 3]) -> [f32; 3] {
+        match self {
+            ColorSpace::Srgb => components,
+            ColorSpace::Hsv => hsv_to_rgb(components),
+            ColorSpace::Hsl => hsl_to_rgb(components),
+            _ => encode_rgb(self.to_linear_srgb(components)),
+        }
+    }
+
+    /// Whether the components are gamma encoded sRGB already, the other spaces go through
+    /// linear sRGB and the transfer function on the way back
+    pub(crate) fn is_gamma_encoded(&self) -> bool {
+        matches!(self, ColorSpace::Srgb | ColorSpace::Hsv | ColorSpace::Hsl)
+    }
+
+    /// Converts the components into linear sRGB, which may land outside [0.0, 1.0] when
+    /// the components are outside the sRGB gamut
+    pub(crate) fn to_linear_srgb(&self, components: [f32; 3]) -> [f32; 3] {
+        match self {
+            ColorSpace::Srgb => components.map(srgb_to_linear),
+            ColorSpace::LinearSrgb => components,
+            ColorSpace::Oklab => oklab_to_linear_srgb(components),
+            ColorSpace::Oklch => oklab_to_linear_srgb(polar_to_rectangular(components)),
+            ColorSpace::Hsv => hsv_to_rgb(components).map(srgb_to_linear),
+            ColorSpace::Hsl => hsl_to_rgb(components).map(srgb_to_linear),
+            ColorSpace::Lab => xyz_to_linear_srgb(lab_to_xyz(components)),
+            ColorSpace::Lch => xyz_to_linear_srgb(lab_to_xyz(polar_to_rectangular(components))),
+            ColorSpace::Xyz => xyz_to_linear_srgb(components),
+            ColorSpace::DisplayP3 => xyz_to_linear_srgb(multiply_matrix(
+                &LINEAR_DISPLAY_P3_TO_XYZ,
+                components.map(srgb_to_linear),
+            )),
+            ColorSpace::Rec2020 => xyz_to_linear_srgb(multiply_matrix(
+                &LINEAR_REC2020_TO_XYZ,
+                components.map(rec2020_to_linear),
+            )),
+            ColorSpace::Custom(space) => xyz_to_linear_srgb(space.decode(components)),
+        }
+    }
+
+    /// The mapping [ColorSpace::from_components] brings colors outside of the sRGB gamut
+    /// back with. The spaces that reach beyond it reduce the chroma, the others clip
+    pub(crate) fn gamut_mapping(&self) -> GamutMapping {
+        match self {
+            ColorSpace::Srgb | ColorSpace::LinearSrgb | ColorSpace::Hsv | ColorSpace::Hsl => {
+                GamutMapping::Clip
+            }
+            ColorSpace::Oklab
+            | ColorSpace::Oklch
+            | ColorSpace::Lab
+            | ColorSpace::Lch
+            | ColorSpace::Xyz
+            | ColorSpace::DisplayP3
+            | ColorSpace::Rec2020
+            | ColorSpace::Custom(_) => GamutMapping::OklchChroma,
+        }
+    }
+
+    /// Converts the components into a color. The spaces that reach beyond the sRGB gamut bring
+    /// the colors outside of it back by reducing their chroma, the others clip every channel
+    pub(crate) fn from_components(&self, components: [f32; 3], alpha: f32) -> Color {
+        self.from_components_mapped(components, alpha, self.gamut_mapping())
+    }
+
+    pub(crate) fn from_components_mapped(
+        &self,
+        components: [f32; 3],
+        alpha: f32,
+        mapping: GamutMapping,
+    ) -> Color {
+        let rgb: [f32; 3] = mapping.apply(self.to_decimal_rgb(components));
+        decimal_rgba_to_color(rgb[0], rgb[1], rgb[2], alpha)
+    }
+}
+
+impl GamutMapping {
+    pub(crate) fn from_name(name: &str) -> PyResult<GamutMapping> {
+        match name.to_lowercase().as_str() {
+            "clip" => Ok(GamutMapping::Clip),
+            "oklch_chroma" => Ok(GamutMapping::OklchChroma),
+            _ => Err(PyValueError::new_err(format!(
+                "Unknown gamut mapping method \"{}\", expected clip or oklch_chroma",
+                name
+            ))),
+        }
+    }
+
+    /// Brings the decimal sRGB channels into [0.0, 1.0], what remains outside of it after
+    /// reducing the chroma (lightness above white or below black) is clipped afterward
+    pub(crate) fn apply(&self, rgb: [f32; 3]) -> [f32; 3] {
+        let in_gamut: bool = rgb.iter().all(|channel| (0.0..=1.0).contains(channel));
+        if in_gamut || *self == GamutMapping::Clip {
+            return rgb;
+        }
+        let lch: [f32; 3] = rectangular_to_polar(linear_srgb_to_oklab(rgb.map(srgb_to_linear)));
+        ColorSpace::Oklch.to_decimal_rgb(fit_oklch_to_srgb(lch))
+    }
+}
+
+impl HueStrategy {
+    pub(crate) fn from_name(name: &str) -> PyResult<HueStrategy> {
+        match name.to_lowercase().as_str() {
+            "shorter" => Ok(HueStrategy::Shorter),
+            "longer" => Ok(HueStrategy::Longer),
+            "increasing" => Ok(HueStrategy::Increasing),
+            "decreasing" => Ok(HueStrategy::Decreasing),
+            _ => Err(PyValueError::new_err(format!(
+                "Unknown hue strategy \"{}\", expected shorter, longer, increasing or decreasing",
+                name
+            ))),
+        }
+    }
+
+    pub(crate) fn name(&self) -> &'static str {
+        match self {
+            HueStrategy::Shorter => "shorter",
+            HueStrategy::Longer => "longer",
+            HueStrategy::Increasing => "increasing",
+            HueStrategy::Decreasing => "decreasing",
+        }
+    }
+}
+
+pub(crate) fn decimal_rgba_to_color(r: f32, g: f32, b: f32, a: f32) -> Color {
+    Color {
+        r: (r.clamp(0.0, 1.0) * 255.0).round() as u8,
+        g: (g.clamp(0.0, 1.0) * 255.0).round() as u8,
+        b: (b.clamp(0.0, 1.0) * 255.0).round() as u8,
+        a: (a.clamp(0.0, 1.0) * 255.0).round() as u8,
+    }
+}
+
+/// Unwraps two hues so that going from the start to the end follows the hue strategy
+fn unwrap_hues(start: f32, end: f32, strategy: HueStrategy) -> (f32, f32) {
+    let mut start: f32 = start.rem_euclid(360.0);
+    let mut end: f32 = end.rem_euclid(360.0);
+    let diff: f32 = end - start;
+    match strategy {
+        HueStrategy::Shorter => {
+            if diff > 180.0 {
+                start += 360.0;
+            } else if diff < -180.0 {
+                end += 360.0;
+            }
+        }
+        HueStrategy::Longer => {
+            if 0.0 < diff && diff < 180.0 {
+                start += 360.0;
+            } else if -180.0 < diff && diff <= 0.0 {
+                end += 360.0;
+            }
+        }
+        HueStrategy::Increasing => {
+            if end < start {
+                end += 360.0;
+            }
+        }
+        HueStrategy::Decreasing => {
+            if start < end {
+                start += 360.0;
+            }
+        }
+    }
+    (start, end)
+}
+
+pub(crate) fn interpolate_hue(start: f32, end: f32, t: f32, strategy: HueStrategy) -> f32 {
+    let (start, end): (f32, f32) = unwrap_hues(start, end, strategy);
+    (start + (end - start) * t).rem_euclid(360.0)
+}
+
+/// Interpolates between two sets of components of the same space, taking care of
+/// the hue component for cylindrical spaces (including hues that are powerless)
+pub(crate) fn interpolate_components(
+    space: ColorSpace,
+    mut start: [f32; 3],
+    mut end: [f32; 3],
+    t: f32,
+    hue: HueStrategy,
+) -> [f32; 3] {
+    if let (Some(hue_index), Some(chroma_index)) = (space.hue_index(), space.chroma_index()) {
+        let start_achromatic: bool = start[chroma_index] < ACHROMATIC_THRESHOLD;
+        let end_achromatic: bool = end[chroma_index] < ACHROMATIC_THRESHOLD;
+        if start_achromatic && !end_achromatic {
+            start[hue_index] = end[hue_index];
+        } else if end_achromatic && !start_achromatic {
+            end[hue_index] = start[hue_index];
+        }
+    }
+    let mut result: [f32; 3] = [0.0; 3];
+    for index in 0..3 {
+        result[index] = if Some(index) == space.hue_index() {
+            interpolate_hue(start[index], end[index], t, hue)
+        } else {
+            start[index] + (end[index] - start[index]) * t
+        };
+    }
+    result
+}
+
+/// Evaluates a uniform Catmull-Rom spline segment going from the second point to the third point,
+/// where the hues of cylindrical spaces are unwrapped along the hue strategy beforehand
+pub(crate) fn catmull_rom_components(
+    space: ColorSpace,
+    mut points: [[f32; 3]; 4],
+    t: f32,
+    hue: HueStrategy,
+) -> [f32; 3] {
+    if let (Some(hue_index), Some(chroma_index)) = (space.hue_index(), space.chroma_index()) {
+        let hues: [f32; 4] = points.map(|point| point[hue_index]);
+        let achromatic: [bool; 4] = points.map(|point| point[chroma_index] < ACHROMATIC_THRESHOLD);
+        for (index, point) in points.iter_mut().enumerate() {
+            if !achromatic[index] {
+                continue;
+            }
+            if let Some(neighbour) = [1, 2, 0, 3]
+                .into_iter()
+                .filter(|neighbour| !achromatic[*neighbour])
+                .min_by_key(|neighbour| neighbour.abs_diff(index))
+            {
+                point[hue_index] = hues[neighbour];
+            }
+        }
+        let mut previous: f32 = points[0][hue_index];
+        for point in points.iter_mut().skip(1) {
+            let (start, end): (f32, f32) = unwrap_hues(previous, point[hue_index], hue);
+            point[hue_index] = previous + (end - start);
+            previous = point[hue_index];
+        }
+    }
+    let mut result: [f32; 3] = [0.0; 3];
+    for (index, value) in result.iter_mut().enumerate() {
+        *value = catmull_rom(points.map(|point| point[index]), t);
+    }
+    if let Some(hue_index) = space.hue_index() {
+        result[hue_index] = result[hue_index].rem_euclid(360.0);
+    }
+    if let Some(chroma_index) = space.chroma_index() {
+        result[chroma_index] = result[chroma_index].max(0.0);
+    }
+    result
+}
+
+pub(crate) fn catmull_rom(points: [f32; 4], t: f32) -> f32 {
+    let [p0, p1, p2, p3]: [f32; 4] = points;
+    0.5 * ((2.0 * p1)
+        + (p2 - p0) * t
+        + (2.0 * p0 - 5.0 * p1 + 4.0 * p2 - p3) * t * t
+        + (3.0 * p1 - p0 - 3.0 * p2 + p3) * t * t * t)
+}
+
+pub(crate) fn interpolate_in_space(
+    start: Color,
+    end: Color,
+    t: f32,
+    space: ColorSpace,
+    hue: HueStrategy,
+) -> Color {
+    let components: [f32; 3] = interpolate_components(
+        space,
+        space.to_components(start),
+        space.to_components(end),
+        t,
+        hue,
+    );
+    let alpha: f32 = ((start.a as f32) + ((end.a as f32) - (start.a as f32)) * t) / 255.0;
+    space.from_components(components, alpha)
+}
+
+/// Interpolates bilinearly between four corner colors, first along u (across the rows)
+/// and then along v, without converting back to RGB in between
+pub(crate) fn bilinear_in_space(
+    corners: [Color; 4],
+    u: f32,
+    v: f32,
+    space: ColorSpace,
+    hue: HueStrategy,
+) -> Color {
+    let [c00, c10, c01, c11]: [[f32; 3]; 4] = corners.map(|color| space.to_components(color));
+    let top: [f32; 3] = interpolate_components(space, c00, c10, u, hue);
+    let bottom: [f32; 3] = interpolate_components(space, c01, c11, u, hue);
+    let components: [f32; 3] = interpolate_components(space, top, bottom, v, hue);
+    let [a00, a10, a01, a11]: [f32; 4] = corners.map(|color| color.a as f32);
+    let top_alpha: f32 = a00 + (a10 - a00) * u;
+    let bottom_alpha: f32 = a01 + (a11 - a01) * u;
+    let alpha: f32 = (top_alpha + (bottom_alpha - top_alpha) * v) / 255.0;
+    space.from_components(components, alpha)
+}
+
+pub(crate) fn linear_srgb_to_oklab(rgb: [f32; 3]) -> [f32; 3] {
+    let l: f32 = (0.412_221_46 * rgb[0]) + (0.536_332_55 * rgb[1]) + (0.051_445_995 * rgb[2]);
+    let m: f32 = (0.211_903_5 * rgb[0]) + (0.680_699_5 * rgb[1]) + (0.107_396_96 * rgb[2]);
+    let s: f32 = (0.088_302_46 * rgb[0]) + (0.281_718_85 * rgb[1]) + (0.629_978_7 * rgb[2]);
+
+    let l: f32 = l.cbrt();
+    let m: f32 = m.cbrt();
+    let s: f32 = s.cbrt();
+
+    [
+        (0.210_454_26 * l) + (0.793_617_8 * m) - (0.004_072_047 * s),
+        (1.977_998_5 * l) - (2.428_592_2 * m) + (0.450_593_7 * s),
+        (0.025_904_037 * l) + (0.782_771_77 * m) - (0.808_675_77 * s),
+    ]
+}
+
+pub(crate) fn oklab_to_linear_srgb(lab: [f32; 3]) -> [f32; 3] {
+    let l: f32 = lab[0] + (0.396_337_78 * lab[1]) + (0.215_803_76 * lab[2]);
+    let m: f32 = lab[0] - (0.105_561_346 * lab[1]) - (0.063_854_17 * lab[2]);
+    let s: f32 = lab[0] - (0.089_484_18 * lab[1]) - (1.291_485_5 * lab[2]);
+
+    let l: f32 = l.powi(3);
+    let m: f32 = m.powi(3);
+    let s: f32 = s.powi(3);
+
+    [
+        (4.076_741_7 * l) - (3.307_711_6 * m) + (0.230_969_94 * s),
+        (-1.268_438 * l) + (2.609_757_4 * m) - (0.341_319_38 * s),
+        (-0.004_196_086_3 * l) - (0.703_418_6 * m) + (1.707_614_7 * s),
+    ]
+}
+
+/// The RGB gamuts wider than sRGB that colors can be checked against, all of them use D65
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum Gamut {
+    Srgb,
+    DisplayP3,
+    Rec2020,
+}
+
+impl Gamut {
+    pub(crate) fn from_name(name: &str) -> PyResult<Gamut> {
+        match name.to_lowercase().as_str() {
+            "srgb" => Ok(Gamut::Srgb),
+            "display_p3" | "display-p3" | "p3" => Ok(Gamut::DisplayP3),
+            "rec2020" | "rec-2020" => Ok(Gamut::Rec2020),
+            _ => Err(PyValueError::new_err(format!(
+                "Unknown gamut \"{}\", expected srgb, display_p3 or rec2020",
+                name
+            ))),
+        }
+    }
+
+    /// Whenever the components of the space land inside the gamut, give or take a small
+    /// tolerance for the rounding errors of the conversions
+    pub(crate) fn contains(&self, space: ColorSpace, components: [f32; 3]) -> bool {
+        let srgb: [f32; 3] = space.to_decimal_rgb(components);
+        let linear: [f32; 3] = srgb.map(srgb_to_linear);
+        let channels: [f32; 3] = match self {
+            Gamut::Srgb => srgb,
+            Gamut::DisplayP3 => {
+                multiply_matrix(&XYZ_TO_LINEAR_DISPLAY_P3, linear_srgb_to_xyz(linear))
+            }
+            Gamut::Rec2020 => multiply_matrix(&XYZ_TO_LINEAR_REC2020, linear_srgb_to_xyz(linear)),
+        };
+        channels
+            .iter()
+            .all(|channel| (-1e-4..=1.0 + 1e-4).contains(channel))
+    }
+}
+
+const LINEAR_DISPLAY_P3_TO_XYZ: [[f32; 3]; 3] = [
+    [0.486_570_95, 0.265_667_7, 0.198_217_29],
+    [0.228_974_56, 0.691_738_5, 0.079_286_91],
+    [0.0, 0.045_113_38, 1.043_944_4],
+];
+
+const XYZ_TO_LINEAR_DISPLAY_P3: [[f32; 3]; 3] = [
+    [2.493_497, -0.931_383_6, -0.402_710_8],
+    [-0.829_489, 1.762_664_1, 0.023_624_69],
+    [0.035_845_83, -0.076_172_39, 0.956_884_5],
+];
+
+const LINEAR_REC2020_TO_XYZ: [[f32; 3]; 3] = [
+    [0.636_958_05, 0.144_616_9, 0.168_880_98],
+    [0.262_700_2, 0.677_998_07, 0.059_301_716],
+    [0.0, 0.028_072_693, 1.060_985_1],
+];
+
+const XYZ_TO_LINEAR_REC2020: [[f32; 3]; 3] = [
+    [1.716_651_2, -0.355_670_8, -0.253_366_3],
+    [-0.666_684_3, 1.616_481_2, 0.015_768_546],
+    [0.017_639_857, -0.042_770_613, 0.942_103_1],
+];
+
+/// The constants of the Rec. 2020 transfer function for 12-bit content
+const REC2020_ALPHA: f32 = 1.099_296_8;
+const REC2020_BETA: f32 = 0.018_053_97;
+
+pub(crate) fn multiply_matrix(matrix: &[[f32; 3]; 3], vector: [f32; 3]) -> [f32; 3] {
+    matrix.map(|row| row[0] * vector[0] + row[1] * vector[1] + row[2] * vector[2])
+}
+
+/// The inverse of the matrix through its adjugate, None when the matrix is singular
+pub(crate) fn invert_matrix(matrix: &[[f32; 3]; 3]) -> Option<[[f32; 3]; 3]> {
+    let cofactor = |row: usize, column: usize| -> f32 {
+        let (r1, r2): (usize, usize) = ((row + 1) % 3, (row + 2) % 3);
+        let (c1, c2): (usize, usize) = ((column + 1) % 3, (column + 2) % 3);
+        matrix[r1][c1] * matrix[r2][c2] - matrix[r1][c2] * matrix[r2][c1]
+    };
+    let determinant: f32 = (0..3)
+        .map(|column| matrix[0][column] * cofactor(0, column))
+        .sum();
+    // Relative to the size of the entries, f32 rounding rarely gives an exact zero
+    let size: f32 = matrix
+        .iter()
+        .map(|row| row.iter().map(|value| value.abs()).fold(0.0, f32::max))
+        .product();
+    if determinant.abs() <= size * 1e-6 || !determinant.is_finite() {
+        return None;
+    }
+    Some(std::array::from_fn(|row| {
+        std::array::from_fn(|column| cofactor(column, row) / determinant)
+    }))
+}
+
+/// The Rec. 2020 transfer functions, mirrored onto negative values like CSS does
+pub(crate) fn linear_to_rec2020(value: f32) -> f32 {
+    let magnitude: f32 = value.abs();
+    let encoded: f32 = if magnitude < REC2020_BETA {
+        4.5 * magnitude
+    } else {
+        REC2020_ALPHA * magnitude.powf(0.45) - (REC2020_ALPHA - 1.0)
+    };
+    encoded.copysign(value)
+}
+
+pub(crate) fn rec2020_to_linear(value: f32) -> f32 {
+    let magnitude: f32 = value.abs();
+    let decoded: f32 = if magnitude < REC2020_BETA * 4.5 {
+        magnitude / 4.5
+    } else {
+        ((magnitude + REC2020_ALPHA - 1.0) / REC2020_ALPHA).powf(1.0 / 0.45)
+    };
+    decoded.copysign(value)
+}
+
+pub(crate) fn linear_srgb_to_xyz(rgb: [f32; 3]) -> [f32; 3] {
+    [
+        rgb[0] * 0.412_456_4 + rgb[1] * 0.357_576_1 + rgb[2] * 0.180_437_5,
+        rgb[0] * 0.212_672_9 + rgb[1] * 0.715_152_2 + rgb[2] * 0.072_175,
+        rgb[0] * 0.019_333_9 + rgb[1] * 0.119_192 + rgb[2] * 0.950_304_1,
+    ]
+}
+
+pub(crate) fn xyz_to_linear_srgb(xyz: [f32; 3]) -> [f32; 3] {
+    [
+        xyz[0] * 3.240_454_2 + xyz[1] * -1.537_138_5 + xyz[2] * -0.498_531_4,
+        xyz[0] * -0.969_266 + xyz[1] * 1.876_010_8 + xyz[2] * 0.041_556,
+        xyz[0] * 0.055_643_4 + xyz[1] * -0.204_025_9 + xyz[2] * 1.057_225_2,
+    ]
+}
+
+pub(crate) fn xyz_to_lab(xyz: [f32; 3]) -> [f32; 3] {
+    xyz_to_lab_relative(xyz, D65_WHITE)
+}
+
+pub(crate) fn lab_to_xyz(lab: [f32; 3]) -> [f32; 3] {
+    lab_to_xyz_relative(lab, D65_WHITE)
+}
+
+/// Converts XYZ into CIELAB relative to the supplied white point
+pub(crate) fn xyz_to_lab_relative(xyz: [f32; 3], white: [f32; 3]) -> [f32; 3] {
+    fn f(value: f32) -> f32 {
+        if value > 216.0 / 24389.0 {
+            value.cbrt()
+        } else {
+            (value * (24389.0 / 27.0) + 16.0) / 116.0
+        }
+    }
+    let fx: f32 = f(xyz[0] / white[0]);
+    let fy: f32 = f(xyz[1] / white[1]);
+    let fz: f32 = f(xyz[2] / white[2]);
+    [116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz)]
+}
+
+pub(crate) fn lab_to_xyz_relative(lab: [f32; 3], white: [f32; 3]) -> [f32; 3] {
+    fn f_inverse(value: f32) -> f32 {
+        if value.powi(3) > 216.0 / 24389.0 {
+            value.powi(3)
+        } else {
+            (116.0 * value - 16.0) / (24389.0 / 27.0)
+        }
+    }
+    let fy: f32 = (lab[0] + 16.0) / 116.0;
+    let fx: f32 = fy + lab[1] / 500.0;
+    let fz: f32 = fy - lab[2] / 200.0;
+    [
+        f_inverse(fx) * white[0],
+        f_inverse(fy) * white[1],
+        f_inverse(fz) * white[2],
+    ]
+}
+
+pub(crate) fn rectangular_to_polar(values: [f32; 3]) -> [f32; 3] {
+    let c: f32 = (values[1].powi(2) + values[2].powi(2)).sqrt();
+    let h: f32 = values[2].atan2(values[1]).to_degrees().rem_euclid(360.0);
+    [values[0], c, h]
+}
+
+pub(crate) fn polar_to_rectangular(values: [f32; 3]) -> [f32; 3] {
+    let h: f32 = values[2].to_radians();
+    [values[0], values[1] * h.cos(), values[1] * h.sin()]
+}
+
+pub(crate) fn rgb_to_hsv(rgb: [f32; 3]) -> [f32; 3] {
+    let c_max: f32 = rgb[0].max(rgb[1]).max(rgb[2]);
+    let c_min: f32 = rgb[0].min(rgb[1]).min(rgb[2]);
+    let s: f32 = if c_max != 0.0 {
+        (c_max - c_min) / c_max
+    } else {
+        0.0
+    };
+    [rgb_hue(rgb, c_max, c_min), s, c_max]
+}
+
+pub(crate) fn rgb_to_hsl(rgb: [f32; 3]) -> [f32; 3] {
+    let c_max: f32 = rgb[0].max(rgb[1]).max(rgb[2]);
+    let c_min: f32 = rgb[0].min(rgb[1]).min(rgb[2]);
+    let l: f32 = (c_max + c_min) / 2.0;
+    let delta: f32 = c_max - c_min;
+    let s: f32 = if delta == 0.0 {
+        0.0
+    } else {
+        delta / (1.0 - (2.0 * l - 1.0).abs())
+    };
+    [rgb_hue(rgb, c_max, c_min), s, l]
+}
+
+fn rgb_hue(rgb: [f32; 3], c_max: f32, c_min: f32) -> f32 {
+    let delta: f32 = c_max - c_min;
+    let h: f32 = if delta == 0.0 {
+        0.0
+    } else if c_max == rgb[0] {
+        ((rgb[1] - rgb[2]) / delta) % 6.0
+    } else if c_max == rgb[1] {
+        ((rgb[2] - rgb[0]) / delta) + 2.0
+    } else {
+        ((rgb[0] - rgb[1]) / delta) + 4.0
+    };
+    (h * 60.0).rem_euclid(360.0)
+}
+
+pub(crate) fn hsv_to_rgb(hsv: [f32; 3]) -> [f32; 3] {
+    let c: f32 = hsv[2] * hsv[1];
+    hue_to_rgb(hsv[0], c, hsv[2] - c)
+}
+
+pub(crate) fn hsl_to_rgb(hsl: [f32; 3]) -> [f32; 3] {
+    let c: f32 = (1.0 - (2.0 * hsl[2] - 1.0).abs()) * hsl[1];
+    hue_to_rgb(hsl[0], c, hsl[2] - c / 2.0)
+}
+
+fn hue_to_rgb(hue: f32, c: f32, m: f32) -> [f32; 3] {
+    let h: f32 = hue.rem_euclid(360.0) / 60.0;
+    let x: f32 = c * (1.0 - ((h % 2.0) - 1.0).abs());
+    let (r, g, b): (f32, f32, f32) = match h as u8 {
+        0 => (c, x, 0.0),
+        1 => (x, c, 0.0),
+        2 => (0.0, c, x),
+        3 => (0.0, x, c),
+        4 => (x, 0.0, c),
+        _ => (c, 0.0, x),
+    };
+    [r + m, g + m, b + m]
+}
+
+pub(crate) fn average_in_space(colors: &[Color], weights: &[f32], space: ColorSpace) -> Color {
+    let total: f32 = weights.iter().sum();
+    let hue_index: Option<usize> = space.hue_index();
+    let mut sums: [f32; 3] = [0.0; 3];
+    let mut hue_vector: (f32, f32) = (0.0, 0.0);
+    let mut alpha: f32 = 0.0;
+    for (color, weight) in colors.iter().zip(weights) {
+        let components: [f32; 3] = space.to_components(*color);
+        let share: f32 = weight / total;
+        for (index, component) in components.iter().enumerate() {
+            if Some(index) == hue_index {
+                let chroma: f32 = components[space.chroma_index().unwrap()];
+                if chroma >= ACHROMATIC_THRESHOLD {
+                    let radians: f32 = component.to_radians();
+                    hue_vector.0 += share * radians.cos();
+                    hue_vector.1 += share * radians.sin();
+                }
+            } else {
+                sums[index] += share * component;
+            }
+        }
+        alpha += share * (color.a as f32) / 255.0;
+    }
+    if let Some(index) = hue_index {
+        sums[index] = hue_vector
+            .1
+            .atan2(hue_vector.0)
+            .to_degrees()
+            .rem_euclid(360.0);
+    }
+    space.from_components(sums, alpha)
+}
+
+/// Evaluates the Bézier curve defined by the control colors with De Casteljau's algorithm,
+/// every level of the algorithm interpolates the components within the supplied space
+pub(crate) fn bezier_in_space(colors: &[Color], t: f32, space: ColorSpace) -> Color {
+    let mut points: Vec<([f32; 3], f32)> = colors
+        .iter()
+        .map(|color| (space.to_components(*color), color.a as f32))
+        .collect();
+    while points.len() > 1 {
+        points = points
+            .windows(2)
+            .map(|pair| {
+                (
+                    interpolate_components(space, pair[0].0, pair[1].0, t, HueStrategy::Shorter),
+                    pair[0].1 + (pair[1].1 - pair[0].1) * t,
+                )
+            })
+            .collect();
+    }
+    space.from_components(points[0].0, points[0].1 / 255.0)
+}
+
+/// Reduces the chroma of an OKLCH color at constant lightness and hue until it fits inside
+/// the sRGB gamut, which keeps the hue intact unlike clipping every channel on its own
+pub(crate) fn fit_oklch_to_srgb(lch: [f32; 3]) -> [f32; 3] {
+    let in_gamut = |chroma: f32| {
+        ColorSpace::Oklch
+            .to_decimal_rgb([lch[0], chroma, lch[2]])
+            .iter()
+            .all(|channel| (-1e-4..=1.0 + 1e-4).contains(channel))
+    };
+    if in_gamut(lch[1]) {
+        return lch;
+    }
+    let (mut low, mut high): (f32, f32) = (0.0, lch[1]);
+    for _ in 0..24 {
+        let middle: f32 = (low + high) / 2.0;
+        if in_gamut(middle) {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+    [lch[0], low, lch[2]]
+}
@@ -0,0 +1,124 @@
+use crate::color::html::escape_html;
+use crate::color::palette::Palette;
+use crate::color::spaces::ColorSpace;
+use crate::color::Color;
+use pyo3::exceptions::PyValueError;
+use pyo3::PyResult;
+
+/// The width of a palette swatch when the palette has no column hint
+const MAXIMUM_SWATCH_WIDTH: f32 = 120.0;
+
+pub(crate) fn check_svg_size(width: f32, height: f32) -> PyResult<()> {
+    if !(width > 0.0 && width.is_finite() && height > 0.0 && height.is_finite()) {
+        return Err(PyValueError::new_err(
+            "The width and the height of the SVG must be positive",
+        ));
+    }
+    Ok(())
+}
+
+/// Formats a coordinate with at most two decimals, dropping the trailing zeros
+pub(crate) fn svg_number(value: f32) -> String {
+    let formatted: String = format!("{:.2}", value);
+    formatted
+        .trim_end_matches('0')
+        .trim_end_matches('.')
+        .to_string()
+}
+
+/// The paint (and its opacity, when the color is not opaque) of an SVG element
+pub(crate) fn svg_paint(attribute: &str, opacity_attribute: &str, color: Color) -> String {
+    let paint: String = format!("{}=\"{}\"", attribute, color.to_hex(false));
+    if color.a == 255 {
+        paint
+    } else {
+        format!(
+            "{} {}=\"{}\"",
+            paint,
+            opacity_attribute,
+            svg_number((color.a as f32) / 255.0)
+        )
+    }
+}
+
+/// Black or white, whichever is readable on top of the color
+pub(crate) fn contrasting_text(color: Color) -> &'static str {
+    let luminance: f32 = ColorSpace::Xyz.to_components(color)[1];
+    if luminance > 0.4 || color.a < 128 {
+        "#000000"
+    } else {
+        "#ffffff"
+    }
+}
+
+pub(crate) fn svg_document(width: f32, height: f32, body: &str) -> String {
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" viewBox=\"0 0 {} {}\">\n{}</svg>\n",
+        svg_number(width),
+        svg_number(height),
+        svg_number(width),
+        svg_number(height),
+        body
+    )
+}
+
+pub(crate) fn svg_text(x: f32, y: f32, size: f32, anchor: &str, fill: &str, text: &str) -> String {
+    format!(
+        "  <text x=\"{}\" y=\"{}\" font-family=\"monospace\" font-size=\"{}\" text-anchor=\"{}\" fill=\"{}\">{}</text>\n",
+        svg_number(x),
+        svg_number(y),
+        svg_number(size),
+        anchor,
+        fill,
+        escape_html(text)
+    )
+}
+
+/// Lays the swatches out in a grid when the palette has a column hint, otherwise in a
+/// single row. Labels show the hex value of every color, preceded by its name
+pub(crate) fn palette_svg(palette: &Palette, width: f32, height: f32, labels: bool) -> String {
+    let count: usize = palette.colors.len().max(1);
+    let columns: usize = palette.columns.unwrap_or(count).clamp(1, count);
+    let rows: usize = count.div_ceil(columns);
+    let cell_width: f32 = width / (columns as f32);
+    let cell_height: f32 = height / (rows as f32);
+    let font_size: f32 = (cell_width.min(MAXIMUM_SWATCH_WIDTH) / 8.0).min(cell_height / 4.0);
+    let mut body: String = String::new();
+    for (index, (color, name)) in palette.colors.iter().zip(&palette.names).enumerate() {
+        let x: f32 = ((index % columns) as f32) * cell_width;
+        let y: f32 = ((index / columns) as f32) * cell_height;
+        body += &format!(
+            "  <rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" {}/>\n",
+            svg_number(x),
+            svg_number(y),
+            svg_number(cell_width),
+            svg_number(cell_height),
+            svg_paint("fill", "fill-opacity", *color)
+        );
+        if !labels {
+            continue;
+        }
+        let center: f32 = x + cell_width / 2.0;
+        let bottom: f32 = y + cell_height - font_size * 0.75;
+        let fill: &str = contrasting_text(*color);
+        body += &svg_text(
+            center,
+            bottom,
+            font_size,
+            "middle",
+            fill,
+            &color.to_hex(color.a != 255),
+        );
+        if let Some(name) = name {
+            body += &svg_text(
+                center,
+                bottom - font_size * 1.25,
+                font_size,
+                "middle",
+                fill,
+                name,
+            );
+        }
+    }
+    svg_document(width, height, &body)
+}
@@ -0,0 +1,625 @@
+use crate::color::adaptation::{to_d65, Illuminant};
+use crate::color::palette::Palette;
+use crate::color::spaces::{decimal_rgba_to_color, lab_to_xyz_relative, ColorSpace};
+use crate::color::Color;
+use pyo3::buffer::PyBuffer;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyDict, PyList, PyString};
+use std::fs;
+use std::path::PathBuf;
+
+pub(crate) const GPL_HEADER: &str = "GIMP Palette";
+const ASE_SIGNATURE: &[u8; 4] = b"ASEF";
+const ASE_GROUP_START: u16 = 0xC001;
+const ASE_GROUP_END: u16 = 0xC002;
+const ASE_COLOR: u16 = 0x0001;
+const ASE_NORMAL_COLOR: u16 = 2;
+
+#[derive(FromPyObject)]
+pub enum BytesOrPath {
+    #[pyo3(transparent, annotation = "bytes")]
+    Bytes(PyBuffer<u8>),
+    #[pyo3(transparent, annotation = "str")]
+    Path(PathBuf),
+}
+
+impl BytesOrPath {
+    pub(crate) fn into_bytes(self, python: Python<'_>) -> PyResult<Vec<u8>> {
+        match self {
+            BytesOrPath::Bytes(buffer) => buffer.to_vec(python),
+            BytesOrPath::Path(path) => Ok(fs::read(path)?),
+        }
+    }
+}
+
+/// Writes the data into the path when one is supplied, otherwise hands back the bytes themselves
+pub(crate) fn write_bytes_or_return<'py>(
+    python: Python<'py>,
+    data: Vec<u8>,
+    path: Option<PathBuf>,
+) -> PyResult<Option<Bound<'py, PyBytes>>> {
+    match path {
+        Some(path) => {
+            fs::write(path, data)?;
+            Ok(None)
+        }
+        None => Ok(Some(PyBytes::new_bound(python, &data))),
+    }
+}
+
+/// A big endian cursor over the bytes of a binary swatch file
+struct Reader<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(bytes: &'a [u8]) -> Reader<'a> {
+        Reader { bytes, offset: 0 }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.offset >= self.bytes.len()
+    }
+
+    fn take(&mut self, length: usize) -> PyResult<&'a [u8]> {
+        if self.bytes.len() - self.offset < length {
+            return Err(PyValueError::new_err("The swatch data ended unexpectedly"));
+        }
+        let taken: &'a [u8] = &self.bytes[self.offset..self.offset + length];
+        self.offset += length;
+        Ok(taken)
+    }
+
+    fn u16(&mut self) -> PyResult<u16> {
+        Ok(u16::from_be_bytes(self.take(2)?.try_into().unwrap()))
+    }
+
+    fn u32(&mut self) -> PyResult<u32> {
+        Ok(u32::from_be_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn f32(&mut self) -> PyResult<f32> {
+        Ok(f32::from_be_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    /// Reads a UTF-16 string of the given amount of code units, the null terminator is dropped
+    fn utf16(&mut self, length: usize) -> PyResult<Option<String>> {
+        let units: Vec<u16> = self
+            .take(length * 2)?
+            .chunks_exact(2)
+            .map(|unit| u16::from_be_bytes([unit[0], unit[1]]))
+            .take_while(|unit| *unit != 0)
+            .collect();
+        let text: String = String::from_utf16(&units)
+            .map_err(|_| PyValueError::new_err("The swatch data contains an invalid name"))?;
+        Ok(Some(text).filter(|text| !text.is_empty()))
+    }
+}
+
+/// Encodes a null terminated UTF-16 string, returning the amount of code units alongside it
+fn encode_utf16(text: &str) -> (usize, Vec<u8>) {
+    let units: Vec<u16> = text.encode_utf16().chain([0]).collect();
+    let bytes: Vec<u8> = units.iter().flat_map(|unit| unit.to_be_bytes()).collect();
+    (units.len(), bytes)
+}
+
+fn gray_to_color(value: f32) -> Color {
+    decimal_rgba_to_color(value, value, value, 1.0)
+}
+
+fn cmyk_to_color(c: f32, m: f32, y: f32, k: f32) -> Color {
+    let [c, m, y, k]: [f32; 4] = [c, m, y, k].map(|value| value.clamp(0.0, 1.0));
+    decimal_rgba_to_color(
+        (1.0 - c) * (1.0 - k),
+        (1.0 - m) * (1.0 - k),
+        (1.0 - y) * (1.0 - k),
+        1.0,
+    )
+}
+
+/// Reads the source as text when it is a string starting with the header of the format
+/// or spanning multiple lines, otherwise it is treated as the path of the file to read
+pub(crate) fn read_text_or_path(source: &Bound<'_, PyAny>, header: &str) -> PyResult<String> {
+    if let Ok(text) = source.downcast::<PyString>() {
+        let text: String = text.to_string();
+        if text.contains('\n')
+            || text
+                .trim_start_matches('\u{feff}')
+                .trim_start()
+                .starts_with(header)
+        {
+            return Ok(text);
+        }
+    }
+    let path: PathBuf = source.extract()?;
+    Ok(fs::read_to_string(path)?)
+}
+
+/// Parses a GIMP palette, the optional name and column hint of the header are kept
+/// and so is the name of every color
+pub(crate) fn parse_gpl(text: &str) -> PyResult<Palette> {
+    let mut lines = text.trim_start_matches('\u{feff}').lines().enumerate();
+    if !matches!(lines.next(), Some((_, line)) if line.trim() == GPL_HEADER) {
+        return Err(PyValueError::new_err(format!(
+            "A GIMP palette has to start with \"{}\"",
+            GPL_HEADER
+        )));
+    }
+    let mut palette: Palette = Palette::default();
+    for (number, line) in lines {
+        let line: &str = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        } else if let Some(name) = line.strip_prefix("Name:") {
+            palette.name = Some(name.trim().to_string());
+            continue;
+        } else if let Some(columns) = line.strip_prefix("Columns:") {
+            palette.columns = Some(columns.trim().parse().map_err(|_| {
+                PyValueError::new_err(format!("Line {} has an invalid column count", number + 1))
+            })?);
+            continue;
+        }
+        let parts: Vec<&str> = line.split_whitespace().collect();
+        let channels: Vec<u8> = parts
+            .iter()
+            .take(3)
+            .map(|part| part.parse::<u8>())
+            .collect::<Result<Vec<u8>, _>>()
+            .ok()
+            .filter(|channels| channels.len() == 3)
+            .ok_or_else(|| {
+                PyValueError::new_err(format!(
+                    "Line {} needs three channels between 0 and 255",
+                    number + 1
+                ))
+            })?;
+        let name: String = parts[3..].join(" ");
+        palette.push(
+            Color::new(channels[0], channels[1], channels[2], 255),
+            Some(name).filter(|name| !name.is_empty()),
+            None,
+        );
+    }
+    Ok(palette)
+}
+
+/// Writes a GIMP palette, the format has no alpha channel so it is dropped
+pub(crate) fn write_gpl(palette: &Palette, name: &str) -> String {
+    let mut lines: Vec<String> = vec![GPL_HEADER.to_string(), format!("Name: {}", name)];
+    if let Some(columns) = palette.columns {
+        lines.push(format!("Columns: {}", columns));
+    }
+    lines.push("#".to_string());
+    for (color, name) in palette.colors.iter().zip(&palette.names) {
+        let entry: String = format!("{:>3} {:>3} {:>3}", color.r, color.g, color.b);
+        lines.push(match name {
+            Some(name) => format!("{}\t{}", entry, name),
+            None => entry,
+        });
+    }
+    lines.join("\n") + "\n"
+}
+
+/// Parses an Adobe Swatch Exchange file, the colors inside groups remember the group name.
+/// Colors of the CMYK, Lab and Gray models are converted into sRGB
+pub(crate) fn parse_ase(bytes: &[u8]) -> PyResult<Palette> {
+    let mut reader: Reader = Reader::new(bytes);
+    if reader.take(4).ok() != Some(ASE_SIGNATURE.as_slice()) {
+        return Err(PyValueError::new_err(
+            "An Adobe Swatch Exchange file has to start with \"ASEF\"",
+        ));
+    }
+    reader.take(4)?;
+    let blocks: u32 = reader.u32()?;
+    let mut palette: Palette = Palette::default();
+    let mut group: Option<String> = None;
+    for _ in 0..blocks {
+        let kind: u16 = reader.u16()?;
+        let length: usize = reader.u32()? as usize;
+        let mut block: Reader = Reader::new(reader.take(length)?);
+        match kind {
+            ASE_GROUP_START => {
+                let length: usize = block.u16()? as usize;
+                group = block.utf16(length)?;
+            }
+            ASE_GROUP_END => group = None,
+            ASE_COLOR => {
+                let length: usize = block.u16()? as usize;
+                let name: Option<String> = block.utf16(length)?;
+                let color: Color = match block.take(4)? {
+                    b"RGB " => decimal_rgba_to_color(block.f32()?, block.f32()?, block.f32()?, 1.0),
+                    b"CMYK" => {
+                        cmyk_to_color(block.f32()?, block.f32()?, block.f32()?, block.f32()?)
+                    }
+                    b"LAB " => {
+                        d50_lab_to_color([block.f32()? * 100.0, block.f32()?, block.f32()?])?
+                    }
+                    b"Gray" => gray_to_color(block.f32()?),
+                    model => {
+                        return Err(PyValueError::new_err(format!(
+                            "Unsupported swatch color model \"{}\"",
+                            String::from_utf8_lossy(model).trim()
+                        )))
+                    }
+                };
+                palette.push(color, name, group.clone());
+            }
+            _ => {}
+        }
+    }
+    Ok(palette)
+}
+
+/// Encodes a name the way the blocks of a swatch exchange file store it, prefixed by its amount
+/// of UTF-16 code units which has to fit into 16 bits
+fn ase_name(name: &str) -> PyResult<Vec<u8>> {
+    let (length, encoded) = encode_utf16(name);
+    let length: u16 = u16::try_from(length).map_err(|_| {
+        PyValueError::new_err(format!(
+            "A swatch exchange file holds names of at most {} UTF-16 code units, got one of {}",
+            u16::MAX - 1,
+            length - 1
+        ))
+    })?;
+    let mut block: Vec<u8> = length.to_be_bytes().to_vec();
+    block.extend(encoded);
+    Ok(block)
+}
+
+fn ase_block(data: &mut Vec<u8>, kind: u16, block: &[u8]) {
+    data.extend(kind.to_be_bytes());
+    data.extend((block.len() as u32).to_be_bytes());
+    data.extend(block);
+}
+
+/// Writes an Adobe Swatch Exchange file, consecutive colors sharing a group are written
+/// inside the same group. The format has no alpha channel so it is dropped
+pub(crate) fn write_ase(palette: &Palette) -> PyResult<Vec<u8>> {
+    let mut blocks: Vec<u8> = Vec::new();
+    let mut count: u32 = 0;
+    let mut current: Option<&String> = None;
+    for ((color, name), group) in palette
+        .colors
+        .iter()
+        .zip(&palette.names)
+        .zip(&palette.groups)
+    {
+        if group.as_ref() != current {
+            if current.is_some() {
+                ase_block(&mut blocks, ASE_GROUP_END, &[]);
+                count += 1;
+            }
+            if let Some(group) = group {
+                ase_block(&mut blocks, ASE_GROUP_START, &ase_name(group)?);
+                count += 1;
+            }
+            current = group.as_ref();
+        }
+        let mut block: Vec<u8> = ase_name(name.as_deref().unwrap_or(""))?;
+        block.extend(b"RGB ");
+        for channel in [color.r, color.g, color.b] {
+            block.extend(((channel as f32) / 255.0).to_be_bytes());
+        }
+        block.extend(ASE_NORMAL_COLOR.to_be_bytes());
+        ase_block(&mut blocks, ASE_COLOR, &block);
+        count += 1;
+    }
+    if current.is_some() {
+        ase_block(&mut blocks, ASE_GROUP_END, &[]);
+        count += 1;
+    }
+    let mut data: Vec<u8> = ASE_SIGNATURE.to_vec();
+    data.extend(1u16.to_be_bytes());
+    data.extend(0u16.to_be_bytes());
+    data.extend(count.to_be_bytes());
+    data.extend(blocks);
+    Ok(data)
+}
+
+/// Adobe's swatch files store Lab relative to D50, so the color is adapted to the D65 white of
+/// sRGB on the way
+fn d50_lab_to_color(lab: [f32; 3]) -> PyResult<Color> {
+    let white: [f32; 3] = Illuminant::named("D50").white_point()?;
+    Ok(ColorSpace::Xyz.from_components(to_d65(lab_to_xyz_relative(lab, white), white), 1.0))
+}
+
+/// Converts an entry of a Photoshop swatch file, whose four values depend on the color space
+fn aco_to_color(space: u16, values: [u16; 4]) -> PyResult<Color> {
+    let fraction = |value: u16| (value as f32) / 65535.0;
+    match space {
+        0 => Ok(decimal_rgba_to_color(
+            fraction(values[0]),
+            fraction(values[1]),
+            fraction(values[2]),
+            1.0,
+        )),
+        1 => Ok(ColorSpace::Hsv.from_components(
+            [
+                fraction(values[0]) * 360.0,
+                fraction(values[1]),
+                fraction(values[2]),
+            ],
+            1.0,
+        )),
+        // Photoshop stores the CMYK inks inverted, 0 being full coverage
+        2 => Ok(cmyk_to_color(
+            1.0 - fraction(values[0]),
+            1.0 - fraction(values[1]),
+            1.0 - fraction(values[2]),
+            1.0 - fraction(values[3]),
+        )),
+        7 => d50_lab_to_color([
+            (values[0] as f32) / 100.0,
+            (values[1] as i16 as f32) / 100.0,
+            (values[2] as i16 as f32) / 100.0,
+        ]),
+        // The gray value is the amount of black ink, from 0 to 10000
+        8 => Ok(gray_to_color(1.0 - (values[0] as f32) / 10000.0)),
+        _ => Err(PyValueError::new_err(format!(
+            "Unsupported swatch color space {}",
+            space
+        ))),
+    }
+}
+
+/// Parses a Photoshop swatch file, the names of the version 2 section are used when present
+pub(crate) fn parse_aco(bytes: &[u8]) -> PyResult<Palette> {
+    let mut reader: Reader = Reader::new(bytes);
+    let mut palette: Palette = Palette::default();
+    while !reader.is_empty() {
+        let version: u16 = reader.u16()?;
+        if version != 1 && version != 2 {
+            return Err(PyValueError::new_err(format!(
+                "Unsupported Photoshop swatch version {}",
+                version
+            )));
+        }
+        let count: u16 = reader.u16()?;
+        let mut section: Palette = Palette::default();
+        for _ in 0..count {
+            let space: u16 = reader.u16()?;
+            let values: [u16; 4] = [reader.u16()?, reader.u16()?, reader.u16()?, reader.u16()?];
+            let name: Option<String> = if version == 2 {
+                let length: usize = reader.u32()? as usize;
+                reader.utf16(length)?
+            } else {
+                None
+            };
+            section.push(aco_to_color(space, values)?, name, None);
+        }
+        palette = section;
+    }
+    Ok(palette)
+}
+
+/// Writes a Photoshop swatch file holding both the version 1 section and the named version 2
+/// section, as Photoshop itself does. The format has no alpha channel so it is dropped
+pub(crate) fn write_aco(palette: &Palette) -> PyResult<Vec<u8>> {
+    let count: u16 = u16::try_from(palette.colors.len()).map_err(|_| {
+        PyValueError::new_err(format!(
+            "A Photoshop swatch file holds at most {} colors, got {}",
+            u16::MAX,
+            palette.colors.len()
+        ))
+    })?;
+    let mut data: Vec<u8> = Vec::new();
+    for version in [1u16, 2] {
+        data.extend(version.to_be_bytes());
+        data.extend(count.to_be_bytes());
+        for (color, name) in palette.colors.iter().zip(&palette.names) {
+            data.extend(0u16.to_be_bytes());
+            for channel in [color.r, color.g, color.b, 0] {
+                data.extend(((channel as u16) * 257).to_be_bytes());
+            }
+            if version == 2 {
+                let (length, encoded) = encode_utf16(name.as_deref().unwrap_or(""));
+                data.extend((length as u32).to_be_bytes());
+                data.extend(encoded);
+            }
+        }
+    }
+    Ok(data)
+}
+
+/// Writes the colors as hex codes, one per line
+pub(crate) fn write_hex_text(palette: &Palette, include_transparency: bool) -> String {
+    palette
+        .colors
+        .iter()
+        .map(|color| color.to_hex(include_transparency) + "\n")
+        .collect()
+}
+
+/// Parses one hex code per line, blank lines and lines starting with ";" or "//" are skipped
+pub(crate) fn parse_hex_text(text: &str) -> PyResult<Palette> {
+    let mut palette: Palette = Palette::default();
+    for (number, line) in text.lines().enumerate() {
+        let line: &str = line.trim();
+        if line.is_empty() || line.starts_with(';') || line.starts_with("//") {
+            continue;
+        }
+        let color: Color = Color::from_hex(line).map_err(|_| {
+            PyValueError::new_err(format!("Line {} is not a valid hex color", number + 1))
+        })?;
+        palette.push(color, None, None);
+    }
+    Ok(palette)
+}
+
+/// The first line of the text format, which is skipped when parsing like every comment
+const TEXT_HEADER: &str = "# tincture palette";
+
+/// Writes the line-oriented text format, the settings come first followed by one "position hex
+/// name" line per color. A "group" line starts every run of colors sharing a group and a bare
+/// "group" line ends it
+pub(crate) fn write_text(palette: &Palette) -> String {
+    let mut lines: Vec<String> = vec![TEXT_HEADER.to_string()];
+    if let Some(name) = &palette.name {
+        lines.push(format!("name {}", name));
+    }
+    if let Some(columns) = palette.columns {
+        lines.push(format!("columns {}", columns));
+    }
+    let mut current_group: Option<&String> = None;
+    for (index, ((color, name), group)) in palette
+        .colors
+        .iter()
+        .zip(&palette.names)
+        .zip(&palette.groups)
+        .enumerate()
+    {
+        if group.as_ref() != current_group {
+            lines.push(match group {
+                Some(group) => format!("group {}", group),
+                None => String::from("group"),
+            });
+            current_group = group.as_ref();
+        }
+        lines.push(match name {
+            Some(name) => format!("{} {} {}", index, color.to_hex(true), name),
+            None => format!("{} {}", index, color.to_hex(true)),
+        });
+    }
+    lines.join("\n") + "\n"
+}
+
+/// Parses the text format of [write_text], the colors are ordered by their positions which have
+/// to be distinct. Blank lines and lines starting with "#" are skipped
+pub(crate) fn parse_text(text: &str) -> PyResult<Palette> {
+    let mut palette: Palette = Palette::default();
+    let mut group: Option<String> = None;
+    let mut entries: Vec<(usize, Color, Option<String>, Option<String>)> = Vec::new();
+    for (number, line) in text.lines().enumerate() {
+        let line: &str = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let (key, value): (&str, Option<&str>) = match line.split_once(char::is_whitespace) {
+            Some((key, value)) => (key, Some(value.trim())),
+            None => (line, None),
+        };
+        match (key, value) {
+            ("group", value) => group = value.map(String::from),
+            ("name", Some(value)) => palette.name = Some(value.to_string()),
+            ("columns", Some(value)) => {
+                palette.columns = Some(value.parse().map_err(|_| {
+                    PyValueError::new_err(format!(
+                        "Line {} has an invalid amount of columns",
+                        number + 1
+                    ))
+                })?)
+            }
+            (key, Some(value)) if key.bytes().all(|byte| byte.is_ascii_digit()) => {
+                let position: usize = key.parse().map_err(|_| {
+                    PyValueError::new_err(format!("Line {} has an invalid position", number + 1))
+                })?;
+                let (hex, name): (&str, Option<&str>) = match value.split_once(char::is_whitespace)
+                {
+                    Some((hex, name)) => (hex, Some(name.trim())),
+                    None => (value, None),
+                };
+                let color: Color = Color::from_hex(hex).map_err(|_| {
+                    PyValueError::new_err(format!("Line {} is not a valid hex color", number + 1))
+                })?;
+                entries.push((position, color, name.map(String::from), group.clone()));
+            }
+            _ => {
+                return Err(PyValueError::new_err(format!(
+                    "Line {} is neither a setting nor a color",
+                    number + 1
+                )))
+            }
+        }
+    }
+    entries.sort_by_key(|(position, _, _, _)| *position);
+    if let Some(pair) = entries.windows(2).find(|pair| pair[0].0 == pair[1].0) {
+        return Err(PyValueError::new_err(format!(
+            "The position {} is used by more than one color",
+            pair[0].0
+        )));
+    }
+    for (_, color, name, group) in entries {
+        palette.push(color, name, group);
+    }
+    Ok(palette)
+}
+
+/// Builds the JSON representation of the palette, the alpha channel of a color is
+/// only written when the color is not fully opaque
+pub(crate) fn palette_to_json<'py>(
+    python: Python<'py>,
+    palette: &Palette,
+) -> PyResult<Bound<'py, PyDict>> {
+    let object: Bound<'py, PyDict> = PyDict::new_bound(python);
+    if let Some(name) = &palette.name {
+        object.set_item("name", name)?;
+    }
+    if let Some(columns) = palette.columns {
+        object.set_item("columns", columns)?;
+    }
+    let mut colors: Vec<Bound<'py, PyDict>> = Vec::with_capacity(palette.colors.len());
+    for ((color, name), group) in palette
+        .colors
+        .iter()
+        .zip(&palette.names)
+        .zip(&palette.groups)
+    {
+        let entry: Bound<'py, PyDict> = PyDict::new_bound(python);
+        entry.set_item("color", color.to_hex(color.a != 255))?;
+        if let Some(name) = name {
+            entry.set_item("name", name)?;
+        }
+        if let Some(group) = group {
+            entry.set_item("group", group)?;
+        }
+        colors.push(entry);
+    }
+    object.set_item("colors", PyList::new_bound(python, colors))?;
+    Ok(object)
+}
+
+/// Reads the JSON representation of a palette, a bare list of colors is accepted as well
+/// and every color may either be a hex string or an object holding one under "color"
+pub(crate) fn palette_from_json(object: &Bound<'_, PyAny>) -> PyResult<Palette> {
+    let mut palette: Palette = Palette::default();
+    let colors: Bound<'_, PyAny> = match object.downcast::<PyDict>() {
+        Ok(object) => {
+            palette.name = object
+                .get_item("name")?
+                .map(|name| name.extract())
+                .transpose()?;
+            palette.columns = object
+                .get_item("columns")?
+                .map(|columns| columns.extract())
+                .transpose()?;
+            object
+                .get_item("colors")?
+                .ok_or_else(|| PyValueError::new_err("The palette JSON is missing \"colors\""))?
+        }
+        Err(_) => object.clone(),
+    };
+    for entry in colors.iter()? {
+        let entry: Bound<'_, PyAny> = entry?;
+        match entry.downcast::<PyDict>() {
+            Ok(entry) => {
+                let hex: String = entry
+                    .get_item("color")?
+                    .ok_or_else(|| PyValueError::new_err("A palette entry is missing \"color\""))?
+                    .extract()?;
+                let name: Option<String> = entry
+                    .get_item("name")?
+                    .map(|name| name.extract())
+                    .transpose()?;
+                let group: Option<String> = entry
+                    .get_item("group")?
+                    .map(|group| group.extract())
+                    .transpose()?;
+                palette.push(Color::from_hex(&hex)?, name, group);
+            }
+            Err(_) => palette.push(Color::from_hex(&entry.extract::<String>()?)?, None, None),
+        }
+    }
+    Ok(palette)
+}
@@ -0,0 +1,109 @@
+use crate::color::gradient::{gradient_from_json, gradient_to_json, Gradient};
+use crate::color::palette::Palette;
+use crate::color::swatches::{palette_from_json, palette_to_json};
+use crate::color::Color;
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+use std::fs;
+use std::path::PathBuf;
+
+pub(crate) const TINCT_FORMAT: &str = "tinct";
+pub(crate) const TINCT_FORMAT_VERSION: u32 = 1;
+
+pub(crate) fn encode_object<'py>(
+    python: Python<'py>,
+    object: &Bound<'py, PyAny>,
+) -> PyResult<Bound<'py, PyDict>> {
+    let entry: Bound<'py, PyDict> = PyDict::new_bound(python);
+    if let Ok(color) = object.extract::<Color>() {
+        entry.set_item("type", "color")?;
+        entry.set_item("data", vec![color.r, color.g, color.b, color.a])?;
+        return Ok(entry);
+    }
+    if let Ok(gradient) = object.downcast::<Gradient>() {
+        entry.set_item("type", "gradient")?;
+        entry.set_item("data", gradient_to_json(python, &gradient.borrow())?)?;
+        return Ok(entry);
+    }
+    if let Ok(palette) = object.downcast::<Palette>() {
+        entry.set_item("type", "palette")?;
+        entry.set_item("data", palette_to_json(python, &palette.borrow())?)?;
+        return Ok(entry);
+    }
+    if let Ok(list) = object.downcast::<PyList>() {
+        let items: Vec<Bound<'py, PyDict>> = list
+            .iter()
+            .map(|item| encode_object(python, &item))
+            .collect::<PyResult<_>>()?;
+        entry.set_item("type", "list")?;
+        entry.set_item("data", items)?;
+        return Ok(entry);
+    }
+    Err(PyTypeError::new_err(format!(
+        "Cannot serialize objects of type {} into a tinct file",
+        object.get_type().name()?
+    )))
+}
+
+pub(crate) fn decode_object<'py>(
+    python: Python<'py>,
+    entry: &Bound<'py, PyAny>,
+) -> PyResult<Bound<'py, PyAny>> {
+    let kind: String = entry.get_item("type")?.extract()?;
+    let data: Bound<'py, PyAny> = entry.get_item("data")?;
+    match kind.as_str() {
+        "color" => {
+            let rgba: [u8; 4] = data.extract()?;
+            Ok(Bound::new(python, Color::new(rgba[0], rgba[1], rgba[2], rgba[3]))?.into_any())
+        }
+        "gradient" => Ok(Bound::new(python, gradient_from_json(&data)?)?.into_any()),
+        "palette" => Ok(Bound::new(python, palette_from_json(&data)?)?.into_any()),
+        "list" => {
+            let items: Vec<Bound<'py, PyAny>> = data
+                .iter()?
+                .map(|item| decode_object(python, &item?))
+                .collect::<PyResult<_>>()?;
+            Ok(PyList::new_bound(python, items).into_any())
+        }
+        _ => Err(PyValueError::new_err(format!(
+            "Unknown object type \"{}\" inside the tinct file",
+            kind
+        ))),
+    }
+}
+
+#[pyfunction]
+#[pyo3(signature = (obj, path))]
+pub fn dump(python: Python, obj: Bound<'_, PyAny>, path: PathBuf) -> PyResult<()> {
+    let container: Bound<PyDict> = PyDict::new_bound(python);
+    container.set_item("format", TINCT_FORMAT)?;
+    container.set_item("version", TINCT_FORMAT_VERSION)?;
+    container.set_item("content", encode_object(python, &obj)?)?;
+    let text: String = python
+        .import_bound("json")?
+        .call_method1("dumps", (container,))?
+        .extract()?;
+    fs::write(path, text)?;
+    Ok(())
+}
+
+#[pyfunction]
+pub fn load(python: Python<'_>, path: PathBuf) -> PyResult<Bound<'_, PyAny>> {
+    let text: String = fs::read_to_string(path)?;
+    let container: Bound<PyAny> = python
+        .import_bound("json")?
+        .call_method1("loads", (text,))?;
+    let format: String = container.get_item("format")?.extract()?;
+    if format != TINCT_FORMAT {
+        return Err(PyValueError::new_err("The file is not a tinct file"));
+    }
+    let version: u32 = container.get_item("version")?.extract()?;
+    if version > TINCT_FORMAT_VERSION {
+        return Err(PyValueError::new_err(format!(
+            "Unsupported tinct format version {}, the newest supported version is {}",
+            version, TINCT_FORMAT_VERSION
+        )));
+    }
+    decode_object(python, &container.get_item("content")?)
+}
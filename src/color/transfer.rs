@@ -0,0 +1,130 @@
+use crate::color::utils::{linear_to_srgb, srgb_to_linear};
+use crate::color::Color;
+use std::sync::LazyLock;
+
+/// The linear value of every 8-bit sRGB channel value. Decoding a color is then a lookup,
+/// which is both exact and much cheaper than evaluating the transfer function
+static DECODE_TABLE: LazyLock<[f32; 256]> =
+    LazyLock::new(|| std::array::from_fn(|value| srgb_to_linear((value as f32) / 255.0)));
+
+/// Decodes the gamma encoded channels of the color into linear sRGB
+pub(crate) fn decode_rgb(color: Color) -> [f32; 3] {
+    let table: &[f32; 256] = &DECODE_TABLE;
+    [
+        table[color.r as usize],
+        table[color.g as usize],
+        table[color.b as usize],
+    ]
+}
+
+/// Gamma encodes four linear sRGB values at once. Infinities and NaNs fall outside of what
+/// the vectorized approximation handles, so their lanes take the scalar path
+fn encode_lanes(values: [f32; 4]) -> [f32; 4] {
+    let mut encoded: [f32; 4] =
+        encode_x4(values.map(|value| if value.is_finite() { value } else { 0.0 }));
+    for (encoded, value) in encoded.iter_mut().zip(values) {
+        if !value.is_finite() {
+            *encoded = linear_to_srgb(value);
+        }
+    }
+    encoded
+}
+
+/// Gamma encodes the linear sRGB channels, all of them at once
+pub(crate) fn encode_rgb(linear: [f32; 3]) -> [f32; 3] {
+    let encoded: [f32; 4] = encode_lanes([linear[0], linear[1], linear[2], 0.0]);
+    [encoded[0], encoded[1], encoded[2]]
+}
+
+/// Gamma encodes the linear sRGB values in place, four at a time no matter which pixel or
+/// channel they belong to, so that runs of pixels leave no lane unused
+pub(crate) fn encode_slice(values: &mut [f32]) {
+    for chunk in values.chunks_mut(4) {
+        let mut lanes: [f32; 4] = [0.0; 4];
+        lanes[..chunk.len()].copy_from_slice(chunk);
+        let encoded: [f32; 4] = encode_lanes(lanes);
+        chunk.copy_from_slice(&encoded[..chunk.len()]);
+    }
+}
+
+/// The sRGB transfer function on four lanes of SSE2, which every x86_64 CPU supports. The
+/// power is evaluated as exp2(log2(x) / 2.4), with the logarithm computed through the atanh
+/// series on a mantissa reduced to [sqrt(0.5), sqrt(2)) and the exponential through its
+/// Taylor series on [-0.5, 0.5], both accurate to about 1e-7 relative to the scalar version
+#[cfg(target_arch = "x86_64")]
+fn encode_x4(values: [f32; 4]) -> [f32; 4] {
+    use std::arch::x86_64::*;
+
+    let mut encoded: [f32; 4] = [0.0; 4];
+    // SAFETY: SSE2 is part of the x86_64 baseline and both pointers are valid for 4 floats
+    unsafe {
+        let x: __m128 = _mm_loadu_ps(values.as_ptr());
+        let bits: __m128i = _mm_castps_si128(x);
+        let mut exponent: __m128i = _mm_sub_epi32(
+            _mm_and_si128(_mm_srli_epi32(bits, 23), _mm_set1_epi32(0xFF)),
+            _mm_set1_epi32(127),
+        );
+        let mut mantissa: __m128 = _mm_castsi128_ps(_mm_or_si128(
+            _mm_and_si128(bits, _mm_set1_epi32(0x007F_FFFF)),
+            _mm_set1_epi32(0x3F80_0000),
+        ));
+        let large: __m128 = _mm_cmpgt_ps(mantissa, _mm_set1_ps(std::f32::consts::SQRT_2));
+        mantissa = _mm_or_ps(
+            _mm_and_ps(large, _mm_mul_ps(mantissa, _mm_set1_ps(0.5))),
+            _mm_andnot_ps(large, mantissa),
+        );
+        // The mask is -1 on the halved lanes, which makes up for the halving
+        exponent = _mm_sub_epi32(exponent, _mm_castps_si128(large));
+
+        let one: __m128 = _mm_set1_ps(1.0);
+        let z: __m128 = _mm_div_ps(_mm_sub_ps(mantissa, one), _mm_add_ps(mantissa, one));
+        let z2: __m128 = _mm_mul_ps(z, z);
+        let mut series: __m128 = _mm_set1_ps(1.0 / 9.0);
+        for coefficient in [1.0 / 7.0, 1.0 / 5.0, 1.0 / 3.0, 1.0] {
+            series = _mm_add_ps(_mm_mul_ps(series, z2), _mm_set1_ps(coefficient));
+        }
+        let log2: __m128 = _mm_add_ps(
+            _mm_cvtepi32_ps(exponent),
+            _mm_mul_ps(
+                _mm_mul_ps(z, series),
+                _mm_set1_ps(2.0 * std::f32::consts::LOG2_E),
+            ),
+        );
+
+        let t: __m128 = _mm_div_ps(log2, _mm_set1_ps(2.4));
+        let whole: __m128i = _mm_cvtps_epi32(t);
+        let fraction: __m128 = _mm_mul_ps(
+            _mm_sub_ps(t, _mm_cvtepi32_ps(whole)),
+            _mm_set1_ps(std::f32::consts::LN_2),
+        );
+        let mut power: __m128 = _mm_set1_ps(1.0 / 5040.0);
+        for coefficient in [
+            1.0 / 720.0,
+            1.0 / 120.0,
+            1.0 / 24.0,
+            1.0 / 6.0,
+            1.0 / 2.0,
+            1.0,
+            1.0,
+        ] {
+            power = _mm_add_ps(_mm_mul_ps(power, fraction), _mm_set1_ps(coefficient));
+        }
+        let scale: __m128 = _mm_castsi128_ps(_mm_slli_epi32(
+            _mm_add_epi32(whole, _mm_set1_epi32(127)),
+            23,
+        ));
+        power = _mm_mul_ps(power, scale);
+
+        let curve: __m128 = _mm_sub_ps(_mm_mul_ps(power, _mm_set1_ps(1.055)), _mm_set1_ps(0.055));
+        let straight: __m128 = _mm_mul_ps(x, _mm_set1_ps(12.92));
+        let dark: __m128 = _mm_cmple_ps(x, _mm_set1_ps(0.0031308));
+        let result: __m128 = _mm_or_ps(_mm_and_ps(dark, straight), _mm_andnot_ps(dark, curve));
+        _mm_storeu_ps(encoded.as_mut_ptr(), result);
+    }
+    encoded
+}
+
+#[cfg(not(target_arch = "x86_64"))]
+fn encode_x4(values: [f32; 4]) -> [f32; 4] {
+    values.map(linear_to_srgb)
+}
@@ -1,10 +1,11 @@
-use crate::color::Color;
+use crate::color::spaces::{ColorSpace, Gamut, GamutMapping};
+use crate::color::transfer::{decode_rgb, encode_rgb};
+use crate::color::{ChannelTuple, Color};
 use num_bigint::{BigInt, Sign};
 use pyo3::exceptions::{PyIndexError, PyValueError};
 use pyo3::PyResult;
 use std::f32::consts::PI;
 use std::ops::Range;
-use rand::prelude::ThreadRng;
 use rand::Rng;
 
 pub(crate) fn create_bigint_from_u8(val: u8) -> BigInt {
@@ -18,6 +19,30 @@ pub(crate) fn interpret_to_hex(adjusted_str: &str, range: Range<usize>) -> Resul
     }
 }
 
+pub(crate) fn parse_hex(hex_string: &str) -> Result<Color, &'static str> {
+    let adjusted_str: &str = hex_string.strip_prefix("#").unwrap_or(hex_string);
+    if adjusted_str.len() != 6 && adjusted_str.len() != 8 {
+        return Err("Invalid Hex String Length");
+    }
+    if !adjusted_str.is_ascii() {
+        return Err("Hex String Contains Non-ASCII Characters");
+    }
+    let r: Result<u8, String> = interpret_to_hex(adjusted_str, 0..2);
+    let g: Result<u8, String> = interpret_to_hex(adjusted_str, 2..4);
+    let b: Result<u8, String> = interpret_to_hex(adjusted_str, 4..6);
+    let mut a: Result<u8, String> = Ok(255);
+    if adjusted_str.len() == 8 {
+        a = interpret_to_hex(adjusted_str, 6..8);
+    }
+    match (r, g, b, a) {
+        (Ok(r), Ok(g), Ok(b), Ok(a)) => Ok(Color::new(r, g, b, a)),
+        (Err(_), _, _, _) => Err("Cannot Interpret The First Hexadecimal Part"),
+        (_, Err(_), _, _) => Err("Cannot Interpret The Second Hexadecimal Part"),
+        (_, _, Err(_), _) => Err("Cannot Interpret The Third Hexadecimal Part"),
+        (_, _, _, Err(_)) => Err("Cannot Interpret The Fourth Hexadecimal Part"),
+    }
+}
+
 pub(crate) fn wrap_around_bigint(value: BigInt) -> (Sign, u32) {
     let sign_and_digits: (Sign, Vec<u32>) = value.to_u32_digits();
     if sign_and_digits.0 == Sign::NoSign && sign_and_digits.1 == Vec::<u32>::new() {
@@ -42,9 +67,414 @@ pub(crate) fn color_to_decimal_rgb(color: Color) -> (f32, f32, f32) {
     )
 }
 
+pub(crate) fn srgb_to_linear(value: f32) -> f32 {
+    if value <= 0.04045 {
+        value / 12.92
+    } else {
+        ((value + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+pub(crate) fn linear_to_srgb(value: f32) -> f32 {
+    if value <= 0.0031308 {
+        value * 12.92
+    } else {
+        1.055 * value.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+pub(crate) fn grayscale_color(color: Color) -> Color {
+    let value: u8 =
+        (0.299 * color.r as f32 + 0.587 * color.g as f32 + 0.114 * color.b as f32).round() as u8;
+    Color {
+        r: value,
+        g: value,
+        b: value,
+        a: color.a,
+    }
+}
+
+pub(crate) fn check_finite(value: f32, name: &str) -> PyResult<()> {
+    if !value.is_finite() {
+        return Err(PyValueError::new_err(format!(
+            "The {} has to be a finite number, got {}",
+            name, value
+        )));
+    }
+    Ok(())
+}
+
+pub(crate) fn check_posterize_levels(levels: u8) -> PyResult<()> {
+    if levels < 2 {
+        return Err(PyValueError::new_err(format!(
+            "Posterizing needs at least 2 levels per channel, got {}",
+            levels
+        )));
+    }
+    Ok(())
+}
+
+/// Snaps every color channel to the closest of the evenly spaced levels, the alpha is kept
+pub(crate) fn posterize_color(color: Color, levels: u8) -> Color {
+    let steps: f32 = (levels - 1) as f32;
+    let snap =
+        |value: u8| ((((value as f32) / 255.0 * steps).round() / steps) * 255.0).round() as u8;
+    Color {
+        r: snap(color.r),
+        g: snap(color.g),
+        b: snap(color.b),
+        a: color.a,
+    }
+}
+
+/// Inverts the color channels at or above the threshold, the same way as exposing film to light
+pub(crate) fn solarize_color(color: Color, threshold: u8) -> Color {
+    let solarize = |value: u8| {
+        if value >= threshold {
+            255 - value
+        } else {
+            value
+        }
+    };
+    Color {
+        r: solarize(color.r),
+        g: solarize(color.g),
+        b: solarize(color.b),
+        a: color.a,
+    }
+}
+
+/// Turns the color white when its gray value reaches the threshold and black otherwise
+pub(crate) fn threshold_color(color: Color, value: u8) -> Color {
+    let channel: u8 = if grayscale_color(color).r >= value {
+        255
+    } else {
+        0
+    };
+    Color {
+        r: channel,
+        g: channel,
+        b: channel,
+        a: color.a,
+    }
+}
+
+pub(crate) fn color_to_linear_rgba(color: Color) -> (f32, f32, f32, f32) {
+    let rgb: [f32; 3] = decode_rgb(color);
+    (rgb[0], rgb[1], rgb[2], (color.a as f32) / 255.0)
+}
+
+pub(crate) fn linear_to_unit_rgb(r: f32, g: f32, b: f32, a: f32) -> Color {
+    let rgb: [f32; 3] = encode_rgb([r, g, b].map(|value| value.clamp(0.0, 1.0)));
+    let encode = |value: f32| (value * 255.0).round() as u8;
+    Color {
+        r: encode(rgb[0]),
+        g: encode(rgb[1]),
+        b: encode(rgb[2]),
+        a: (a.clamp(0.0, 1.0) * 255.0).round() as u8,
+    }
+}
+
+/// Multiplies the color channels in linear light, the results are clipped to the sRGB gamut
+pub(crate) fn scale_linear(color: Color, gains: [f32; 3]) -> Color {
+    let rgb: [f32; 3] = decode_rgb(color);
+    linear_to_unit_rgb(
+        rgb[0] * gains[0],
+        rgb[1] * gains[1],
+        rgb[2] * gains[2],
+        (color.a as f32) / 255.0,
+    )
+}
+
+pub(crate) fn check_gamma(gamma: f32) -> PyResult<()> {
+    if !(gamma > 0.0 && gamma.is_finite()) {
+        return Err(PyValueError::new_err(format!(
+            "The gamma has to be a positive number, got {}",
+            gamma
+        )));
+    }
+    Ok(())
+}
+
+/// Raises every color channel scaled to [0.0, 1.0] to the power of gamma, the alpha is kept
+pub(crate) fn gamma_color(color: Color, gamma: f32) -> Color {
+    let power = |value: u8| (((value as f32) / 255.0).powf(gamma) * 255.0).round() as u8;
+    Color {
+        r: power(color.r),
+        g: power(color.g),
+        b: power(color.b),
+        a: color.a,
+    }
+}
+
+/// Where a channel of a swizzled color comes from
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum SwizzleSource {
+    Channel(usize),
+    Constant(u8),
+}
+
+/// Parses a swizzle pattern such as "bgra", a pattern of only 3 letters keeps the alpha
+pub(crate) fn parse_swizzle(pattern: &str) -> PyResult<[SwizzleSource; 4]> {
+    let letters: Vec<char> = pattern.to_lowercase().chars().collect();
+    if letters.len() != 3 && letters.len() != 4 {
+        return Err(PyValueError::new_err(format!(
+            "A swizzle pattern needs 3 or 4 letters, got \"{}\"",
+            pattern
+        )));
+    }
+    let mut sources: [SwizzleSource; 4] = [SwizzleSource::Channel(3); 4];
+    for (source, letter) in sources.iter_mut().zip(letters) {
+        *source = match letter {
+            'r' => SwizzleSource::Channel(0),
+            'g' => SwizzleSource::Channel(1),
+            'b' => SwizzleSource::Channel(2),
+            'a' => SwizzleSource::Channel(3),
+            '0' => SwizzleSource::Constant(0),
+            '1' => SwizzleSource::Constant(255),
+            _ => {
+                return Err(PyValueError::new_err(format!(
+                    "Unknown swizzle channel '{}' in \"{}\", expected r, g, b, a, 0 or 1",
+                    letter, pattern
+                )))
+            }
+        };
+    }
+    Ok(sources)
+}
+
+pub(crate) fn swizzle_color(color: Color, sources: &[SwizzleSource; 4]) -> Color {
+    let channels: [u8; 4] = [color.r, color.g, color.b, color.a];
+    let [r, g, b, a]: [u8; 4] = sources.map(|source| match source {
+        SwizzleSource::Channel(index) => channels[index],
+        SwizzleSource::Constant(value) => value,
+    });
+    Color { r, g, b, a }
+}
+
+/// Scales the HSV saturation by one plus the factor, clamping it to [0.0, 1.0]. The hue is kept
+/// as a float so no precision is lost on the way through HSV
+pub(crate) fn saturate_color(color: Color, factor: f32) -> Color {
+    let hsv: [f32; 3] = ColorSpace::Hsv.to_components(color);
+    let saturation: f32 = (hsv[1] * (factor + 1.0)).clamp(0.0, 1.0);
+    ColorSpace::Hsv.from_components([hsv[0], saturation, hsv[2]], (color.a as f32) / 255.0)
+}
+
+/// The hue that skin tones gather around and how far from it the protection of vibrance fades out
+const SKIN_HUE: f32 = 25.0;
+const SKIN_HUE_FALLOFF: f32 = 35.0;
+
+/// Scales the HSV saturation like [Color::saturate], but the less saturated the color already
+/// is the stronger the effect. Hues close to skin tones only receive half of the effect
+pub(crate) fn vibrance_color(color: Color, factor: f32) -> Color {
+    let hsv: [f32; 3] = ColorSpace::Hsv.to_components(color);
+    let distance: f32 = ((hsv[0] - SKIN_HUE + 180.0).rem_euclid(360.0) - 180.0).abs();
+    let closeness: f32 = (1.0 - distance / SKIN_HUE_FALLOFF).clamp(0.0, 1.0);
+    let protection: f32 = 0.5 * closeness * closeness * (3.0 - 2.0 * closeness);
+    let strength: f32 = factor * (1.0 - hsv[1]) * (1.0 - protection);
+    let saturation: f32 = (hsv[1] * (1.0 + strength)).clamp(0.0, 1.0);
+    ColorSpace::Hsv.from_components([hsv[0], saturation, hsv[2]], (color.a as f32) / 255.0)
+}
+
+/// Moves the OKLCH lightness by the amount, which is on the [0.0, 1.0] scale of the lightness
+/// itself, so equal amounts look like equal steps. The hue is kept while the chroma gets reduced
+/// when the lighter or darker color would fall outside of sRGB
+pub(crate) fn lighten_color(color: Color, amount: f32) -> Color {
+    let lch: [f32; 3] = ColorSpace::Oklch.to_components(color);
+    let lightness: f32 = (lch[0] + amount).clamp(0.0, 1.0);
+    ColorSpace::Oklch.from_components([lightness, lch[1], lch[2]], (color.a as f32) / 255.0)
+}
+
+/// Scales the OKLCH chroma by one plus the amount at a constant lightness and hue, unlike
+/// [Color::saturate] which changes the perceived brightness along with the saturation. Chroma
+/// beyond sRGB is reduced back to the most saturated color the gamut holds
+pub(crate) fn saturate_oklch_color(color: Color, amount: f32) -> Color {
+    let lch: [f32; 3] = ColorSpace::Oklch.to_components(color);
+    let chroma: f32 = (lch[1] * (1.0 + amount)).max(0.0);
+    ColorSpace::Oklch.from_components([lch[0], chroma, lch[2]], (color.a as f32) / 255.0)
+}
+
+/// The relative luminance of WCAG out of linear sRGB channels
+pub(crate) fn relative_luminance(rgb: [f32; 3]) -> f32 {
+    0.2126 * rgb[0] + 0.7152 * rgb[1] + 0.0722 * rgb[2]
+}
+
+/// Blends the color toward the gray that has the same relative luminance. The blend happens in
+/// linear light where both ends share the luminance, so every step in between keeps it as well
+pub(crate) fn neutralize_color(color: Color, amount: f32) -> Color {
+    let rgb: [f32; 3] = decode_rgb(color);
+    let luminance: f32 = relative_luminance(rgb);
+    let [r, g, b]: [f32; 3] = rgb.map(|channel| channel + (luminance - channel) * amount);
+    linear_to_unit_rgb(r, g, b, (color.a as f32) / 255.0)
+}
+
+/// Brings the color to its brightest version without touching the hue. "value" scales the
+/// channels until the largest one reaches 255, "oklch" raises the OKLCH lightness as far as the
+/// chroma and hue still fit inside sRGB
+pub(crate) fn normalize_color(color: Color, method: &str) -> PyResult<Color> {
+    match method.to_lowercase().as_str() {
+        "value" => {
+            let max: u8 = color.r.max(color.g).max(color.b);
+            if max == 0 {
+                return Ok(color);
+            }
+            let scale = |channel: u8| ((channel as f32) * 255.0 / (max as f32)).round() as u8;
+            Ok(Color::new(
+                scale(color.r),
+                scale(color.g),
+                scale(color.b),
+                color.a,
+            ))
+        }
+        "oklch" => {
+            let lch: [f32; 3] = ColorSpace::Oklch.to_components(color);
+            if lch[0] <= 0.0 {
+                return Ok(color);
+            }
+            let fits = |lightness: f32| {
+                Gamut::Srgb.contains(ColorSpace::Oklch, [lightness, lch[1], lch[2]])
+            };
+            let (mut low, mut high): (f32, f32) = (lch[0], 1.0);
+            for _ in 0..24 {
+                let middle: f32 = (low + high) / 2.0;
+                if fits(middle) {
+                    low = middle;
+                } else {
+                    high = middle;
+                }
+            }
+            Ok(ColorSpace::Oklch.from_components([low, lch[1], lch[2]], (color.a as f32) / 255.0))
+        }
+        _ => Err(PyValueError::new_err(format!(
+            "Unknown normalization method \"{}\", expected value or oklch",
+            method
+        ))),
+    }
+}
+
+/// Rotates the OKLCH hue and then searches the lightness at which the rotated color, after being
+/// brought into sRGB, has the relative luminance of the original. Colors of other hues at the same
+/// OKLCH lightness can differ a lot in luminance, which would change their contrast ratios
+pub(crate) fn rotate_hue_preserving_luminance(color: Color, degrees: f32) -> Color {
+    let target: f32 = relative_luminance(decode_rgb(color));
+    let lch: [f32; 3] = ColorSpace::Oklch.to_components(color);
+    let hue: f32 = (lch[2] + degrees).rem_euclid(360.0);
+    let luminance = |lightness: f32| {
+        let rgb: [f32; 3] = GamutMapping::OklchChroma
+            .apply(ColorSpace::Oklch.to_decimal_rgb([lightness, lch[1], hue]));
+        relative_luminance(rgb.map(|channel| srgb_to_linear(channel.clamp(0.0, 1.0))))
+    };
+    let (mut low, mut high): (f32, f32) = (0.0, 1.0);
+    for _ in 0..24 {
+        let middle: f32 = (low + high) / 2.0;
+        if luminance(middle) < target {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+    let lightness: f32 = (low + high) / 2.0;
+    ColorSpace::Oklch.from_components([lightness, lch[1], hue], (color.a as f32) / 255.0)
+}
+
+/// Chroma below which a color counts as gray and has no hue worth harmonizing
+const ACHROMATIC_CHROMA: f32 = 1e-4;
+
+/// Pulls the OKLCH hue of the color along the shorter arc toward the hue of the target, the
+/// lightness and chroma stay the same. Grays have no hue to pull, so they never change
+pub(crate) fn harmonize_color(color: Color, target: Color, strength: f32) -> Color {
+    let lch: [f32; 3] = ColorSpace::Oklch.to_components(color);
+    let target_lch: [f32; 3] = ColorSpace::Oklch.to_components(target);
+    if lch[1] < ACHROMATIC_CHROMA || target_lch[1] < ACHROMATIC_CHROMA {
+        return color;
+    }
+    let difference: f32 = (target_lch[2] - lch[2] + 180.0).rem_euclid(360.0) - 180.0;
+    let hue: f32 = (lch[2] + difference * strength).rem_euclid(360.0);
+    ColorSpace::Oklch.from_components([lch[0], lch[1], hue], (color.a as f32) / 255.0)
+}
+
+/// The settings of a levels adjustment, the input range is stretched onto the output range
+/// with the gamma bending the midtones in between
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Levels {
+    in_black: f32,
+    in_white: f32,
+    gamma: f32,
+    out_black: f32,
+    out_white: f32,
+}
+
+impl Levels {
+    pub(crate) fn new(
+        in_black: u8,
+        in_white: u8,
+        gamma: f32,
+        out_black: u8,
+        out_white: u8,
+    ) -> PyResult<Levels> {
+        if in_black >= in_white {
+            return Err(PyValueError::new_err(format!(
+                "The input black point ({}) has to be below the input white point ({})",
+                in_black, in_white
+            )));
+        }
+        check_gamma(gamma)?;
+        Ok(Levels {
+            in_black: in_black as f32,
+            in_white: in_white as f32,
+            gamma,
+            out_black: out_black as f32,
+            out_white: out_white as f32,
+        })
+    }
+
+    fn map(&self, value: f32) -> f32 {
+        let position: f32 =
+            ((value - self.in_black) / (self.in_white - self.in_black)).clamp(0.0, 1.0);
+        self.out_black + position.powf(1.0 / self.gamma) * (self.out_white - self.out_black)
+    }
+
+    /// Adjusts either every channel on its own or only the gray value, which is the sRGB gray
+    /// sharing the relative luminance of the color. In that case the linear channels are scaled
+    /// by the same ratio so that the hue is kept
+    pub(crate) fn apply(&self, color: Color, luminance: bool) -> Color {
+        let adjusted: [f32; 3] = if luminance {
+            let linear: [f32; 3] = decode_rgb(color);
+            let gray_luminance: f32 = relative_luminance(linear);
+            let target: f32 = self.map(linear_to_srgb(gray_luminance) * 255.0);
+            if gray_luminance == 0.0 {
+                [target; 3]
+            } else {
+                let ratio: f32 = srgb_to_linear(target / 255.0) / gray_luminance;
+                encode_rgb(linear.map(|value| value * ratio)).map(|value| value * 255.0)
+            }
+        } else {
+            [color.r, color.g, color.b].map(|value| self.map(value as f32))
+        };
+        let [r, g, b]: [u8; 3] = adjusted.map(|value| value.round().clamp(0.0, 255.0) as u8);
+        Color {
+            r,
+            g,
+            b,
+            a: color.a,
+        }
+    }
+}
+
+/// The gains that turn the linear reference white into the gray of the same luminance
+pub(crate) fn white_balance_gains(reference: [f32; 3]) -> PyResult<[f32; 3]> {
+    if reference.iter().any(|value| *value <= 0.0) {
+        return Err(PyValueError::new_err(
+            "The reference white cannot have any channel at zero",
+        ));
+    }
+    let luminance: f32 = 0.2126 * reference[0] + 0.7152 * reference[1] + 0.0722 * reference[2];
+    Ok(reference.map(|value| luminance / value))
+}
+
 pub(crate) fn color_to_oklab(color: Color) -> (f32, f32, f32) {
     let rgba = color_to_decimal_rgb(color);
-    let l: f32 = (0.4122_214_708 * &rgba.0) + (0.536_332_536 * &rgba.1) + (0.051_445_995 * &rgba.2);
+    let l: f32 = (0.412_221_46 * &rgba.0) + (0.536_332_55 * &rgba.1) + (0.051_445_995 * &rgba.2);
     let a: f32 = (0.211_903_5 * &rgba.0) + (0.680_699_5 * &rgba.1) + (0.107_396_96 * &rgba.2);
     let b: f32 = (0.088_302_46 * rgba.0) + (0.281_718_85 * rgba.1) + (0.629_978_7 * rgba.2);
 
@@ -74,6 +504,20 @@ pub(crate) fn color_to_lch(color: Color) -> (f32, f32, u16) {
     (lab.0, c, h.floor() as u16)
 }
 
+pub(crate) fn color_to_cmyk(color: Color) -> (f32, f32, f32, f32) {
+    let rgb: (f32, f32, f32) = color_to_decimal_rgb(color);
+    let k: f32 = 1.0 - rgb.0.max(rgb.1).max(rgb.2);
+    let k_invert: f32 = 1.0 - k;
+
+    if k_invert == 0.0 {
+        return (0.0, 0.0, 0.0, 1.0);
+    }
+    let c: f32 = (k_invert - rgb.0) / k_invert;
+    let m: f32 = (k_invert - rgb.1) / k_invert;
+    let y: f32 = (k_invert - rgb.2) / k_invert;
+    (c, m, y, k)
+}
+
 pub(crate) fn color_to_hsv(color: Color) -> (u16, f32, f32) {
     let values: (u16, f32, f32, f32) = calculate_hs(color);
     (values.0, values.1, values.2)
@@ -105,6 +549,66 @@ pub(crate) fn calculate_hs(color: Color) -> (u16, f32, f32, f32) {
     (h.round() as u16, s, c_max, c_min)
 }
 
+/// Formats a number with at most the given decimal places, dropping trailing zeros
+fn trim_decimals(value: f32, places: usize) -> String {
+    let formatted: String = format!("{:.*}", places, value);
+    if !formatted.contains('.') {
+        return formatted;
+    }
+    formatted
+        .trim_end_matches('0')
+        .trim_end_matches('.')
+        .to_string()
+}
+
+/// Formats the color by one of the `__format__` specs, or None when the spec is unknown
+pub(crate) fn format_color(color: Color, spec: &str) -> Option<String> {
+    // Specs are written either all lowercase or all uppercase, the latter giving uppercase
+    // hex digits while the functional notations stay lowercase
+    let uppercase: bool = if spec == spec.to_ascii_lowercase() {
+        false
+    } else if spec == spec.to_ascii_uppercase() {
+        true
+    } else {
+        return None;
+    };
+    let alpha: String = trim_decimals((color.a as f32) / 255.0, 3);
+    let hsl = || {
+        let values: (u16, f32, f32, f32) = calculate_hs(color);
+        let l: f32 = (values.2 + values.3) / 2.0;
+        let delta: f32 = values.2 - values.3;
+        let s: f32 = if delta == 0.0 {
+            0.0
+        } else {
+            delta / (1.0 - (2.0 * l - 1.0).abs())
+        };
+        format!(
+            "{}, {}%, {}%",
+            values.0,
+            trim_decimals(s * 100.0, 1),
+            trim_decimals(l * 100.0, 1)
+        )
+    };
+    let formatted: String = match spec.to_ascii_lowercase().as_str() {
+        "hex" => format!("#{:02x}{:02x}{:02x}", color.r, color.g, color.b),
+        "hex8" => format!(
+            "#{:02x}{:02x}{:02x}{:02x}",
+            color.r, color.g, color.b, color.a
+        ),
+        "rgb" => format!("rgb({}, {}, {})", color.r, color.g, color.b),
+        "rgba" => format!("rgba({}, {}, {}, {})", color.r, color.g, color.b, alpha),
+        "hsl" => format!("hsl({})", hsl()),
+        "hsla" => format!("hsla({}, {})", hsl(), alpha),
+        "css" if color.a == 255 => format!("#{:02x}{:02x}{:02x}", color.r, color.g, color.b),
+        "css" => format!("rgba({}, {}, {}, {})", color.r, color.g, color.b, alpha),
+        _ => return None,
+    };
+    if uppercase && formatted.starts_with('#') {
+        return Some(formatted.to_uppercase());
+    }
+    Some(formatted)
+}
+
 pub(crate) fn to_unit_rgb(r: f32, g: f32, b: f32, a: f32) -> Color {
     Color {
         r: (r * 255.0).floor() as u8,
@@ -114,6 +618,57 @@ pub(crate) fn to_unit_rgb(r: f32, g: f32, b: f32, a: f32) -> Color {
     }
 }
 
+pub(crate) fn premultiply_color(color: Color) -> Color {
+    let alpha: f32 = (color.a as f32) / 255.0;
+    Color {
+        r: ((color.r as f32) * alpha).round() as u8,
+        g: ((color.g as f32) * alpha).round() as u8,
+        b: ((color.b as f32) * alpha).round() as u8,
+        a: color.a,
+    }
+}
+
+pub(crate) fn unpremultiply_color(color: Color) -> Color {
+    if color.a == 0 {
+        return Color {
+            r: 0,
+            g: 0,
+            b: 0,
+            a: 0,
+        };
+    }
+    let alpha: f32 = (color.a as f32) / 255.0;
+    Color {
+        r: ((color.r as f32) / alpha).round().min(255.0) as u8,
+        g: ((color.g as f32) / alpha).round().min(255.0) as u8,
+        b: ((color.b as f32) / alpha).round().min(255.0) as u8,
+        a: color.a,
+    }
+}
+
+/// Snaps every RGB channel to the closest of the 6 levels of the web-safe cube (multiples of 51)
+pub(crate) fn web_safe_color(color: Color) -> Color {
+    let snap = |channel: u8| ((channel as f32) / 51.0).round() as u8 * 51;
+    Color {
+        r: snap(color.r),
+        g: snap(color.g),
+        b: snap(color.b),
+        a: color.a,
+    }
+}
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0100_0000_01b3;
+
+/// Hashes the bytes with 64-bit FNV-1a into 16 hex digits, unlike the hasher of the standard
+/// library the result stays the same between runs, platforms and compiler versions
+pub(crate) fn fingerprint(bytes: &[u8]) -> String {
+    let hash: u64 = bytes.iter().fold(FNV_OFFSET_BASIS, |hash, byte| {
+        (hash ^ (*byte as u64)).wrapping_mul(FNV_PRIME)
+    });
+    format!("{:016x}", hash)
+}
+
 pub(crate) fn unwrap_color(color: Color) -> (u8, u8, u8, u8) {
     (color.r, color.g, color.b, color.a)
 }
@@ -141,6 +696,40 @@ pub(crate) fn color_add_color(value: &Color, other: &Color, include_transparency
     }
 }
 
+pub(crate) fn color_add_color_linear(
+    value: &Color,
+    other: &Color,
+    include_transparency: bool,
+) -> Color {
+    let rgba1: (f32, f32, f32, f32) = color_to_linear_rgba(*value);
+    let rgba2: (f32, f32, f32, f32) = color_to_linear_rgba(*other);
+    let mut result: Color =
+        linear_to_unit_rgb(rgba1.0 + rgba2.0, rgba1.1 + rgba2.1, rgba1.2 + rgba2.2, 0.0);
+    result.a = if include_transparency {
+        ((value.a as u16) + (other.a as u16)).min(255) as u8
+    } else {
+        value.a
+    };
+    result
+}
+
+pub(crate) fn color_add_scalar_linear(
+    value: &Color,
+    other: BigInt,
+    include_transparency: bool,
+) -> Color {
+    let scalar: f32 = (wrap_around_bigint_as_i16(other.clone()) as f32) / 255.0;
+    let rgba: (f32, f32, f32, f32) = color_to_linear_rgba(*value);
+    let mut result: Color =
+        linear_to_unit_rgb(rgba.0 + scalar, rgba.1 + scalar, rgba.2 + scalar, 0.0);
+    result.a = if include_transparency {
+        (wrap_around_bigint(create_bigint_from_u8(value.a) + &other).1).min(255) as u8
+    } else {
+        value.a
+    };
+    result
+}
+
 pub(crate) fn color_add_scalar(value: &Color, other: BigInt, include_transparency: bool) -> Color {
     Color {
         r: (wrap_around_bigint(create_bigint_from_u8(value.r) + &other).1).min(255) as u8,
@@ -154,6 +743,49 @@ pub(crate) fn color_add_scalar(value: &Color, other: BigInt, include_transparenc
     }
 }
 
+pub(crate) fn color_add_channels(
+    value: &Color,
+    channels: &ChannelTuple<i64>,
+    include_transparency: bool,
+    linear: bool,
+) -> Color {
+    let [r, g, b]: [i64; 3] = channels.rgb;
+    let mut result: Color = if linear {
+        let rgba: (f32, f32, f32, f32) = color_to_linear_rgba(*value);
+        linear_to_unit_rgb(
+            rgba.0 + (r as f32) / 255.0,
+            rgba.1 + (g as f32) / 255.0,
+            rgba.2 + (b as f32) / 255.0,
+            0.0,
+        )
+    } else {
+        Color {
+            r: (value.r as i64).saturating_add(r).clamp(0, 255) as u8,
+            g: (value.g as i64).saturating_add(g).clamp(0, 255) as u8,
+            b: (value.b as i64).saturating_add(b).clamp(0, 255) as u8,
+            a: 0,
+        }
+    };
+    result.a = match channels.alpha {
+        Some(a) if include_transparency => (value.a as i64).saturating_add(a).clamp(0, 255) as u8,
+        _ => value.a,
+    };
+    result
+}
+
+pub(crate) fn color_mul_channels(value: &Color, factors: &ChannelTuple<f32>) -> Color {
+    let scale =
+        |channel: u8, factor: f32| ((channel as f32) * factor).clamp(0.0, 255.0).floor() as u8;
+    Color {
+        r: scale(value.r, factors.rgb[0]),
+        g: scale(value.g, factors.rgb[1]),
+        b: scale(value.b, factors.rgb[2]),
+        a: factors
+            .alpha
+            .map_or(value.a, |factor| scale(value.a, factor)),
+    }
+}
+
 pub(crate) fn color_sub_color(value: &Color, other: &Color, include_transparency: bool) -> Color {
     Color {
         r: ((value.r as i16) - (other.r as i16)).max(0) as u8,
@@ -181,7 +813,11 @@ pub(crate) fn color_sub_scalar(value: &Color, other: BigInt, include_transparenc
 }
 
 pub(crate) fn randomise_component(
-    value: u8, start: Option<u8>, end: Option<u8>, rng: &mut ThreadRng, name: &str
+    value: u8,
+    start: Option<u8>,
+    end: Option<u8>,
+    rng: &mut impl Rng,
+    name: &str,
 ) -> PyResult<u8> {
     match (start, end) {
         (Some(val1), Some(val2)) => {
@@ -1,6 +1,9 @@
 #![allow(unused_must_use)]
 #![allow(clippy::wrong_self_convention)]
 
+// pyo3 0.22 converts the error of every fallible wrapper it generates with an Into::into
+// that clippy flags, an allow on the functions themselves does not reach that code
+#[allow(clippy::useless_conversion)]
 mod color;
 
 use pyo3::prelude::*;
@@ -8,7 +11,26 @@ use pyo3::prelude::*;
 #[pymodule]
 fn tincture(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<color::Color>();
+    m.add_class::<color::frozen::FrozenColor>();
     m.add_class::<color::blending::BlendingMode>();
+    m.add_class::<color::gradient::Gradient>();
+    m.add_class::<color::calibration::GrayRampReport>();
+    m.add_class::<color::pool::ColorPool>();
+    m.add_class::<color::reactive::ReactiveColor>();
+    m.add_class::<color::scale::ColorScale>();
+    m.add_class::<color::palette::Palette>();
+    m.add_class::<color::array::ColorArray>();
+    m.add_class::<color::matrix::ColorMatrix>();
+    m.add_class::<color::custom_space::ColorSpaceDefinition>();
+    m.add_class::<color::appearance::ViewingConditions>();
+    m.add_class::<color::random::RandomColorGenerator>();
+    m.add_function(wrap_pyfunction!(color::tinct::dump, m)?);
+    m.add_function(wrap_pyfunction!(color::tinct::load, m)?);
+    m.add_function(wrap_pyfunction!(color::picker::pick, m)?);
+    m.add_function(wrap_pyfunction!(color::calibration::evaluate_gray_ramp, m)?);
+    m.add_function(wrap_pyfunction!(color::lut::export_lut, m)?);
+    m.add_function(wrap_pyfunction!(color::parallel::set_num_threads, m)?);
+    m.add_function(wrap_pyfunction!(color::parallel::get_num_threads, m)?);
     m.add("WHITE", color::consts::WHITE);
     m.add("RED", color::consts::RED);
     m.add("BLUE", color::consts::BLUE);
@@ -45,5 +67,8 @@ fn tincture(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add("LIGHT_TEAL", color::consts::LIGHT_TEAL);
     m.add("LIGHT_PINK", color::consts::LIGHT_PINK);
     m.add("VIVID_BLUE", color::consts::VIVID_BLUE);
+    color::batch::register(m)?;
+    color::brewer::register(m)?;
+    color::colormaps::register(m)?;
     Ok(())
 }
@@ -139,6 +139,32 @@ def test_color_grayscale(color1, expected):
 def test_color_brightness(color1, factor, expected):
     assert color1.brightness(factor) == expected
 
+@pytest.mark.parametrize("color,delta,expected", [
+    (tincture.Color(128, 128, 128), 0, tincture.Color(128, 128, 128)),
+    (tincture.Color(128, 128, 128), -3000, tincture.Color(155, 122, 82)),
+    (tincture.Color(128, 128, 128), 1000, tincture.Color(124, 128, 136)),
+    (tincture.Color(255, 255, 255), -1000, tincture.Color(255, 254, 235)),
+    (tincture.Color(200, 30, 60, 7), -1000, tincture.Color(203, 28, 53, 7)),
+    (tincture.Color(200, 30, 60, 7), 5000, tincture.Color(193, 33, 76, 7)),
+    (tincture.Color(0, 0, 0), -3000, tincture.Color(0, 0, 0)),
+    (tincture.Color(128, 128, 128), 1667 - 6504, tincture.Color(208, 95, 0)),
+    (tincture.Color(128, 128, 128), -5000, ValueError),
+    (tincture.Color(128, 128, 128), 25000 - 6504 + 1, ValueError),
+    (tincture.Color(128, 128, 128), -1e9, ValueError),
+    (tincture.Color(128, 128, 128), float("nan"), ValueError),
+])
+def test_color_adjust_temperature(color, delta, expected):
+    if expected is ValueError:
+        with pytest.raises(ValueError):
+            color.adjust_temperature(delta)
+        return
+    assert color.adjust_temperature(delta) == expected
+
+def test_color_adjust_temperature_direction():
+    color = tincture.Color(100, 150, 200)
+    warmer, cooler = color.adjust_temperature(-2000), color.adjust_temperature(2000)
+    assert warmer.r > color.r > cooler.r and warmer.b < color.b < cooler.b
+
 @pytest.mark.parametrize("color,factor,expected", [
     (tincture.Color(66, 135, 245), 40, tincture.Color(116, 66, 245)),
     (tincture.Color(50, 168, 82), -20, tincture.Color(57, 168, 49)),
@@ -152,6 +178,306 @@ def test_color_brightness(color1, factor, expected):
 def test_color_tint(color, factor, expected):
     assert color.tint(factor).approx_equal(expected, 2, True)
 
+@pytest.mark.parametrize("color,degrees,space,expected", [
+    (tincture.Color(255, 0, 0, 200), 120, "hsl", tincture.Color(0, 255, 0, 200)),
+    (tincture.Color(255, 0, 0, 200), -90.5, "hsl", tincture.Color(125, 0, 255, 200)),
+    (tincture.Color(255, 0, 0, 200), 180, "OKLCH", tincture.Color(0, 154, 172, 200)),
+    (tincture.Color(255, 0, 0, 200), 360, "oklch", tincture.Color(255, 0, 0, 200)),
+    (tincture.Color(255, 0, 0, 200), 180, "matrix", tincture.Color(0, 109, 109, 200)),
+    (tincture.Color(120, 120, 120), 77, "matrix", tincture.Color(120, 120, 120)),
+    (tincture.Color(255, 0, 0), 10, "hsv", ValueError),
+    (tincture.Color(200, 40, 40), float("nan"), "hsl", ValueError),
+    (tincture.Color(200, 40, 40), float("inf"), "oklch", ValueError),
+    (tincture.Color(200, 40, 40), float("-inf"), "matrix", ValueError),
+])
+def test_color_hue_rotate(color, degrees, space, expected):
+    if expected is ValueError:
+        with pytest.raises(ValueError):
+            color.hue_rotate(degrees, space)
+        return
+    assert color.hue_rotate(degrees, space) == expected
+    assert color.hue_rotate(degrees, space=space) == expected
+
+def test_color_hue_rotate_matches_tint():
+    color = tincture.Color(66, 135, 245)
+    assert color.hue_rotate(40).approx_equal(color.tint(40), 1, True)
+
+@pytest.mark.parametrize("operation,expected", [
+    (lambda color: color.sepia(), tincture.Color(161, 143, 112, 77)),
+    (lambda color: color.sepia(0.0), tincture.Color(200, 100, 30, 77)),
+    (lambda color: color.sepia(0.5), tincture.Color(181, 122, 71, 77)),
+    (lambda color: color.posterize(2), tincture.Color(255, 0, 0, 77)),
+    (lambda color: color.posterize(3), tincture.Color(255, 128, 0, 77)),
+    (lambda color: color.posterize(255), tincture.Color(200, 100, 30, 77)),
+    (lambda color: color.solarize(), tincture.Color(55, 100, 30, 77)),
+    (lambda color: color.solarize(0), tincture.Color(55, 155, 225, 77)),
+    (lambda color: color.threshold(), tincture.Color(0, 0, 0, 77)),
+    (lambda color: color.threshold(122), tincture.Color(255, 255, 255, 77)),
+])
+def test_color_filters(operation, expected):
+    assert operation(tincture.Color(200, 100, 30, 77)) == expected
+
+@pytest.mark.parametrize("operation,expected", [
+    (lambda color: color.exposure(0), tincture.Color(100, 150, 200, 9)),
+    (lambda color: color.exposure(1), tincture.Color(138, 205, 255, 9)),
+    (lambda color: color.exposure(-1), tincture.Color(71, 109, 146, 9)),
+    (lambda color: color.exposure(10), tincture.Color(255, 255, 255, 9)),
+    (lambda color: color.gamma(1), tincture.Color(100, 150, 200, 9)),
+    (lambda color: color.gamma(2.2), tincture.Color(33, 79, 149, 9)),
+    (lambda color: color.gamma(0.5), tincture.Color(160, 196, 226, 9)),
+])
+def test_color_exposure_and_gamma(operation, expected):
+    assert operation(tincture.Color(100, 150, 200, 9)) == expected
+
+@pytest.mark.parametrize("color,arguments,expected", [
+    (tincture.Color(100, 150, 200, 9), {"in_black": 0, "in_white": 255}, tincture.Color(100, 150, 200, 9)),
+    (tincture.Color(100, 150, 200, 9), {"in_black": 50, "in_white": 200}, tincture.Color(85, 170, 255, 9)),
+    (tincture.Color(100, 150, 200, 9), {"in_black": 0, "in_white": 255, "gamma": 2.0}, tincture.Color(160, 196, 226, 9)),
+    (tincture.Color(100, 150, 200, 9), {"in_black": 0, "in_white": 255, "out_black": 20, "out_white": 220}, tincture.Color(98, 138, 177, 9)),
+    (tincture.Color(100, 150, 200, 9), {"in_black": 0, "in_white": 255, "out_black": 255, "out_white": 0}, tincture.Color(155, 105, 55, 9)),
+    (tincture.Color(100, 150, 200, 9), {"in_black": 50, "in_white": 200, "luminance": True}, tincture.Color(112, 168, 223, 9)),
+    (tincture.Color(0, 0, 0), {"in_black": 0, "in_white": 255, "out_black": 40, "luminance": True}, tincture.Color(40, 40, 40)),
+    (tincture.Color(0, 0, 0), {"in_black": 200, "in_white": 200}, ValueError),
+    (tincture.Color(0, 0, 0), {"in_black": 0, "in_white": 200, "gamma": 0.0}, ValueError),
+])
+def test_color_levels(color, arguments, expected):
+    if expected is ValueError:
+        with pytest.raises(ValueError):
+            color.levels(**arguments)
+        return
+    assert color.levels(**arguments) == expected
+
+@pytest.mark.parametrize("stops", [float("nan"), float("inf"), float("-inf")])
+def test_color_exposure_invalid(stops):
+    with pytest.raises(ValueError):
+        tincture.Color(100, 150, 200).exposure(stops)
+
+@pytest.mark.parametrize("amount", [float("nan"), float("inf"), float("-inf")])
+def test_color_sepia_invalid(amount):
+    with pytest.raises(ValueError):
+        tincture.Color(100, 150, 200).sepia(amount)
+    with pytest.raises(ValueError):
+        tincture.ColorMatrix.sepia(amount)
+
+def test_color_levels_luminance_matches_get_luminance():
+    for color in (tincture.Color(100, 150, 200), tincture.Color(200, 40, 40), tincture.Color(40, 160, 60)):
+        luminance = color.get_luminance()
+        encoded = 1.055 * luminance ** (1 / 2.4) - 0.055 if luminance > 0.0031308 else 12.92 * luminance
+        gray = tincture.Color(*[round(encoded * 255)] * 3)
+        assert gray.get_luminance() == pytest.approx(luminance, abs=0.003)
+        adjusted = color.levels(50, 200, 1.5, luminance=True)
+        assert adjusted.get_luminance() == pytest.approx(gray.levels(50, 200, 1.5).get_luminance(), abs=0.005)
+
+@pytest.mark.parametrize("gamma", [0.0, -1.0, float("nan"), float("inf")])
+def test_color_gamma_invalid(gamma):
+    with pytest.raises(ValueError):
+        tincture.Color(100, 150, 200).gamma(gamma)
+
+@pytest.mark.parametrize("pattern,expected", [
+    ("rgba", tincture.Color(10, 20, 30, 40)),
+    ("bgra", tincture.Color(30, 20, 10, 40)),
+    ("BGR", tincture.Color(30, 20, 10, 40)),
+    ("rrra", tincture.Color(10, 10, 10, 40)),
+    ("argb", tincture.Color(40, 10, 20, 30)),
+    ("aaa1", tincture.Color(40, 40, 40, 255)),
+    ("rg0", tincture.Color(10, 20, 0, 40)),
+    ("rg", ValueError),
+    ("rgbar", ValueError),
+    ("rgbx", ValueError),
+])
+def test_color_swizzle(pattern, expected):
+    color = tincture.Color(10, 20, 30, 40)
+    if expected is ValueError:
+        with pytest.raises(ValueError):
+            color.swizzle(pattern)
+        return
+    assert color.swizzle(pattern) == expected
+
+@pytest.mark.parametrize("color,factor,expected", [
+    (tincture.Color(40, 80, 200, 9), 0.2, tincture.Color(8, 56, 200, 9)),
+    (tincture.Color(40, 80, 200, 9), -0.5, tincture.Color(120, 140, 200, 9)),
+    (tincture.Color(100, 150, 200), 5.0, tincture.Color(0, 100, 200)),
+    (tincture.Color(90, 90, 90), 1.0, tincture.Color(90, 90, 90)),
+    (tincture.Color(90, 90, 90), float("nan"), ValueError),
+    (tincture.Color(90, 90, 90), float("inf"), ValueError),
+])
+def test_color_saturate(color, factor, expected):
+    if expected is ValueError:
+        with pytest.raises(ValueError):
+            color.saturate(factor)
+        return
+    assert color.saturate(factor) == expected
+
+@pytest.mark.parametrize("color,factor,expected", [
+    (tincture.Color(120, 140, 160), 0.0, tincture.Color(120, 140, 160)),
+    (tincture.Color(120, 140, 160), 1.0, tincture.Color(90, 125, 160)),
+    (tincture.Color(120, 140, 160), -1.0, tincture.Color(150, 155, 160)),
+    (tincture.Color(200, 40, 40), 1.0, tincture.Color(200, 11, 11)),
+    (tincture.Color(128, 128, 128, 7), 1.0, tincture.Color(128, 128, 128, 7)),
+])
+def test_color_vibrance(color, factor, expected):
+    assert color.vibrance(factor) == expected
+
+@pytest.mark.parametrize("factor", [float("nan"), float("inf"), float("-inf")])
+def test_color_vibrance_invalid(factor):
+    with pytest.raises(ValueError):
+        tincture.Color(100, 150, 200).vibrance(factor)
+
+def test_color_vibrance_protects_muted_and_skin_tones():
+    muted, vivid = tincture.Color(120, 140, 160), tincture.Color(40, 120, 200)
+    assert muted.vibrance(1.0).get_saturation() / muted.get_saturation() > vivid.vibrance(1.0).get_saturation() / vivid.get_saturation()
+    # The same saturation and value, once with a skin tone hue and once with its opposite
+    skin, sky = tincture.Color(224, 172, 140), tincture.Color(140, 192, 224)
+    assert skin.vibrance(1.0).get_saturation() < sky.vibrance(1.0).get_saturation()
+
+@pytest.mark.parametrize("color,amount,expected", [
+    (tincture.Color(120, 140, 160), 0.0, tincture.Color(120, 140, 160)),
+    (tincture.Color(120, 140, 160), 0.1, tincture.Color(150, 171, 191)),
+    (tincture.Color(120, 140, 160), -0.1, tincture.Color(91, 110, 130)),
+    (tincture.Color(128, 128, 128), 1.0, tincture.Color(255, 255, 255)),
+    (tincture.Color(128, 128, 128), -1.0, tincture.Color(0, 0, 0)),
+    (tincture.Color(200, 40, 40, 9), 0.2, tincture.Color(255, 128, 117, 9)),
+])
+def test_color_lighten(color, amount, expected):
+    assert color.lighten(amount) == expected
+    assert color.darken(-amount) == expected
+
+@pytest.mark.parametrize("amount", [float("nan"), float("inf"), float("-inf")])
+def test_color_lighten_invalid(amount):
+    with pytest.raises(ValueError):
+        tincture.Color(100, 150, 200).lighten(amount)
+    with pytest.raises(ValueError):
+        tincture.Color(100, 150, 200).darken(amount)
+
+def test_color_lighten_steps_are_perceptually_even():
+    for color in (tincture.Color(0, 0, 255), tincture.Color(40, 160, 60), tincture.Color(200, 180, 40)):
+        shades = tincture.ColorArray([color.darken(0.3 - 0.1 * step) for step in range(4)])
+        lightness = [components[0] for components in shades.to_components("oklch")]
+        for index in range(1, 4):
+            assert lightness[index] - lightness[0] == pytest.approx(0.1 * index, abs=0.01)
+
+@pytest.mark.parametrize("target,strength,expected", [
+    (tincture.Color(40, 60, 220), 0.0, tincture.Color(200, 40, 40, 9)),
+    (tincture.Color(40, 60, 220), 0.5, tincture.Color(167, 55, 168, 9)),
+    (tincture.Color(40, 60, 220), 1.0, tincture.Color(64, 97, 223, 9)),
+    (tincture.Color(40, 160, 60), 0.5, tincture.Color(140, 106, 0, 9)),
+    (tincture.Color(128, 128, 128), 1.0, tincture.Color(200, 40, 40, 9)),
+    (tincture.Color(40, 60, 220), 1.5, ValueError),
+    (tincture.Color(40, 60, 220), -0.5, ValueError),
+])
+def test_color_harmonize(target, strength, expected):
+    color = tincture.Color(200, 40, 40, 9)
+    if expected is ValueError:
+        with pytest.raises(ValueError):
+            color.harmonize(target, strength)
+        return
+    assert color.harmonize(target, strength) == expected
+
+def test_color_harmonize_takes_the_shorter_arc():
+    color, target = tincture.Color(200, 40, 40), tincture.Color(40, 160, 60)
+    assert color.harmonize(target) == color.harmonize(target, 0.5)
+    assert tincture.Color(128, 128, 128).harmonize(color, 1.0) == tincture.Color(128, 128, 128)
+    hues = [components[2] for components in tincture.ColorArray([color, color.harmonize(target, 0.25), target]).to_components("oklch")]
+    # From roughly 27 to 146 degrees the shorter way is through the yellows, not the blues
+    assert hues[0] < hues[1] < hues[2]
+
+@pytest.mark.parametrize("color,degrees,expected", [
+    (tincture.Color(200, 40, 40, 9), 0.0, tincture.Color(200, 40, 40, 9)),
+    (tincture.Color(200, 40, 40, 9), 180.0, tincture.Color(0, 116, 127, 9)),
+    (tincture.Color(40, 160, 60), 180.0, tincture.Color(198, 103, 203)),
+    (tincture.Color(30, 60, 220), -120.0, tincture.Color(0, 97, 29)),
+    (tincture.Color(128, 128, 128), 90.0, tincture.Color(128, 128, 128)),
+])
+def test_color_rotate_hue_preserve_luminance(color, degrees, expected):
+    assert color.rotate_hue_preserve_luminance(degrees) == expected
+
+@pytest.mark.parametrize("degrees", [float("nan"), float("inf"), float("-inf")])
+def test_color_rotate_hue_preserve_luminance_invalid(degrees):
+    with pytest.raises(ValueError):
+        tincture.Color(100, 150, 200).rotate_hue_preserve_luminance(degrees)
+
+def test_color_rotate_hue_preserve_luminance_keeps_luminance():
+    for color in (tincture.Color(200, 40, 40), tincture.Color(40, 160, 60), tincture.Color(30, 60, 220), tincture.Color(255, 255, 0)):
+        for degrees in (45.0, 90.0, 180.0, 270.0):
+            rotated = color.rotate_hue_preserve_luminance(degrees)
+            assert rotated.get_luminance() == pytest.approx(color.get_luminance(), abs=0.005)
+    # A plain OKLCH rotation of the same green loses a noticeable part of its luminance
+    green = tincture.Color(40, 160, 60)
+    assert green.get_luminance() - green.hue_rotate(180.0, "oklch").get_luminance() > 0.04
+
+@pytest.mark.parametrize("color,method,expected", [
+    (tincture.Color(60, 30, 10, 9), "value", tincture.Color(255, 128, 43, 9)),
+    (tincture.Color(0, 0, 120), "value", tincture.Color(0, 0, 255)),
+    (tincture.Color(128, 128, 128), "value", tincture.Color(255, 255, 255)),
+    (tincture.Color(0, 0, 0), "value", tincture.Color(0, 0, 0)),
+    (tincture.Color(60, 30, 10, 9), "oklch", tincture.Color(255, 214, 190, 9)),
+    (tincture.Color(0, 0, 120), "OKLCH", tincture.Color(89, 140, 255)),
+    (tincture.Color(128, 128, 128), "oklch", tincture.Color(255, 255, 255)),
+    (tincture.Color(0, 0, 0, 40), "oklch", tincture.Color(0, 0, 0, 40)),
+    (tincture.Color(60, 30, 10), "hsl", ValueError),
+])
+def test_color_normalize(color, method, expected):
+    if expected is ValueError:
+        with pytest.raises(ValueError):
+            color.normalize(method)
+        return
+    assert color.normalize(method) == expected
+
+def test_color_normalize_keeps_hue_and_chroma():
+    for color in (tincture.Color(60, 30, 10), tincture.Color(20, 80, 40), tincture.Color(0, 0, 120)):
+        original, normalized = tincture.ColorArray([color, color.normalize("oklch")]).to_components("oklch")
+        assert normalized[0] > original[0]
+        assert normalized[1] == pytest.approx(original[1], abs=0.005)
+        assert normalized[2] == pytest.approx(original[2], abs=1.0)
+    assert tincture.Color(60, 30, 10).normalize().to_hsv()[0] == tincture.Color(60, 30, 10).to_hsv()[0]
+
+@pytest.mark.parametrize("color,amount,expected", [
+    (tincture.Color(120, 140, 160), 0.0, tincture.Color(120, 140, 160)),
+    (tincture.Color(120, 140, 160), 0.5, tincture.Color(111, 141, 171)),
+    (tincture.Color(120, 140, 160), -0.5, tincture.Color(129, 139, 149)),
+    (tincture.Color(120, 140, 160), -1.0, tincture.Color(137, 137, 137)),
+    (tincture.Color(120, 140, 160), -2.0, tincture.Color(137, 137, 137)),
+    (tincture.Color(200, 40, 40, 9), 5.0, tincture.Color(209, 0, 24, 9)),
+])
+def test_color_saturate_oklch(color, amount, expected):
+    assert color.saturate_oklch(amount) == expected
+    assert color.desaturate_oklch(-amount) == expected
+
+@pytest.mark.parametrize("amount", [float("nan"), float("inf"), float("-inf")])
+def test_color_saturate_oklch_invalid(amount):
+    with pytest.raises(ValueError):
+        tincture.Color(100, 150, 200).saturate_oklch(amount)
+    with pytest.raises(ValueError):
+        tincture.Color(100, 150, 200).desaturate_oklch(amount)
+
+def test_color_saturate_oklch_keeps_lightness_and_hue():
+    for color in (tincture.Color(120, 140, 160), tincture.Color(200, 40, 40), tincture.Color(40, 160, 60)):
+        original, saturated, gray = tincture.ColorArray(
+            [color, color.saturate_oklch(5.0), color.desaturate_oklch(1.0)]
+        ).to_components("oklch")
+        assert saturated[0] == pytest.approx(original[0], abs=0.005)
+        assert gray[0] == pytest.approx(original[0], abs=0.005)
+        assert saturated[1] > original[1]
+        assert saturated[2] == pytest.approx(original[2], abs=1.0)
+
+@pytest.mark.parametrize("color,reference,expected", [
+    (tincture.Color(255, 230, 200), tincture.Color(255, 230, 200), tincture.Color(234, 234, 234)),
+    (tincture.Color(100, 150, 200, 9), tincture.Color(255, 230, 200), tincture.Color(91, 152, 234, 9)),
+    (tincture.Color(100, 150, 200), tincture.Color(128, 128, 128), tincture.Color(100, 150, 200)),
+    (tincture.Color(100, 150, 200), tincture.Color(255, 0, 0), ValueError),
+])
+def test_color_white_balance(color, reference, expected):
+    if expected is ValueError:
+        with pytest.raises(ValueError):
+            color.white_balance(reference)
+        return
+    assert color.white_balance(reference) == expected
+
+@pytest.mark.parametrize("levels", [0, 1])
+def test_color_posterize_invalid(levels):
+    with pytest.raises(ValueError):
+        tincture.Color(200, 100, 30).posterize(levels)
+
 @pytest.mark.parametrize("color1,start,end,expected", [
     (tincture.Color(66, 135, 245), [None, None, None, None], [None, None, None, None], tincture.Color(66, 135, 245)),
     (tincture.Color(66, 135, 245), [None, None, None, 254], [None, None, None, 255], tincture.Color(66, 135, 245)),
@@ -228,4 +554,340 @@ def test_color_clerp(color1, color2, t, expected):
     color_cloned2.clerp_inplace(color1, 1.0 - t)
     assert result.approx_equal(color_cloned, 1)
     assert color_cloned2.approx_equal(tincture.Color.clerp(color2, color1, 1.0 - t), 1)
-    assert result.approx_equal(expected, 1)
\ No newline at end of file
+    assert result.approx_equal(expected, 1)
+
+@pytest.mark.parametrize("color1,color2,t,expected", [
+    (tincture.BLACK, tincture.WHITE, 0.5, tincture.Color(188, 188, 188)),
+    (tincture.RED, tincture.GREEN, 0.5, tincture.Color(188, 188, 0)),
+    (tincture.Color(20, 52, 86), tincture.Color(20, 52, 86), 0.5, tincture.Color(20, 52, 86)),
+    (tincture.Color(2, 2, 2), tincture.Color(4, 3, 2), 1.0, tincture.Color(4, 3, 2)),
+    (tincture.Color(10, 20, 30, 0), tincture.Color(10, 20, 30, 255), 0.5, tincture.Color(10, 20, 30, 128)),
+])
+def test_color_mlerp_linear(color1, color2, t, expected):
+    result = tincture.Color.mlerp(color1, color2, t, linear=True)
+    color_cloned = color1.copy()
+    color_cloned.mlerp_inplace(color2, t, linear=True)
+    assert result == color_cloned
+    assert expected.approx_equal(result, 1, True)
+
+@pytest.mark.parametrize("color1,other,expected", [
+    (tincture.Color(200, 10, 30), tincture.Color(10, 200, 30), tincture.Color(200, 200, 45)),
+    (tincture.Color(50, 50, 50), 20, tincture.Color(93, 93, 93)),
+    (tincture.Color(0, 0, 0, 10), tincture.Color(0, 0, 0, 0), tincture.Color(0, 0, 0, 10)),
+    (tincture.WHITE, tincture.Color(255, 255, 255), tincture.WHITE),
+])
+def test_color_add_linear(color1, other, expected):
+    assert color1.add(other, linear=True) == expected
+
+@pytest.mark.parametrize("color1,factor,expected", [
+    (tincture.Color(100, 100, 100), 2.0, tincture.Color(138, 138, 138)),
+    (tincture.Color(255, 255, 255, 40), 3.0, tincture.Color(255, 255, 255, 40)),
+    (tincture.Color(90, 40, 10), 1.0, tincture.Color(90, 40, 10)),
+    (tincture.Color(90, 40, 10), 0.0, tincture.Color(0, 0, 0)),
+])
+def test_color_mul_linear(color1, factor, expected):
+    assert color1.mul(factor, linear=True) == expected
+
+def test_color_blend_linear():
+    blended = tincture.Color.blend(tincture.BlendingMode.Average, tincture.RED, tincture.BLUE, linear=True)
+    assert blended == tincture.Color(188, 0, 188)
+
+@pytest.mark.parametrize("color1,color2,weight,space,hue,expected", [
+    (tincture.RED, tincture.BLUE, 0.5, "srgb", "shorter", tincture.Color(128, 0, 128)),
+    (tincture.RED, tincture.BLUE, 0.5, "linear", "shorter", tincture.Color(188, 0, 188)),
+    (tincture.RED, tincture.BLUE, 0.5, "oklab", "shorter", tincture.Color(140, 83, 162)),
+    (tincture.WHITE, tincture.RED, 0.5, "oklch", "shorter", tincture.Color(255, 161, 145)),
+    (tincture.Color(255, 0, 43), tincture.Color(255, 43, 0), 0.5, "hsl", "shorter", tincture.RED),
+    (tincture.Color(255, 0, 43), tincture.Color(255, 43, 0), 0.5, "hsl", "longer", tincture.CYAN),
+    (tincture.Color(20, 52, 86), tincture.Color(20, 52, 86), 0.3, "lch", "shorter", tincture.Color(20, 52, 86)),
+    (tincture.Color(10, 20, 30, 0), tincture.Color(10, 20, 30, 200), 0.5, "oklab", "shorter", tincture.Color(10, 20, 30, 100)),
+    (tincture.WHITE, tincture.BLACK, 0.0, "xyz", "shorter", tincture.WHITE),
+    (tincture.WHITE, tincture.BLACK, 1.0, "hsv", "shorter", tincture.BLACK),
+])
+def test_color_mix(color1, color2, weight, space, hue, expected):
+    assert expected.approx_equal(color1.mix(color2, weight, space, hue), 1, True)
+
+@pytest.mark.parametrize("weight,space,hue", [
+    (1.5, "oklab", "shorter"),
+    (0.5, "cmyk", "shorter"),
+    (0.5, "oklch", "sideways"),
+])
+def test_color_mix_invalid(weight, space, hue):
+    with pytest.raises(ValueError):
+        tincture.RED.mix(tincture.BLUE, weight, space, hue)
+
+@pytest.mark.parametrize("operation,expected", [
+    (lambda color: color.tint_with_white(0.0), tincture.Color(200, 30, 60, 77)),
+    (lambda color: color.tint_with_white(0.5), tincture.Color(228, 143, 158, 77)),
+    (lambda color: color.tint_with_white(1.0), tincture.Color(255, 255, 255, 77)),
+    (lambda color: color.tint_with_white(0.5, "oklch"), tincture.Color(237, 154, 154, 77)),
+    (lambda color: color.shade_with_black(0.25), tincture.Color(150, 23, 45, 77)),
+    (lambda color: color.shade_with_black(0.5, space="oklab"), tincture.Color(76, 5, 17, 77)),
+    (lambda color: color.tone_with_gray(0.5), tincture.Color(164, 79, 94, 77)),
+    (lambda color: color.tone_with_gray(1.0), tincture.Color(128, 128, 128, 77)),
+    (lambda color: color.tint_with_white(1.5), ValueError),
+    (lambda color: color.shade_with_black(-0.1), ValueError),
+    (lambda color: color.tone_with_gray(0.5, "cmyk"), ValueError),
+])
+def test_color_tint_shade_tone(operation, expected):
+    color = tincture.Color(200, 30, 60, 77)
+    if expected is ValueError:
+        with pytest.raises(ValueError):
+            operation(color)
+        return
+    assert operation(color) == expected
+
+@pytest.mark.parametrize("value,min,max,scheme,expected", [
+    (0.5, 0.0, 1.0, "turbo", tincture.colormaps.turbo.sample(0.5)),
+    (50.0, 0.0, 100.0, "VIRIDIS", tincture.colormaps.viridis.sample(0.5)),
+    (-5.0, 0.0, 1.0, "turbo", tincture.colormaps.turbo.sample(0.0)),
+    (7.0, -1.0, 1.0, "magma", tincture.colormaps.magma.sample(1.0)),
+    (0.0, 0.0, 1.0, "blackbody", tincture.Color(11, 0, 0)),
+    (30.0, 0.0, 100.0, "blackbody", tincture.Color(212, 0, 0)),
+    (1.0, 0.0, 1.0, "blackbody", tincture.WHITE),
+    (0.5, 0.0, 1.0, "rainbow", ValueError),
+    (0.5, 1.0, 1.0, "turbo", ValueError),
+    (float("nan"), 0.0, 1.0, "turbo", ValueError),
+])
+def test_color_from_heat(value, min, max, scheme, expected):
+    if expected is ValueError:
+        with pytest.raises(ValueError):
+            tincture.Color.from_heat(value, min, max, scheme)
+        return
+    assert tincture.Color.from_heat(value, min, max, scheme) == expected
+
+def test_color_from_heat_default():
+    assert tincture.Color.from_heat(0.25) == tincture.colormaps.turbo.sample(0.25)
+
+@pytest.mark.parametrize("value,mid,space,expected", [
+    (0.0, None, "oklab", tincture.Color(20, 30, 90)),
+    (1.0, None, "oklab", tincture.Color(250, 220, 120)),
+    (0.5, None, "oklab", tincture.Color(124, 124, 118)),
+    (0.5, tincture.Color(220, 60, 90), "oklab", tincture.Color(220, 60, 90)),
+    (0.25, tincture.Color(220, 60, 90), "srgb", tincture.Color(120, 45, 90)),
+    (1.5, None, "oklab", ValueError),
+    (0.5, None, "cmyk", ValueError),
+])
+def test_color_duotone(value, mid, space, expected):
+    dark, light = tincture.Color(20, 30, 90), tincture.Color(250, 220, 120)
+    if expected is ValueError:
+        with pytest.raises(ValueError):
+            tincture.Color.duotone(value, dark, light, mid, space)
+        return
+    assert tincture.Color.duotone(value, dark, light, mid, space) == expected
+    assert tincture.Gradient.duotone(dark, light, mid, space).sample(value) == expected
+
+@pytest.mark.parametrize("color,amount,expected", [
+    (tincture.Color(120, 140, 160), 0.0, tincture.Color(120, 140, 160)),
+    (tincture.Color(120, 140, 160), 0.5, tincture.Color(129, 139, 149)),
+    (tincture.Color(120, 140, 160), 1.0, tincture.Color(138, 138, 138)),
+    (tincture.Color(200, 40, 40, 9), 0.5, tincture.Color(161, 80, 80, 9)),
+    (tincture.Color(200, 40, 40, 9), 1.0, tincture.Color(104, 104, 104, 9)),
+    (tincture.Color(200, 40, 40), 1.5, ValueError),
+    (tincture.Color(200, 40, 40), -0.5, ValueError),
+])
+def test_color_neutralize(color, amount, expected):
+    if expected is ValueError:
+        with pytest.raises(ValueError):
+            color.neutralize(amount)
+        return
+    assert color.neutralize(amount) == expected
+
+def test_color_neutralize_keeps_luminance():
+    for color in (tincture.Color(200, 40, 40), tincture.Color(40, 160, 60), tincture.Color(30, 60, 220)):
+        for amount in (0.25, 0.5, 1.0):
+            assert color.neutralize(amount).get_luminance() == pytest.approx(color.get_luminance(), abs=0.005)
+    # Mixing with middle gray instead changes how dark blue reads against white
+    blue = tincture.Color(30, 60, 220)
+    assert abs(blue.tone_with_gray(1.0).get_luminance() - blue.get_luminance()) > 0.05
+
+@pytest.mark.parametrize("colors,weights,space,expected", [
+    ([tincture.RED, tincture.BLUE], None, "srgb", tincture.Color(128, 0, 128)),
+    ([tincture.RED, tincture.BLUE, tincture.GREEN], [1, 0, 0], "oklab", tincture.RED),
+    ([tincture.Color(255, 0, 43), tincture.Color(255, 43, 0)], None, "hsl", tincture.RED),
+    ([tincture.RED, tincture.BLUE], [3, 1], "linear", tincture.Color(225, 0, 137)),
+    ([tincture.WHITE, tincture.BLACK], None, "oklch", tincture.Color(99, 99, 99)),
+    ([tincture.Color(10, 20, 30, 0), tincture.Color(10, 20, 30, 255)], None, "oklab", tincture.Color(10, 20, 30, 128)),
+    ([tincture.TEAL], None, "lab", tincture.TEAL),
+])
+def test_color_average(colors, weights, space, expected):
+    assert expected.approx_equal(tincture.Color.average(colors, weights, space), 1, True)
+
+@pytest.mark.parametrize("colors,weights", [
+    ([], None),
+    ([tincture.RED], [1, 2]),
+    ([tincture.RED, tincture.BLUE], [1, -1]),
+    ([tincture.RED, tincture.BLUE], [0, 0]),
+    ([tincture.RED, tincture.BLUE], [1, float("nan")]),
+    ([tincture.RED, tincture.BLUE], [float("inf"), 1]),
+    ([tincture.RED, tincture.BLUE], [3e38, 3e38]),
+])
+def test_color_average_invalid(colors, weights):
+    with pytest.raises(ValueError):
+        tincture.Color.average(colors, weights)
+
+@pytest.mark.parametrize("color1,color2,t,space,expected", [
+    (tincture.RED, tincture.BLUE, 0.5, "srgb", tincture.Color(128, 0, 128)),
+    (tincture.BLACK, tincture.WHITE, 0.5, "linear", tincture.Color(188, 188, 188)),
+    (tincture.RED, tincture.BLUE, 0.5, "oklab", tincture.Color(140, 83, 162)),
+    (tincture.RED, tincture.BLUE, 0.5, "hsv", tincture.MAGENTA),
+    (tincture.Color(121, 211, 32), tincture.Color(64, 92, 41), 0.5, "srgb", tincture.Color(92, 151, 36)),
+    (tincture.Color(20, 52, 86), tincture.Color(20, 52, 86), 0.5, "lab", tincture.Color(20, 52, 86)),
+    (tincture.GREEN, tincture.PURPLE, 0.0, "oklch", tincture.GREEN),
+    (tincture.GREEN, tincture.PURPLE, 1.0, "oklch", tincture.PURPLE),
+])
+def test_color_lerp(color1, color2, t, space, expected):
+    result = tincture.Color.lerp(color1, color2, t, space)
+    assert expected.approx_equal(result, 1, True)
+    assert result == color1.mix(color2, t, space)
+
+@pytest.mark.parametrize("hue_strategy,expected_forward,expected_backward", [
+    ("shorter", tincture.RED, tincture.RED),
+    ("longer", tincture.CYAN, tincture.CYAN),
+    ("increasing", tincture.RED, tincture.CYAN),
+    ("decreasing", tincture.CYAN, tincture.RED),
+])
+def test_color_lerp_hue_strategy(hue_strategy, expected_forward, expected_backward):
+    hue_350 = tincture.Color(255, 0, 43)
+    hue_10 = tincture.Color(255, 43, 0)
+    assert tincture.Color.lerp(hue_350, hue_10, 0.5, "hsl", hue_strategy) == expected_forward
+    assert tincture.Color.lerp(hue_10, hue_350, 0.5, "hsl", hue_strategy) == expected_backward
+
+@pytest.mark.parametrize("hue_strategy,expected", [
+    ("shorter", tincture.Color(255, 28, 28)),
+    ("longer", tincture.Color(0, 172, 217)),
+])
+def test_color_clerp_hue_strategy(hue_strategy, expected):
+    result = tincture.Color.clerp(tincture.Color(255, 0, 43), tincture.Color(255, 43, 0), 0.5, hue_strategy)
+    color_cloned = tincture.Color(255, 0, 43)
+    color_cloned.clerp_inplace(tincture.Color(255, 43, 0), 0.5, hue_strategy)
+    assert expected.approx_equal(result, 1, True)
+    assert result == color_cloned
+
+@pytest.mark.parametrize("colors,t,space,expected", [
+    ([tincture.RED, tincture.GREEN, tincture.BLUE], 0.0, "srgb", tincture.RED),
+    ([tincture.RED, tincture.GREEN, tincture.BLUE], 1.0, "srgb", tincture.BLUE),
+    ([tincture.RED, tincture.GREEN, tincture.BLUE], 0.5, "srgb", tincture.Color(64, 128, 64)),
+    ([tincture.BLACK, tincture.WHITE], 0.5, "srgb", tincture.Color(128, 128, 128)),
+    ([tincture.Color(0, 0, 0, 0), tincture.Color(0, 0, 0, 255), tincture.Color(0, 0, 0, 0)], 0.5, "srgb", tincture.Color(0, 0, 0, 128)),
+    ([tincture.TEAL], 0.3, "oklab", tincture.TEAL),
+])
+def test_color_bezier(colors, t, space, expected):
+    assert tincture.Color.bezier(colors, t, space) == expected
+
+@pytest.mark.parametrize("colors,t,space", [
+    ([], 0.5, "oklab"),
+    ([tincture.RED, tincture.BLUE], 1.5, "oklab"),
+    ([tincture.RED, tincture.BLUE], 0.5, "cmyk"),
+])
+def test_color_bezier_invalid(colors, t, space):
+    with pytest.raises(ValueError):
+        tincture.Color.bezier(colors, t, space)
+
+@pytest.mark.parametrize("u,v,space,expected", [
+    (0.0, 0.0, "srgb", tincture.RED),
+    (1.0, 0.0, "srgb", tincture.GREEN),
+    (0.0, 1.0, "srgb", tincture.BLUE),
+    (1.0, 1.0, "srgb", tincture.WHITE),
+    (0.5, 0.0, "srgb", tincture.Color(128, 128, 0)),
+    (0.5, 0.5, "srgb", tincture.Color(128, 128, 128)),
+    (0.0, 0.5, "hsv", tincture.MAGENTA),
+    (0.5, 0.5, "oklab", tincture.Color(163, 172, 171)),
+])
+def test_color_bilerp(u, v, space, expected):
+    result = tincture.Color.bilerp(tincture.RED, tincture.GREEN, tincture.BLUE, tincture.WHITE, u, v, space)
+    assert all(abs(channel - expected_channel) <= 1 for channel, expected_channel in zip(result.to_rgba_list(), expected.to_rgba_list()))
+
+def test_color_bilerp_invalid():
+    with pytest.raises(ValueError):
+        tincture.Color.bilerp(tincture.RED, tincture.GREEN, tincture.BLUE, tincture.WHITE, 1.5, 0.5)
+    with pytest.raises(ValueError):
+        tincture.Color.bilerp(tincture.RED, tincture.GREEN, tincture.BLUE, tincture.WHITE, 0.5, 0.5, "cmyk")
+
+def test_color_inplace_operators():
+    color = tincture.Color(10, 20, 30, 40)
+    alias = color
+    color += tincture.Color(1, 2, 3, 4)
+    assert alias is color and alias == tincture.Color(11, 22, 33, 44)
+    color -= tincture.Color(1, 2, 3, 4)
+    assert alias == tincture.Color(10, 20, 30, 40)
+    color *= 2
+    assert alias == tincture.Color(20, 40, 60, 80)
+    color /= 4
+    assert alias == tincture.Color(5, 10, 15, 20)
+    with pytest.raises(ZeroDivisionError):
+        color /= 0
+    assert alias == tincture.Color(5, 10, 15, 20)
+
+@pytest.mark.parametrize("method,argument,expected", [
+    ("add_inplace", tincture.Color(1, 2, 3, 4), tincture.Color(11, 22, 33, 40)),
+    ("sub_inplace", tincture.Color(1, 2, 3, 4), tincture.Color(9, 18, 27, 40)),
+    ("mul_inplace", 2, tincture.Color(20, 40, 60, 40)),
+    ("div_inplace", 2, tincture.Color(5, 10, 15, 40)),
+])
+def test_color_named_inplace(method, argument, expected):
+    color = tincture.Color(10, 20, 30, 40)
+    assert getattr(color, method)(argument) is None
+    assert color == expected
+
+@pytest.mark.parametrize("other,expected", [
+    ((0, 20, 0), tincture.Color(10, 40, 30, 40)),
+    ([1, 2, 3, 4], tincture.Color(11, 22, 33, 44)),
+    ((-20, 300, 0), tincture.Color(0, 255, 30, 40)),
+])
+def test_color_add_channels(other, expected):
+    color = tincture.Color(10, 20, 30, 40)
+    assert color + other == expected
+    assert other + color == expected
+
+@pytest.mark.parametrize("other,expected", [
+    ((5, 5, 5), tincture.Color(5, 15, 25, 40)),
+    ([20, 0, 0, 50], tincture.Color(0, 20, 30, 0)),
+])
+def test_color_sub_channels(other, expected):
+    assert tincture.Color(10, 20, 30, 40) - other == expected
+
+@pytest.mark.parametrize("factors,expected", [
+    ((1, 0.5, 2), tincture.Color(10, 10, 60, 40)),
+    ([2, 2, 2, 0.5], tincture.Color(20, 40, 60, 20)),
+    (2, tincture.Color(20, 40, 60, 80)),
+])
+def test_color_mul_channels(factors, expected):
+    color = tincture.Color(10, 20, 30, 40)
+    assert color * factors == expected
+    assert factors * color == expected
+
+@pytest.mark.parametrize("operation", [
+    lambda color: color + (1, 2),
+    lambda color: (1, 2, 3, 4, 5) + color,
+    lambda color: color - [1] * 5,
+    lambda color: color * [1, 2],
+    lambda color: [1, 2] * color,
+    lambda color: color * (),
+    lambda color: color.add((1, 2), True),
+    lambda color: color.sub([1, 2]),
+])
+def test_color_channel_operators_wrong_length(operation):
+    with pytest.raises(ValueError):
+        operation(tincture.Color(0, 0, 0, 3))
+
+def test_color_channel_operators_wrong_length_inplace():
+    color = tincture.Color(10, 20, 30, 40)
+    with pytest.raises(ValueError):
+        color += (1, 2)
+    with pytest.raises(ValueError):
+        color *= [1, 2]
+    assert color == tincture.Color(10, 20, 30, 40)
+
+def test_color_channel_operators_invalid():
+    color = tincture.Color(10, 20, 30, 40)
+    with pytest.raises(TypeError):
+        color * None
+    with pytest.raises(TypeError):
+        color * ["a", "b", "c"]
+    with pytest.raises(TypeError):
+        color + "abc"
+    assert color.add((1, 1, 1, 1)) == tincture.Color(11, 21, 31, 40)
+    assert color.add((1, 1, 1, 1), True) == tincture.Color(11, 21, 31, 41)
+    color += (0, 0, 5)
+    assert color == tincture.Color(10, 20, 35, 40)
@@ -0,0 +1,123 @@
+import array
+
+import pytest
+import tincture
+
+def create_array():
+    return tincture.ColorArray([tincture.RED, tincture.GREEN, tincture.BLUE, tincture.Color(10, 20, 30, 40)])
+
+def test_array_construction():
+    array = create_array()
+    assert len(array) == 4
+    assert array.to_list() == [tincture.RED, tincture.GREEN, tincture.BLUE, tincture.Color(10, 20, 30, 40)]
+    assert array.to_bytes()[-4:] == bytes([10, 20, 30, 40])
+    assert tincture.ColorArray(array.to_bytes()) == array
+    assert len(tincture.ColorArray()) == 0
+    with pytest.raises(ValueError):
+        tincture.ColorArray(bytes(5))
+
+@pytest.mark.parametrize("index,expected", [
+    (0, tincture.RED),
+    (-1, tincture.Color(10, 20, 30, 40)),
+    (slice(1, 3), tincture.ColorArray([tincture.GREEN, tincture.BLUE])),
+    (slice(None, None, -2), tincture.ColorArray([tincture.Color(10, 20, 30, 40), tincture.GREEN])),
+])
+def test_array_getitem(index, expected):
+    assert create_array()[index] == expected
+
+def test_array_setitem():
+    array = create_array()
+    array[-1] = tincture.WHITE
+    assert list(array) == [tincture.RED, tincture.GREEN, tincture.BLUE, tincture.WHITE]
+    with pytest.raises(IndexError):
+        array[4] = tincture.WHITE
+
+def test_array_arithmetic():
+    array = tincture.ColorArray([tincture.Color(100, 200, 50, 128), tincture.Color(10, 20, 30)])
+    assert (array + tincture.Color(100, 100, 100, 100)).to_list() == [tincture.Color(200, 255, 150, 128), tincture.Color(110, 120, 130)]
+    assert (array - array[::-1]).to_list() == [tincture.Color(90, 180, 20, 128), tincture.Color(0, 0, 0)]
+    assert (array * 0.5).to_list() == [tincture.Color(50, 100, 25, 128), tincture.Color(5, 10, 15)]
+    assert (array * tincture.Color(2, 1, 0)).to_list() == [tincture.Color(200, 200, 0, 128), tincture.Color(20, 20, 0)]
+    with pytest.raises(ValueError):
+        array + create_array()
+
+def test_array_lerp():
+    array = tincture.ColorArray([tincture.BLACK, tincture.WHITE])
+    assert array.lerp(tincture.WHITE, 0.5).to_list() == [tincture.Color.lerp(tincture.BLACK, tincture.WHITE, 0.5), tincture.WHITE]
+    assert array.lerp(array[::-1], 1.0) == array[::-1]
+    assert array.lerp(tincture.RED, 0.25, "oklch")[0] == tincture.Color.lerp(tincture.BLACK, tincture.RED, 0.25, "oklch")
+
+def test_array_components():
+    array = create_array()
+    components = array.to_components("oklab")
+    assert len(components) == 4
+    assert abs(components[3][3] - 40 / 255) < 1e-6
+    assert tincture.ColorArray.from_components(components, "oklab") == array
+    assert tincture.ColorArray.from_components([(1.0, 0.0, 0.0, 1.0)], "srgb")[0] == tincture.RED
+    with pytest.raises(ValueError):
+        array.to_components("cmy")
+
+def test_array_delta_e():
+    array = tincture.ColorArray([tincture.RED, tincture.Color(254, 1, 0), tincture.BLUE])
+    distances = array.delta_e(tincture.RED)
+    assert distances[0] == 0.0
+    assert distances[1] < 1.0 < distances[2]
+    with pytest.raises(ValueError):
+        array.delta_e(tincture.RED, "euclid")
+
+def test_array_apply_rules():
+    array = tincture.ColorArray([tincture.BLUE, tincture.RED, tincture.Color(0, 0, 255, 128)])
+    recolored = array.apply_rules([{"if": {"hue": [200, 260]}, "then": {"shift_hue": -30, "saturate": 0.2}}])
+    assert recolored.to_list() == [tincture.Color(0, 128, 255), tincture.RED, tincture.Color(0, 128, 255, 128)]
+    assert array.apply_rules([{"if": {"hue": [330, 30]}, "then": {"replace": "#00ff00"}}])[1] == tincture.GREEN
+    assert array.apply_rules([{"if": {"alpha": [0.0, 0.9]}, "then": {"set_alpha": 1.0}}])[2] == tincture.BLUE
+    assert array.apply_rules([{"then": {"darken": 1.0}}]).to_list() == [tincture.BLACK, tincture.BLACK, tincture.Color(0, 0, 0, 128)]
+
+def test_array_apply_rules_order():
+    array = tincture.ColorArray([tincture.RED])
+    rules = [{"if": {"hue": [0, 10]}, "then": {"shift_hue": 120}}, {"if": {"hue": [110, 130]}, "then": {"replace": tincture.BLUE}}]
+    assert array.apply_rules(rules)[0] == tincture.BLUE
+    assert array.apply_rules(rules[::-1])[0] == tincture.GREEN
+
+@pytest.mark.parametrize("rule", [
+    {"if": {"hue": [200, 260]}},
+    {"if": {"chroma": [0, 1]}, "then": {"lighten": 0.1}},
+    {"then": {"recolor": 0.1}},
+    {"when": {}, "then": {"lighten": 0.1}},
+    {"if": {"lightness": [0.8, 0.2]}, "then": {"lighten": 0.1}},
+    {"if": {"hue": [0, float("nan")]}, "then": {"lighten": 0.1}},
+    {"then": {"saturate": float("inf")}},
+    {"then": {"set_alpha": 2.0}},
+    {"then": {"replace": "not a color"}},
+])
+def test_array_apply_rules_invalid(rule):
+    with pytest.raises(ValueError):
+        create_array().apply_rules([rule])
+
+def test_array_repr():
+    assert repr(tincture.ColorArray([tincture.RED])) == "ColorArray([Color(255, 0, 0, 255)])"
+    long_repr = repr(tincture.ColorArray([tincture.BLACK] * 10))
+    assert long_repr == "ColorArray([" + ", ".join(["Color(0, 0, 0, 255)"] * 3 + ["..."] + ["Color(0, 0, 0, 255)"] * 3) + "])"
+
+def test_array_buffer_protocol():
+    view = memoryview(create_array())
+    assert view.shape == (4, 4)
+    assert view.strides == (4, 1)
+    assert view.format == "B"
+    assert view.readonly
+    assert view.tobytes() == create_array().to_bytes()
+    assert view[3, 2] == 30
+    assert bytes(tincture.ColorArray()) == b""
+
+def test_array_float_buffer():
+    values = array.array("f", [1.0, 0.0, 0.5, 1.0, -1.0, 2.0, 0.2, 0.0])
+    assert tincture.ColorArray(memoryview(values)).to_list() == [
+        tincture.Color(255, 0, 128, 255),
+        tincture.Color(0, 255, 51, 0),
+    ]
+    with pytest.raises(ValueError):
+        tincture.ColorArray(array.array("f", [0.0] * 6))
+
+def test_array_from_memoryview_of_array():
+    colors = create_array()
+    assert tincture.ColorArray(memoryview(colors)) == colors
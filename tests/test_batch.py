@@ -0,0 +1,161 @@
+import array
+import random
+import struct
+
+import pytest
+import tincture
+
+pixels = bytes([255, 0, 0, 255, 0, 128, 255, 64, 12, 34, 56, 0])
+colors = [tincture.Color(255, 0, 0, 255), tincture.Color(0, 128, 255, 64), tincture.Color(12, 34, 56, 0)]
+
+@pytest.mark.parametrize("space,to_space,from_space", [
+    ("linear", tincture.batch.to_linear, tincture.batch.from_linear),
+    ("oklab", tincture.batch.to_oklab, tincture.batch.from_oklab),
+    ("oklch", tincture.batch.to_oklch, tincture.batch.from_oklch),
+    ("hsv", tincture.batch.to_hsv, tincture.batch.from_hsv),
+    ("hsl", tincture.batch.to_hsl, tincture.batch.from_hsl),
+    ("lab", tincture.batch.to_lab, tincture.batch.from_lab),
+    ("xyz", tincture.batch.to_xyz, tincture.batch.from_xyz),
+])
+def test_batch_round_trip(space, to_space, from_space):
+    components = to_space(pixels)
+    assert len(components) == len(pixels) * 4
+    assert components == tincture.batch.to_components(pixels, space)
+    expected = tincture.ColorArray(colors).to_components(space)
+    values = struct.unpack(f"={len(colors) * 4}f", components)
+    for index, color in enumerate(expected):
+        for first, second in zip(values[index * 4:index * 4 + 4], color):
+            assert abs(first - second) < 1e-6
+    assert from_space(memoryview(components).cast("f")) == pixels
+    assert tincture.batch.from_components(array.array("f", values), space) == pixels
+
+def test_batch_accepts_color_arrays():
+    assert tincture.batch.to_oklab(tincture.ColorArray(colors)) == tincture.batch.to_oklab(pixels)
+
+@pytest.mark.parametrize("call", [
+    lambda: tincture.batch.to_oklab(bytes(5)),
+    lambda: tincture.batch.from_oklab(array.array("f", [0.0] * 5)),
+    lambda: tincture.batch.to_components(pixels, "cmyk"),
+    lambda: tincture.batch.hue_rotate(pixels, 10, "hsv"),
+    lambda: tincture.batch.hue_rotate(pixels, float("nan")),
+    lambda: tincture.batch.posterize(pixels, 1),
+    lambda: tincture.batch.exposure(pixels, float("inf")),
+    lambda: tincture.batch.vibrance(pixels, float("nan")),
+    lambda: tincture.batch.brightness(pixels, float("nan")),
+    lambda: tincture.batch.contrast(pixels, float("inf")),
+    lambda: tincture.batch.saturate(pixels, float("nan")),
+    lambda: tincture.batch.gamma(pixels, 0.0),
+    lambda: tincture.batch.sepia(pixels, float("nan")),
+    lambda: tincture.batch.levels(pixels, 100, 50),
+    lambda: tincture.batch.swizzle(pixels, "rgbq"),
+    lambda: tincture.batch.white_balance(pixels, tincture.Color(0, 10, 10)),
+    lambda: tincture.batch.white_balance(bytes([0, 50, 50, 255])),
+    lambda: tincture.batch.duotone(pixels, tincture.BLACK, tincture.WHITE, space="cmyk"),
+])
+def test_batch_invalid(call):
+    with pytest.raises(ValueError):
+        call()
+
+@pytest.mark.parametrize("space", ["linear", "oklab", "lab", "xyz"])
+def test_batch_transfer_round_trip(space):
+    levels = bytes(value for level in range(256) for value in (level, 255 - level, level // 2, 255))
+    components = tincture.batch.to_components(levels, space)
+    assert tincture.batch.from_components(memoryview(components).cast("f"), space) == levels
+
+@pytest.mark.parametrize("space", ["linear", "oklab", "oklch", "lab", "lch", "xyz", "display_p3", "rec2020"])
+@pytest.mark.parametrize("count", [1, 3, 4, 7])
+def test_batch_from_components_matches_colors(space, count):
+    rng = random.Random(count)
+    components = [(rng.uniform(-0.5, 1.5), rng.uniform(-0.5, 1.5), rng.uniform(-0.5, 1.5), rng.random()) for _ in range(count)]
+    values = array.array("f", [value for component in components for value in component])
+    pixels = tincture.batch.from_components(values, space)
+    assert tincture.ColorArray(pixels).to_list() == tincture.ColorArray.from_components(components, space).to_list()
+
+@pytest.mark.parametrize("adjust,expected", [
+    (lambda buffer, **options: tincture.batch.brightness(buffer, 0.5, **options), [color.brightness(0.5) for color in colors]),
+    (lambda buffer, **options: tincture.batch.grayscale(buffer, **options), [color.grayscale() for color in colors]),
+    (lambda buffer, **options: tincture.batch.duotone(buffer, tincture.Color(20, 30, 90), tincture.Color(250, 220, 120), **options), [tincture.Color.duotone(color.grayscale().r / 255, tincture.Color(20, 30, 90), tincture.Color(250, 220, 120)).with_alpha(color.a) for color in colors]),
+    (lambda buffer, **options: tincture.batch.duotone(buffer, tincture.BLACK, tincture.WHITE, tincture.RED, "srgb", **options), [tincture.Color.duotone(color.grayscale().r / 255, tincture.BLACK, tincture.WHITE, tincture.RED, "srgb").with_alpha(color.a) for color in colors]),
+    (lambda buffer, **options: tincture.batch.hue_rotate(buffer, 120, **options), [tincture.Color(0, 255, 0, 255), tincture.Color(255, 0, 128, 64), tincture.Color(56, 12, 34, 0)]),
+    (lambda buffer, **options: tincture.batch.hue_rotate(buffer, 75, "oklch", **options), [color.hue_rotate(75, "oklch") for color in colors]),
+    (lambda buffer, **options: tincture.batch.hue_rotate(buffer, 75, space="matrix", **options), [color.hue_rotate(75, "matrix") for color in colors]),
+    (lambda buffer, **options: tincture.batch.vibrance(buffer, 0.6, **options), [color.vibrance(0.6) for color in colors]),
+    (lambda buffer, **options: tincture.batch.exposure(buffer, 0.5, **options), [color.exposure(0.5) for color in colors]),
+    (lambda buffer, **options: tincture.batch.gamma(buffer, 1.8, **options), [color.gamma(1.8) for color in colors]),
+    (lambda buffer, **options: tincture.batch.levels(buffer, 20, 230, 1.4, **options), [color.levels(20, 230, 1.4) for color in colors]),
+    (lambda buffer, **options: tincture.batch.levels(buffer, 20, 230, luminance=True, **options), [color.levels(20, 230, luminance=True) for color in colors]),
+    (lambda buffer, **options: tincture.batch.white_balance(buffer, tincture.Color(255, 230, 200), **options), [color.white_balance(tincture.Color(255, 230, 200)) for color in colors]),
+    (lambda buffer, **options: tincture.batch.swizzle(buffer, "bgra", **options), [color.swizzle("bgra") for color in colors]),
+    (lambda buffer, **options: tincture.batch.sepia(buffer, 0.7, **options), [color.sepia(0.7) for color in colors]),
+    (lambda buffer, **options: tincture.batch.posterize(buffer, 4, **options), [color.posterize(4) for color in colors]),
+    (lambda buffer, **options: tincture.batch.solarize(buffer, **options), [color.solarize() for color in colors]),
+    (lambda buffer, **options: tincture.batch.threshold(buffer, 30, **options), [color.threshold(30) for color in colors]),
+    (lambda buffer, **options: tincture.batch.color_matrix(buffer, tincture.ColorMatrix.sepia(), **options), [tincture.ColorMatrix.sepia().apply(color) for color in colors]),
+    (lambda buffer, **options: tincture.batch.saturate(buffer, 0.4, **options), [color.saturate(0.4) for color in colors]),
+    (lambda buffer, **options: tincture.batch.saturate(buffer, -1.0, **options), [tincture.Color(255, 255, 255, 255), tincture.Color(255, 255, 255, 64), tincture.Color(56, 56, 56, 0)]),
+])
+def test_batch_adjustments(adjust, expected):
+    assert tincture.ColorArray(adjust(pixels)).to_list() == expected
+    buffer = bytearray(pixels)
+    assert adjust(buffer, in_place=True) is None
+    assert tincture.ColorArray(buffer).to_list() == expected
+
+def test_batch_contrast():
+    expected = [tincture.Color(color.r, color.g, color.b, color.a) for color in colors]
+    for color in expected:
+        color.contrast(0.5)
+    assert tincture.ColorArray(tincture.batch.contrast(pixels, 0.5)).to_list() == expected
+
+def test_batch_white_balance_gray_world():
+    # The transparent pixel is skipped, so the average of the other two is the reference white
+    buffer = bytes([200, 100, 50, 255, 180, 120, 60, 255, 10, 10, 10, 0])
+    balanced = tincture.ColorArray(tincture.batch.white_balance(buffer)).to_list()
+    assert balanced == [tincture.Color(137, 118, 120, 255), tincture.Color(123, 142, 141, 255), tincture.Color(4, 14, 36, 0)]
+    gray = bytes([90, 90, 90, 255] * 3)
+    assert tincture.batch.white_balance(gray) == gray
+
+def test_batch_adjust_read_only():
+    with pytest.raises(ValueError):
+        tincture.batch.grayscale(pixels, in_place=True)
+
+def brute_force_dedup(colors, tolerance, metric):
+    representatives = []
+    for color in colors:
+        distances = tincture.ColorArray(representatives).delta_e(color, metric) if representatives else []
+        if not any(distance < tolerance for distance in distances):
+            representatives.append(color)
+    return representatives
+
+@pytest.mark.parametrize("metric,tolerance", [
+    ("ciede2000", 8.0),
+    ("cie76", 12.0),
+    ("oklab", 6.0),
+    ("ciede2000", 0.0),
+])
+def test_batch_dedup(metric, tolerance):
+    generator = random.Random(1600)
+    scraped = [tincture.Color(*(generator.randrange(0, 256, 17) for _ in range(3))) for _ in range(300)]
+    expected = brute_force_dedup(scraped, tolerance, metric)
+    assert tincture.batch.dedup(scraped, tolerance, metric).to_list() == expected
+    assert tincture.Palette(scraped).dedup(tolerance, metric).colors == expected
+
+def test_batch_dedup_merge():
+    merged = tincture.batch.dedup([tincture.Color(100, 100, 100), tincture.Color(102, 102, 102), tincture.BLUE], merge=True)
+    assert merged.to_list() == [tincture.Color(101, 101, 101), tincture.BLUE]
+
+@pytest.mark.parametrize("needle,tolerance,expected", [
+    (tincture.Color(0, 129, 254, 64), 2.0, True),
+    (tincture.Color(0, 140, 255), 2.0, False),
+    (tincture.Color(0, 140, 255), 10.0, True),
+])
+def test_batch_approx_contains(needle, tolerance, expected):
+    assert tincture.batch.approx_contains(pixels, needle, tolerance) == expected
+
+@pytest.mark.parametrize("call", [
+    lambda: tincture.batch.dedup(colors, -1.0),
+    lambda: tincture.batch.dedup(colors, 2.0, "euclid"),
+    lambda: tincture.batch.approx_contains(colors, tincture.RED, float("nan")),
+])
+def test_batch_similarity_invalid(call):
+    with pytest.raises(ValueError):
+        call()
@@ -0,0 +1,30 @@
+import pytest
+import tincture
+
+ideal_ramp = [tincture.Color(value, value, value) for value in (0, 64, 128, 191, 255)]
+warm_ramp = [tincture.Color(min(255, value + 10), value, max(0, value - 10)) for value in (0, 64, 128, 191, 255)]
+dark_ramp = [tincture.Color(value // 2, value // 2, value // 2) for value in (0, 64, 128, 191, 255)]
+
+def test_evaluate_gray_ramp_ideal():
+    report = tincture.evaluate_gray_ramp(ideal_ramp)
+    assert 2.1 < report.gamma < 2.3
+    assert abs(report.cast[0]) < 0.01 and abs(report.cast[1]) < 0.01
+    assert len(report.delta_e) == len(ideal_ramp)
+    assert report.max_delta_e < 0.5
+
+def test_evaluate_gray_ramp_cast():
+    report = tincture.evaluate_gray_ramp(warm_ramp)
+    assert report.cast[0] > 0.5
+    assert report.cast[1] > 3.0
+    assert report.average_delta_e > 3.0
+
+def test_evaluate_gray_ramp_gamma():
+    assert tincture.evaluate_gray_ramp(dark_ramp).gamma > tincture.evaluate_gray_ramp(ideal_ramp).gamma
+
+@pytest.mark.parametrize("colors", [
+    [],
+    [tincture.BLACK, tincture.WHITE],
+])
+def test_evaluate_gray_ramp_invalid(colors):
+    with pytest.raises(ValueError):
+        tincture.evaluate_gray_ramp(colors)
@@ -0,0 +1,93 @@
+import contextlib
+import importlib.util
+import io
+import os
+import tempfile
+
+import pytest
+import tincture
+
+spec = importlib.util.spec_from_file_location(
+    "tincture_cli", os.path.join(os.path.dirname(__file__), "..", "python", "tincture", "__main__.py")
+)
+cli = importlib.util.module_from_spec(spec)
+spec.loader.exec_module(cli)
+
+
+def run(*argv):
+    output = io.StringIO()
+    errors = io.StringIO()
+    with contextlib.redirect_stdout(output), contextlib.redirect_stderr(errors):
+        code = cli.main(list(argv))
+    return code, output.getvalue(), errors.getvalue()
+
+def write_pixels(directory, colors):
+    path = os.path.join(directory, "pixels.rgba")
+    with open(path, "wb") as file:
+        file.write(bytes(channel for color in colors for channel in color))
+    return path
+
+@pytest.mark.parametrize("method", ["kmeans", "median_cut", "octree"])
+def test_cli_palette_extract(method):
+    with tempfile.TemporaryDirectory() as directory:
+        path = write_pixels(directory, [tincture.RED] * 6 + [tincture.BLUE] * 3)
+        code, output, _ = run("palette", "extract", path, "--raw", "--count", "2", "--method", method, "--seed", "1")
+    assert code == 0
+    assert sorted(output.split()) == ["#0000ff", "#ff0000"]
+
+def test_cli_palette_extract_formats():
+    with tempfile.TemporaryDirectory() as directory:
+        path = write_pixels(directory, [tincture.RED, tincture.Color(0, 0, 255, 128)])
+        _, hex_output, _ = run("palette", "extract", path, "--raw", "--method", "octree")
+        _, json_output, _ = run("palette", "extract", path, "--raw", "--method", "octree", "--format", "json")
+        _, gpl_output, _ = run("palette", "extract", path, "--raw", "--method", "octree", "--format", "gpl")
+    assert sorted(hex_output.split()) == ["#0000ff80", "#ff0000ff"]
+    assert tincture.Palette.from_json(json_output) == tincture.Palette.from_hex_text(hex_output)
+    assert gpl_output.startswith("GIMP Palette")
+
+@pytest.mark.parametrize("pixels", [b"", b"\x00\x01\x02"])
+def test_cli_palette_extract_invalid(pixels):
+    with tempfile.TemporaryDirectory() as directory:
+        path = os.path.join(directory, "pixels.rgba")
+        with open(path, "wb") as file:
+            file.write(pixels)
+        code, output, errors = run("palette", "extract", path, "--raw")
+    assert code == 2
+    assert output == ""
+    assert errors.startswith("error:")
+
+def test_cli_gradient_sample():
+    code, output, _ = run("gradient", "sample", "ff0000", "0000ff", "--steps", "3", "--space", "srgb")
+    assert code == 0
+    gradient = tincture.Gradient.from_colors([tincture.RED, tincture.BLUE], "srgb")
+    assert output.split() == [gradient.sample(t).to_hex() for t in (0.0, 0.5, 1.0)]
+
+def test_cli_gradient_sample_colormap():
+    code, output, _ = run("gradient", "sample", "--colormap", "viridis", "--steps", "2", "--to", "rgba")
+    assert code == 0
+    assert output.splitlines() == [str(tincture.colormaps.viridis.sample(t).to_rgba_tuple()) for t in (0.0, 1.0)]
+
+def test_cli_gradient_sample_file():
+    gradient = tincture.Gradient([(0.0, tincture.BLACK), (0.2, tincture.RED), (1.0, tincture.WHITE)], "oklch")
+    with tempfile.TemporaryDirectory() as directory:
+        outputs = []
+        for name, text in [("gradient.txt", gradient.to_text()), ("gradient.json", gradient.to_json())]:
+            path = os.path.join(directory, name)
+            with open(path, "w") as file:
+                file.write(text)
+            code, output, _ = run("gradient", "sample", "--file", path, "--steps", "6")
+            assert code == 0
+            outputs.append(output)
+    assert outputs[0] == outputs[1]
+    assert outputs[0].split() == [gradient.sample(step / 5).to_hex() for step in range(6)]
+
+@pytest.mark.parametrize("argv", [
+    ["gradient", "sample"],
+    ["gradient", "sample", "ff0000", "--colormap", "viridis"],
+    ["gradient", "sample", "ff0000", "0000ff", "--space", "unknown"],
+])
+def test_cli_gradient_sample_invalid(argv):
+    code, output, errors = run(*argv)
+    assert code == 2
+    assert output == ""
+    assert errors.startswith("error:")
@@ -0,0 +1,17 @@
+import pytest
+import tincture
+
+@pytest.mark.parametrize("colormap,start,middle,end", [
+    (tincture.colormaps.viridis, "#440154", "#23908c", "#fde725"),
+    (tincture.colormaps.magma, "#000004", "#b63878", "#fcfdbf"),
+    (tincture.colormaps.inferno, "#000004", "#ba3853", "#fcffa4"),
+    (tincture.colormaps.plasma, "#0d0887", "#cb4779", "#f0f921"),
+    (tincture.colormaps.cividis, "#00204d", "#7d7c76", "#ffea46"),
+    (tincture.colormaps.turbo, "#30123b", "#9cf749", "#7a0403"),
+])
+def test_colormaps(colormap, start, middle, end):
+    assert len(colormap) == 10
+    assert colormap.space == "srgb"
+    assert colormap.sample(0.0) == tincture.Color.from_hex(start)
+    assert colormap.sample(0.5) == tincture.Color.from_hex(middle)
+    assert colormap.sample(1.0) == tincture.Color.from_hex(end)
@@ -1,3 +1,4 @@
+import json
 import pytest
 import tincture
 
@@ -94,3 +95,369 @@ def test_color_hsl(color, expected):
     result = color.to_hsl()
     assert approx_equal_field(result, expected, 0.05)
 
+
+@pytest.mark.parametrize("color,expected", [
+    (tincture.Color(255, 255, 255, 255), tincture.Color(255, 255, 255, 255)),
+    (tincture.Color(200, 100, 50, 0), tincture.Color(0, 0, 0, 0)),
+    (tincture.Color(200, 100, 50, 128), tincture.Color(100, 50, 25, 128)),
+    (tincture.Color(10, 20, 30, 51), tincture.Color(2, 4, 6, 51)),
+])
+def test_color_premultiply(color, expected):
+    assert color.premultiply() == expected
+    assert color.to_bytes(premultiplied=True) == bytes(expected.to_rgba_list())
+    assert expected.unpremultiply().approx_equal(color if color.a != 0 else expected, 2, True)
+
+@pytest.mark.parametrize("color,expected", [
+    (tincture.RED, 0xFF0000FF),
+    (tincture.Color(1, 2, 3, 4), 0x01020304),
+    (tincture.Color(0, 0, 0, 0), 0),
+    (tincture.WHITE, 0xFFFFFFFF),
+])
+def test_color_to_int(color, expected):
+    assert color.to_int() == expected
+    assert color.to_bytes() == expected.to_bytes(4, "big")
+    assert int(color) == expected and hex(color) == hex(expected)
+    assert int(color.freeze()) == expected
+
+def test_color_index_arithmetic():
+    assert tincture.RED + tincture.Color(0, 255, 0, 0) == tincture.Color(255, 255, 0, 255)
+    assert tincture.Color(2, 3, 4) * tincture.Color(5, 6, 7, 1) == tincture.Color(10, 18, 28, 255)
+    assert [0, 1, 2, 3, 4, 5][tincture.Color(0, 0, 0, 5)] == 5
+    assert hex(tincture.RED.freeze()) == "0xff0000ff"
+    # Sequences are channel values for the operators, a color never works as their repeat count
+    with pytest.raises(ValueError):
+        [1, 2] * tincture.Color(0, 0, 0, 2)
+    with pytest.raises(TypeError):
+        ["a"] * tincture.Color(0, 0, 0, 2)
+    with pytest.raises(TypeError):
+        "ab" * tincture.Color(0, 0, 0, 2)
+
+@pytest.mark.parametrize("values,expected", [
+    ({"r": 1, "g": 2, "b": 3, "a": 4}, tincture.Color(1, 2, 3, 4)),
+    ({"red": 1, "green": 2, "blue": 3}, tincture.Color(1, 2, 3)),
+    ({"Red": 1, "g": 2, "BLUE": 3, "alpha": 0}, tincture.Color(1, 2, 3, 0)),
+])
+def test_color_from_dict(values, expected):
+    assert tincture.Color.from_dict(values) == expected
+    assert tincture.Color.from_dict(expected.to_dict()) == expected
+
+def test_color_to_dict():
+    assert tincture.Color(1, 2, 3, 4).to_dict() == {"r": 1, "g": 2, "b": 3, "a": 4}
+
+def test_color_json():
+    color = tincture.Color(1, 2, 3, 4)
+    assert json.loads(color.to_json()) == {"r": 1, "g": 2, "b": 3, "a": 4}
+    assert tincture.Color.from_json(color.to_json()) == color
+    assert tincture.Color.from_json('{"red": 255, "green": 0, "blue": 0}') == tincture.RED
+    assert tincture.Color.from_json('"#0000ff"') == tincture.BLUE
+    with pytest.raises(ValueError):
+        tincture.Color.from_json('{"r": 1}')
+
+@pytest.mark.parametrize("values", [
+    {"r": 1, "g": 2},
+    {"r": 1, "g": 2, "b": 3, "cyan": 4},
+    {"r": 1, "red": 1, "g": 2, "b": 3},
+])
+def test_color_from_dict_invalid(values):
+    with pytest.raises(ValueError):
+        tincture.Color.from_dict(values)
+
+@pytest.mark.parametrize("hex_string,expected", [
+    ("#ff0000", tincture.RED),
+    ("00ff00", tincture.GREEN),
+    ("#0a141e28", tincture.Color(10, 20, 30, 40)),
+    ("FFFFFF", tincture.WHITE),
+    ("#fff", ValueError),
+    ("#gg0000", ValueError),
+])
+def test_color_from_hex(hex_string, expected):
+    if not isinstance(expected, tincture.Color):
+        with pytest.raises(expected):
+            tincture.Color.from_hex(hex_string)
+        return
+    assert tincture.Color.from_hex(hex_string) == expected
+
+@pytest.mark.parametrize("hex_strings,expected", [
+    (["#ff0000", " 00ff00 ", "#0a141e28"], [tincture.RED, tincture.GREEN, tincture.Color(10, 20, 30, 40)]),
+    ("#ff0000\r\n\n  FFFFFF\n", [tincture.RED, tincture.WHITE]),
+    ([], []),
+    (["#ff0000", "#fff"], ValueError),
+    ("#ff0000\n#gg0000", ValueError),
+])
+def test_color_parse_hex_many(hex_strings, expected):
+    if not isinstance(expected, list):
+        with pytest.raises(expected):
+            tincture.Color.parse_hex_many(hex_strings)
+        return
+    assert tincture.Color.parse_hex_many(hex_strings) == expected
+
+def test_color_format_hex_many():
+    colors = [tincture.RED, tincture.Color(1, 2, 3, 4)]
+    assert tincture.Color.format_hex_many(colors) == ["#ff0000", "#010203"]
+    assert tincture.Color.format_hex_many(tincture.ColorArray(colors), True) == ["#ff0000ff", "#01020304"]
+    assert tincture.Color.parse_hex_many(tincture.Color.format_hex_many(colors, True)) == colors
+
+@pytest.mark.parametrize("color,expected", [
+    (tincture.RED, "#ff0000"),
+    (tincture.Color(1, 2, 3, 4), "#010203"),
+    (tincture.BLACK, "#000000"),
+])
+def test_color_to_hex(color, expected):
+    assert color.to_hex(False) == expected
+    assert color.to_hex(True) == expected + f"{color.a:02x}"
+    assert tincture.Color.from_hex(color.to_hex(True)) == color
+
+@pytest.mark.parametrize("spec,expected", [
+    ("hex", "#abcdef"),
+    ("HEX", "#ABCDEF"),
+    ("hex8", "#abcdef80"),
+    ("HEX8", "#ABCDEF80"),
+    ("rgb", "rgb(171, 205, 239)"),
+    ("rgba", "rgba(171, 205, 239, 0.502)"),
+    ("hsl", "hsl(210, 68%, 80.4%)"),
+    ("hsla", "hsla(210, 68%, 80.4%, 0.502)"),
+    ("css", "rgba(171, 205, 239, 0.502)"),
+    ("", "(171 : 205 : 239 : 128)"),
+])
+def test_color_format(spec, expected):
+    color = tincture.Color(171, 205, 239, 128)
+    assert format(color, spec) == expected
+    assert "{:{}}".format(color, spec) == expected
+
+def test_color_format_css_opaque():
+    assert f"{tincture.RED:css}" == "#ff0000"
+    assert f"{tincture.BLACK:hsl}" == "hsl(0, 0%, 0%)"
+    with pytest.raises(ValueError):
+        format(tincture.RED, "cmyk")
+
+@pytest.mark.parametrize("spec,expected", [
+    ("CSS", "#FF0000"),
+    ("RGB", "rgb(255, 0, 0)"),
+    ("HSLA", "hsla(0, 100%, 50%, 1)"),
+])
+def test_color_format_uppercase(spec, expected):
+    assert format(tincture.RED, spec) == expected
+
+@pytest.mark.parametrize("spec", ["Hex", "hEX8", "Css"])
+def test_color_format_mixed_case(spec):
+    with pytest.raises(ValueError):
+        format(tincture.RED, spec)
+
+@pytest.mark.parametrize("color,unity,godot,unreal", [
+    (tincture.RED, (1.0, 0.0, 0.0, 1.0), "Color(1.0000, 0.0000, 0.0000, 1.0000)", "(R=1.000000,G=0.000000,B=0.000000,A=1.000000)"),
+    (tincture.Color(128, 64, 0, 0), (0.21586, 0.05127, 0.0, 0.0), "Color(0.5020, 0.2510, 0.0000, 0.0000)", "(R=0.215861,G=0.051269,B=0.000000,A=0.000000)"),
+])
+def test_color_engine_exports(color, unity, godot, unreal):
+    assert all(abs(value - expected) < 1e-4 for value, expected in zip(color.to_unity(), unity))
+    assert color.to_godot() == godot
+    assert color.to_unreal() == unreal
+
+@pytest.mark.parametrize("color,expected", [
+    (tincture.Color(0, 25, 26, 40), tincture.Color(0, 0, 51, 40)),
+    (tincture.Color(100, 130, 230), tincture.Color(102, 153, 255)),
+    (tincture.Color(255, 204, 153), tincture.Color(255, 204, 153)),
+])
+def test_color_to_web_safe(color, expected):
+    assert color.to_web_safe() == expected
+
+@pytest.mark.parametrize("color,ansi256,ansi16", [
+    (tincture.RED, 196, 9),
+    (tincture.BLACK, 16, 0),
+    (tincture.WHITE, 231, 15),
+    (tincture.Color(128, 128, 128), 244, 8),
+    (tincture.Color(95, 135, 175), 67, 8),
+    (tincture.Color(0, 0, 200), 20, 4),
+])
+def test_color_to_ansi(color, ansi256, ansi16):
+    assert color.to_ansi256() == ansi256
+    assert color.to_ansi16() == ansi16
+
+@pytest.mark.parametrize("index,expected", [
+    (1, tincture.Color(205, 0, 0)),
+    (16, tincture.BLACK),
+    (67, tincture.Color(95, 135, 175)),
+    (231, tincture.WHITE),
+    (232, tincture.Color(8, 8, 8)),
+    (255, tincture.Color(238, 238, 238)),
+])
+def test_color_from_ansi256(index, expected):
+    assert tincture.Color.from_ansi256(index) == expected
+
+def test_color_ansi256_round_trip():
+    assert all(tincture.Color.from_ansi256(index).to_ansi256() == index for index in range(16, 256))
+
+def test_color_ansi_escapes():
+    color = tincture.Color(255, 128, 0, 10)
+    assert color.ansi_fg() == "\x1b[38;2;255;128;0m"
+    assert color.ansi_bg() == "\x1b[48;2;255;128;0m"
+    assert color.paint("warning") == "\x1b[38;2;255;128;0mwarning\x1b[0m"
+    assert color.paint("warning", tincture.BLACK) == "\x1b[38;2;255;128;0m\x1b[48;2;0;0;0mwarning\x1b[0m"
+
+@pytest.mark.parametrize("color,text", [
+    (tincture.RED, "#ff0000"),
+    (tincture.Color(0, 0, 255, 128), "#0000ff80"),
+])
+def test_color_repr_html(color, text):
+    html = color._repr_html_()
+    assert html.startswith("<div") and html.endswith("</div>")
+    assert f"<span>{text}</span>" in html
+    assert f"linear-gradient({text}, {text})" in html
+
+@pytest.mark.parametrize("components,space,method,expected", [
+    ((0.7, 0.4, 30), "oklch", "oklch_chroma", tincture.Color(255, 101, 81)),
+    ((0.7, 0.4, 30), "oklch", "clip", tincture.Color(255, 0, 0)),
+    ((0.5, 0.05, 200), "oklch", "oklch_chroma", tincture.Color(63, 108, 110)),
+    ((0.5, 0.05, 200), "oklch", "clip", tincture.Color(63, 108, 110)),
+    ((1.2, 0.1, 200), "oklch", "oklch_chroma", tincture.Color(255, 255, 255)),
+    ((60, 120, 40), "lch", "OKLCH_CHROMA", tincture.Color(255, 94, 79)),
+    ((0.5, 0.0, 0.0), "oklab", "gamut", ValueError),
+    ((0.5, 0.0, 0.0), "cmyk", "clip", ValueError),
+])
+def test_clamp_to_srgb(components, space, method, expected):
+    if expected is ValueError:
+        with pytest.raises(ValueError):
+            tincture.Color.clamp_to_srgb(components, space, method)
+        return
+    assert tincture.Color.clamp_to_srgb(components, space, method) == expected
+
+def test_clamp_to_srgb_keeps_the_hue():
+    mapped = tincture.Color.clamp_to_srgb((0.6, 0.5, 140), transparency=0.5)
+    assert mapped.a == 128
+    assert abs(tincture.ColorArray([mapped]).to_components("oklch")[0][2] - 140) < 2
+    assert tincture.ColorArray.from_components([(0.6, 0.5, 140, 0.5)], "oklch").to_list() == [mapped]
+
+@pytest.mark.parametrize("components,space,expected", [
+    ((0.628, 0.2577, 29.23), "oklch", [True, True, True]),
+    ((0.7, 0.4, 30), "oklch", [False, False, False]),
+    ((1.0, 0.0, 0.0), "display_p3", [False, True, False]),
+    ((1.0, 0.0, 0.0), "rec2020", [False, False, True]),
+    ((0.9, 0.2, 0.2), "display-p3", [True, True, True]),
+])
+def test_is_in_gamut(components, space, expected):
+    assert [tincture.Color.is_in_gamut(components, space, gamut) for gamut in ["srgb", "display_p3", "rec2020"]] == expected
+
+@pytest.mark.parametrize("call", [
+    lambda: tincture.Color.is_in_gamut((0.5, 0.0, 0.0), "oklab", "adobe_rgb"),
+    lambda: tincture.Color.is_in_gamut((0.5, 0.0, 0.0), "cmyk"),
+])
+def test_is_in_gamut_invalid(call):
+    with pytest.raises(ValueError):
+        call()
+
+@pytest.mark.parametrize("space", ["display_p3", "rec2020"])
+def test_wide_gamut_spaces_round_trip(space):
+    colors = [tincture.Color(255, 0, 0), tincture.Color(10, 200, 30, 7), tincture.Color(1, 2, 3)]
+    components = tincture.ColorArray(colors).to_components(space)
+    assert tincture.ColorArray.from_components(components, space).to_list() == colors
+    assert all(tincture.Color.is_in_gamut(component[:3], space, "srgb") for component in components)
+
+def test_display_p3_components():
+    red = tincture.ColorArray([tincture.Color(255, 0, 0)]).to_components("display_p3")[0]
+    assert [round(component, 3) for component in red] == [0.918, 0.2, 0.139, 1.0]
+
+@pytest.mark.parametrize("color,arguments,expected", [
+    (tincture.Color(255, 255, 255), {}, tincture.Color(255, 252, 221)),
+    (tincture.Color(128, 128, 128), {"method": "cat16"}, tincture.Color(138, 127, 110)),
+    (tincture.Color(100, 150, 200, 9), {"src_white": "d65", "dst_white": "D65"}, tincture.Color(100, 150, 200, 9)),
+    (tincture.Color(100, 150, 200, 9), {"method": "BRADFORD"}, tincture.Color(116, 149, 174, 9)),
+    (tincture.Color(100, 150, 200, 9), {"method": "xyz_scaling"}, tincture.Color(127, 148, 175, 9)),
+    (tincture.Color(255, 255, 255), {"dst_white": "A", "method": "von_kries"}, tincture.Color(255, 234, 133)),
+    (tincture.Color(255, 255, 255), {"dst_white": (0.3457, 0.3585)}, tincture.Color(255, 252, 221)),
+    (tincture.Color(255, 255, 255), {"dst_white": "D93"}, ValueError),
+    (tincture.Color(255, 255, 255), {"dst_white": (0.3, 0.0)}, ValueError),
+    (tincture.Color(255, 255, 255), {"method": "cat02"}, ValueError),
+])
+def test_chromatic_adaptation(color, arguments, expected):
+    if expected is ValueError:
+        with pytest.raises(ValueError):
+            color.adapt(**arguments)
+        return
+    assert color.adapt(**arguments) == expected
+
+@pytest.mark.parametrize("method", ["bradford", "cat16", "von_kries", "xyz_scaling"])
+def test_chromatic_adaptation_round_trip(method):
+    color = tincture.Color(100, 150, 200)
+    assert color.adapt("D65", "D50", method).adapt("D50", "D65", method).approx_equal(color, 1, True)
+
+@pytest.mark.parametrize("illuminant,expected", [
+    ("D65", (95.05, 100.0, 108.9)),
+    ("d50", (96.42, 100.0, 82.53)),
+    ((0.3457, 0.3585), (96.42, 100.0, 82.53)),
+    ("A", (109.85, 100.0, 35.59)),
+    ("D93", ValueError),
+    ((0.5, 0.6), ValueError),
+])
+def test_xyz_illuminant(illuminant, expected):
+    white = tincture.Color(255, 255, 255)
+    if expected is ValueError:
+        with pytest.raises(ValueError):
+            white.to_xyz(illuminant)
+        return
+    assert all(abs(value - target) < 0.05 for value, target in zip(white.to_xyz(illuminant), expected))
+
+@pytest.mark.parametrize("illuminant", ["D65", "D50", "A", "F11", (0.31, 0.32)])
+def test_xyz_and_lab_illuminant_round_trip(illuminant):
+    color = tincture.Color(120, 180, 40, 200)
+    x, y, z, transparency = color.to_xyz(illuminant)
+    assert tincture.Color.from_xyz(x, y, z, transparency, illuminant).approx_equal(color, 1, True)
+    l, a, b, transparency = color.to_lab(illuminant)
+    assert tincture.Color.from_lab(l, a, b, transparency, illuminant).approx_equal(color, 1, True)
+
+def test_lab_illuminant():
+    assert [round(value) for value in tincture.Color(255, 255, 255).to_lab("D50")] == [100, 0, 0, 1]
+    assert [round(value, 1) for value in tincture.Color(200, 30, 60).to_lab()[:3]] == [43.6, 64.1, 28.5]
+    assert [round(value, 1) for value in tincture.Color(200, 30, 60).to_lab("D50")[:3]] == [44.3, 64.4, 30.1]
+    with pytest.raises(ValueError):
+        tincture.Color.from_xyz(96.0, 100.0, 82.0)
+    assert tincture.Color.from_xyz(96.0, 100.0, 82.0, illuminant="D50").approx_equal(tincture.Color(255, 255, 255), 2, True)
+
+@pytest.mark.parametrize("color,conditions,expected", [
+    (tincture.Color(200, 30, 60), ("dark_room", "sunlight"), tincture.Color(226, 69, 86)),
+    (tincture.Color(200, 30, 60), ("sunlight", "dark_room"), tincture.Color(168, 0, 35)),
+    (tincture.Color(128, 128, 128, 9), ("dark_room", "sunlight"), tincture.Color(146, 151, 152, 9)),
+    (tincture.Color(128, 128, 128), ("default", "d50"), tincture.Color(136, 127, 113)),
+    (tincture.Color(255, 255, 255), ("default", "d50"), tincture.Color(255, 253, 226)),
+    (tincture.Color(20, 20, 20), ("default", "dim"), tincture.Color(10, 10, 10)),
+])
+def test_appearance_adaptation(color, conditions, expected):
+    presets = {
+        "default": tincture.ViewingConditions(),
+        "dark_room": tincture.ViewingConditions.dark_room(),
+        "sunlight": tincture.ViewingConditions.sunlight(),
+        "d50": tincture.ViewingConditions(white="D50"),
+        "dim": tincture.ViewingConditions(surround="dim"),
+    }
+    assert color.adapt_appearance(presets[conditions[0]], presets[conditions[1]]) == expected
+
+@pytest.mark.parametrize("conditions", [
+    tincture.ViewingConditions(),
+    tincture.ViewingConditions.sunlight(),
+    tincture.ViewingConditions(1000.0, 50.0, "dark", (0.31, 0.32), True),
+])
+def test_appearance_adaptation_identity(conditions):
+    for color in [tincture.Color(200, 30, 60), tincture.Color(5, 90, 250, 17), tincture.Color(250, 250, 245)]:
+        assert color.adapt_appearance(conditions, conditions).approx_equal(color, 1, True)
+
+def test_appearance_adaptation_round_trip():
+    color = tincture.Color(100, 150, 200)
+    dark, sunlight = tincture.ViewingConditions.dark_room(), tincture.ViewingConditions.sunlight()
+    assert color.adapt_appearance(dark, sunlight).adapt_appearance(sunlight, dark).approx_equal(color, 1, True)
+
+@pytest.mark.parametrize("arguments", [
+    {"adapting_luminance": 0.0},
+    {"adapting_luminance": float("inf")},
+    {"background_luminance": 0.0},
+    {"background_luminance": 150.0},
+    {"surround": "bright"},
+    {"white": "D93"},
+])
+def test_viewing_conditions_invalid(arguments):
+    with pytest.raises(ValueError):
+        tincture.ViewingConditions(**arguments)
+
+def test_viewing_conditions_properties():
+    conditions = tincture.ViewingConditions(100.0, 30.0, "DIM", "D50")
+    assert (conditions.adapting_luminance, conditions.background_luminance) == (100.0, 30.0)
+    assert conditions.surround == "dim" and not conditions.discounting
+    assert [round(value, 4) for value in conditions.white] == [0.9642, 1.0, 0.8252]
+    assert conditions == tincture.ViewingConditions(100.0, 30.0, "dim", "d50")
@@ -0,0 +1,157 @@
+import os
+import struct
+import tempfile
+import pytest
+import tincture
+
+SRGB_PRIMARIES = ((0.64, 0.33), (0.30, 0.60), (0.15, 0.06))
+P3_PRIMARIES = ((0.68, 0.32), (0.265, 0.69), (0.15, 0.06))
+IDENTITY = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]
+
+def test_custom_space_matches_srgb():
+    space = tincture.ColorSpace.from_primaries("custom_srgb", *SRGB_PRIMARIES)
+    color = tincture.Color(200, 30, 60)
+    assert [round(component * 255) for component in space.to_components(color)] == [200, 30, 60]
+    assert space.from_components(space.to_components(color)) == color
+    assert space.from_components((0.2, 0.4, 0.6), 0.5) == tincture.Color(51, 102, 153, 128)
+    assert [round(value, 4) for value in space.matrix[1]] == [0.2127, 0.7152, 0.0722]
+
+def test_custom_space_registry():
+    space = tincture.ColorSpace.from_primaries("custom_p3", *P3_PRIMARIES)
+    space.register()
+    try:
+        assert "custom_p3" in tincture.ColorSpace.registered()
+        colors = tincture.ColorArray([tincture.Color(200, 30, 60), tincture.Color(5, 250, 9, 40)])
+        custom = colors.to_components("CUSTOM_P3")
+        builtin = colors.to_components("display_p3")
+        assert all(abs(a - b) < 1e-3 for row, other in zip(custom, builtin) for a, b in zip(row, other))
+        assert tincture.ColorArray.from_components(custom, "custom_p3").to_list() == colors.to_list()
+        red, blue = tincture.Color(200, 30, 60), tincture.Color(0, 0, 255)
+        assert red.mix(blue, 0.5, "custom_p3") == red.mix(blue, 0.5, "display_p3")
+        gradient = tincture.Gradient([(0.0, red), (1.0, blue)], space="custom_p3")
+        assert gradient.space == "custom_p3"
+        assert gradient.sample(0.5) == tincture.Gradient([(0.0, red), (1.0, blue)], space="display_p3").sample(0.5)
+    finally:
+        assert tincture.ColorSpace.unregister("custom_p3")
+    assert not tincture.ColorSpace.unregister("custom_p3")
+    with pytest.raises(ValueError):
+        tincture.ColorArray([tincture.Color(1, 2, 3)]).to_components("custom_p3")
+
+def test_custom_space_white_point_and_gamma():
+    prophoto = tincture.ColorSpace.from_primaries(
+        "custom_prophoto", (0.7347, 0.2653), (0.1596, 0.8404), (0.0366, 0.0001), white="D50", transfer=1.8
+    )
+    assert [round(component, 4) for component in prophoto.to_components(tincture.Color(255, 255, 255))] == [1, 1, 1]
+    color = tincture.Color(200, 30, 60)
+    assert prophoto.from_components(prophoto.to_components(color)) == color
+    xyz = tincture.ColorSpace.from_matrix("custom_xyz", IDENTITY)
+    assert [round(value, 3) for value in xyz.to_components(tincture.Color(255, 255, 255))] == [0.950, 1.0, 1.089]
+    assert repr(xyz) == 'ColorSpace("custom_xyz")'
+
+@pytest.mark.parametrize("create", [
+    lambda: tincture.ColorSpace.from_primaries("oklab", *SRGB_PRIMARIES),
+    lambda: tincture.ColorSpace.from_primaries("", *SRGB_PRIMARIES),
+    lambda: tincture.ColorSpace.from_primaries("two words", *SRGB_PRIMARIES),
+    lambda: tincture.ColorSpace.from_primaries("flat", (0.1, 0.1), (0.2, 0.2), (0.3, 0.3)),
+    lambda: tincture.ColorSpace.from_primaries("zero", (0.64, 0.0), (0.30, 0.60), (0.15, 0.06)),
+    lambda: tincture.ColorSpace.from_primaries("pq", *SRGB_PRIMARIES, transfer="pq"),
+    lambda: tincture.ColorSpace.from_primaries("negative", *SRGB_PRIMARIES, transfer=-2.2),
+    lambda: tincture.ColorSpace.from_primaries("white", *SRGB_PRIMARIES, white="D93"),
+    lambda: tincture.ColorSpace.from_matrix("singular", [[1, 0, 0], [1, 0, 0], [0, 0, 1]]),
+])
+def test_custom_space_invalid(create):
+    with pytest.raises(ValueError):
+        create()
+
+# The colorants of sRGB adapted to D50, as every sRGB ICC profile stores them
+SRGB_COLORANTS = ((0.4361, 0.2225, 0.0139), (0.3851, 0.7169, 0.0971), (0.1431, 0.0606, 0.7141))
+SRGB_CURVE = ("para", 3, (2.4, 1 / 1.055, 0.055 / 1.055, 1 / 12.92, 0.04045))
+
+def _fixed(*values):
+    return b"".join(struct.pack(">i", round(value * 65536)) for value in values)
+
+def _curve_tag(curve):
+    kind, *arguments = curve
+    if kind == "para":
+        function, parameters = arguments
+        return b"para" + bytes(4) + struct.pack(">HH", function, 0) + _fixed(*parameters)
+    samples = arguments[0]
+    return b"curv" + bytes(4) + struct.pack(">I", len(samples)) + b"".join(struct.pack(">H", sample) for sample in samples)
+
+def _text_tag(description, version):
+    if version == 2:
+        text = description.encode("ascii") + b"\0"
+        return b"desc" + bytes(4) + struct.pack(">I", len(text)) + text + bytes(79)
+    text = description.encode("utf-16-be")
+    return b"mluc" + bytes(4) + struct.pack(">II", 1, 12) + b"enUS" + struct.pack(">II", len(text), 28) + text
+
+def build_icc(curves=(SRGB_CURVE,) * 3, description="Custom sRGB", version=4, colorants=SRGB_COLORANTS):
+    """A minimal matrix/TRC profile, the header only carries what the parser reads"""
+    tags = [(b"rXYZ", b"XYZ " + bytes(4) + _fixed(*colorants[0])),
+            (b"gXYZ", b"XYZ " + bytes(4) + _fixed(*colorants[1])),
+            (b"bXYZ", b"XYZ " + bytes(4) + _fixed(*colorants[2]))]
+    tags += [(name, _curve_tag(curve)) for name, curve in zip((b"rTRC", b"gTRC", b"bTRC"), curves)]
+    if description is not None:
+        tags.append((b"desc", _text_tag(description, version)))
+    offset = 128 + 4 + 12 * len(tags)
+    table, data = struct.pack(">I", len(tags)), b""
+    for name, tag in tags:
+        table += name + struct.pack(">II", offset + len(data), len(tag))
+        data += tag + bytes(-len(tag) % 4)
+    header = bytearray(128)
+    header[8] = version
+    header[16:24] = b"RGB XYZ "
+    header[36:40] = b"acsp"
+    header[68:80] = _fixed(0.9642, 1.0, 0.8249)
+    return bytes(header) + table + data
+
+@pytest.mark.parametrize("version", [2, 4])
+def test_icc_profile_matches_srgb(version):
+    space = tincture.ColorSpace.from_icc(build_icc(version=version))
+    assert space.name == "Custom_sRGB"
+    for color in [tincture.Color(200, 30, 60), tincture.Color(0, 0, 0), tincture.Color(255, 255, 255), tincture.Color(3, 2, 1)]:
+        components = space.to_components(color)
+        assert [round(component * 255) for component in components] == list(color)[:3]
+        assert space.from_components(components) == color
+
+def test_icc_profile_curves_and_registry():
+    gamma = ("curv", [round(2.2 * 256)])
+    table = ("curv", [round((index / 255) ** 1.8 * 65535) for index in range(256)])
+    space = tincture.ColorSpace.from_icc(build_icc(curves=(gamma, table, ("curv", [])), description=None), "mixed_curves")
+    assert space.name == "mixed_curves"
+    gray = tincture.Color(128, 128, 128)
+    components = space.to_components(gray)
+    assert round(components[0], 3) == round((0.2158605) ** (1 / 2.2), 3)
+    assert round(components[1], 2) == round(0.2158605 ** (1 / 1.8), 2)
+    assert round(components[2], 3) == 0.216
+    assert space.from_components(components) == gray
+    space.register()
+    try:
+        colors = tincture.ColorArray([gray, tincture.Color(250, 10, 100)])
+        assert tincture.ColorArray.from_components(colors.to_components("mixed_curves"), "mixed_curves") == colors
+    finally:
+        tincture.ColorSpace.unregister("mixed_curves")
+
+def test_icc_profile_from_path():
+    with tempfile.TemporaryDirectory() as directory:
+        path = os.path.join(directory, "profile.icc")
+        with open(path, "wb") as file:
+            file.write(build_icc(description="Wide Profile"))
+        assert tincture.ColorSpace.from_icc(path).name == "Wide_Profile"
+
+@pytest.mark.parametrize("data", [
+    b"",
+    bytes(128),
+    build_icc()[:200],
+    build_icc(description=None),
+    build_icc().replace(b"RGB XYZ ", b"CMYKLab "),
+    build_icc().replace(b"gTRC", b"zTRC"),
+    build_icc(curves=(("para", 9, (1.0,)),) * 3),
+    build_icc(curves=(("curv", [0]),) * 3),
+    build_icc(curves=(("para", 0, (0.0,)),) * 3),
+    build_icc(curves=(("para", 1, (2.2, 0.0, 0.1)),) * 3),
+    build_icc(curves=(("para", 2, (2.2, 0.0, 0.1, 0.05)),) * 3),
+])
+def test_icc_profile_invalid(data):
+    with pytest.raises(ValueError):
+        tincture.ColorSpace.from_icc(data)
@@ -0,0 +1,39 @@
+import pickle
+import pytest
+import tincture
+
+def test_frozen_color_conversion():
+    color = tincture.Color(10, 20, 30, 40)
+    frozen = color.freeze()
+    assert isinstance(frozen, tincture.FrozenColor)
+    assert (frozen.r, frozen.g, frozen.b, frozen.a) == (10, 20, 30, 40)
+    assert tincture.FrozenColor.from_color(color) == frozen
+    thawed = frozen.thaw()
+    thawed.r = 99
+    assert frozen.r == 10 and color.r == 10
+    assert repr(frozen) == "FrozenColor(10, 20, 30, 40)"
+
+def test_frozen_color_immutable():
+    frozen = tincture.FrozenColor(1, 2, 3)
+    with pytest.raises(AttributeError):
+        frozen.r = 5
+    with pytest.raises(TypeError):
+        frozen[0] = 5
+    assert frozen == tincture.FrozenColor(1, 2, 3, 255)
+
+def test_frozen_color_hashing():
+    color = tincture.Color(1, 2, 3)
+    frozen = color.freeze()
+    assert frozen == color and color == frozen
+    assert frozen != tincture.Color(1, 2, 4)
+    assert hash(frozen) == hash(color)
+    lookup = {frozen: "value"}
+    assert lookup[color] == "value"
+    assert len({frozen, tincture.FrozenColor(1, 2, 3)}) == 1
+
+def test_frozen_color_sequence():
+    frozen = tincture.FrozenColor(1, 2, 3, 4)
+    assert tuple(frozen) == (1, 2, 3, 4)
+    assert frozen[:3] == [1, 2, 3] and frozen["a"] == 4
+    assert f"{frozen:hex}" == "#010203"
+    assert pickle.loads(pickle.dumps(frozen)) == frozen
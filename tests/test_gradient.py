@@ -0,0 +1,300 @@
+import json
+import pytest
+import tincture
+
+red_white_blue = tincture.Gradient([(1.0, tincture.BLUE), (0.0, tincture.RED), (0.5, tincture.WHITE)], "srgb")
+
+@pytest.mark.parametrize("gradient,t,expected", [
+    (red_white_blue, -1.0, tincture.RED),
+    (red_white_blue, 0.0, tincture.RED),
+    (red_white_blue, 0.25, tincture.Color(255, 128, 128)),
+    (red_white_blue, 0.5, tincture.WHITE),
+    (red_white_blue, 0.75, tincture.Color(128, 128, 255)),
+    (red_white_blue, 1.0, tincture.BLUE),
+    (red_white_blue, 2.0, tincture.BLUE),
+    (tincture.Gradient.from_colors([tincture.RED, tincture.BLUE], "hsl", "longer"), 0.5, tincture.GREEN),
+    (tincture.Gradient.from_colors([tincture.RED, tincture.BLUE], "oklab"), 0.5, tincture.Color(140, 83, 162)),
+    (tincture.Gradient.from_colors([tincture.TEAL]), 0.3, tincture.TEAL),
+    (tincture.Gradient([(0.2, tincture.BLACK), (0.2, tincture.WHITE)], "srgb"), 0.2, tincture.WHITE),
+])
+def test_gradient_sample(gradient, t, expected):
+    assert gradient.sample(t) == expected
+
+def test_gradient_properties():
+    assert len(red_white_blue) == 3
+    assert red_white_blue.stops == [(0.0, tincture.RED), (0.5, tincture.WHITE), (1.0, tincture.BLUE)]
+    assert red_white_blue.space == "srgb"
+    assert red_white_blue.hue_strategy == "shorter"
+    assert red_white_blue.interpolation == "linear"
+
+@pytest.mark.parametrize("colors,space,t,expected", [
+    ([tincture.BLACK, tincture.Color(200, 0, 0), tincture.WHITE], "srgb", 0.0, tincture.BLACK),
+    ([tincture.BLACK, tincture.Color(200, 0, 0), tincture.WHITE], "srgb", 0.5, tincture.Color(200, 0, 0)),
+    ([tincture.BLACK, tincture.Color(200, 0, 0), tincture.WHITE], "srgb", 0.25, tincture.Color(97, 0, 0)),
+    ([tincture.BLACK, tincture.Color(200, 0, 0), tincture.WHITE], "srgb", 0.75, tincture.Color(240, 128, 128)),
+    ([tincture.BLACK, tincture.WHITE], "srgb", 0.5, tincture.Color(128, 128, 128)),
+    ([tincture.RED, tincture.GREEN, tincture.BLUE, tincture.RED], "oklch", 2 / 3, tincture.BLUE),
+])
+def test_gradient_catmull_rom(colors, space, t, expected):
+    gradient = tincture.Gradient.from_colors(colors, space, interpolation="catmull_rom")
+    assert gradient.interpolation == "catmull_rom"
+    assert gradient.sample(t) == expected
+
+def test_gradient_catmull_rom_smoothness():
+    linear = tincture.Gradient.from_colors([tincture.BLACK, tincture.Color(200, 0, 0), tincture.WHITE], "srgb")
+    spline = tincture.Gradient.from_colors([tincture.BLACK, tincture.Color(200, 0, 0), tincture.WHITE], "srgb", interpolation="catmull_rom")
+    step = 0.01
+    linear_kink = (linear.sample(0.5 + step).g - linear.sample(0.5).g) - (linear.sample(0.5).g - linear.sample(0.5 - step).g)
+    spline_kink = (spline.sample(0.5 + step).g - spline.sample(0.5).g) - (spline.sample(0.5).g - spline.sample(0.5 - step).g)
+    assert spline_kink < linear_kink
+
+@pytest.mark.parametrize("stops,space,hue_strategy", [
+    ([], "oklab", "shorter"),
+    ([(1.5, tincture.RED)], "oklab", "shorter"),
+    ([(0.0, tincture.RED)], "cmyk", "shorter"),
+    ([(0.0, tincture.RED)], "oklab", "around"),
+])
+def test_gradient_invalid(stops, space, hue_strategy):
+    with pytest.raises(ValueError):
+        tincture.Gradient(stops, space, hue_strategy)
+
+@pytest.mark.parametrize("n,expected", [
+    (0, b""),
+    (1, bytes([255, 0, 0, 255])),
+    (3, bytes([255, 0, 0, 255, 255, 255, 255, 255, 0, 0, 255, 255])),
+    (5, bytes([255, 0, 0, 255, 255, 128, 128, 255, 255, 255, 255, 255, 128, 128, 255, 255, 0, 0, 255, 255])),
+])
+def test_gradient_sample_many(n, expected):
+    assert red_white_blue.sample_many(n) == expected
+    pixels = red_white_blue.sample_into(n)
+    assert isinstance(pixels, bytearray)
+    assert pixels == bytearray(expected)
+    samples = red_white_blue.sample_array(n)
+    assert len(samples) == n
+    assert memoryview(samples).shape == (n, 4)
+    assert samples.to_bytes() == expected
+
+@pytest.mark.parametrize("n", [2 ** 62, 2 ** 64 - 1])
+def test_gradient_sample_many_too_large(n):
+    for sample in (red_white_blue.sample_many, red_white_blue.sample_into, red_white_blue.sample_array):
+        with pytest.raises(MemoryError):
+            sample(n)
+
+def test_gradient_to_unity_gradient_json():
+    gradient = tincture.Gradient([(0.0, tincture.RED), (1.0, tincture.Color(0, 0, 255, 0))])
+    assert json.loads(gradient.to_unity_gradient_json()) == {
+        "colorKeys": [
+            {"color": {"r": 1.0, "g": 0.0, "b": 0.0, "a": 1.0}, "time": 0.0},
+            {"color": {"r": 0.0, "g": 0.0, "b": 1.0, "a": 1.0}, "time": 1.0},
+        ],
+        "alphaKeys": [{"alpha": 1.0, "time": 0.0}, {"alpha": 0.0, "time": 1.0}],
+        "mode": 0,
+    }
+    with pytest.raises(ValueError):
+        tincture.Gradient.from_colors([tincture.RED] * 9).to_unity_gradient_json()
+
+def test_gradient_to_glsl():
+    gradient = tincture.Gradient([(0.0, tincture.RED), (0.5, tincture.RED), (0.5, tincture.BLUE), (1.0, tincture.WHITE)], "srgb")
+    assert gradient.to_glsl("palette") == (
+        "vec4 palette(float t) {\n"
+        "    if (t <= 0.000000) return vec4(1.0000, 0.0000, 0.0000, 1.0000);\n"
+        "    if (t < 0.500000) return mix(vec4(1.0000, 0.0000, 0.0000, 1.0000), vec4(1.0000, 0.0000, 0.0000, 1.0000), (t - 0.000000) / 0.500000);\n"
+        "    if (t < 1.000000) return mix(vec4(0.0000, 0.0000, 1.0000, 1.0000), vec4(1.0000, 1.0000, 1.0000, 1.0000), (t - 0.500000) / 0.500000);\n"
+        "    return vec4(1.0000, 1.0000, 1.0000, 1.0000);\n"
+        "}\n"
+    )
+    assert tincture.Gradient.from_colors([tincture.RED, tincture.BLUE]).to_glsl().count("mix(") == 8
+    with pytest.raises(ValueError):
+        gradient.to_glsl("2d gradient")
+
+def test_gradient_invalid_interpolation():
+    with pytest.raises(ValueError):
+        tincture.Gradient([(0.0, tincture.RED)], interpolation="cubic")
+
+@pytest.mark.parametrize("start,rotations,hue,gamma", [
+    (0.5, -1.5, 1.0, 1.0),
+    (2.0, 1.0, 0.8, 0.7),
+    (0.0, 0.0, 0.0, 1.0),
+])
+def test_gradient_cubehelix(start, rotations, hue, gamma):
+    gradient = tincture.Gradient.cubehelix(start, rotations, hue, gamma)
+    assert gradient.sample(0.0) == tincture.BLACK
+    assert gradient.sample(1.0) == tincture.WHITE
+    luminance = [sum(weight * channel for weight, channel in zip((0.2126, 0.7152, 0.0722), gradient.sample(t / 32).to_decimal_rgb())) for t in range(33)]
+    assert all(second >= first - 0.02 for first, second in zip(luminance, luminance[1:]))
+
+def test_gradient_cubehelix_default():
+    assert tincture.Gradient.cubehelix().sample(0.5).approx_equal(tincture.Color(161, 121, 74), 2)
+
+@pytest.mark.parametrize("arguments", [
+    {"gamma": 0.0},
+    {"gamma": float("nan")},
+    {"gamma": float("inf")},
+    {"hue": -1.0},
+    {"hue": float("nan")},
+    {"start": float("nan")},
+    {"start": float("inf")},
+    {"rotations": float("nan")},
+    {"rotations": float("-inf")},
+])
+def test_gradient_cubehelix_invalid(arguments):
+    with pytest.raises(ValueError):
+        tincture.Gradient.cubehelix(**arguments)
+
+def test_gradient_duotone():
+    dark, light, mid = tincture.Color(20, 30, 90), tincture.Color(250, 220, 120), tincture.Color(220, 60, 90)
+    gradient = tincture.Gradient.duotone(dark, light)
+    assert gradient.stops == [(0.0, dark), (1.0, light)]
+    assert gradient.space == "oklab"
+    tritone = tincture.Gradient.duotone(dark, light, mid, "srgb")
+    assert tritone.stops == [(0.0, dark), (0.5, mid), (1.0, light)]
+    assert tritone.space == "srgb"
+    with pytest.raises(ValueError):
+        tincture.Gradient.duotone(dark, light, space="cmyk")
+
+def test_gradient_text_roundtrip():
+    gradient = tincture.Gradient([(1 / 3, tincture.RED), (0.0, tincture.Color(1, 2, 3, 4))], "oklch", "longer", "catmull_rom")
+    assert gradient.to_text() == (
+        "# tincture gradient\n"
+        "space oklch\n"
+        "hue_strategy longer\n"
+        "interpolation catmull_rom\n"
+        "0.0 #01020304\n"
+        "0.33333334 #ff0000ff\n"
+    )
+    assert tincture.Gradient.from_text(gradient.to_text()) == gradient
+
+def test_gradient_equality():
+    gradient = tincture.Gradient([(0.0, tincture.RED), (1.0, tincture.BLUE)], "oklch")
+    assert gradient == tincture.Gradient([(1.0, tincture.BLUE), (0.0, tincture.RED)], "oklch")
+    assert gradient != tincture.Gradient([(0.0, tincture.RED), (1.0, tincture.BLUE)], "srgb")
+    assert gradient != tincture.Gradient([(0.0, tincture.RED), (1.0, tincture.BLUE)], "oklch", "longer")
+    assert gradient != tincture.Gradient([(0.0, tincture.RED), (1.0, tincture.BLUE)], "oklch", interpolation="catmull_rom")
+    assert gradient != tincture.Gradient([(0.0, tincture.RED), (0.9, tincture.BLUE)], "oklch")
+    assert gradient != tincture.Gradient([(0.0, tincture.BLUE), (1.0, tincture.RED)], "oklch")
+
+def test_gradient_json_roundtrip():
+    gradient = tincture.Gradient([(1 / 3, tincture.RED), (0.0, tincture.Color(1, 2, 3, 4))], "oklch", "longer", "catmull_rom")
+    assert json.loads(gradient.to_json()) == {
+        "space": "oklch",
+        "hue_strategy": "longer",
+        "interpolation": "catmull_rom",
+        "stops": [
+            {"position": 0.0, "color": "#01020304"},
+            {"position": gradient.stops[1][0], "color": "#ff0000"},
+        ],
+    }
+    assert tincture.Gradient.from_json(gradient.to_json(indent=2)) == gradient
+
+def test_gradient_from_json_defaults():
+    parsed = tincture.Gradient.from_json('{"stops": [{"position": 1, "color": "#0000ff"}, {"position": 0, "color": "ff0000"}]}')
+    assert parsed.stops == [(0.0, tincture.RED), (1.0, tincture.BLUE)]
+    assert (parsed.space, parsed.hue_strategy, parsed.interpolation) == ("oklab", "shorter", "linear")
+
+@pytest.mark.parametrize("text", [
+    "[]",
+    "{}",
+    '{"space": "cmyk", "stops": [{"position": 0, "color": "#ff0000"}]}',
+    '{"stops": [{"position": 0, "color": "#zz0000"}]}',
+])
+def test_gradient_from_json_invalid(text):
+    with pytest.raises(ValueError):
+        tincture.Gradient.from_json(text)
+
+def test_gradient_from_text_defaults():
+    parsed = tincture.Gradient.from_text("\n# comment\n1 #0000ff\n0 #ff0000\n")
+    assert parsed.stops == [(0.0, tincture.RED), (1.0, tincture.BLUE)]
+    assert parsed.space == "oklab"
+
+@pytest.mark.parametrize("text", [
+    "",
+    "space\n0 #ff0000",
+    "0 #ff0000\nsomething #00ff00",
+    "0 #ff00",
+    "space cmyk\n0 #ff0000",
+])
+def test_gradient_from_text_invalid(text):
+    with pytest.raises(ValueError):
+        tincture.Gradient.from_text(text)
+
+@pytest.mark.parametrize("kind,angle,expected", [
+    ("linear", 90, "linear-gradient(90deg, #ff0000 0%, #ffffff80 33.33%, #0000ff 100%)"),
+    ("conic", 45.5, "conic-gradient(from 45.5deg, #ff0000 0%, #ffffff80 33.33%, #0000ff 100%)"),
+    ("radial", 0, "radial-gradient(circle, #ff0000 0%, #ffffff80 33.33%, #0000ff 100%)"),
+])
+def test_gradient_to_css(kind, angle, expected):
+    gradient = tincture.Gradient([(0.0, tincture.RED), (1 / 3, tincture.Color(255, 255, 255, 128)), (1.0, tincture.BLUE)], "srgb")
+    assert gradient.to_css(kind, angle) == expected
+
+def test_gradient_to_css_approximation():
+    css = tincture.Gradient.from_colors([tincture.RED, tincture.BLUE]).to_css()
+    assert css.startswith("linear-gradient(90deg, #ff0000 0%, ")
+    assert css.endswith(", #8c53a2 50%, #6f50ba 62.5%, #5147d2 75%, #3036e8 87.5%, #0000ff 100%)")
+    with pytest.raises(ValueError):
+        tincture.Gradient.from_colors([tincture.RED]).to_css("diagonal")
+
+@pytest.mark.parametrize("t,wrap,expected", [
+    (1.25, "clamp", tincture.BLUE),
+    (-0.5, "clamp", tincture.RED),
+    (1.25, "repeat", tincture.Color(255, 128, 128)),
+    (-0.25, "repeat", tincture.Color(128, 128, 255)),
+    (2.0, "repeat", tincture.RED),
+    (1.25, "mirror", tincture.Color(128, 128, 255)),
+    (-0.25, "mirror", tincture.Color(255, 128, 128)),
+    (2.0, "mirror", tincture.RED),
+    (3.0, "mirror", tincture.BLUE),
+    (0.5, "repeat", tincture.WHITE),
+    (1.0, "repeat", tincture.BLUE),
+])
+def test_gradient_sample_wrap(t, wrap, expected):
+    assert red_white_blue.sample(t, wrap) == expected
+
+@pytest.mark.parametrize("gradient", [
+    red_white_blue,
+    tincture.Gradient([(0.5, tincture.RED)]),
+    tincture.Gradient.from_colors([tincture.RED, tincture.WHITE, tincture.BLUE], interpolation="catmull_rom"),
+    tincture.colormaps.viridis,
+])
+@pytest.mark.parametrize("t", [float("nan"), float("inf"), float("-inf")])
+@pytest.mark.parametrize("wrap", ["clamp", "repeat", "mirror"])
+def test_gradient_sample_non_finite(gradient, t, wrap):
+    with pytest.raises(ValueError):
+        gradient.sample(t, wrap)
+
+def test_gradient_sample_invalid_wrap():
+    with pytest.raises(ValueError):
+        red_white_blue.sample(0.5, "bounce")
+
+def test_gradient_repr_html():
+    gradient = tincture.Gradient.from_colors([tincture.RED, tincture.BLUE], space="srgb")
+    html = gradient._repr_html_()
+    assert "Gradient (2 stops)" in html
+    assert gradient.to_css() in html
+
+def test_gradient_to_svg():
+    gradient = tincture.Gradient.from_colors([tincture.RED, tincture.Color(0, 0, 255, 128)], space="srgb")
+    svg = gradient.to_svg(200, 20)
+    assert '<stop offset="0%" stop-color="#ff0000"/>' in svg
+    assert '<stop offset="100%" stop-color="#0000ff" stop-opacity="0.5"/>' in svg
+    assert '<rect width="200" height="20" fill="url(#gradient)"/>' in svg
+    assert "<text" not in svg
+    assert gradient.to_svg(labels=True).count("<text") == 2
+    with pytest.raises(ValueError):
+        gradient.to_svg(0, 20)
+
+def test_gradient_fingerprint():
+    fingerprint = red_white_blue.fingerprint()
+    assert len(fingerprint) == 16
+    assert int(fingerprint, 16) >= 0
+    assert tincture.Gradient.from_text(red_white_blue.to_text()).fingerprint() == fingerprint
+    assert tincture.Gradient(red_white_blue.stops, "srgb").fingerprint() == fingerprint
+    assert tincture.Gradient([(0.0, tincture.RED), (0.5000001, tincture.WHITE), (1.0, tincture.BLUE)], "srgb").fingerprint() == fingerprint
+
+@pytest.mark.parametrize("gradient", [
+    tincture.Gradient(red_white_blue.stops, "oklab"),
+    tincture.Gradient(red_white_blue.stops, "srgb", interpolation="catmull_rom"),
+    tincture.Gradient([(0.0, tincture.RED), (0.6, tincture.WHITE), (1.0, tincture.BLUE)], "srgb"),
+    tincture.Gradient([(0.0, tincture.RED), (0.5, tincture.Color(255, 255, 254)), (1.0, tincture.BLUE)], "srgb"),
+])
+def test_gradient_fingerprint_changes(gradient):
+    assert gradient.fingerprint() != red_white_blue.fingerprint()
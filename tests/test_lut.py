@@ -0,0 +1,34 @@
+import os
+import tempfile
+
+import pytest
+import tincture
+
+def test_export_lut_identity():
+    lines = tincture.export_lut(lambda color: color, size=3, title="identity").splitlines()
+    assert lines[:4] == ['TITLE "identity"', "LUT_3D_SIZE 3", "DOMAIN_MIN 0.0 0.0 0.0", "DOMAIN_MAX 1.0 1.0 1.0"]
+    assert len(lines) == 4 + 3 ** 3
+    assert lines[4] == "0.000000 0.000000 0.000000"
+    assert lines[5] == "0.501961 0.000000 0.000000"
+    assert lines[7] == "0.000000 0.501961 0.000000"
+    assert lines[-1] == "1.000000 1.000000 1.000000"
+
+def test_export_lut_file():
+    with tempfile.TemporaryDirectory() as directory:
+        path = os.path.join(directory, "invert.cube")
+        assert tincture.export_lut(lambda color: tincture.Color(255 - color.r, 255 - color.g, 255 - color.b), 2, path) is None
+        with open(path) as file:
+            assert file.read().splitlines()[4] == "1.000000 1.000000 1.000000"
+
+def test_gradient_to_cube():
+    lines = tincture.Gradient.from_colors([tincture.BLUE, tincture.YELLOW], "srgb").to_cube(size=2).splitlines()
+    assert lines[1] == "LUT_3D_SIZE 2"
+    assert lines[4] == "0.000000 0.000000 1.000000"
+    assert lines[-1] == "1.000000 1.000000 0.000000"
+
+@pytest.mark.parametrize("size", [1, 257])
+def test_lut_invalid_size(size):
+    with pytest.raises(ValueError):
+        tincture.export_lut(lambda color: color, size)
+    with pytest.raises(ValueError):
+        tincture.Gradient.from_colors([tincture.RED]).to_cube(size=size)
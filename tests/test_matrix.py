@@ -0,0 +1,56 @@
+import copy
+import pickle
+import pytest
+import tincture
+
+def test_color_matrix_identity():
+    color = tincture.Color(12, 130, 250, 77)
+    assert tincture.ColorMatrix.identity().apply(color) == color
+    assert tincture.ColorMatrix.identity()(color) == color
+    assert tincture.ColorMatrix.saturation(1.0).apply(color) == color
+    assert tincture.ColorMatrix.sepia(0.0).apply(color) == color
+
+@pytest.mark.parametrize("length", [0, 19, 21])
+def test_color_matrix_invalid_length(length):
+    with pytest.raises(ValueError):
+        tincture.ColorMatrix([0.0] * length)
+
+def test_color_matrix_custom():
+    # Swaps the red and blue channels and adds half of the range to the green one
+    matrix = tincture.ColorMatrix([
+        0, 0, 1, 0, 0,
+        0, 1, 0, 0, 0.5,
+        1, 0, 0, 0, 0,
+        0, 0, 0, 1, 0,
+    ])
+    assert matrix.apply(tincture.Color(10, 20, 30, 40)) == tincture.Color(30, 148, 10, 40)
+    assert matrix.apply(tincture.Color(0, 200, 0)) == tincture.Color(0, 255, 0)
+    assert len(matrix.values) == 20
+
+def test_color_matrix_presets():
+    assert tincture.ColorMatrix.invert().apply(tincture.Color(0, 100, 255, 9)) == tincture.Color(255, 155, 0, 9)
+    gray = tincture.ColorMatrix.saturation(0.0).apply(tincture.Color(255, 0, 0))
+    assert gray.r == gray.g == gray.b == 54
+    sepia = tincture.ColorMatrix.sepia().apply(tincture.Color(100, 100, 100))
+    assert sepia.r > sepia.g > sepia.b
+    assert tincture.ColorMatrix.sepia(5.0) == tincture.ColorMatrix.sepia(1.0)
+    color = tincture.Color(255, 0, 0)
+    assert tincture.ColorMatrix.hue_rotate(180).apply(color) == color.hue_rotate(180, "matrix")
+    assert tincture.ColorMatrix.hue_rotate(0).apply(color) == color
+
+def test_color_matrix_composition():
+    invert = tincture.ColorMatrix.invert()
+    sepia = tincture.ColorMatrix.sepia(0.5)
+    color = tincture.Color(40, 90, 200)
+    assert invert.then(invert) == tincture.ColorMatrix.identity()
+    assert invert.then(sepia).apply(color) == sepia.apply(invert.apply(color))
+    assert (sepia @ invert).apply(color) == sepia.apply(invert.apply(color))
+    assert sepia @ invert == invert.then(sepia)
+
+def test_color_matrix_copying():
+    matrix = tincture.ColorMatrix.saturation(2.0)
+    assert pickle.loads(pickle.dumps(matrix)) == matrix
+    assert copy.deepcopy(matrix) == matrix
+    assert eval(repr(matrix), {"ColorMatrix": tincture.ColorMatrix}) == matrix
+    assert matrix != tincture.ColorMatrix.identity()
+    assert matrix != "matrix"
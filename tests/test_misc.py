@@ -1,6 +1,8 @@
 import pytest
 import tincture
 import copy
+import pickle
+import sys
 
 @pytest.mark.parametrize("color,expected", [
     (tincture.Color(1, 2, 3), "(1 : 2 : 3 : 255)"),
@@ -20,6 +22,74 @@ def test_color_str(color, expected):
 def test_color_repr(color, expected):
     assert repr(color) == expected
 
+@pytest.mark.parametrize("color,expected", [
+    (tincture.Color(1, 2, 3), (1, 2, 3, 255)),
+    (tincture.Color(12, 13, 30, 201), (12, 13, 30, 201)),
+    (tincture.BLACK, (0, 0, 0, 255)),
+])
+def test_color_iteration(color, expected):
+    assert len(color) == 4
+    assert tuple(color) == expected
+    r, g, b, a = color
+    assert (r, g, b, a) == expected
+    assert list(color)[:3] == [color.r, color.g, color.b]
+
+def test_color_match_args():
+    assert tincture.Color.__match_args__ == ("r", "g", "b", "a")
+
+@pytest.mark.skipif(sys.version_info < (3, 10), reason="match statements need Python 3.10")
+@pytest.mark.parametrize("color,expected", [
+    (tincture.Color(255, 0, 0), "red"),
+    (tincture.Color(255, 0, 0, 0), "transparent"),
+    (tincture.Color(255, 0, 9), "reddish"),
+    (tincture.Color(1, 2, 3), "other"),
+])
+def test_color_pattern_matching(color, expected):
+    source = """
+def classify(color):
+    match color:
+        case Color(_, _, _, 0):
+            return "transparent"
+        case Color(255, 0, 0):
+            return "red"
+        case Color(r=255, g=0):
+            return "reddish"
+        case _:
+            return "other"
+"""
+    namespace = {"Color": tincture.Color}
+    exec(source, namespace)
+    assert namespace["classify"](color) == expected
+
+@pytest.mark.parametrize("index,expected", [
+    (slice(0, 3), [1, 2, 3]),
+    (slice(None, None), [1, 2, 3, 4]),
+    (slice(2, None), [3, 4]),
+    (slice(None, None, -1), [4, 3, 2, 1]),
+    (slice(-1, None), [4]),
+    (slice(5, 9), []),
+])
+def test_color_slicing(index, expected):
+    color = tincture.Color(1, 2, 3, 4)
+    assert color[index] == expected
+    assert color[index] == list(color)[index]
+
+def test_color_slice_assignment():
+    color = tincture.Color(1, 2, 3, 4)
+    color[:3] = (10, 20, 30)
+    assert color == tincture.Color(10, 20, 30, 4)
+    color[::2] = [0, 0]
+    assert color == tincture.Color(0, 20, 0, 4)
+    with pytest.raises(ValueError):
+        color[:3] = (1, 2)
+    with pytest.raises(TypeError):
+        color[:3] = 5
+    with pytest.raises(TypeError):
+        color[0] = (1, 2)
+    assert color == tincture.Color(0, 20, 0, 4)
+    with pytest.raises(IndexError):
+        color[4]
+
 @pytest.mark.parametrize("color,expected", [
     (tincture.Color(50, 168, 82), 0.292),
     (tincture.Color(103, 184, 255), 0.443),
@@ -34,6 +104,14 @@ def test_color_repr(color, expected):
 def test_color_luminance(color, expected):
     assert expected - 0.05 <= color.get_luminance() <= expected + 0.05
 
+@pytest.mark.parametrize("level", [0, 10, 11, 128, 255])
+def test_color_luminance_matches_transfer(level):
+    value = level / 255
+    linear = value / 12.92 if value <= 0.03928 else ((value + 0.055) / 1.055) ** 2.4
+    gray = tincture.Color(level, level, level)
+    assert abs(gray.get_luminance() - linear) < 1e-6
+    assert abs(gray.to_xyz()[1] - linear * 100) < 1e-4
+
 @pytest.mark.parametrize("color,expected", [
     (tincture.Color(106, 240, 117), 0.55),
     (tincture.Color(150, 136, 78), 0.48),
@@ -77,4 +155,107 @@ randomised = tincture.Color.randomise(tincture.WHITE)
     (randomised, randomised),
 ])
 def test_color_copy(color, expected):
-    assert color.copy() == copy.copy(color) == expected
\ No newline at end of file
+    assert color.copy() == copy.copy(color) == expected
+
+@pytest.mark.parametrize("color", [
+    tincture.Color(106, 240, 117),
+    tincture.Color(30, 90, 0, 0),
+    tincture.WHITE,
+])
+def test_color_pickle(color):
+    restored = pickle.loads(pickle.dumps(color))
+    assert restored == color
+    duplicate = copy.deepcopy(color)
+    duplicate.r = 1
+    assert duplicate != color
+
+def test_palette_pickle():
+    palette = tincture.Palette([tincture.RED], name="Primaries")
+    palette.append(tincture.BLUE, "Blue", "Cool")
+    palette.columns = 2
+    restored = pickle.loads(pickle.dumps(palette))
+    assert restored == palette
+    assert restored.names == [None, "Blue"]
+    assert restored.groups == [None, "Cool"]
+    assert restored.name == "Primaries"
+    assert restored.columns == 2
+    assert copy.deepcopy(palette).names == palette.names
+
+def test_gradient_pickle():
+    gradient = tincture.Gradient([(0.0, tincture.RED), (0.3, tincture.WHITE), (1.0, tincture.BLUE)], "lab", "longer", "catmull_rom")
+    restored = pickle.loads(pickle.dumps(gradient))
+    assert (restored.space, restored.hue_strategy, restored.interpolation) == ("lab", "longer", "catmull_rom")
+    assert restored.sample_many(16) == gradient.sample_many(16)
+
+def test_color_array_pickle():
+    array = tincture.ColorArray([tincture.RED, tincture.Color(1, 2, 3, 4)])
+    assert pickle.loads(pickle.dumps(array)) == array
+    assert copy.deepcopy(array) == array
+
+def test_palette_copies():
+    palette = tincture.Palette([tincture.RED, tincture.BLUE], name="Primaries")
+    for duplicate in (copy.copy(palette), copy.deepcopy(palette)):
+        assert type(duplicate) is tincture.Palette and duplicate is not palette
+        assert duplicate == palette and duplicate.name == "Primaries"
+        duplicate.append(tincture.GREEN, "Green")
+        duplicate[0] = tincture.WHITE
+        assert len(palette) == 2 and palette[0] == tincture.RED
+        assert palette.names == [None, None]
+
+def test_gradient_copies():
+    gradient = tincture.Gradient([(0.0, tincture.RED), (1.0, tincture.BLUE)], "lab")
+    for duplicate in (copy.copy(gradient), copy.deepcopy(gradient)):
+        assert type(duplicate) is tincture.Gradient and duplicate is not gradient
+        assert duplicate == gradient and duplicate.space == "lab"
+        duplicate.stops[0][1].r = 0
+        assert duplicate.stops == gradient.stops == [(0.0, tincture.RED), (1.0, tincture.BLUE)]
+
+def test_color_array_copies():
+    array = tincture.ColorArray([tincture.RED, tincture.Color(1, 2, 3, 4)])
+    for duplicate in (copy.copy(array), copy.deepcopy(array)):
+        assert type(duplicate) is tincture.ColorArray and duplicate is not array
+        assert duplicate == array
+        duplicate[1] = tincture.WHITE
+        assert array[1] == tincture.Color(1, 2, 3, 4)
+
+def test_pool_and_scale_copies():
+    pool = tincture.ColorPool([tincture.RED])
+    duplicate = copy.deepcopy(pool)
+    duplicate.intern(tincture.BLUE)
+    assert len(pool) == 1 and len(duplicate) == 2
+    scale = tincture.ColorScale([tincture.RED, tincture.BLUE], [0.0, 10.0])
+    duplicate = copy.copy(scale)
+    assert duplicate is not scale and duplicate.domain == [0.0, 10.0]
+    assert duplicate(5.0) == scale(5.0)
+
+def test_copies_are_independent():
+    palette = tincture.Palette([tincture.RED])
+    duplicate = copy.copy(palette)
+    duplicate.append(tincture.BLUE)
+    assert len(palette) == 1 and len(duplicate) == 2
+    colors = [tincture.Color(1, 2, 3)]
+    nested = copy.deepcopy(colors)
+    nested[0].r = 9
+    assert colors[0].r == 1
+    frozen = tincture.FrozenColor(1, 2, 3)
+    assert copy.copy(frozen) is frozen and copy.deepcopy(frozen) is frozen
+
+@pytest.mark.parametrize("method,expected", [
+    ("with_red", tincture.Color(200, 2, 3, 4)),
+    ("with_green", tincture.Color(1, 200, 3, 4)),
+    ("with_blue", tincture.Color(1, 2, 200, 4)),
+    ("with_alpha", tincture.Color(1, 2, 3, 200)),
+])
+def test_color_with_channel(method, expected):
+    color = tincture.Color(1, 2, 3, 4)
+    assert getattr(color, method)(200) == expected
+    assert color == tincture.Color(1, 2, 3, 4)
+
+def test_color_replace():
+    color = tincture.Color(1, 2, 3, 4)
+    assert color.replace() == color and color.replace() is not color
+    assert color.replace(g=0, a=128) == tincture.Color(1, 0, 3, 128)
+    assert color.with_red(9).with_alpha(255) == tincture.Color(9, 2, 3)
+    assert tincture.FrozenColor(1, 2, 3).replace(b=30) == tincture.FrozenColor(1, 2, 30)
+    with pytest.raises(OverflowError):
+        color.replace(r=256)
@@ -0,0 +1,605 @@
+import json
+import os
+import struct
+import tempfile
+
+import pytest
+import tincture
+
+def create_palette():
+    return tincture.Palette([tincture.RED, tincture.GREEN, tincture.BLUE, tincture.WHITE, tincture.BLACK])
+
+@pytest.mark.parametrize("index,expected", [
+    (0, tincture.RED),
+    (2, tincture.BLUE),
+    (-1, tincture.BLACK),
+    (slice(1, 3), tincture.Palette([tincture.GREEN, tincture.BLUE])),
+    (slice(None, None, -2), tincture.Palette([tincture.BLACK, tincture.BLUE, tincture.RED])),
+    (slice(10, 20), tincture.Palette()),
+])
+def test_palette_getitem(index, expected):
+    assert create_palette()[index] == expected
+
+@pytest.mark.parametrize("index", [5, -6])
+def test_palette_getitem_invalid(index):
+    with pytest.raises(IndexError):
+        create_palette()[index]
+
+def test_palette_mutation():
+    palette = tincture.Palette()
+    palette.append(tincture.RED)
+    palette.extend([tincture.GREEN, tincture.BLUE])
+    palette[-1] = tincture.TEAL
+    assert len(palette) == 3
+    assert palette.colors == [tincture.RED, tincture.GREEN, tincture.TEAL]
+    with pytest.raises(IndexError):
+        palette[3] = tincture.BLACK
+
+def test_palette_sequence_protocol():
+    palette = create_palette()
+    assert list(palette) == [tincture.RED, tincture.GREEN, tincture.BLUE, tincture.WHITE, tincture.BLACK]
+    assert tincture.BLUE in palette
+    assert tincture.TEAL not in palette
+    assert palette == create_palette()
+    assert palette != tincture.Palette([tincture.RED])
+    assert repr(tincture.Palette([tincture.RED])) == "Palette([Color(255, 0, 0, 255)])"
+
+@pytest.mark.parametrize("color,metric,expected", [
+    (tincture.Color(250, 10, 10), "oklab", tincture.RED),
+    (tincture.Color(10, 10, 200), "srgb", tincture.BLUE),
+    (tincture.Color(40, 40, 40), "lab", tincture.BLACK),
+    (tincture.Color(200, 200, 200), "linear", tincture.WHITE),
+    (tincture.GREEN, "xyz", tincture.GREEN),
+])
+def test_palette_nearest(color, metric, expected):
+    assert create_palette().nearest(color, metric) == expected
+
+def test_palette_nearest_many():
+    palette = tincture.Palette([tincture.Color(value, value, value) for value in range(0, 256, 17)])
+    colors = [tincture.Color(value, value, value) for value in range(256)]
+    expected = [min(palette, key=lambda candidate: abs(candidate.r - color.r)) for color in colors]
+    assert palette.nearest_many(colors, "srgb") == expected
+    palette.append(tincture.Color(1, 1, 1))
+    assert palette.nearest(tincture.Color(2, 2, 2), "srgb") == tincture.Color(1, 1, 1)
+
+@pytest.mark.parametrize("palette,metric", [
+    (tincture.Palette(), "oklab"),
+    (tincture.Palette([tincture.RED]), "oklch"),
+    (tincture.Palette([tincture.RED]), "cmyk"),
+])
+def test_palette_nearest_invalid(palette, metric):
+    with pytest.raises(ValueError):
+        palette.nearest(tincture.RED, metric)
+
+@pytest.mark.parametrize("colors,count,expected", [
+    ([tincture.RED] * 10 + [tincture.BLUE] * 5, 2, [tincture.BLUE, tincture.RED]),
+    ([tincture.RED] * 10 + [tincture.BLUE] * 5, 1, [tincture.Color(170, 0, 85)]),
+    ([tincture.RED, tincture.RED], 8, [tincture.RED]),
+    ([tincture.Color(value, 0, 0) for value in range(4)], 2, [tincture.Color(1, 0, 0), tincture.Color(3, 0, 0)]),
+    (bytes([255, 0, 0, 255] * 10 + [0, 255, 0, 255] * 3), 4, [tincture.GREEN, tincture.RED]),
+    (bytearray(8), 1, [tincture.Color(0, 0, 0, 0)]),
+])
+def test_palette_median_cut(colors, count, expected):
+    assert tincture.Palette.from_colors_median_cut(colors, count).colors == expected
+
+@pytest.mark.parametrize("colors,count", [
+    ([], 4),
+    ([tincture.RED], 0),
+    (bytes(5), 1),
+])
+def test_palette_median_cut_invalid(colors, count):
+    with pytest.raises(ValueError):
+        tincture.Palette.from_colors_median_cut(colors, count)
+
+def test_palette_kmeans():
+    colors = [tincture.Color(200 + offset, 30, 30) for offset in range(-5, 6)] * 5
+    colors += [tincture.Color(30, 200 + offset, 30) for offset in range(-5, 6)] * 3
+    colors += [tincture.Color(30, 30, 200 + offset) for offset in range(-5, 6)]
+    palette = tincture.Palette.kmeans(colors, 3, seed=7)
+    assert palette.colors == [tincture.Color(200, 30, 30), tincture.Color(30, 200, 30), tincture.Color(30, 30, 200)]
+    assert tincture.Palette.kmeans(colors, 2, seed=3) == tincture.Palette.kmeans(colors, 2, seed=3)
+
+@pytest.mark.parametrize("colors,k,expected", [
+    ([tincture.RED] * 3, 5, [tincture.RED]),
+    (bytes([0, 0, 255, 255] * 2 + [255, 0, 0, 255] * 3), 2, [tincture.RED, tincture.BLUE]),
+    ([tincture.BLACK, tincture.WHITE], 1, [tincture.Color(128, 128, 128)]),
+])
+def test_palette_kmeans_small(colors, k, expected):
+    assert tincture.Palette.kmeans(colors, k, seed=1).colors == expected
+
+@pytest.mark.parametrize("colors,k", [
+    ([], 2),
+    ([tincture.RED], 0),
+    (bytes(3), 1),
+])
+def test_palette_kmeans_invalid(colors, k):
+    with pytest.raises(ValueError):
+        tincture.Palette.kmeans(colors, k)
+
+@pytest.mark.parametrize("colors,count,expected", [
+    ([tincture.RED] * 10 + [tincture.BLUE] * 5 + [tincture.Color(250, 0, 0)] * 2, 8, [tincture.BLUE, tincture.Color(250, 0, 0), tincture.RED]),
+    ([tincture.RED] * 10 + [tincture.BLUE] * 5 + [tincture.Color(250, 0, 0)] * 2, 2, [tincture.BLUE, tincture.Color(254, 0, 0)]),
+    ([tincture.RED] * 10 + [tincture.BLUE] * 5 + [tincture.Color(250, 0, 0)] * 2, 1, [tincture.Color(179, 0, 75)]),
+    (bytes([0, 255, 0, 255, 0, 255, 0, 128]), 4, [tincture.Color(0, 255, 0, 192)]),
+])
+def test_palette_octree(colors, count, expected):
+    assert tincture.Palette.octree(colors, count).colors == expected
+
+def test_palette_octree_count():
+    colors = [tincture.Color(red, green, 128) for red in range(0, 256, 8) for green in range(0, 256, 8)]
+    assert 0 < len(tincture.Palette.octree(colors, 16)) <= 16
+
+@pytest.mark.parametrize("colors,count", [
+    ([], 2),
+    ([tincture.RED], 0),
+    (bytes(6), 1),
+])
+def test_palette_octree_invalid(colors, count):
+    with pytest.raises(ValueError):
+        tincture.Palette.octree(colors, count)
+
+@pytest.mark.parametrize("colors,tolerance,metric,expected", [
+    ([tincture.RED, tincture.Color(254, 1, 0), tincture.BLUE, tincture.RED], 2.0, "ciede2000", [tincture.RED, tincture.BLUE]),
+    ([tincture.RED, tincture.Color(254, 1, 0), tincture.BLUE], 0.0, "ciede2000", [tincture.RED, tincture.Color(254, 1, 0), tincture.BLUE]),
+    ([tincture.BLACK, tincture.Color(40, 40, 40), tincture.WHITE], 20.0, "cie76", [tincture.BLACK, tincture.WHITE]),
+    ([tincture.BLACK, tincture.Color(40, 40, 40), tincture.WHITE], 20.0, "oklab", [tincture.BLACK, tincture.Color(40, 40, 40), tincture.WHITE]),
+    ([], 2.0, "ciede2000", []),
+])
+def test_palette_dedup(colors, tolerance, metric, expected):
+    assert tincture.Palette(colors).dedup(tolerance, metric).colors == expected
+
+def test_palette_dedup_merge():
+    palette = tincture.Palette([tincture.Color(100, 100, 100), tincture.Color(102, 102, 102), tincture.BLUE])
+    assert palette.dedup(merge=True).colors == [tincture.Color(101, 101, 101), tincture.BLUE]
+
+@pytest.mark.parametrize("tolerance,metric", [
+    (-1.0, "ciede2000"),
+    (2.0, "euclid"),
+])
+def test_palette_dedup_invalid(tolerance, metric):
+    with pytest.raises(ValueError):
+        create_palette().dedup(tolerance, metric)
+
+GPL_TEXT = """GIMP Palette
+Name: Primaries
+Columns: 3
+#
+255   0   0\tBright Red
+  0 255   0
+  0   0 255\tBlue
+"""
+
+def test_palette_from_gpl():
+    palette = tincture.Palette.from_gpl(GPL_TEXT)
+    assert palette.colors == [tincture.RED, tincture.Color(0, 255, 0), tincture.BLUE]
+    assert palette.names == ["Bright Red", None, "Blue"]
+    assert palette.name == "Primaries"
+    assert palette.columns == 3
+
+def test_palette_gpl_round_trip():
+    palette = tincture.Palette.from_gpl(GPL_TEXT)
+    assert palette.to_gpl() == GPL_TEXT
+    with tempfile.TemporaryDirectory() as directory:
+        path = os.path.join(directory, "primaries.gpl")
+        assert palette.to_gpl("Other", path) is None
+        loaded = tincture.Palette.from_gpl(path)
+    assert loaded == palette
+    assert loaded.name == "Other"
+
+def test_palette_to_gpl_drops_alpha():
+    palette = tincture.Palette([tincture.Color(10, 20, 30, 40)])
+    palette.append(tincture.RED, "Red")
+    assert palette.to_gpl() == "GIMP Palette\nName: Untitled\n#\n 10  20  30\n255   0   0\tRed\n"
+
+@pytest.mark.parametrize("text", [
+    "Name: Missing header\n255 0 0\n",
+    "GIMP Palette\n255 0\n",
+    "GIMP Palette\n256 0 0\n",
+    "GIMP Palette\nColumns: many\n",
+])
+def test_palette_from_gpl_invalid(text):
+    with pytest.raises(ValueError):
+        tincture.Palette.from_gpl(text)
+
+def create_grouped_palette():
+    palette = tincture.Palette()
+    palette.append(tincture.RED, "Red", "Warm")
+    palette.append(tincture.Color(255, 128, 0), None, "Warm")
+    palette.append(tincture.BLUE, "Blue")
+    palette.append(tincture.Color(0, 128, 255), "Sky", "Cool")
+    return palette
+
+def test_palette_ase_round_trip():
+    palette = create_grouped_palette()
+    loaded = tincture.Palette.from_ase(palette.to_ase())
+    assert loaded == palette
+    assert loaded.names == ["Red", None, "Blue", "Sky"]
+    assert loaded.groups == ["Warm", "Warm", None, "Cool"]
+    with tempfile.TemporaryDirectory() as directory:
+        path = os.path.join(directory, "swatches.ase")
+        assert palette.to_ase(path) is None
+        assert tincture.Palette.from_ase(path) == palette
+
+def test_palette_from_ase_models():
+    def block(model, values):
+        data = (2).to_bytes(2, "big") + "A".encode("utf-16-be") + bytes(2) + model
+        data += b"".join(struct.pack(">f", value) for value in values) + (2).to_bytes(2, "big")
+        return (1).to_bytes(2, "big") + len(data).to_bytes(4, "big") + data
+    data = b"ASEF" + bytes([0, 1, 0, 0]) + (3).to_bytes(4, "big")
+    data += block(b"CMYK", [0.0, 1.0, 1.0, 0.0]) + block(b"Gray", [0.5]) + block(b"LAB ", [1.0, 0.0, 0.0])
+    assert tincture.Palette.from_ase(data).colors == [tincture.RED, tincture.Color(128, 128, 128), tincture.WHITE]
+
+def test_palette_swatches_lab_is_d50():
+    # Adobe's Lab is relative to D50, decoding it against D65 would tint the colors
+    expected = tincture.Color.from_lab(60.0, 40.0, -30.0, illuminant="D50")
+    assert expected != tincture.Color.from_lab(60.0, 40.0, -30.0)
+    data = (2).to_bytes(2, "big") + "A".encode("utf-16-be") + bytes(2) + b"LAB "
+    data += struct.pack(">fff", 0.6, 40.0, -30.0) + (2).to_bytes(2, "big")
+    data = b"ASEF" + bytes([0, 1, 0, 0]) + (1).to_bytes(4, "big") + (1).to_bytes(2, "big") + len(data).to_bytes(4, "big") + data
+    assert tincture.Palette.from_ase(data).colors == [expected]
+    data = bytes([0, 1, 0, 1, 0, 7]) + struct.pack(">HhhH", 6000, 4000, -3000, 0)
+    assert tincture.Palette.from_aco(data).colors == [expected]
+
+def test_palette_aco_round_trip():
+    palette = create_grouped_palette()
+    data = palette.to_aco()
+    assert data[:4] == bytes([0, 1, 0, 4])
+    loaded = tincture.Palette.from_aco(data)
+    assert loaded == palette
+    assert loaded.names == ["Red", None, "Blue", "Sky"]
+    assert loaded.groups == [None] * 4
+
+def test_palette_swatches_too_large():
+    palette = tincture.Palette()
+    palette.append(tincture.RED, "x" * 65534, "y" * 65534)
+    assert tincture.Palette.from_ase(palette.to_ase()).names == ["x" * 65534]
+    palette.append(tincture.BLUE, "x" * 65535)
+    with pytest.raises(ValueError):
+        palette.to_ase()
+    palette = tincture.Palette()
+    palette.append(tincture.RED, None, "y" * 65535)
+    with pytest.raises(ValueError):
+        palette.to_ase()
+    assert len(tincture.Palette.from_aco(tincture.Palette([tincture.RED] * 65535).to_aco())) == 65535
+    with pytest.raises(ValueError):
+        tincture.Palette([tincture.RED] * 65536).to_aco()
+
+def test_palette_from_aco_version_1():
+    data = bytes([0, 1, 0, 2])
+    data += bytes([0, 0, 255, 255, 0, 0, 0, 0, 0, 0])
+    data += bytes([0, 2, 255, 255, 255, 255, 255, 255, 0, 0])
+    palette = tincture.Palette.from_aco(data)
+    assert palette.colors == [tincture.RED, tincture.BLACK]
+    assert palette.names == [None, None]
+
+@pytest.mark.parametrize("data", [
+    b"ASEX",
+    b"ASEF" + bytes([0, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0]),
+])
+def test_palette_from_ase_invalid(data):
+    with pytest.raises(ValueError):
+        tincture.Palette.from_ase(data)
+
+@pytest.mark.parametrize("data", [
+    bytes([0, 3, 0, 0]),
+    bytes([0, 1, 0, 1, 0, 0]),
+    bytes([0, 1, 0, 1, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0]),
+])
+def test_palette_from_aco_invalid(data):
+    with pytest.raises(ValueError):
+        tincture.Palette.from_aco(data)
+
+def test_palette_json_round_trip():
+    palette = create_grouped_palette()
+    palette.name = "Mixed"
+    palette.columns = 2
+    palette.append(tincture.Color(0, 0, 0, 128))
+    loaded = tincture.Palette.from_json(palette.to_json(indent=2))
+    assert loaded == palette
+    assert loaded.names == palette.names
+    assert loaded.groups == palette.groups
+    assert (loaded.name, loaded.columns) == ("Mixed", 2)
+
+def test_palette_text_round_trip():
+    palette = create_grouped_palette()
+    palette.name = "Mixed colors"
+    palette.columns = 2
+    palette.append(tincture.Color(0, 0, 0, 128), "Half black")
+    assert palette.to_text() == (
+        "# tincture palette\n"
+        "name Mixed colors\n"
+        "columns 2\n"
+        "group Warm\n"
+        "0 #ff0000ff Red\n"
+        "1 #ff8000ff\n"
+        "group\n"
+        "2 #0000ffff Blue\n"
+        "group Cool\n"
+        "3 #0080ffff Sky\n"
+        "group\n"
+        "4 #00000080 Half black\n"
+    )
+    loaded = tincture.Palette.from_text(palette.to_text())
+    assert loaded == palette
+    assert loaded.names == palette.names
+    assert loaded.groups == palette.groups
+    assert (loaded.name, loaded.columns) == ("Mixed colors", 2)
+
+def test_palette_from_text_order():
+    loaded = tincture.Palette.from_text("\n# comment\n10 #0000ff Blue\ngroup Reds\n2 #ff0000\n")
+    assert loaded == tincture.Palette([tincture.RED, tincture.BLUE])
+    assert loaded.names == [None, "Blue"]
+    assert loaded.groups == ["Reds", None]
+    assert tincture.Palette.from_text("") == tincture.Palette()
+
+@pytest.mark.parametrize("text", [
+    "0 #ff0000\n0 #0000ff",
+    "0 #ff00",
+    "0",
+    "red #ff0000",
+    "-1 #ff0000",
+    "columns two\n0 #ff0000",
+    "name",
+])
+def test_palette_from_text_invalid(text):
+    with pytest.raises(ValueError):
+        tincture.Palette.from_text(text)
+
+def test_palette_to_json():
+    palette = tincture.Palette([tincture.RED, tincture.Color(0, 0, 255, 0)])
+    palette.append(tincture.WHITE, "Paper", "Neutrals")
+    assert json.loads(palette.to_json()) == {"colors": [
+        {"color": "#ff0000"},
+        {"color": "#0000ff00"},
+        {"color": "#ffffff", "name": "Paper", "group": "Neutrals"},
+    ]}
+
+def test_palette_from_json_list():
+    assert tincture.Palette.from_json('["#ff0000", {"color": "0000ff"}]').colors == [tincture.RED, tincture.BLUE]
+
+@pytest.mark.parametrize("text", [
+    "{}",
+    '{"colors": [{"name": "Nothing"}]}',
+    '["#12345"]',
+    "[1, 2, 3]",
+])
+def test_palette_from_json_invalid(text):
+    with pytest.raises((ValueError, TypeError)):
+        tincture.Palette.from_json(text)
+
+def test_palette_hex_text():
+    palette = tincture.Palette([tincture.RED, tincture.Color(0, 0, 255, 128)])
+    assert palette.to_hex_text() == "#ff0000\n#0000ff\n"
+    assert palette.to_hex_text(True) == "#ff0000ff\n#0000ff80\n"
+    assert tincture.Palette.from_hex_text(palette.to_hex_text(True)) == palette
+
+def test_palette_from_hex_text():
+    text = "; exported palette\nFF0000\n\n// accents\n#0000ff80\n"
+    assert tincture.Palette.from_hex_text(text).colors == [tincture.RED, tincture.Color(0, 0, 255, 128)]
+    with pytest.raises(ValueError):
+        tincture.Palette.from_hex_text("#ff0000\nnot a color\n")
+
+@pytest.mark.parametrize("by,reverse,expected", [
+    ("hue", False, [tincture.BLACK, tincture.RED, tincture.WHITE, tincture.GREEN, tincture.BLUE]),
+    ("hue", True, [tincture.BLUE, tincture.GREEN, tincture.WHITE, tincture.RED, tincture.BLACK]),
+    ("luminance", False, [tincture.BLACK, tincture.BLUE, tincture.RED, tincture.GREEN, tincture.WHITE]),
+    ("saturation", False, [tincture.BLACK, tincture.WHITE, tincture.RED, tincture.GREEN, tincture.BLUE]),
+])
+def test_palette_sorted(by, reverse, expected):
+    assert create_palette().sorted(by, reverse).colors == expected
+
+def test_palette_sorted_step():
+    colors = [tincture.Color(100, 100, 0), tincture.Color(250, 250, 0), tincture.Color(250, 0, 0), tincture.Color(100, 0, 0)]
+    # Yellow falls into an odd hue band, so its lightness runs backwards
+    assert tincture.Palette(colors).sorted("step").colors == [colors[3], colors[2], colors[1], colors[0]]
+
+@pytest.mark.parametrize("by", ["hue", "luminance", "saturation", "step"])
+def test_color_sort_key_matches_palette(by):
+    palette = create_palette()
+    assert sorted(palette.colors, key=lambda color: color.sort_key(by)) == palette.sorted(by).colors
+
+def test_color_sort_key():
+    colors = [tincture.BLUE, tincture.RED, tincture.GREEN]
+    assert sorted(colors, key=tincture.Color.sort_key) == [tincture.RED, tincture.GREEN, tincture.BLUE]
+    assert tincture.RED.sort_key("luminance")[0] < tincture.GREEN.sort_key("luminance")[0]
+    with pytest.raises(ValueError):
+        tincture.RED.sort_key("brightness")
+
+def test_palette_sorted_keeps_names():
+    palette = create_grouped_palette().sorted("luminance")
+    assert palette.names == ["Blue", "Red", "Sky", None]
+    assert palette.groups == [None, "Warm", "Cool", "Warm"]
+
+def test_palette_sorted_invalid():
+    with pytest.raises(ValueError):
+        create_palette().sorted("brightness")
+
+@pytest.mark.parametrize("style", [None, "pastel", "vivid", "dark"])
+@pytest.mark.parametrize("hue_strategy", ["golden_ratio", "even", "random"])
+def test_palette_random(style, hue_strategy):
+    palette = tincture.Palette.random(12, 7, style, hue_strategy)
+    assert len(palette) == 12
+    assert palette == tincture.Palette.random(12, 7, style, hue_strategy)
+    assert len(palette.dedup(1.0)) > 1
+
+@pytest.mark.parametrize("style,minimum,maximum", [
+    ("pastel", 0.5, 1.0),
+    ("dark", 0.0, 0.1),
+])
+def test_palette_random_style(style, minimum, maximum):
+    for color in tincture.Palette.random(20, 3, style):
+        assert minimum <= color.get_luminance() <= maximum
+
+@pytest.mark.parametrize("style,hue_strategy", [
+    ("neon", "golden_ratio"),
+    (None, "shorter"),
+])
+def test_palette_random_invalid(style, hue_strategy):
+    with pytest.raises(ValueError):
+        tincture.Palette.random(4, style=style, hue_strategy=hue_strategy)
+
+def test_palette_from_pixels():
+    pixels = bytes([0, 0, 255, 255] * 2 + [255, 0, 0, 255] * 3 + [0, 0, 0, 0] + [0, 0, 255, 255])
+    assert tincture.Palette.from_pixels(pixels).colors == [tincture.BLUE, tincture.RED, tincture.Color(0, 0, 0, 0)]
+    assert tincture.Palette.from_pixels(memoryview(bytearray(pixels))) == tincture.Palette.from_pixels(pixels)
+
+@pytest.mark.parametrize("pixels,stride,sample_rate,expected", [
+    (bytes([255, 0, 0, 0, 0, 255]), 3, 1, [tincture.RED, tincture.BLUE]),
+    (bytes([255, 0, 0, 255, 9, 9, 0, 0, 255, 128, 9, 9]), 6, 1, [tincture.RED, tincture.Color(0, 0, 255, 128)]),
+    (bytes([255, 0, 0, 255, 0, 0, 255, 255] * 4), 4, 2, [tincture.RED]),
+])
+def test_palette_from_pixels_layout(pixels, stride, sample_rate, expected):
+    assert tincture.Palette.from_pixels(pixels, stride, sample_rate).colors == expected
+
+@pytest.mark.parametrize("pixels,stride,sample_rate", [
+    (bytes(6), 4, 1),
+    (bytes(4), 2, 1),
+    (bytes(4), 4, 0),
+])
+def test_palette_from_pixels_invalid(pixels, stride, sample_rate):
+    with pytest.raises(ValueError):
+        tincture.Palette.from_pixels(pixels, stride, sample_rate)
+
+def test_palette_web_safe():
+    palette = tincture.Palette.web_safe()
+    assert len(palette) == 216
+    assert palette[0] == tincture.BLACK and palette[-1] == tincture.WHITE
+    assert all(color.to_web_safe() == color for color in palette)
+    assert palette.nearest(tincture.Color(250, 5, 10)) == tincture.RED
+
+def test_palette_to_web_safe():
+    palette = create_grouped_palette().to_web_safe()
+    assert palette.colors == [tincture.RED, tincture.Color(255, 153, 0), tincture.BLUE, tincture.Color(0, 153, 255)]
+    assert palette.names == ["Red", None, "Blue", "Sky"]
+
+def test_palette_repr_html():
+    palette = create_grouped_palette()
+    palette.name = "<Mixed>"
+    html = palette._repr_html_()
+    assert "&lt;Mixed&gt; (4 colors)" in html
+    assert html.count("<span") == 4
+    assert 'title="Red #ff0000"' in html and 'title="#ff8000"' in html
+    palette.columns = 2
+    assert "repeat(2, 26px)" in palette._repr_html_()
+
+def test_palette_to_svg():
+    palette = tincture.Palette([tincture.RED, tincture.Color(0, 0, 255, 128)])
+    palette.append(tincture.WHITE, "Paper & Ink")
+    svg = palette.to_svg(300, 100)
+    assert svg.startswith('<svg xmlns="http://www.w3.org/2000/svg" width="300" height="100"')
+    assert '<rect x="100" y="0" width="100" height="100" fill="#0000ff" fill-opacity="0.5"/>' in svg
+    assert ">#0000ff80</text>" in svg and ">Paper &amp; Ink</text>" in svg
+    assert "<text" not in palette.to_svg(300, 100, labels=False)
+
+def test_palette_to_svg_columns():
+    palette = create_palette()
+    palette.columns = 2
+    svg = palette.to_svg(200, 300, False)
+    assert '<rect x="0" y="200" width="100" height="100" fill="#000000"/>' in svg
+
+@pytest.mark.parametrize("width,height", [(0, 10), (10, -1)])
+def test_palette_to_svg_invalid(width, height):
+    with pytest.raises(ValueError):
+        create_palette().to_svg(width, height)
+
+def test_palette_fingerprint():
+    palette = tincture.Palette([tincture.RED, tincture.GREEN, tincture.BLUE])
+    fingerprint = palette.fingerprint()
+    assert len(fingerprint) == 16
+    assert tincture.Palette(palette.colors, "named").fingerprint() == fingerprint
+    assert tincture.Palette([tincture.RED, tincture.BLUE, tincture.GREEN]).fingerprint() != fingerprint
+    palette[0] = tincture.Color(255, 0, 0, 254)
+    assert palette.fingerprint() != fingerprint
+    assert tincture.Palette().fingerprint() == "cbf29ce484222325"
+
+def check_pdf(data):
+    assert data.startswith(b"%PDF-1.4\n")
+    assert data.endswith(b"%%EOF\n")
+    text = data.decode("latin-1")
+    xref = int(text[text.rindex("startxref") :].split()[1])
+    assert text[xref:].startswith("xref\n")
+    entries = text[xref:].split("\n")
+    count = int(entries[1].split()[1])
+    for number, entry in enumerate(entries[3 : 2 + count], start=1):
+        assert text[int(entry.split()[0]) :].startswith(f"{number} 0 obj\n")
+    return text
+
+def test_palette_to_pdf():
+    palette = create_grouped_palette()
+    palette.name = "Brand (v2) café"
+    text = check_pdf(palette.to_pdf())
+    assert "/Count 1" in text
+    assert "(Brand \\(v2\\) caf\\351)" in text
+    assert "(Red)" in text
+    assert "(#ff0000)" in text
+    assert "(CMYK 0 100 100 0)" in text
+    assert "(Lab 54.3 80.8 69.9)" in text
+    assert "(Lab 100.0 0.0 0.0)" not in text
+    assert "q 1 0 0 rg" in text
+
+def test_palette_to_pdf_transparent_over_white():
+    text = check_pdf(tincture.Palette([tincture.Color(0, 0, 0, 0), tincture.WHITE]).to_pdf(layout="list"))
+    assert text.count("q 1 1 1 rg") == 2
+    assert "(#00000000)" in text
+    assert "(Lab 100.0 0.0 0.0)" in text
+
+@pytest.mark.parametrize("layout,count,pages", [
+    ("grid", 0, 1),
+    ("grid", 20, 1),
+    ("grid", 40, 2),
+    ("list", 14, 1),
+    ("list", 15, 2),
+    ("list", 100, 8),
+])
+def test_palette_to_pdf_pages(layout, count, pages):
+    palette = tincture.Palette([tincture.Color(index, 0, 0) for index in range(count)])
+    text = check_pdf(palette.to_pdf(layout=layout))
+    assert f"/Count {pages}" in text
+    assert text.count(" re B Q") == count
+
+def test_palette_to_pdf_path():
+    palette = create_palette()
+    with tempfile.TemporaryDirectory() as directory:
+        path = os.path.join(directory, "swatches.pdf")
+        assert palette.to_pdf(path) is None
+        with open(path, "rb") as file:
+            assert file.read() == palette.to_pdf()
+
+def test_palette_to_pdf_invalid_layout():
+    with pytest.raises(ValueError):
+        create_palette().to_pdf(layout="hexagons")
+
+@pytest.mark.parametrize("name,n,expected", [
+    ("RdYlBu", 3, ["#fc8d59", "#ffffbf", "#91bfdb"]),
+    ("rdylbu", 5, ["#d7191c", "#fdae61", "#ffffbf", "#abd9e9", "#2c7bb6"]),
+    ("Blues", 4, ["#eff3ff", "#bdd7e7", "#6baed6", "#2171b5"]),
+    ("Set1", 3, ["#e41a1c", "#377eb8", "#4daf4a"]),
+])
+def test_palette_brewer(name, n, expected):
+    palette = tincture.Palette.brewer(name, n)
+    assert [color.to_hex() for color in palette] == expected
+    assert palette.name == tincture.Palette.brewer(name).name
+
+def test_palette_brewer_sizes():
+    assert len(tincture.Palette.brewer("RdYlBu", 9)) == 9
+    assert len(tincture.Palette.brewer("RdYlBu")) == 11
+    assert len(tincture.Palette.brewer("Greys")) == 9
+    assert len(tincture.Palette.brewer("Paired")) == 12
+    assert tincture.Palette.brewer("Set3", 5) == tincture.Palette(list(tincture.brewer.Set3)[:5])
+    assert tincture.brewer.RdYlBu == tincture.Palette.brewer("RdYlBu", 11)
+    assert tincture.brewer.RdYlBu.name == "RdYlBu"
+
+def test_palette_brewer_names():
+    names = tincture.brewer.names()
+    assert len(names) == 35
+    assert all(isinstance(getattr(tincture.brewer, name), tincture.Palette) for name in names)
+    assert len(tincture.brewer.names("sequential")) == 18
+    assert "RdYlBu" in tincture.brewer.names("Diverging")
+    assert tincture.brewer.names("qualitative") == ["Accent", "Dark2", "Paired", "Pastel1", "Pastel2", "Set1", "Set2", "Set3"]
+    with pytest.raises(ValueError):
+        tincture.brewer.names("cyclic")
+
+@pytest.mark.parametrize("name,n", [("Viridis", 5), ("RdYlBu", 2), ("RdYlBu", 12), ("Blues", 10), ("Set1", 10)])
+def test_palette_brewer_invalid(name, n):
+    with pytest.raises(ValueError):
+        tincture.Palette.brewer(name, n)
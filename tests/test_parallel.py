@@ -0,0 +1,22 @@
+import pytest
+import tincture
+
+def test_num_threads():
+    tincture.set_num_threads(2)
+    assert tincture.get_num_threads() == 2
+    tincture.set_num_threads()
+    assert tincture.get_num_threads() >= 1
+    with pytest.raises(ValueError):
+        tincture.set_num_threads(0)
+
+@pytest.mark.parametrize("threads", [1, 3])
+def test_parallel_results_match(threads):
+    tincture.set_num_threads(threads)
+    colors = [tincture.Color(index % 256, (index * 7) % 256, (index * 13) % 256, 255) for index in range(40000)]
+    array = tincture.ColorArray(colors)
+    blended = array.lerp(tincture.WHITE, 0.5)
+    assert len(blended) == len(colors)
+    assert blended[12345] == colors[12345].lerp(colors[12345], tincture.WHITE, 0.5)
+    assert array.delta_e(tincture.BLACK)[39999] == array[39999:].delta_e(tincture.BLACK)[0]
+    assert tincture.batch.from_oklab(memoryview(tincture.batch.to_oklab(array)).cast("f")) == array.to_bytes()
+    tincture.set_num_threads()
@@ -0,0 +1,33 @@
+import pytest
+import tincture
+
+def test_pool_intern():
+    pool = tincture.ColorPool([tincture.RED])
+    assert pool.intern(tincture.RED) == 0
+    assert pool.intern(tincture.BLUE) == 1
+    assert pool.intern(tincture.Color(255, 0, 0)) == 0
+    assert pool.intern_many([tincture.GREEN, tincture.BLUE, tincture.GREEN]) == [2, 1, 2]
+    assert len(pool) == 3
+    assert pool.colors == [tincture.RED, tincture.BLUE, tincture.GREEN]
+    assert tincture.BLUE in pool
+    assert tincture.WHITE not in pool
+    assert pool.handle_of(tincture.GREEN) == 2
+    assert pool.handle_of(tincture.WHITE) is None
+
+def test_pool_resolve():
+    pool = tincture.ColorPool([tincture.RED, tincture.Color(1, 2, 3, 4)])
+    assert pool.get(1) == tincture.Color(1, 2, 3, 4)
+    assert pool[0] == tincture.RED
+    assert pool.resolve([1, 0, 1]) == [tincture.Color(1, 2, 3, 4), tincture.RED, tincture.Color(1, 2, 3, 4)]
+    assert pool.resolve_rgba([0, 1]) == bytes([255, 0, 0, 255, 1, 2, 3, 4])
+
+def test_pool_invalid_handle():
+    pool = tincture.ColorPool([tincture.RED])
+    with pytest.raises(IndexError):
+        pool.get(1)
+    with pytest.raises(IndexError):
+        pool.resolve_rgba([0, 5])
+    pool.clear()
+    assert len(pool) == 0
+    with pytest.raises(IndexError):
+        pool[0]
@@ -0,0 +1,139 @@
+import pytest
+import tincture
+
+def test_random_generator_is_reproducible():
+    first, second = tincture.RandomColorGenerator(42), tincture.RandomColorGenerator(42)
+    assert first.colors(16) == second.colors(16)
+    assert first.color(random_alpha=True) == second.color(random_alpha=True)
+    assert tincture.RandomColorGenerator(43).colors(16) != tincture.RandomColorGenerator(42).colors(16)
+    assert all(color.a == 255 for color in tincture.RandomColorGenerator(7).colors(32))
+    assert any(color.a != 255 for color in tincture.RandomColorGenerator(7).colors(32, True))
+
+def test_random_generator_reseed():
+    generator = tincture.RandomColorGenerator(5)
+    colors = generator.colors(4)
+    generator.reseed(5)
+    assert generator.colors(4) == colors
+    assert generator.seed == 5
+    generator.reseed()
+    assert generator.seed is None
+    assert repr(generator) == "RandomColorGenerator()"
+    assert repr(tincture.RandomColorGenerator(9)) == "RandomColorGenerator(seed=9)"
+
+def test_random_generator_randomise():
+    color = tincture.Color(66, 135, 245, 10)
+    first, second = tincture.RandomColorGenerator(1), tincture.RandomColorGenerator(1)
+    start, end = [100, None, 0, None], [120, None, 10, None]
+    for _ in range(16):
+        randomised = first.randomise(color, start, end)
+        assert randomised == second.randomise(color, start, end)
+        assert 100 <= randomised.r < 120 and 0 <= randomised.b < 10
+        assert (randomised.g, randomised.a) == (135, 10)
+    with pytest.raises(IndexError):
+        first.randomise(color, [10, None, None, None], [5, None, None, None])
+    with pytest.raises(ValueError):
+        first.randomise(color, [10, None, None, None], [None, None, None, None])
+
+def test_color_randomise_seed():
+    color = tincture.Color(66, 135, 245)
+    assert color.randomise(seed=3) == color.randomise(seed=3)
+    assert len({color.randomise(seed=seed) for seed in range(8)}) > 1
+    assert color.randomise([None, None, None, None], [None, None, None, None], 3) == color
+
+def test_color_random_default_is_pastel():
+    colors = [tincture.Color.random(seed=seed) for seed in range(100)]
+    assert colors == [tincture.Color.random(seed=seed) for seed in range(100)]
+    for lightness, chroma, _, alpha in tincture.ColorArray(colors).to_components("oklch"):
+        assert 0.7 - 1e-3 <= lightness <= 0.9 + 1e-3
+        assert chroma <= 0.12 + 1e-3
+        assert alpha == 1.0
+
+@pytest.mark.parametrize("space,l,c,h,hue_range", [
+    ("oklch", (0.6, 0.7), (0.1, 0.12), (330.0, 30.0), [(329.0, 360.0), (0.0, 31.0)]),
+    ("oklch", (0.3, 0.5), None, (100.0, 140.0), [(99.0, 141.0)]),
+    ("hsl", (0.4, 0.6), (0.9, 1.0), (200.0, 220.0), [(199.0, 221.0)]),
+    ("hsv", (0.8, 1.0), (0.5, 1.0), (0.0, 360.0), [(0.0, 360.0)]),
+])
+def test_color_random_ranges(space, l, c, h, hue_range):
+    first, second = tincture.RandomColorGenerator(11), tincture.RandomColorGenerator(11)
+    colors = [first.random(space, l, c, h) for _ in range(100)]
+    assert colors == [second.random(space, l, c, h) for _ in range(100)]
+    measured = "oklch" if space == "oklch" else "hsl"
+    for components in tincture.ColorArray(colors).to_components(measured):
+        hue = components[2] if space == "oklch" else components[0]
+        assert any(start <= hue <= end for start, end in hue_range)
+        if space == "oklch":
+            assert l[0] - 1e-3 <= components[0] <= l[1] + 1e-3
+
+def test_color_random_lch_scale():
+    for lightness, _, _, _ in tincture.ColorArray([tincture.Color.random("lch", (50.0, 60.0), None, seed=seed) for seed in range(20)]).to_components("lch"):
+        assert 49.5 <= lightness <= 60.5
+
+@pytest.mark.parametrize("options", [
+    {"space": "oklab"},
+    {"space": "cmyk"},
+    {"l": (0.9, 0.1)},
+    {"c": (0.0, float("inf"))},
+    {"h": (float("nan"), 10.0)},
+])
+def test_color_random_invalid(options):
+    with pytest.raises(ValueError):
+        tincture.Color.random(**options)
+    with pytest.raises(ValueError):
+        tincture.RandomColorGenerator(1).random(**options)
+
+def test_color_jitter():
+    color = tincture.Color(120, 140, 160, 200)
+    assert color.jitter(seed=1) == color.jitter(seed=1)
+    assert color.jitter(0.0) == color
+    jittered = [color.jitter(10.0, seed=seed) for seed in range(1000)]
+    assert all(other.a == 200 for other in jittered)
+    mean = sum(other.r for other in jittered) / len(jittered)
+    deviation = (sum((other.r - mean) ** 2 for other in jittered) / len(jittered)) ** 0.5
+    assert mean == pytest.approx(120, abs=1.5)
+    assert deviation == pytest.approx(10, abs=1.0)
+    # Noise far beyond the channel range is clamped onto its ends
+    extremes = [tincture.Color(250, 5, 128).jitter(1e6, seed=seed) for seed in range(20)]
+    assert all(channel in (0, 255) for other in extremes for channel in (other.r, other.g, other.b))
+
+def test_color_jitter_channels():
+    color = tincture.Color(120, 140, 160, 200)
+    for seed in range(20):
+        alpha_only = color.jitter(20.0, "a", seed=seed)
+        assert (alpha_only.r, alpha_only.g, alpha_only.b) == (120, 140, 160)
+        lightness_only = color.jitter(20.0, "l", "oklch", seed)
+        original, shifted = tincture.ColorArray([color, lightness_only]).to_components("oklch")
+        assert shifted[2] == pytest.approx(original[2], abs=1.5)
+        assert lightness_only.a == 200
+    assert len({color.jitter(20.0, "a", seed=seed).a for seed in range(20)}) > 1
+    generator, other = tincture.RandomColorGenerator(5), tincture.RandomColorGenerator(5)
+    assert generator.jitter(color, space="oklch") == other.jitter(color, space="oklch")
+
+@pytest.mark.parametrize("options", [
+    {"sigma": -1.0},
+    {"sigma": float("inf")},
+    {"channels": "x"},
+    {"channels": "r", "space": "oklch"},
+    {"space": "hsl"},
+])
+def test_color_jitter_invalid(options):
+    with pytest.raises(ValueError):
+        tincture.Color(1, 2, 3).jitter(**options)
+    with pytest.raises(ValueError):
+        tincture.batch.jitter(bytes(4), **options)
+
+def test_batch_jitter():
+    # Large enough to be split between threads
+    pixels = bytes([120, 140, 160, 255]) * 20000
+    jittered = tincture.batch.jitter(pixels, 8.0, seed=4)
+    assert jittered == tincture.batch.jitter(pixels, 8.0, seed=4)
+    assert jittered != tincture.batch.jitter(pixels, 8.0, seed=5)
+    assert jittered[3::4] == pixels[3::4]
+    tincture.set_num_threads(1)
+    try:
+        assert tincture.batch.jitter(pixels, 8.0, seed=4) == jittered
+    finally:
+        tincture.set_num_threads()
+    buffer = bytearray(pixels)
+    assert tincture.batch.jitter(buffer, 8.0, None, "oklch", 4, in_place=True) is None
+    assert bytes(buffer) == tincture.batch.jitter(pixels, 8.0, space="oklch", seed=4)
@@ -0,0 +1,47 @@
+import pytest
+import tincture
+
+def test_reactive_color_callbacks():
+    color = tincture.ReactiveColor(tincture.RED)
+    changes = []
+
+    @color.on_change
+    def record(old, new):
+        changes.append((old, new))
+
+    color.g = 10
+    color["b"] = 20
+    color[3] = 255
+    color.color = tincture.BLUE
+    assert changes == [
+        (tincture.RED, tincture.Color(255, 10, 0)),
+        (tincture.Color(255, 10, 0), tincture.Color(255, 10, 20)),
+        (tincture.Color(255, 10, 20), tincture.BLUE),
+    ]
+    assert color.color == tincture.BLUE
+    assert color.b == 255 and color["r"] == 0
+
+    color.remove_on_change(record)
+    color.r = 100
+    assert len(changes) == 3
+    with pytest.raises(ValueError):
+        color.remove_on_change(record)
+
+def test_reactive_color_reentrant_callback():
+    color = tincture.ReactiveColor(tincture.BLACK)
+    color.on_change(lambda old, new: setattr(color, "a", 128))
+    color.r = 50
+    assert color.color == tincture.Color(50, 0, 0, 128)
+
+def test_reactive_color_invalid_item():
+    color = tincture.ReactiveColor(tincture.BLACK)
+    with pytest.raises(IndexError):
+        color["cyan"] = 10
+
+def test_reactive_color_slices():
+    color = tincture.ReactiveColor(tincture.Color(1, 2, 3, 4))
+    changes = []
+    color.on_change(lambda old, new: changes.append(new))
+    color[1:3] = (20, 30)
+    assert color[:] == [1, 20, 30, 4]
+    assert changes == [tincture.Color(1, 20, 30, 4)]
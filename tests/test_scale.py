@@ -0,0 +1,40 @@
+import math
+
+import pytest
+import tincture
+
+red_white_blue = tincture.Gradient.from_colors([tincture.RED, tincture.WHITE, tincture.BLUE], "srgb")
+
+@pytest.mark.parametrize("scale,value,expected", [
+    (tincture.ColorScale(red_white_blue), 0.5, tincture.WHITE),
+    (tincture.ColorScale(red_white_blue, [0, 100]), 25, tincture.Color(255, 128, 128)),
+    (tincture.ColorScale(red_white_blue, [0, 10, 100]), 5, tincture.Color(255, 128, 128)),
+    (tincture.ColorScale(red_white_blue, [0, 10, 100]), 10, tincture.WHITE),
+    (tincture.ColorScale(red_white_blue, [0, 10, 100]), 55, tincture.Color(128, 128, 255)),
+    (tincture.ColorScale(red_white_blue, [100, 0]), 0, tincture.BLUE),
+    (tincture.ColorScale(red_white_blue, [0, 100]), -50, tincture.RED),
+    (tincture.ColorScale(red_white_blue, [0, 100]), 150, tincture.BLUE),
+    (tincture.ColorScale(red_white_blue, [0, 100], clamp=False), 150, None),
+    (tincture.ColorScale(red_white_blue, [0, 100]), math.nan, None),
+    (tincture.ColorScale([tincture.BLACK, tincture.WHITE], [0, 1]), 1, tincture.WHITE),
+])
+def test_color_scale(scale, value, expected):
+    assert scale.scale(value) == expected
+    assert scale(value) == expected
+
+def test_color_scale_many():
+    scale = tincture.ColorScale(red_white_blue, [0, 100], clamp=False)
+    assert scale.scale_many([0, 50, 100, 101]) == [tincture.RED, tincture.WHITE, tincture.BLUE, None]
+    assert scale.domain == [0.0, 100.0]
+    assert not scale.clamp
+    assert scale.gradient.stops == red_white_blue.stops
+
+@pytest.mark.parametrize("domain", [
+    [],
+    [5],
+    [0, 10, 5],
+    [0, 0],
+])
+def test_color_scale_invalid(domain):
+    with pytest.raises(ValueError):
+        tincture.ColorScale(red_white_blue, domain)
@@ -0,0 +1,67 @@
+import json
+import os
+import tempfile
+
+import pytest
+import tincture
+
+
+def roundtrip(obj):
+    with tempfile.TemporaryDirectory() as directory:
+        path = os.path.join(directory, "assets.tinct")
+        tincture.dump(obj, path)
+        with open(path) as file:
+            container = json.load(file)
+        assert container["format"] == "tinct"
+        assert container["version"] == 1
+        return tincture.load(path)
+
+@pytest.mark.parametrize("obj", [
+    tincture.RED,
+    tincture.Color(1, 2, 3, 4),
+    [tincture.RED, tincture.Color(10, 20, 30, 40)],
+    [[tincture.WHITE], [tincture.BLACK, tincture.TEAL]],
+    [],
+])
+def test_tinct_roundtrip(obj):
+    assert roundtrip(obj) == obj
+
+def test_tinct_roundtrip_palette():
+    palette = tincture.Palette([tincture.RED, tincture.Color(1, 2, 3, 4)], "brand")
+    palette.append(tincture.TEAL, "accent", "ui")
+    palette.columns = 2
+    loaded = roundtrip(palette)
+    assert isinstance(loaded, tincture.Palette)
+    assert loaded == palette
+    assert (loaded.name, loaded.columns) == ("brand", 2)
+    assert loaded.names == [None, None, "accent"]
+    assert loaded.groups == [None, None, "ui"]
+
+def test_tinct_roundtrip_gradient():
+    gradient = tincture.Gradient(
+        [(0.0, tincture.RED), (0.25, tincture.Color(0, 255, 0, 128)), (1.0, tincture.BLUE)],
+        "oklch", "longer", "catmull_rom",
+    )
+    loaded = roundtrip(gradient)
+    assert isinstance(loaded, tincture.Gradient)
+    assert loaded == gradient
+
+def test_tinct_roundtrip_nested():
+    gradient = tincture.Gradient.from_colors([tincture.BLACK, tincture.WHITE], "srgb")
+    palette = tincture.Palette([tincture.RED, tincture.BLUE])
+    loaded = roundtrip([palette, [gradient, tincture.TEAL]])
+    assert loaded[0] == palette
+    assert loaded[1][0] == gradient
+    assert loaded[1][1] == tincture.TEAL
+
+def test_tinct_newer_version():
+    with tempfile.TemporaryDirectory() as directory:
+        path = os.path.join(directory, "assets.tinct")
+        with open(path, "w") as file:
+            json.dump({"format": "tinct", "version": 999, "content": {"type": "color", "data": [0, 0, 0, 0]}}, file)
+        with pytest.raises(ValueError):
+            tincture.load(path)
+
+def test_tinct_unsupported_object():
+    with pytest.raises(TypeError):
+        roundtrip("not a color")
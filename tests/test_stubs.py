@@ -0,0 +1,26 @@
+import importlib.util
+from pathlib import Path
+
+import tincture
+
+STUBGEN_PATH = Path(__file__).resolve().parent.parent / "scripts" / "stubgen.py"
+
+def load_stubgen():
+    spec = importlib.util.spec_from_file_location("stubgen", STUBGEN_PATH)
+    module = importlib.util.module_from_spec(spec)
+    spec.loader.exec_module(module)
+    return module
+
+def test_stubs_match_the_compiled_module():
+    assert load_stubgen().find_problems(tincture) == []
+
+def test_stub_skeleton():
+    stubgen = load_stubgen()
+    assert stubgen.skeleton(tincture.Color, "from_hex", tincture.Color.from_hex) == (
+        "    @staticmethod\n    def from_hex(hex_string): ...\n"
+    )
+    assert stubgen.skeleton(tincture.Color, "to_hex", tincture.Color.to_hex) == (
+        "    def to_hex(self, include_transparency=False): ...\n"
+    )
+    assert stubgen.skeleton(tincture.Color, "r", tincture.Color.r) == "    @property\n    def r(self): ...\n"
+    assert stubgen.skeleton(None, "pick", tincture.pick) == "def pick(initial=None): ...\n"